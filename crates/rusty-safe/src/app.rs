@@ -1,7 +1,6 @@
 //! Main application state and update loop
 
 use alloy::hex;
-use alloy::primitives::ChainId;
 use eframe::egui;
 use safe_hash::SafeWarnings;
 use safe_utils::{
@@ -11,7 +10,8 @@ use safe_utils::{
 use std::sync::{Arc, Mutex};
 
 use crate::api::SafeTransaction;
-use crate::decode::{self, ComparisonResult, SignatureLookup, TransactionKind};
+use crate::counterparty::{self, CounterpartyHistory};
+use crate::decode::{self, ComparisonResult, ContractLookup, SignatureLookup, TransactionKind};
 
 /// Log to console (works in both WASM and native)
 macro_rules! debug_log {
@@ -41,20 +41,36 @@ macro_rules! lock_or_recover {
     };
 }
 use crate::expected;
+use crate::export;
 use crate::hasher::{
-    compute_hashes_from_api_tx, fetch_transactions, get_warnings_for_tx, get_warnings_from_api_tx,
+    audit_transaction_export, chain_id_of, check_nonce_position, check_nonce_range,
+    check_owner_threshold, diagnose_hash_mismatch, domain_components, estimate_refund,
+    fetch_transaction_range, fetch_transactions, fetch_transactions_for_hash, get_warnings_for_tx,
+    is_contract_signature, parse_confirmations, parse_origin, parse_safe_share_url,
+    parse_transaction_json, recover_confirmation_signers, safe_api_url, safe_message_hash,
+    set_max_retries, set_service_overrides, validate_confirmations, validate_eip712_domain,
+    validate_eip712_types, ConfigChange, ConfigChangeKind, ConfirmationIssue, ConfirmationKind,
+    DomainMismatch, FetchedRange, FetchedSafeMessage, FetchedTransactions, HashingInputs,
+    NonceStatus, OwnerManagement, RefundEstimate, SafeInfo, SafeMessageBody, MAX_NONCE_RANGE,
 };
+use crate::roster;
+use crate::shortcuts;
 use crate::sidebar;
 use crate::state::{
-    get_chain_name, AddressValidation, Eip712State, MsgVerifyState, OfflineState, SafeContext,
-    SidebarState, TxVerifyState, SAFE_VERSIONS,
+    get_chain_name, AddressValidation, AuditLogEntry, AuditState, ChainScan, ComputedHashes,
+    Eip712State, MsgVerifyState, OfflineState, RiskSummary, SafeContext, SidebarState, TxFindings,
+    TxVerifyState, WarningKind, SAFE_VERSIONS,
 };
+use crate::trust;
 use crate::ui;
+use crate::verify;
+use crate::warnings;
 
 /// Result from async fetch operation
 #[derive(Clone)]
 pub enum FetchResult {
-    Success(Vec<SafeTransaction>),
+    Success(FetchedTransactions),
+    Range(FetchedRange),
     Error(String),
 }
 
@@ -68,6 +84,19 @@ pub enum DecodeResult {
     MultiSendBulk {
         multi: decode::MultiSendDecode,
     },
+    UserOpBulk {
+        bundle: decode::UserOpBundle,
+    },
+}
+
+/// Result from async explorer verified-ABI decode, with the chain, target
+/// and calldata it decoded so a stale result is dropped
+#[derive(Clone)]
+pub struct ExplorerResult {
+    chain_name: String,
+    to: String,
+    data: String,
+    decode: Result<decode::LocalDecode, String>,
 }
 
 /// Result from async Safe info fetch
@@ -84,6 +113,54 @@ pub enum OfflineDecodeResult {
     Error(String),
 }
 
+/// Concurrent requests for the multi-chain Safe lookup
+const MAX_CONCURRENT_CHAIN_LOOKUPS: usize = 3;
+
+/// Result from async counterparty history fetch
+#[derive(Clone)]
+pub enum HistoryResult {
+    Success(CounterpartyHistory),
+    Error(String),
+}
+
+/// Result from async Safe message fetch
+#[derive(Clone)]
+pub enum SafeMessageResult {
+    Success(FetchedSafeMessage),
+    Error(String),
+}
+
+/// Result from async Safe version detection
+#[derive(Clone)]
+pub enum VersionResult {
+    Detected(String),
+    Error(String),
+}
+
+/// Result from async nonce position check, for the nonce it was run on
+#[derive(Clone)]
+pub enum NonceCheckResult {
+    Success(u64, NonceStatus),
+    Error(u64, String),
+}
+
+/// Outstanding async tasks per kind. A count goes up when a task is
+/// dispatched and back down when its result is consumed.
+#[derive(Debug, Default)]
+struct InFlight {
+    fetch: usize,
+    decode: usize,
+    /// A large MultiSend is still being parsed across frames
+    parsing: bool,
+}
+
+impl InFlight {
+    /// Whether any Verify-tab work is still outstanding
+    fn verifying(&self) -> bool {
+        self.fetch > 0 || self.decode > 0 || self.parsing
+    }
+}
+
 /// The main application state
 pub struct App {
     /// Current active tab
@@ -100,22 +177,49 @@ pub struct App {
     eip712_state: Eip712State,
     /// Offline verification state
     offline_state: OfflineState,
+    /// Bulk audit state
+    audit_state: AuditState,
     /// Cached chain names from safe_utils
     chain_names: Vec<String>,
     /// Async fetch result receiver
     fetch_result: Arc<Mutex<Option<FetchResult>>>,
     /// Signature lookup client (with cache)
     signature_lookup: SignatureLookup,
+    /// Sourcify contract names for `to` addresses
+    contract_lookup: ContractLookup,
     /// Async decode result receiver
     decode_result: Arc<Mutex<Option<DecodeResult>>>,
+    /// Async explorer verified-ABI decode result receiver
+    explorer_result: Arc<Mutex<Option<ExplorerResult>>>,
     /// Async Safe info fetch result receiver
     safe_info_result: Arc<Mutex<Option<SafeInfoResult>>>,
     /// Async offline decode result receiver
     offline_decode_result: Arc<Mutex<Option<OfflineDecodeResult>>>,
+    /// Async counterparty history result receiver
+    history_result: Arc<Mutex<Option<HistoryResult>>>,
+    /// Async Safe message fetch result receiver
+    message_result: Arc<Mutex<Option<SafeMessageResult>>>,
+    /// Async Safe version detection result receiver
+    version_result: Arc<Mutex<Option<VersionResult>>>,
+    /// Async nonce position check result receiver
+    nonce_check_result: Arc<Mutex<Option<NonceCheckResult>>>,
+    /// Multi-chain Safe lookup, filled in by the lookup tasks
+    chain_scan: Arc<Mutex<ChainScan>>,
     /// Fetched Safe info
     safe_info: Option<crate::hasher::SafeInfo>,
     /// Whether Safe info fetch is in progress
     safe_info_loading: bool,
+    /// Outstanding verification tasks, used to block overlapping runs
+    in_flight: InFlight,
+    /// Ctrl+K command palette
+    palette: shortcuts::Palette,
+    /// Outcome of the last audit log export
+    history_export: Option<Result<String, String>>,
+    /// "Clear audit log" was clicked and awaits confirmation
+    history_confirm_clear: bool,
+    /// Shared tokio runtime for native async work (creation error if it failed)
+    #[cfg(not(target_arch = "wasm32"))]
+    runtime: Result<tokio::runtime::Runtime, String>,
     /// Address book UI state
     address_book_open: bool,
     address_book_import_text: String,
@@ -124,6 +228,7 @@ pub struct App {
     address_book_add_name: String,
     address_book_add_addr: String,
     address_book_add_chain: String,
+    address_book_add_decimals: String,
 }
 
 /// Available tabs in the application
@@ -134,6 +239,8 @@ pub enum Tab {
     Message,
     Eip712,
     Offline,
+    Audit,
+    History,
 }
 
 impl App {
@@ -159,14 +266,31 @@ impl App {
             msg_state: MsgVerifyState::default(),
             eip712_state: Eip712State::default(),
             offline_state: OfflineState::default(),
+            audit_state: AuditState::default(),
             chain_names: get_all_supported_chain_names(),
             fetch_result: Arc::new(Mutex::new(None)),
             signature_lookup: SignatureLookup::load(cc.storage),
+            contract_lookup: ContractLookup::load(cc.storage),
             decode_result: Arc::new(Mutex::new(None)),
+            explorer_result: Arc::new(Mutex::new(None)),
             safe_info_result: Arc::new(Mutex::new(None)),
             offline_decode_result: Arc::new(Mutex::new(None)),
+            history_result: Arc::new(Mutex::new(None)),
+            message_result: Arc::new(Mutex::new(None)),
+            version_result: Arc::new(Mutex::new(None)),
+            nonce_check_result: Arc::new(Mutex::new(None)),
+            chain_scan: Arc::new(Mutex::new(ChainScan::default())),
             safe_info: None,
             safe_info_loading: false,
+            in_flight: InFlight::default(),
+            history_export: None,
+            history_confirm_clear: false,
+            palette: shortcuts::Palette::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            runtime: tokio::runtime::Builder::new_multi_thread()
+                .enable_all()
+                .build()
+                .map_err(|e| format!("Failed to start async runtime: {}", e)),
             address_book_open: false,
             address_book_import_text: String::new(),
             address_book_error: None,
@@ -174,6 +298,7 @@ impl App {
             address_book_add_name: String::new(),
             address_book_add_addr: String::new(),
             address_book_add_chain: "ethereum".to_string(),
+            address_book_add_decimals: String::new(),
         }
     }
 }
@@ -183,6 +308,7 @@ impl eframe::App for App {
     fn save(&mut self, storage: &mut dyn eframe::Storage) {
         self.safe_context.save(storage);
         self.signature_lookup.save(storage);
+        self.contract_lookup.save(storage);
     }
 
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
@@ -193,13 +319,40 @@ impl eframe::App for App {
 
         // Check for async decode results
         self.check_decode_result();
+        self.check_explorer_result();
+
+        // Continue parsing a large MultiSend
+        self.advance_multisend_parse(ctx);
+
+        // Record the verification once its decode has settled
+        self.record_verification();
 
         // Check for async Safe info results
-        self.check_safe_info_result();
+        self.check_safe_info_result(ctx);
 
         // Check for async offline decode results
         self.check_offline_decode_result();
 
+        // Check for async counterparty history results
+        self.check_history_result();
+
+        // Check for async Safe message results
+        self.check_message_result();
+
+        // Check for async version detection results
+        self.check_version_result();
+
+        // Check for async nonce position results
+        self.check_nonce_check_result();
+
+        // Keyboard shortcuts and the command palette
+        if let Some(action) = shortcuts::read(ctx, self.active_tab) {
+            self.run_action(ctx, action, false);
+        }
+        if let Some(action) = self.palette.show(ctx) {
+            self.run_action(ctx, action, true);
+        }
+
         // Header with tabs
         egui::TopBottomPanel::top("header").show(ctx, |ui| {
             ui.add_space(8.0);
@@ -221,6 +374,8 @@ impl eframe::App for App {
                 ui.selectable_value(&mut self.active_tab, Tab::Message, "💬 Message");
                 ui.selectable_value(&mut self.active_tab, Tab::Eip712, "🔢 EIP-712");
                 ui.selectable_value(&mut self.active_tab, Tab::Offline, "📴 Offline");
+                ui.selectable_value(&mut self.active_tab, Tab::Audit, "📚 Bulk Audit");
+                ui.selectable_value(&mut self.active_tab, Tab::History, "🕘 History");
 
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                     if ui.button("📖 Address Book").clicked() {
@@ -234,7 +389,13 @@ impl eframe::App for App {
         // Address Book Window
         self.render_address_book_window(ctx);
 
+        // Copy confirmation and clipboard auto-clear
+        ui::render_clipboard_guard(ctx, &self.safe_context.clipboard);
+        set_max_retries(self.safe_context.api.max_retries);
+        set_service_overrides(&self.safe_context.service_overrides);
+
         // Sidebar with Safe context
+        let chain_scan = lock_or_recover!(self.chain_scan).clone();
         let sidebar_action = sidebar::render(
             ctx,
             &mut self.sidebar_state,
@@ -242,6 +403,7 @@ impl eframe::App for App {
             &self.safe_info,
             self.safe_info_loading,
             &self.chain_names,
+            &chain_scan,
         );
 
         // Handle sidebar actions
@@ -249,9 +411,16 @@ impl eframe::App for App {
             sidebar::SidebarAction::FetchDetails => {
                 self.trigger_safe_info_fetch();
             }
+            sidebar::SidebarAction::DetectVersion => {
+                self.trigger_version_detect(ctx);
+            }
+            sidebar::SidebarAction::ScanChains => {
+                self.trigger_chain_scan(ctx);
+            }
             sidebar::SidebarAction::ClearStorage => {
                 self.safe_context.clear();
                 self.signature_lookup = SignatureLookup::new();
+                self.contract_lookup = ContractLookup::new();
             }
             sidebar::SidebarAction::None => {}
         }
@@ -262,9 +431,11 @@ impl eframe::App for App {
                 ui.add_space(10.0);
                 match self.active_tab {
                     Tab::VerifySafeApi => self.render_verify_safe_api_tab(ui, ctx),
-                    Tab::Message => self.render_message_tab(ui),
+                    Tab::Message => self.render_message_tab(ui, ctx),
                     Tab::Eip712 => self.render_eip712_tab(ui),
                     Tab::Offline => self.render_offline_tab(ui, ctx),
+                    Tab::Audit => self.render_audit_tab(ui),
+                    Tab::History => self.render_history_tab(ui),
                 }
                 ui.add_space(20.0);
             });
@@ -285,6 +456,54 @@ impl App {
         ui.label(egui::RichText::new("Select Transaction").strong().size(13.0));
         ui.add_space(6.0);
 
+        ui.horizontal(|ui| {
+            ui.label("Share link:");
+            ui.add(
+                egui::TextEdit::singleline(&mut self.tx_state.share_link_input)
+                    .hint_text("https://app.safe.global/transactions/tx?safe=eth:0x...")
+                    .desired_width(360.0),
+            );
+            let can_open = !self.tx_state.share_link_input.trim().is_empty()
+                && !self.tx_state.is_loading
+                && !self.in_flight.verifying();
+            if ui
+                .add_enabled(can_open, egui::Button::new("🔗 Open"))
+                .on_hover_text(
+                    "Set chain and Safe from a Safe{Wallet} link and fetch its transaction",
+                )
+                .clicked()
+            {
+                self.open_share_link();
+            }
+        });
+
+        egui::CollapsingHeader::new("📄 Paste tx JSON")
+            .id_salt("verify_paste_tx_json")
+            .show(ui, |ui| {
+                ui.label(
+                    egui::RichText::new(
+                        "A single transaction as returned by the Transaction Service, \
+                         verified without fetching",
+                    )
+                    .small(),
+                );
+                ui::multiline_input(
+                    ui,
+                    &mut self.tx_state.tx_json_input,
+                    "{\"safe\": \"0x...\", \"to\": \"0x...\", \"nonce\": 42, ...}",
+                    6,
+                );
+                let can_verify = !self.tx_state.tx_json_input.trim().is_empty()
+                    && !self.tx_state.is_loading
+                    && !self.in_flight.verifying();
+                if ui
+                    .add_enabled(can_verify, egui::Button::new("🔍 Verify JSON"))
+                    .clicked()
+                {
+                    self.verify_pasted_tx(ctx);
+                }
+            });
+
         ui.horizontal(|ui| {
             ui.label("Nonce:");
 
@@ -294,20 +513,14 @@ impl App {
                 .on_hover_text("Previous nonce")
                 .clicked()
             {
-                if let Ok(n) = self.tx_state.nonce.parse::<u64>() {
-                    if n > 0 {
-                        self.tx_state.nonce = (n - 1).to_string();
-                    }
-                }
+                self.step_nonce(false);
             }
 
             ui::number_input(ui, &mut self.tx_state.nonce, "e.g., 42");
 
             // Increment button
             if ui.small_button("▶").on_hover_text("Next nonce").clicked() {
-                if let Ok(n) = self.tx_state.nonce.parse::<u64>() {
-                    self.tx_state.nonce = (n + 1).to_string();
-                }
+                self.step_nonce(true);
             }
 
             // Show latest nonce info and pending count
@@ -339,31 +552,103 @@ impl App {
             }
         });
 
+        ui.horizontal(|ui| {
+            ui.label("Through:");
+            ui::number_input(ui, &mut self.tx_state.range_end, "optional");
+            ui.label(
+                egui::RichText::new(format!(
+                    "Fetch range pulls every nonce from Nonce through this one (up to {})",
+                    MAX_NONCE_RANGE
+                ))
+                .small()
+                .weak(),
+            );
+        });
+
         // Expected values section
         ui.add_space(10.0);
+        let expected_hash = self.tx_state.expected.safe_tx_hash.clone();
         expected::render_section(ui, &mut self.tx_state.expected);
+        // Check a hash pasted after the fetch without refetching
+        if self.tx_state.expected.safe_tx_hash != expected_hash {
+            if let Some(tx) = self.tx_state.fetched_tx.clone() {
+                self.tx_state.expected.hash_result = self.check_expected_hash(&tx);
+            }
+        }
+        ui.add_space(5.0);
+        if roster::render_section(ui, &mut self.tx_state.roster) {
+            self.tx_state
+                .findings
+                .reconcile(&self.tx_state.roster.entries);
+        }
 
         ui.add_space(15.0);
 
         ui.horizontal(|ui| {
-            let can_compute = !self.safe_context.safe_address.is_empty()
-                && !self.tx_state.nonce.is_empty()
-                && !self.tx_state.is_loading;
+            let can_compute = self.can_fetch();
 
-            if ui::primary_button_enabled(ui, "🔍 Fetch & Verify", can_compute).clicked() {
+            if ui::primary_button_enabled(ui, "🔍 Fetch & Verify", can_compute)
+                .on_hover_text("Enter")
+                .on_disabled_hover_text("Wait for the current verification to finish")
+                .clicked()
+            {
                 self.fetch_and_verify(ctx);
             }
 
             ui.add_space(8.0);
 
+            if ui
+                .add_enabled(
+                    can_compute && !self.tx_state.range_end.trim().is_empty(),
+                    egui::Button::new("📚 Fetch range"),
+                )
+                .on_disabled_hover_text("Set the last nonce of the range in \"Through\"")
+                .clicked()
+            {
+                self.fetch_range(ctx);
+            }
+
+            ui.add_space(8.0);
+
             if ui::secondary_button(ui, "🗑 Clear").clicked() {
                 self.tx_state.clear_results();
             }
+
+            ui.add_space(8.0);
+
+            ui.add_enabled(
+                !verifying,
+                egui::Checkbox::new(&mut self.tx_state.trustless, "🛡 Trustless mode"),
+            )
+            .on_hover_text(
+                "Ignore the Safe service's decode and reported hash. Everything is \
+                 recomputed from the raw transaction fields; the service's claims are \
+                 only shown for comparison. Applies to the next fetch.",
+            );
+
+            let history_toggle = ui
+                .checkbox(
+                    &mut self.tx_state.check_counterparties,
+                    "👤 Flag new counterparties",
+                )
+                .on_hover_text(
+                    "Fetch this Safe's executed transaction history and flag transfers or \
+                     approvals to addresses it has never paid or approved before",
+                );
+            if history_toggle.changed() && self.tx_state.check_counterparties {
+                self.trigger_history_fetch(ctx);
+            }
         });
 
         if self.tx_state.is_loading {
             ui.add_space(10.0);
             ui::loading_spinner(ui);
+        } else if self.in_flight.verifying() {
+            ui.add_space(10.0);
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label("Verification in progress...");
+            });
         }
 
         if let Some(error) = &self.tx_state.error {
@@ -371,13 +656,69 @@ impl App {
             ui::error_message(ui, error);
         }
 
-        if self.tx_state.fetched_txs.len() > 1 {
+        if self.tx_state.check_counterparties {
+            if self.tx_state.counterparty_loading {
+                ui.horizontal(|ui| {
+                    ui.spinner();
+                    ui.label("Loading transaction history...");
+                });
+            } else if let Some(error) = &self.tx_state.counterparty_error {
+                ui::error_message(ui, &format!("History fetch failed: {}", error));
+            } else if let Some(history) = &self.tx_state.counterparty_history {
+                ui.label(
+                    egui::RichText::new(format!(
+                        "Counterparties from {} executed transactions ({} addresses)",
+                        history.scanned,
+                        history.known.len()
+                    ))
+                    .small()
+                    .weak(),
+                );
+            }
+        }
+
+        if !self.tx_state.range_errors.is_empty() {
             ui.add_space(10.0);
-            ui::section_header(ui, &format!("Select Transaction for Nonce: {}", self.tx_state.nonce));
-            ui::warning_banner(
-                ui,
-                "Multiple transactions found for this nonce. Safe API keeps all proposals with the same nonce (replacements/cancellations). Select one to verify.",
+            ui.collapsing(
+                format!(
+                    "⚠️ {} nonce(s) in the range could not be fetched",
+                    self.tx_state.range_errors.len()
+                ),
+                |ui| {
+                    for (nonce, error) in &self.tx_state.range_errors {
+                        ui.label(
+                            egui::RichText::new(format!("Nonce {}: {}", nonce, error))
+                                .color(egui::Color32::from_rgb(220, 180, 50)),
+                        );
+                    }
+                },
             );
+        }
+
+        if self.tx_state.fetched_txs.len() > 1 {
+            ui.add_space(10.0);
+            let range = self.tx_state.fetched_range;
+            match range {
+                Some((start, end)) => ui::section_header(
+                    ui,
+                    &format!("Select Transaction for Nonces: {}–{}", start, end),
+                ),
+                None => ui::section_header(
+                    ui,
+                    &format!("Select Transaction for Nonce: {}", self.tx_state.nonce),
+                ),
+            }
+            let replaced = self
+                .tx_state
+                .fetched_txs
+                .windows(2)
+                .any(|pair| pair[0].nonce == pair[1].nonce);
+            if replaced {
+                ui::warning_banner(
+                    ui,
+                    "Multiple transactions found for the same nonce. Safe API keeps all proposals with the same nonce (replacements/cancellations). Select one to verify.",
+                );
+            }
 
             let mut selected_index = self.tx_state.selected_tx_index.unwrap_or(0);
             if selected_index >= self.tx_state.fetched_txs.len() {
@@ -391,7 +732,10 @@ impl App {
                 .max_height(120.0)
                 .show(ui, |ui| {
                     for (idx, tx) in self.tx_state.fetched_txs.iter().enumerate() {
-                        let label = self.format_tx_label(idx, tx, false, show_submission_date);
+                        let mut label = self.format_tx_label(idx, tx, false, show_submission_date);
+                        if range.is_some() {
+                            label = format!("Nonce {} · {}", tx.nonce, label);
+                        }
                         let selected = selected_index == idx;
                         let indicator = if selected { "●" } else { "○" };
 
@@ -433,7 +777,9 @@ impl App {
                     }
                 });
 
-            if selection_changed {
+            // Switching transactions mid-verification would let the old result
+            // land on the new one
+            if selection_changed && !self.in_flight.verifying() {
                 self.tx_state.selected_tx_index = Some(selected_index);
                 if let Some(tx) = self.tx_state.fetched_txs.get(selected_index).cloned() {
                     self.apply_fetched_tx(ctx, tx);
@@ -441,6 +787,12 @@ impl App {
             }
         }
 
+        let risk = self.verify_risk();
+        if let Some(risk) = &risk {
+            ui.add_space(15.0);
+            ui::risk_banner(ui, risk);
+        }
+
         if let Some(tx) = &self.tx_state.fetched_tx {
             ui.add_space(15.0);
             ui::section_header(ui, "Transaction Details");
@@ -480,10 +832,12 @@ impl App {
 
                     ui.label("To:");
                     let to_str = format!("{}", tx.to);
-                    let chain_id =
-                        alloy::primitives::ChainId::of(&self.safe_context.chain_name).unwrap_or(1);
-                    let name = self.safe_context.address_book.get_name(&to_str, chain_id);
-                    ui::address_link(ui, &self.safe_context.chain_name, &to_str, name);
+                    let chain_id = self.safe_context.chain_id();
+                    let name = self.safe_context.name_of(&to_str);
+                    ui.horizontal(|ui| {
+                        ui::address_link(ui, &self.safe_context.chain_name, &to_str, name);
+                        decode::render_contract_name(ui, &self.contract_lookup, chain_id, &to_str);
+                    });
                     if ui.small_button("📋").on_hover_text("Copy").clicked() {
                         ui::copy_to_clipboard(&to_str);
                     }
@@ -495,23 +849,114 @@ impl App {
                     ui.end_row();
 
                     ui.label("Operation:");
-                    ui.label(if tx.operation == 0 {
-                        "Call (0)"
-                    } else {
-                        "DelegateCall (1)"
-                    });
+                    ui.label(ui::operation_text(tx.operation.into()));
                     ui.label(""); // Empty for alignment
                     ui.end_row();
 
+                    let confirmations = self
+                        .tx_state
+                        .raw_responses
+                        .get(&tx.safe_tx_hash.to_lowercase())
+                        .map(parse_confirmations)
+                        .unwrap_or_default();
+                    let on_chain = confirmations
+                        .iter()
+                        .filter(|c| matches!(c.kind, ConfirmationKind::ApprovedHash { .. }))
+                        .count();
+                    // Signers recovered against our own hash, not the service's
+                    let recovered = self
+                        .tx_state
+                        .hashes
+                        .as_ref()
+                        .and_then(|h| h.safe_tx_hash.parse::<alloy::primitives::B256>().ok())
+                        .map(|hash| recover_confirmation_signers(tx, hash))
+                        .unwrap_or_default();
+
                     ui.label("Confirmations:");
-                    ui.label(format!(
-                        "{} / {}",
-                        tx.confirmations.len(),
-                        tx.confirmations_required
-                    ));
+                    if on_chain > 0 {
+                        ui.label(format!(
+                            "{} / {} ({} on-chain approval{})",
+                            tx.confirmations.len(),
+                            tx.confirmations_required,
+                            on_chain,
+                            if on_chain == 1 { "" } else { "s" }
+                        ));
+                    } else {
+                        ui.label(format!(
+                            "{} / {}",
+                            tx.confirmations.len(),
+                            tx.confirmations_required
+                        ));
+                    }
                     ui.label(""); // Empty for alignment
                     ui.end_row();
 
+                    if !confirmations.is_empty() {
+                        ui.label("Confirmed by:");
+                        ui.vertical(|ui| {
+                            for confirmation in &confirmations {
+                                ui.horizontal(|ui| {
+                                    let owner = confirmation.owner.to_string();
+                                    let name = self.safe_context.name_of(&owner);
+                                    ui::address_link(
+                                        ui,
+                                        &self.safe_context.chain_name,
+                                        &owner,
+                                        name,
+                                    );
+                                    match &confirmation.kind {
+                                        ConfirmationKind::OffChain(sig_type) => {
+                                            ui.label(
+                                                egui::RichText::new(format!(
+                                                    "off-chain signature {}",
+                                                    sig_type
+                                                ))
+                                                .weak(),
+                                            );
+                                            self.render_recovered_signer(
+                                                ui,
+                                                tx,
+                                                &confirmation.owner,
+                                                &recovered,
+                                            );
+                                        }
+                                        ConfirmationKind::ApprovedHash { owner_matches: true } => {
+                                            ui.label(
+                                                egui::RichText::new("on-chain approveHash")
+                                                    .color(egui::Color32::from_rgb(120, 170, 230)),
+                                            )
+                                            .on_hover_text(
+                                                "Pre-approved on-chain; counts toward the threshold \
+                                                 without an off-chain signature",
+                                            );
+                                            if let Some(hash) = &confirmation.transaction_hash {
+                                                let link = ui
+                                                    .link("↗ approval tx")
+                                                    .on_hover_text(hash.as_str());
+                                                if link.clicked() {
+                                                    ui::open_url_new_tab(&ui::get_explorer_tx_url(
+                                                        &self.safe_context.chain_name,
+                                                        hash,
+                                                    ));
+                                                }
+                                            }
+                                        }
+                                        ConfirmationKind::ApprovedHash { owner_matches: false } => {
+                                            ui.label(
+                                                egui::RichText::new(
+                                                    "on-chain approveHash (signature does not name this owner)",
+                                                )
+                                                .color(egui::Color32::from_rgb(220, 80, 80)),
+                                            );
+                                        }
+                                    }
+                                });
+                            }
+                        });
+                        ui.label(""); // Empty for alignment
+                        ui.end_row();
+                    }
+
                     if let Some(execution_date) = &tx.execution_date {
                         ui.label("Executed:");
                         ui.label(Self::format_datetime(execution_date));
@@ -521,16 +966,106 @@ impl App {
 
                     if let Some(tx_hash) = &tx.transaction_hash {
                         ui.label("Transaction Hash:");
-                        ui.label(egui::RichText::new(tx_hash).monospace().size(11.0));
-                        if ui.small_button("📋").on_hover_text("Copy").clicked() {
-                            ui::copy_to_clipboard(tx_hash);
+                        ui::tx_hash_link(ui, &self.safe_context.chain_name, tx_hash);
+                        ui.label(""); // Empty for alignment
+                        ui.end_row();
+                    }
+
+                    ui.label("Proposer:");
+                    match self.tx_proposal(tx).and_then(|p| p.proposer) {
+                        Some(proposer) => {
+                            ui.horizontal(|ui| {
+                                let proposer_str = proposer.to_string();
+                                let name = self.safe_context.name_of(&proposer_str);
+                                ui::address_link(
+                                    ui,
+                                    &self.safe_context.chain_name,
+                                    &proposer_str,
+                                    name,
+                                );
+                                match self.is_owner(&proposer) {
+                                    Some(true) => {
+                                        ui.label(egui::RichText::new("owner").weak());
+                                    }
+                                    Some(false) => {
+                                        ui.label(
+                                            egui::RichText::new("NOT an owner")
+                                                .color(egui::Color32::from_rgb(220, 80, 80)),
+                                        );
+                                    }
+                                    None => {
+                                        ui.label(egui::RichText::new("owners unknown").weak())
+                                            .on_hover_text(
+                                                "Fetch Safe details in the sidebar to check",
+                                            );
+                                    }
+                                }
+                            });
                         }
+                        None => {
+                            ui.label(egui::RichText::new("not provided by service").weak());
+                        }
+                    }
+                    ui.label(""); // Empty for alignment
+                    ui.end_row();
+
+                    if let Some(service_url) = &self.tx_state.service_url {
+                        ui.label("Service:");
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new(service_url).monospace().size(11.0));
+                            if self
+                                .safe_context
+                                .service_overrides
+                                .contains_key(&self.safe_context.chain_name)
+                            {
+                                ui.label(egui::RichText::new("custom").weak());
+                            }
+                        });
+                        ui.label(""); // Empty for alignment
+                        ui.end_row();
+                    }
+
+                    if let Some(delegate) =
+                        self.tx_proposal(tx).and_then(|p| p.proposed_by_delegate)
+                    {
+                        ui.label("Via Delegate:");
+                        let delegate_str = delegate.to_string();
+                        ui::address_link(ui, &self.safe_context.chain_name, &delegate_str, None);
+                        ui.label(""); // Empty for alignment
                         ui.end_row();
                     }
 
-                    if !tx.origin.is_empty() {
+                    if let Some(origin) = parse_origin(&tx.origin) {
                         ui.label("Origin:");
-                        ui.label(&tx.origin);
+                        let text = match (&origin.name, &origin.url) {
+                            (Some(name), Some(url)) => format!("{} ({})", name, url),
+                            (Some(name), None) => name.clone(),
+                            (None, Some(url)) => url.clone(),
+                            (None, None) => origin.raw.clone(),
+                        };
+                        ui.label(text).on_hover_text(&origin.raw);
+                        ui.label(""); // Empty for alignment
+                        ui.end_row();
+                    }
+
+                    if let Some(cost) = decode::cost::calldata_cost(&tx.data) {
+                        ui.label("Calldata Size:");
+                        let mut size = format!("{} bytes ({} zero)", cost.bytes, cost.zero_bytes);
+                        if let Some(packed) = decode::cost::multisend_packed_size(&tx.data) {
+                            size.push_str(&format!(", MultiSend packed: {} bytes", packed));
+                        }
+                        ui.label(size);
+                        ui.label(""); // Empty for alignment
+                        ui.end_row();
+
+                        ui.label("Calldata Gas:");
+                        let mut gas = format!("~{} gas", cost.gas);
+                        if decode::cost::is_l1_data_chain(&self.safe_context.chain_name) {
+                            gas.push_str(" + rollup L1 data fee (not included in the estimate)");
+                        }
+                        ui.label(gas).on_hover_text(
+                            "Intrinsic calldata cost: 16 gas per non-zero byte, 4 per zero byte",
+                        );
                         ui.label(""); // Empty for alignment
                         ui.end_row();
                     }
@@ -602,11 +1137,85 @@ impl App {
                 }
             }
 
+            if let Some(info) = self.current_safe_info() {
+                ui.add_space(15.0);
+                ui::section_header(ui, "Confirmations");
+                self.render_confirmation_check(ui, tx, info);
+            }
+
+            if let Some(status) = &self.tx_state.nonce_status {
+                ui.add_space(15.0);
+                ui::section_header(ui, "Nonce Position");
+                self.render_nonce_status(ui, status);
+            }
+
             // Calldata decode section
             if let Some(decode_state) = &mut self.tx_state.decode {
                 ui.add_space(15.0);
                 ui::section_header(ui, "Calldata Verification");
-                decode::render_decode_section(ui, decode_state, &self.safe_context);
+
+                if let (TransactionKind::Single(_), Some(tx)) =
+                    (&decode_state.kind, &self.tx_state.fetched_tx)
+                {
+                    decode::render_wrap_summary(
+                        ui,
+                        &tx.to.to_string(),
+                        &tx.value,
+                        &tx.data,
+                        &self.safe_context,
+                    );
+                    if let Some(relay) = &self.tx_state.findings.relay {
+                        decode::render_relay_summary(
+                            ui,
+                            &tx.to.to_string(),
+                            relay,
+                            &self.safe_context,
+                        );
+                    }
+                    if let Some(nested) = &self.tx_state.findings.nested_safe {
+                        decode::render_nested_safe_summary(
+                            ui,
+                            &tx.to.to_string(),
+                            nested,
+                            &self.safe_context,
+                        );
+                    }
+                }
+
+                let web_ui = self.tx_state.web_ui_decode.as_ref();
+                if web_ui.is_some() {
+                    ui.checkbox(
+                        &mut self.tx_state.show_web_ui_decode,
+                        "Compare with Safe{Wallet} web UI decode",
+                    )
+                    .on_hover_text("Show the API decode the way the official web app renders it");
+                }
+
+                match web_ui {
+                    Some(web_ui) if self.tx_state.show_web_ui_decode => {
+                        ui.columns(2, |cols| {
+                            cols[0].label(egui::RichText::new("Safe{Wallet} web UI").strong());
+                            decode::render_web_ui_decode(&mut cols[0], web_ui, &self.safe_context);
+                            cols[1].label(egui::RichText::new("Independent decode").strong());
+                            decode::render_decode_section(
+                                &mut cols[1],
+                                decode_state,
+                                &self.safe_context,
+                                &self.contract_lookup,
+                            );
+                        });
+                    }
+                    _ => decode::render_decode_section(
+                        ui,
+                        decode_state,
+                        &self.safe_context,
+                        &self.contract_lookup,
+                    ),
+                }
+
+                if let Some(result) = &self.tx_state.findings.reconciliation {
+                    roster::render_reconciliation(ui, result, &self.safe_context);
+                }
             }
         }
 
@@ -614,27 +1223,124 @@ impl App {
         expected::render_result(ui, &self.tx_state.expected);
 
         let warnings_error = self.tx_state.warnings_error.as_deref();
-        if self.tx_state.warnings.has_warnings() || warnings_error.is_some() {
+        let non_owner_proposer = self
+            .tx_state
+            .fetched_tx
+            .as_ref()
+            .and_then(|tx| self.tx_proposal(tx))
+            .and_then(|p| p.proposer)
+            .filter(|p| self.is_owner(p) == Some(false));
+        let admin_calls = &self.tx_state.findings.admin_calls;
+        let config_changes = &self.tx_state.findings.config_changes;
+        let owner_changes = &self.tx_state.findings.owner_changes;
+        let refund = self.tx_state.fetched_tx.as_ref().and_then(|tx| {
+            estimate_refund(
+                &tx.safe_tx_gas.to_string(),
+                &tx.base_gas.to_string(),
+                &tx.gas_price,
+                &tx.gas_token.to_string(),
+                &tx.refund_receiver.to_string(),
+            )
+        });
+        let approvals = &self.tx_state.findings.approvals;
+        let permits = &self.tx_state.findings.permits;
+        let first_interactions = match (
+            &self.tx_state.counterparty_history,
+            &self.tx_state.fetched_tx,
+        ) {
+            (Some(history), Some(tx))
+                if self.tx_state.check_counterparties
+                    && history.is_for(
+                        &self.safe_context.chain_name,
+                        &self.safe_context.safe_address,
+                    ) =>
+            {
+                history.first_interactions(&tx.to.to_string(), &tx.value, &tx.data)
+            }
+            _ => vec![],
+        };
+        if self.tx_state.warnings.has_warnings()
+            || warnings_error.is_some()
+            || non_owner_proposer.is_some()
+            || refund.is_some()
+            || !config_changes.is_empty()
+            || !owner_changes.is_empty()
+            || !admin_calls.is_empty()
+            || !approvals.is_empty()
+            || !permits.is_empty()
+            || !first_interactions.is_empty()
+        {
             ui.add_space(15.0);
             ui::section_header(ui, "⚠️ Warnings");
 
+            if let Some(proposer) = non_owner_proposer {
+                ui::warning_banner(
+                    ui,
+                    &format!("Proposed by {} who is NOT a Safe owner", proposer),
+                );
+            }
+
             if let Some(error) = warnings_error {
                 ui::error_message(ui, &format!("Warning computation failed: {}", error));
             }
 
+            let chain_id = self.safe_context.chain_id();
+            let trusted = match (&self.tx_state.fetched_tx, chain_id) {
+                (Some(tx), Some(chain_id)) => trust::lookup(
+                    &tx.to.to_string(),
+                    chain_id,
+                    &self.safe_context.address_book,
+                ),
+                _ => None,
+            };
+            if let Some(t) = &trusted {
+                ui::success_message(
+                    ui,
+                    &format!(
+                        "Target is a known contract: {} ({})",
+                        t.label,
+                        t.category.label()
+                    ),
+                );
+            }
+
             let w = &self.tx_state.warnings;
+            let untrusted_delegatecall = self
+                .tx_state
+                .findings
+                .risk_flags(chain_id, &self.safe_context.address_book)
+                .delegatecall;
             if w.delegatecall {
-                ui::error_banner(ui, "DELEGATECALL - can modify Safe state!");
+                Self::render_delegatecall_banner(ui, trusted.as_ref(), untrusted_delegatecall);
+                Self::render_warning_explanation(ui, WarningKind::Delegatecall, "verify");
             }
             if w.non_zero_gas_token {
                 ui::warning_banner(ui, "Non-zero gas token");
+                Self::render_warning_explanation(ui, WarningKind::NonZeroGasToken, "verify");
             }
             if w.non_zero_refund_receiver {
                 ui::warning_banner(ui, "Non-zero refund receiver");
+                Self::render_warning_explanation(ui, WarningKind::NonZeroRefundReceiver, "verify");
+            }
+            if let Some(refund) = &refund {
+                self.render_refund_estimate(ui, refund);
             }
+            self.render_config_changes(ui, config_changes);
+            if !config_changes.is_empty() {
+                Self::render_warning_explanation(ui, WarningKind::ConfigChange, "verify");
+            }
+            self.render_owner_management(ui, owner_changes);
             if w.dangerous_methods {
                 ui::warning_banner(ui, "Dangerous method (owner/threshold change)");
+                Self::render_warning_explanation(ui, WarningKind::DangerousMethods, "verify");
+            }
+            decode::render_admin_warnings(ui, admin_calls, &self.safe_context);
+            decode::render_approval_warnings(ui, approvals, &self.safe_context);
+            decode::render_permit_warnings(ui, permits, &self.safe_context);
+            if self.tx_state.findings.unlimited_approval() {
+                Self::render_warning_explanation(ui, WarningKind::UnlimitedApproval, "verify");
             }
+            counterparty::render_first_interactions(ui, &first_interactions, &self.safe_context);
             for mismatch in &w.argument_mismatches {
                 ui::error_banner(
                     ui,
@@ -644,11 +1350,16 @@ impl App {
                     ),
                 );
             }
+            if !w.argument_mismatches.is_empty() {
+                Self::render_warning_explanation(ui, WarningKind::ArgumentMismatch, "verify");
+            }
         }
 
         if let Some(hashes) = &self.tx_state.hashes {
             ui.add_space(15.0);
             ui::section_header(ui, "Hash Results");
+            expected::render_hash_check(ui, &self.tx_state.expected);
+            let copy_allowed = ui::copy_gate(ui, risk.as_ref(), &mut self.tx_state.copy_override);
 
             egui::Grid::new("hash_results")
                 .num_columns(3)
@@ -660,9 +1371,7 @@ impl App {
                             .monospace()
                             .size(12.0),
                     );
-                    if ui.small_button("📋").on_hover_text("Copy").clicked() {
-                        ui::copy_to_clipboard(&hashes.domain_hash);
-                    }
+                    ui::hash_copy_button(ui, &hashes.domain_hash, copy_allowed);
                     ui.end_row();
 
                     ui.label(egui::RichText::new("Message Hash:").strong());
@@ -671,9 +1380,7 @@ impl App {
                             .monospace()
                             .size(12.0),
                     );
-                    if ui.small_button("📋").on_hover_text("Copy").clicked() {
-                        ui::copy_to_clipboard(&hashes.message_hash);
-                    }
+                    ui::hash_copy_button(ui, &hashes.message_hash, copy_allowed);
                     ui.end_row();
 
                     ui.label(egui::RichText::new("Safe Tx Hash:").strong());
@@ -682,31 +1389,119 @@ impl App {
                             .monospace()
                             .size(12.0),
                     );
-                    if ui.small_button("📋").on_hover_text("Copy").clicked() {
-                        ui::copy_to_clipboard(&hashes.safe_tx_hash);
-                    }
+                    ui::hash_copy_button(ui, &hashes.safe_tx_hash, copy_allowed);
                     ui.end_row();
 
-                    // Ledger binary format
-                    let binary_literal = ui::hash_to_binary_literal(&hashes.safe_tx_hash);
-                    ui.label(egui::RichText::new("Ledger Binary:").strong());
-                    ui.label(egui::RichText::new(&binary_literal).monospace().size(12.0));
-                    if ui.small_button("📋").on_hover_text("Copy").clicked() {
-                        ui::copy_to_clipboard(&binary_literal);
-                    }
-                    ui.end_row();
+                    // Hardware wallet display format
+                    ui::render_device_hash_row(ui, &hashes.safe_tx_hash, copy_allowed);
                 });
 
+            ui.add_space(5.0);
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(
+                        copy_allowed,
+                        egui::Button::new("📋 Copy all hashes").small(),
+                    )
+                    .on_hover_text("Domain, message and safe tx hash plus the Ledger binary")
+                    .on_disabled_hover_text("Blocked by the warning policy")
+                    .clicked()
+                {
+                    ui::copy_to_clipboard(&export::hashes_text(hashes));
+                }
+                if let Some(tx) = &self.tx_state.fetched_tx {
+                    if ui
+                        .add_enabled(
+                            copy_allowed,
+                            egui::Button::new("📋 Copy safe-hash CLI").small(),
+                        )
+                        .on_hover_text(
+                            "Command line that recomputes these hashes offline with the \
+                             safe-hash tool",
+                        )
+                        .on_disabled_hover_text("Blocked by the warning policy")
+                        .clicked()
+                    {
+                        ui::copy_to_clipboard(&export::safe_hash_command(
+                            &self.safe_context.chain_name,
+                            &self.safe_context.safe_address,
+                            &self.safe_context.safe_version,
+                            tx,
+                        ));
+                    }
+                }
+            });
+
+            self.render_domain_breakdown(ui, "verify_domain_breakdown");
+            if let Some(inputs) = &self.tx_state.hashing_inputs {
+                self.render_hashing_inputs(ui, inputs, hashes, copy_allowed);
+            }
+
             ui.add_space(10.0);
-            if let Some(matches) = hashes.matches_api {
+            if hashes.trustless {
+                ui::success_banner(ui, "Computed entirely independently of the Safe service");
+                self.render_service_claims(ui, hashes);
+            } else if let Some(matches) = hashes.matches_api {
                 if matches {
                     ui::success_banner(ui, "Computed hash matches API data");
                 } else {
                     ui::error_banner(ui, "Computed hash does NOT match API data!");
                 }
             }
-        }
-    }
+
+            ui.add_space(10.0);
+            if ui
+                .add_enabled(copy_allowed, egui::Button::new("📋 Copy JSON"))
+                .on_hover_text("Copy the full decode tree, warnings and hashes as JSON")
+                .on_disabled_hover_text("Blocked by the warning policy")
+                .clicked()
+            {
+                match export::export_decode_tree(
+                    self.tx_state.decode.as_ref(),
+                    &self.tx_state.warnings,
+                    Some(hashes),
+                ) {
+                    Ok(json) => {
+                        ui::copy_to_clipboard(&json);
+                        self.tx_state.copy_json_error = None;
+                    }
+                    Err(e) => {
+                        self.tx_state.copy_json_error = Some(format!("JSON export failed: {}", e));
+                    }
+                }
+            }
+            if let Some(error) = &self.tx_state.copy_json_error {
+                ui::error_message(ui, error);
+            }
+            if ui
+                .add_enabled(
+                    !self.in_flight.verifying(),
+                    egui::Button::new("💾 Export report"),
+                )
+                .on_hover_text(
+                    "Save the transaction, hashes, warnings and decode comparison as a \
+                     self-describing JSON report",
+                )
+                .on_disabled_hover_text("Wait for the current verification to finish")
+                .clicked()
+            {
+                self.tx_state.report_export = Some(self.export_report());
+            }
+            match &self.tx_state.report_export {
+                Some(Ok(saved)) => {
+                    ui.label(egui::RichText::new(saved).small().weak());
+                }
+                Some(Err(e)) => ui::error_message(ui, e),
+                None => {}
+            }
+
+            ui.add_space(10.0);
+            self.render_attestation(ui);
+
+            ui.add_space(10.0);
+            self.render_diagnostic_report(ui);
+        }
+    }
 
     fn format_tx_label(
         &self,
@@ -747,8 +1542,580 @@ impl App {
         )
     }
 
+    /// Attestation hash over the verification result, plus a check of a
+    /// reviewer's signature over it
+    fn render_attestation(&mut self, ui: &mut egui::Ui) {
+        egui::CollapsingHeader::new("🔏 Attestation")
+            .id_salt("verify_attestation")
+            .show(ui, |ui| {
+                let hash = match export::attestation_hash(
+                    self.tx_state.decode.as_ref(),
+                    &self.tx_state.warnings,
+                    self.tx_state.hashes.as_ref(),
+                ) {
+                    Ok(hash) => hash,
+                    Err(e) => {
+                        ui::error_message(ui, &format!("Attestation failed: {}", e));
+                        return;
+                    }
+                };
+
+                ui.label(
+                    egui::RichText::new(
+                        "keccak256 of the canonical JSON export. Reviewers with matching \
+                         hashes reviewed byte-identical results.",
+                    )
+                    .small(),
+                );
+                ui.add_space(5.0);
+
+                let check = &mut self.tx_state.attestation;
+                egui::Grid::new("attestation_grid")
+                    .num_columns(2)
+                    .spacing([10.0, 6.0])
+                    .show(ui, |ui| {
+                        ui.label("Attestation hash:");
+                        ui::copyable_hash(ui, &hash.to_string());
+                        ui.end_row();
+
+                        ui.label("Reviewer:");
+                        ui::address_input(ui, &mut check.reviewer);
+                        ui.end_row();
+
+                        ui.label("Signature:");
+                        ui.add(
+                            egui::TextEdit::singleline(&mut check.signature)
+                                .hint_text("0x... (personal_sign over the hash)")
+                                .font(egui::TextStyle::Monospace)
+                                .desired_width(400.0),
+                        );
+                        ui.end_row();
+                    });
+
+                ui.label(
+                    egui::RichText::new(format!("Sign with: cast wallet sign {}", hash))
+                        .monospace()
+                        .weak()
+                        .small(),
+                );
+
+                if check.reviewer.trim().is_empty() || check.signature.trim().is_empty() {
+                    return;
+                }
+                ui.add_space(5.0);
+                match check.verify(&hash) {
+                    Ok(true) => ui::success_message(ui, "Signed by the reviewer"),
+                    Ok(false) => ui::error_message(
+                        ui,
+                        "Signature is not from this reviewer for this attestation hash",
+                    ),
+                    Err(e) => ui::error_message(ui, &e),
+                }
+            });
+    }
+
+    /// Diagnostic report for bug reports: inputs, raw service response,
+    /// hashes, warnings and decode in one JSON document
+    fn render_diagnostic_report(&mut self, ui: &mut egui::Ui) {
+        egui::CollapsingHeader::new("🐞 Diagnostic Report")
+            .id_salt("verify_diagnostic_report")
+            .show(ui, |ui| {
+                ui.label(
+                    egui::RichText::new(
+                        "Everything a maintainer needs to reproduce this verification. \
+                         Paste it into a .json file and attach it to a GitHub issue.",
+                    )
+                    .small(),
+                );
+                ui.add_space(5.0);
+                let options = &mut self.tx_state.diagnostics;
+                ui.checkbox(&mut options.redact_addresses, "Redact addresses")
+                    .on_hover_text("Replace every address with a placeholder like <address 1>");
+                ui.checkbox(&mut options.include_calldata, "Include raw calldata")
+                    .on_hover_text(
+                        "Needed to reproduce the decode. Calldata embeds addresses that \
+                         redaction does not touch.",
+                    );
+                ui.add_space(5.0);
+
+                if ui.button("📋 Generate diagnostic report").clicked() {
+                    let tx = self.tx_state.fetched_tx.as_ref();
+                    let report = export::DiagnosticReport {
+                        schema_version: export::DIAGNOSTIC_SCHEMA_VERSION,
+                        build: export::BuildInfo::current(),
+                        inputs: export::DiagnosticInputs {
+                            chain_name: &self.safe_context.chain_name,
+                            safe_address: &self.safe_context.safe_address,
+                            safe_version: &self.safe_context.safe_version,
+                            nonce: &self.tx_state.nonce,
+                            trustless: self.tx_state.trustless,
+                            check_counterparties: self.tx_state.check_counterparties,
+                        },
+                        service_response: tx.and_then(|tx| {
+                            self.tx_state
+                                .raw_responses
+                                .get(&tx.safe_tx_hash.to_lowercase())
+                        }),
+                        raw_calldata: tx.map(|tx| tx.data.as_str()),
+                        hashes: self.tx_state.hashes.as_ref(),
+                        warnings: (&self.tx_state.warnings).into(),
+                        decode: self.tx_state.decode.as_ref(),
+                        redacted: self.tx_state.diagnostics.redact_addresses,
+                    };
+                    match report.to_json(&self.tx_state.diagnostics) {
+                        Ok(json) => ui::copy_to_clipboard(&json),
+                        Err(e) => {
+                            debug_log!("Diagnostic report failed: {}", e);
+                        }
+                    }
+                }
+            });
+    }
+
+    /// What the Safe service reported for the fetched transaction, next to the
+    /// independently computed values. Only used in trustless mode.
+    fn render_service_claims(&self, ui: &mut egui::Ui, hashes: &ComputedHashes) {
+        let Some(tx) = &self.tx_state.fetched_tx else {
+            return;
+        };
+
+        let independent_method = self.tx_state.decode.as_ref().and_then(|d| match &d.kind {
+            TransactionKind::Single(single) => single.local.as_ref().map(|l| l.method.clone()),
+            TransactionKind::MultiSend(_) => Some("multiSend".to_string()),
+            TransactionKind::UserOp(_) => Some("handleOps".to_string()),
+            TransactionKind::Empty | TransactionKind::Unknown => None,
+        });
+        let claimed_method = tx.data_decoded.as_ref().map(|d| d.method.clone());
+
+        ui.add_space(5.0);
+        egui::CollapsingHeader::new("What the service claims")
+            .id_salt("trustless_service_claims")
+            .default_open(true)
+            .show(ui, |ui| {
+                egui::Grid::new("trustless_claims_grid")
+                    .num_columns(3)
+                    .spacing([10.0, 6.0])
+                    .show(ui, |ui| {
+                        let hash_matches = tx.safe_tx_hash.eq_ignore_ascii_case(&hashes.safe_tx_hash);
+                        ui.label(egui::RichText::new("Reported Safe Tx Hash:").strong());
+                        ui.label(
+                            egui::RichText::new(&tx.safe_tx_hash)
+                                .monospace()
+                                .size(12.0),
+                        );
+                        ui.label(if hash_matches { "✅" } else { "❌" });
+                        ui.end_row();
+
+                        ui.label(egui::RichText::new("Reported method:").strong());
+                        ui.label(claimed_method.as_deref().unwrap_or("(no decode)"));
+                        match (&claimed_method, &independent_method) {
+                            (Some(claimed), Some(ours)) if claimed == ours => {
+                                ui.label("✅");
+                            }
+                            (Some(_), Some(ours)) => {
+                                ui.label(format!("❌ independent: {}", ours));
+                            }
+                            _ => {
+                                ui.label("—");
+                            }
+                        }
+                        ui.end_row();
+                    });
+
+                if !tx.safe_tx_hash.eq_ignore_ascii_case(&hashes.safe_tx_hash) {
+                    ui::error_banner(
+                        ui,
+                        "The service reports a different hash for these fields. Sign only the independently computed hash.",
+                    );
+                }
+            });
+    }
+
+    /// Proposal metadata for a fetched transaction, if the service sent any
+    fn tx_proposal(&self, tx: &SafeTransaction) -> Option<&crate::hasher::ProposalInfo> {
+        self.tx_state.proposals.get(&tx.safe_tx_hash.to_lowercase())
+    }
+
+    /// Aggregate risk of the Verify tab's result under the warning policy
+    fn verify_risk(&self) -> Option<RiskSummary> {
+        let chain_id = self.safe_context.chain_id();
+        let flags = self
+            .tx_state
+            .findings
+            .risk_flags(chain_id, &self.safe_context.address_book);
+        let kinds = WarningKind::active(&self.tx_state.warnings, &flags);
+        self.safe_context.warning_policy.assess(&kinds)
+    }
+
+    /// Whether `address` owns the current Safe. `None` if owners aren't loaded
+    /// (or were loaded for a different Safe).
+    fn is_owner(&self, address: &alloy::primitives::Address) -> Option<bool> {
+        Some(self.current_safe_info()?.owners.contains(address))
+    }
+
+    /// Badge comparing a confirmation's reported signer with the address
+    /// its signature recovers to
+    fn render_recovered_signer(
+        &self,
+        ui: &mut egui::Ui,
+        tx: &SafeTransaction,
+        owner: &alloy::primitives::Address,
+        recovered: &[(
+            alloy::primitives::Address,
+            Option<alloy::primitives::Address>,
+        )],
+    ) {
+        let Some((_, signer)) = recovered.iter().find(|(reported, _)| reported == owner) else {
+            return;
+        };
+        match signer {
+            Some(signer) if signer == owner => {
+                ui.label(
+                    egui::RichText::new("✓ signature recovers")
+                        .color(egui::Color32::from_rgb(100, 200, 100)),
+                )
+                .on_hover_text("ECDSA-recovered against the computed Safe tx hash");
+            }
+            Some(signer) => {
+                ui.label(
+                    egui::RichText::new(format!("⚠️ signature recovers to {}", signer))
+                        .color(egui::Color32::from_rgb(220, 80, 80)),
+                )
+                .on_hover_text("The service reports a different signer than the signature");
+            }
+            None => {
+                let contract = tx
+                    .confirmations
+                    .iter()
+                    .find(|c| &c.owner == owner)
+                    .is_some_and(|c| is_contract_signature(&c.signature));
+                if contract {
+                    ui.label(egui::RichText::new("contract signature - cannot recover").weak())
+                        .on_hover_text("EIP-1271: the signing contract validates it at execution");
+                } else {
+                    ui.label(
+                        egui::RichText::new("⚠️ signature could not be recovered")
+                            .color(egui::Color32::from_rgb(220, 80, 80)),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Save the current verification as a `VerificationReport` file
+    fn export_report(&self) -> Result<String, String> {
+        let tx = self
+            .tx_state
+            .fetched_tx
+            .as_ref()
+            .ok_or("No transaction to export")?;
+        let report = verify::VerificationReport::new(
+            &self.safe_context.chain_name,
+            &self.safe_context.safe_address,
+            &self.safe_context.safe_version,
+            tx.nonce,
+            vec![verify::TransactionReport {
+                safe_tx_hash: tx.safe_tx_hash.clone(),
+                transaction: self
+                    .tx_state
+                    .raw_responses
+                    .get(&tx.safe_tx_hash.to_lowercase())
+                    .cloned(),
+                hashes: self.tx_state.hashes.clone(),
+                hash_error: None,
+                warnings: (&self.tx_state.warnings).into(),
+                warnings_error: self.tx_state.warnings_error.clone(),
+                decode: self.tx_state.decode.clone().unwrap_or_default(),
+            }],
+        );
+        let json = serde_json::to_string_pretty(&report)
+            .map_err(|e| format!("Failed to serialize report: {}", e))?;
+        let file_name = format!(
+            "rusty-safe-report-{}-{}-{}.json",
+            self.safe_context.chain_name,
+            self.safe_context.safe_address.trim(),
+            tx.nonce
+        );
+        ui::save_file(&file_name, &json)
+    }
+
+    /// Most the gas refund can pay out, in the gas token. Token decimals come
+    /// from the address book; without them the amount is shown in raw units.
+    fn render_refund_estimate(&self, ui: &mut egui::Ui, refund: &RefundEstimate) {
+        let chain_id = self.safe_context.chain_id();
+        let (decimals, token) = if refund.gas_token.is_zero() {
+            (Some(18), "ETH".to_string())
+        } else {
+            let token = refund.gas_token.to_string();
+            (
+                chain_id.and_then(|id| self.safe_context.address_book.decimals(&token, id)),
+                self.safe_context.name_of(&token).unwrap_or(token),
+            )
+        };
+        let raw = refund.max_refund.to_string();
+        let amount = match decimals {
+            Some(decimals) => ui::format_uint_with_decimals(&raw, decimals),
+            None => format!("{} raw units (unknown decimals) of", raw),
+        };
+        let receiver = if refund.refund_receiver.is_zero() {
+            "the executor (tx.origin)".to_string()
+        } else {
+            let receiver = refund.refund_receiver.to_string();
+            self.safe_context.name_of(&receiver).unwrap_or(receiver)
+        };
+
+        let message = if refund.uncapped {
+            format!(
+                "Gas refund: at least {} {} to {} - safeTxGas is 0, so gas used is not capped",
+                amount, token, receiver
+            )
+        } else {
+            format!("Gas refund: up to {} {} to {}", amount, token, receiver)
+        };
+        ui::warning_banner(ui, &message);
+        if refund.pays_executor_in_token() {
+            ui::error_banner(
+                ui,
+                "Token gas refund with a zero refundReceiver: whoever executes the \
+                 transaction is paid from the Safe",
+            );
+        }
+    }
+
+    /// Banner for a top-level DELEGATECALL. One into a library meant for it
+    /// is only expected while `untrusted` (the policy's verdict, which also
+    /// looks inside the batch) is false.
+    fn render_delegatecall_banner(
+        ui: &mut egui::Ui,
+        trusted: Option<&trust::TrustedContract>,
+        untrusted: bool,
+    ) {
+        match trusted {
+            Some(t) if t.expects_delegatecall() && !untrusted => ui::warning_banner(
+                ui,
+                &format!("DELEGATECALL to {} (expected for this library)", t.label),
+            ),
+            Some(t) if t.expects_delegatecall() => ui::error_banner(
+                ui,
+                &format!(
+                    "DELEGATECALL to {}, whose batch delegatecalls an untrusted contract - can \
+                     modify Safe state!",
+                    t.label
+                ),
+            ),
+            _ => ui::error_banner(ui, "DELEGATECALL - can modify Safe state!"),
+        }
+    }
+
+    /// Expandable explanation under a warning banner. The text is selectable
+    /// and copyable for review notes.
+    fn render_warning_explanation(ui: &mut egui::Ui, kind: WarningKind, id_salt: &str) {
+        let explanation = warnings::explain(kind);
+        egui::CollapsingHeader::new(egui::RichText::new("What does this mean?").small())
+            .id_salt(format!("{}_explain_{}", id_salt, kind.label()))
+            .default_open(false)
+            .show(ui, |ui| {
+                for (heading, text) in [
+                    ("What it means", explanation.meaning),
+                    ("Why it's risky", explanation.risk),
+                    ("Legitimate use", explanation.legitimate),
+                ] {
+                    ui.label(egui::RichText::new(heading).strong().small());
+                    ui.add(egui::Label::new(text).selectable(true));
+                    ui.add_space(4.0);
+                }
+                if ui
+                    .small_button("📋 Copy")
+                    .on_hover_text("Copy the explanation for review notes")
+                    .clicked()
+                {
+                    ui::copy_to_clipboard(&explanation.text(kind));
+                }
+            });
+    }
+
+    /// Owner and threshold changes the Safe makes on itself, checked against
+    /// the fetched owners when they're for this Safe
+    fn render_owner_management(&self, ui: &mut egui::Ui, changes: &[OwnerManagement]) {
+        if changes.is_empty() {
+            return;
+        }
+        for change in changes {
+            let prefix = match change.index {
+                Some(i) => format!("TX #{}: ", i + 1),
+                None => String::new(),
+            };
+            ui::warning_banner(
+                ui,
+                &format!("{}Owner management: {}", prefix, change.call.summary()),
+            );
+        }
+        if let Some(info) = self.current_safe_info() {
+            if let Some(issue) = check_owner_threshold(changes, info.owners.len(), info.threshold) {
+                ui::error_banner(ui, &issue);
+            }
+        }
+    }
+
+    /// Banners for changes that replace or bypass the Safe's logic. The new
+    /// singleton is shown so it can be checked against the official
+    /// deployments.
+    fn render_config_changes(&self, ui: &mut egui::Ui, changes: &[ConfigChange]) {
+        let chain_id = self.safe_context.chain_id();
+        for change in changes {
+            let prefix = match change.index {
+                Some(i) => format!("TX #{}: ", i + 1),
+                None => String::new(),
+            };
+            match &change.kind {
+                ConfigChangeKind::MasterCopy(implementation) => {
+                    let implementation = implementation.to_string();
+                    let known = chain_id
+                        .and_then(|id| {
+                            trust::lookup(&implementation, id, &self.safe_context.address_book)
+                        })
+                        .map(|t| format!(" ({})", t.label))
+                        .unwrap_or_default();
+                    ui::error_banner(
+                        ui,
+                        &format!(
+                            "{}SAFE IMPLEMENTATION CHANGE: changeMasterCopy on {} to {}{}. \
+                             Verify it is an official Safe singleton.",
+                            prefix, change.target, implementation, known
+                        ),
+                    );
+                }
+                ConfigChangeKind::SingletonMigration(method) => {
+                    ui::error_banner(
+                        ui,
+                        &format!(
+                            "{}SAFE IMPLEMENTATION CHANGE: DELEGATECALL {} on {} rewrites the \
+                             singleton. Verify it is an official Safe migration contract.",
+                            prefix, method, change.target
+                        ),
+                    );
+                }
+                ConfigChangeKind::Guard(guard) if guard.is_zero() => {
+                    ui::warning_banner(
+                        ui,
+                        &format!(
+                            "{}Transaction guard REMOVED on {}: later transactions skip its checks",
+                            prefix, change.target
+                        ),
+                    );
+                }
+                ConfigChangeKind::Guard(guard) => {
+                    ui::error_banner(
+                        ui,
+                        &format!(
+                            "{}GUARD CHANGE: setGuard on {} to {}. A guard runs on every \
+                             transaction and can block all future ones, including its own removal.",
+                            prefix, change.target, guard
+                        ),
+                    );
+                }
+                ConfigChangeKind::FallbackHandler(handler) => {
+                    ui::error_banner(
+                        ui,
+                        &format!(
+                            "{}FALLBACK HANDLER CHANGE: setFallbackHandler on {} to {}. The \
+                             handler answers calls the Safe doesn't implement, including \
+                             EIP-1271 signature checks.",
+                            prefix, change.target, handler
+                        ),
+                    );
+                }
+                ConfigChangeKind::EnableModule(module) => {
+                    ui::error_banner(
+                        ui,
+                        &format!(
+                            "{}MODULE ENABLED: enableModule on {} for {}. A module can execute \
+                             any transaction from the Safe without owner signatures.",
+                            prefix, change.target, module
+                        ),
+                    );
+                }
+                ConfigChangeKind::DisableModule(module) => {
+                    ui::warning_banner(
+                        ui,
+                        &format!("{}Module {} disabled on {}", prefix, module, change.target),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Fetched Safe details, if they are for the current Safe
+    fn current_safe_info(&self) -> Option<&SafeInfo> {
+        let info = self.safe_info.as_ref()?;
+        let current: alloy::primitives::Address =
+            self.safe_context.safe_address.trim().parse().ok()?;
+        (info.address == current).then_some(info)
+    }
+
+    /// Each signer checked against the fetched owners, and whether the
+    /// confirmations that would count reach the threshold
+    fn render_confirmation_check(&self, ui: &mut egui::Ui, tx: &SafeTransaction, info: &SafeInfo) {
+        let issues = validate_confirmations(tx, info);
+        let green = egui::Color32::from_rgb(100, 200, 100);
+        let red = egui::Color32::from_rgb(220, 80, 80);
+
+        if tx.confirmations.is_empty() {
+            ui.label(egui::RichText::new("No confirmations collected yet").weak());
+        }
+        let mut seen = vec![];
+        for confirmation in &tx.confirmations {
+            let signer = confirmation.owner;
+            ui.horizontal(|ui| {
+                let signer_str = signer.to_string();
+                let name = self.safe_context.name_of(&signer_str);
+                ui::address_link(ui, &self.safe_context.chain_name, &signer_str, name);
+                if info.owners.contains(&signer) {
+                    ui.label(egui::RichText::new("✓ owner").color(green));
+                } else {
+                    ui.label(egui::RichText::new("✗ not an owner").color(red))
+                        .on_hover_text("The Safe rejects this signature at execution");
+                }
+                if seen.contains(&signer) {
+                    ui.label(egui::RichText::new("duplicate, counted once").color(red));
+                }
+            });
+            seen.push(signer);
+        }
+
+        ui.add_space(4.0);
+        let below = issues.iter().find_map(|issue| match issue {
+            ConfirmationIssue::BelowThreshold { valid, threshold } => Some((*valid, *threshold)),
+            _ => None,
+        });
+        match below {
+            Some((valid, threshold)) => {
+                ui.label(
+                    egui::RichText::new(format!(
+                        "✗ {} of {} valid confirmations — below threshold",
+                        valid, threshold
+                    ))
+                    .color(red),
+                );
+            }
+            None => {
+                ui.label(
+                    egui::RichText::new(format!(
+                        "✓ Threshold of {} met by current owners",
+                        info.threshold
+                    ))
+                    .color(green),
+                );
+            }
+        }
+    }
+
     fn tx_action_label(&self, tx: &SafeTransaction) -> String {
-        if let Some(decoded) = &tx.data_decoded {
+        if let Some(decoded) = tx
+            .data_decoded
+            .as_ref()
+            .filter(|_| !self.tx_state.trustless)
+        {
             if !decoded.method.is_empty() {
                 return decoded.method.clone();
             }
@@ -858,11 +2225,16 @@ impl App {
         }
     }
 
-    fn render_message_tab(&mut self, ui: &mut egui::Ui) {
+    fn render_message_tab(&mut self, ui: &mut egui::Ui, ctx: &egui::Context) {
         ui::styled_heading(ui, "Message Verification");
         ui.label("Verify Safe message signing hashes.");
         ui.add_space(15.0);
 
+        self.render_message_fetch(ui, ctx);
+        ui.add_space(15.0);
+        ui.separator();
+        ui.add_space(10.0);
+
         ui.checkbox(&mut self.msg_state.is_hex, "Message is hex bytes");
 
         ui.add_space(5.0);
@@ -930,42 +2302,165 @@ impl App {
         }
     }
 
-    fn render_eip712_tab(&mut self, ui: &mut egui::Ui) {
-        ui::styled_heading(ui, "EIP-712 Typed Data");
-        ui.label("Hash and verify EIP-712 typed data structures.");
-        ui.add_space(15.0);
-
-        ui.checkbox(
-            &mut self.eip712_state.standalone,
-            "Standalone mode (raw EIP-712 only, no Safe wrapping)",
-        );
-        ui.add_space(10.0);
-
-        ui.label("EIP-712 JSON:");
-        ui::multiline_input(
-            ui,
-            &mut self.eip712_state.json_input,
-            r#"{"types": {...}, "domain": {...}, "primaryType": "...", "message": {...}}"#,
-            12,
-        );
-
-        ui.add_space(15.0);
+    /// Fetch an existing Safe message and check its hash against the body
+    fn render_message_fetch(&mut self, ui: &mut egui::Ui, ctx: &egui::Context) {
+        ui::section_header(ui, "Fetch from Transaction Service");
+        ui.horizontal(|ui| {
+            ui.label("Safe message hash:");
+            ui.add(
+                egui::TextEdit::singleline(&mut self.msg_state.fetch_hash)
+                    .hint_text("0x...")
+                    .font(egui::TextStyle::Monospace)
+                    .desired_width(480.0),
+            );
+        });
+        ui.add_space(5.0);
 
-        if ui::primary_button(ui, "🔐 Compute Hash").clicked() {
-            self.compute_eip712_hash();
-        }
+        let can_fetch = !self.msg_state.fetch_loading
+            && !self.msg_state.fetch_hash.trim().is_empty()
+            && !self.safe_context.safe_address.is_empty();
+        ui.horizontal(|ui| {
+            if ui::primary_button_enabled(ui, "📥 Fetch message", can_fetch).clicked() {
+                self.fetch_safe_message(ctx);
+            }
+            if self.msg_state.fetch_loading {
+                ui::loading_spinner(ui);
+            }
+        });
 
-        if let Some(error) = &self.eip712_state.error {
+        if let Some(error) = &self.msg_state.fetch_error {
             ui.add_space(10.0);
             ui::error_message(ui, error);
         }
 
-        if let Some(hashes) = &self.eip712_state.hashes {
-            ui.add_space(15.0);
-            ui::section_header(ui, "EIP-712 Hash Results");
-
-            egui::Grid::new("eip712_hash_results")
-                .num_columns(3)
+        let Some(message) = &self.msg_state.fetched else {
+            return;
+        };
+        ui.add_space(10.0);
+        match &self.msg_state.local_hash {
+            Some(Ok(local)) if local.eq_ignore_ascii_case(&message.message_hash) => {
+                ui::success_banner(ui, "Service message hash matches the message body")
+            }
+            Some(Ok(local)) => ui::error_banner(
+                ui,
+                &format!(
+                    "Service message hash {} ≠ locally computed {}",
+                    message.message_hash, local
+                ),
+            ),
+            Some(Err(e)) => ui::error_message(ui, &format!("Could not recompute hash: {}", e)),
+            None => {}
+        }
+
+        ui.add_space(10.0);
+        egui::Grid::new("fetched_message")
+            .num_columns(2)
+            .spacing([10.0, 8.0])
+            .show(ui, |ui| {
+                ui.label(egui::RichText::new("Message Hash:").strong());
+                ui::copyable_hash(ui, &message.message_hash);
+                ui.end_row();
+
+                if let Some(proposer) = &message.proposed_by {
+                    ui.label(egui::RichText::new("Proposed by:").strong());
+                    ui::address_link(ui, &self.safe_context.chain_name, proposer, None);
+                    ui.end_row();
+                }
+
+                if let Some(prepared) = &message.prepared_signature {
+                    ui.label(egui::RichText::new("Prepared signature:").strong());
+                    ui::copyable_hash(ui, prepared);
+                    ui.end_row();
+                }
+            });
+
+        ui.add_space(5.0);
+        let (kind, body) = match &message.body {
+            SafeMessageBody::Text(text) => ("Message (text)", text.clone()),
+            SafeMessageBody::TypedData(typed) => (
+                "Message (EIP-712)",
+                serde_json::to_string_pretty(typed).unwrap_or_else(|_| typed.to_string()),
+            ),
+        };
+        egui::CollapsingHeader::new(kind)
+            .default_open(true)
+            .show(ui, |ui| {
+                ui.label(egui::RichText::new(body).monospace().size(12.0));
+            });
+
+        egui::CollapsingHeader::new(format!("Confirmations ({})", message.confirmations.len()))
+            .default_open(true)
+            .show(ui, |ui| {
+                egui::Grid::new("message_confirmations")
+                    .num_columns(2)
+                    .spacing([10.0, 4.0])
+                    .show(ui, |ui| {
+                        for confirmation in &message.confirmations {
+                            ui::address_link(
+                                ui,
+                                &self.safe_context.chain_name,
+                                &confirmation.owner,
+                                None,
+                            );
+                            ui.label(egui::RichText::new(&confirmation.signature_type).weak());
+                            ui.end_row();
+                        }
+                    });
+            });
+    }
+
+    fn render_eip712_tab(&mut self, ui: &mut egui::Ui) {
+        ui::styled_heading(ui, "EIP-712 Typed Data");
+        ui.label("Hash and verify EIP-712 typed data structures.");
+        ui.add_space(15.0);
+
+        ui.checkbox(
+            &mut self.eip712_state.standalone,
+            "Standalone mode (raw EIP-712 only, no Safe wrapping)",
+        );
+        ui.add_space(10.0);
+
+        ui.label("EIP-712 JSON:");
+        ui::multiline_input(
+            ui,
+            &mut self.eip712_state.json_input,
+            r#"{"types": {...}, "domain": {...}, "primaryType": "...", "message": {...}}"#,
+            12,
+        );
+
+        ui.add_space(15.0);
+
+        if ui::primary_button(ui, "🔐 Compute Hash").clicked() {
+            self.compute_eip712_hash();
+        }
+
+        if let Some(error) = &self.eip712_state.error {
+            ui.add_space(10.0);
+            ui::error_message(ui, error);
+        }
+
+        if !self.eip712_state.type_issues.is_empty() {
+            ui.add_space(10.0);
+            for issue in &self.eip712_state.type_issues {
+                if issue.is_error() {
+                    ui::error_banner(ui, &issue.message());
+                } else {
+                    ui::warning_banner(ui, &issue.message());
+                }
+            }
+        }
+
+        if !self.eip712_state.domain_mismatches.is_empty() {
+            ui.add_space(10.0);
+            self.render_domain_mismatches(ui);
+        }
+
+        if let Some(hashes) = &self.eip712_state.hashes {
+            ui.add_space(15.0);
+            ui::section_header(ui, "EIP-712 Hash Results");
+
+            egui::Grid::new("eip712_hash_results")
+                .num_columns(3)
                 .spacing([10.0, 8.0])
                 .show(ui, |ui| {
                     ui.label(egui::RichText::new("EIP-712 Hash:").strong());
@@ -1049,15 +2544,68 @@ impl App {
         }
     }
 
+    /// Warnings for a typed-data domain scoped to another chain or contract
+    fn render_domain_mismatches(&self, ui: &mut egui::Ui) {
+        let chain_name = &self.safe_context.chain_name;
+        for mismatch in &self.eip712_state.domain_mismatches {
+            match mismatch {
+                DomainMismatch::ChainId { found, expected } => ui::error_banner(
+                    ui,
+                    &format!(
+                        "JSON domain chainId {} ≠ selected chain {} ({})",
+                        found, expected, chain_name
+                    ),
+                ),
+                DomainMismatch::MissingChainId => ui::warning_banner(
+                    ui,
+                    "JSON domain has no chainId - a signature would be valid on every chain",
+                ),
+                DomainMismatch::VerifyingContract { found, expected } => ui::warning_message(
+                    ui,
+                    &format!(
+                        "JSON domain verifyingContract {} ≠ selected Safe {}. Expected when \
+                         signing for a dapp, not for messages addressed to the Safe itself.",
+                        found, expected
+                    ),
+                    egui::Color32::from_rgb(220, 180, 50),
+                ),
+            }
+        }
+    }
+
     fn compute_eip712_hash(&mut self) {
         self.eip712_state.error = None;
         self.eip712_state.hashes = None;
+        self.eip712_state.domain_mismatches.clear();
+        self.eip712_state.type_issues.clear();
 
         if self.eip712_state.json_input.trim().is_empty() {
             self.eip712_state.error = Some("Please enter EIP-712 JSON data".to_string());
             return;
         }
 
+        // Name the broken type rather than failing inside the hasher
+        match validate_eip712_types(&self.eip712_state.json_input) {
+            Ok(issues) => self.eip712_state.type_issues = issues,
+            Err(e) => {
+                self.eip712_state.error = Some(format!("Failed to parse EIP-712 data: {:#}", e));
+                return;
+            }
+        }
+        let errors = self
+            .eip712_state
+            .type_issues
+            .iter()
+            .filter(|issue| issue.is_error())
+            .count();
+        if errors > 0 {
+            self.eip712_state.error = Some(format!(
+                "{} type error(s) in the EIP-712 JSON - fix them before hashing",
+                errors
+            ));
+            return;
+        }
+
         // Parse and hash the EIP-712 typed data
         let hasher = Eip712Hasher::new(self.eip712_state.json_input.clone());
         let eip712_result = match hasher.hash() {
@@ -1080,10 +2628,10 @@ impl App {
             });
         } else {
             // Safe-wrapped mode - wrap the EIP-712 hash in a Safe message
-            let chain_id = match ChainId::of(&self.safe_context.chain_name) {
+            let chain_id = match chain_id_of(&self.safe_context.chain_name) {
                 Ok(id) => id,
                 Err(e) => {
-                    self.eip712_state.error = Some(format!("Invalid chain: {}", e));
+                    self.eip712_state.error = Some(format!("{:#}", e));
                     return;
                 }
             };
@@ -1105,6 +2653,14 @@ impl App {
                 }
             };
 
+            match validate_eip712_domain(&self.eip712_state.json_input, chain_id, safe_addr) {
+                Ok(mismatches) => self.eip712_state.domain_mismatches = mismatches,
+                Err(e) => {
+                    self.eip712_state.error = Some(format!("Invalid EIP-712 domain: {:#}", e));
+                    return;
+                }
+            }
+
             // Create message hash from the EIP-712 hash
             let eip712_hash_bytes =
                 match hex::decode(eip712_result.eip_712_hash.trim_start_matches("0x")) {
@@ -1150,10 +2706,10 @@ impl App {
         self.msg_state.hashes = None;
 
         // Use safe_utils::Of to get chain ID from name
-        let chain_id = match ChainId::of(&self.safe_context.chain_name) {
+        let chain_id = match chain_id_of(&self.safe_context.chain_name) {
             Ok(id) => id,
             Err(e) => {
-                self.msg_state.error = Some(format!("Invalid chain: {}", e));
+                self.msg_state.error = Some(format!("{:#}", e));
                 return;
             }
         };
@@ -1208,20 +2764,78 @@ impl App {
         });
     }
 
+    /// Whether Fetch & Verify can start
+    fn can_fetch(&self) -> bool {
+        !self.safe_context.safe_address.is_empty()
+            && !self.tx_state.nonce.is_empty()
+            && !self.tx_state.is_loading
+            && !self.in_flight.verifying()
+    }
+
+    fn step_nonce(&mut self, up: bool) {
+        if let Ok(n) = self.tx_state.nonce.parse::<u64>() {
+            let next = if up {
+                n.checked_add(1)
+            } else {
+                n.checked_sub(1)
+            };
+            if let Some(next) = next {
+                self.tx_state.nonce = next.to_string();
+            }
+        }
+    }
+
+    /// Dispatch a keyboard shortcut or palette command. Fetch and nonce keys
+    /// only act on the Verify tab; from the palette they switch to it first.
+    fn run_action(&mut self, ctx: &egui::Context, action: shortcuts::Action, from_palette: bool) {
+        use shortcuts::Action;
+        let verify_action = matches!(
+            action,
+            Action::FetchAndVerify | Action::NextNonce | Action::PreviousNonce
+        );
+        if verify_action {
+            if from_palette {
+                self.active_tab = Tab::VerifySafeApi;
+            } else if self.active_tab != Tab::VerifySafeApi {
+                return;
+            }
+        }
+        match action {
+            Action::FetchAndVerify => {
+                if self.can_fetch() {
+                    self.fetch_and_verify(ctx);
+                }
+            }
+            Action::NextNonce => self.step_nonce(true),
+            Action::PreviousNonce => self.step_nonce(false),
+            Action::SwitchTab(tab) => self.active_tab = tab,
+            Action::FetchSafeDetails => self.trigger_safe_info_fetch(),
+            Action::ToggleAddressBook => self.address_book_open = !self.address_book_open,
+            Action::OpenPalette => self.palette.open(),
+        }
+    }
+
     fn fetch_and_verify(&mut self, ctx: &egui::Context) {
         self.tx_state.is_loading = true;
         self.tx_state.error = None;
         self.tx_state.warnings = SafeWarnings::new();
         self.tx_state.hashes = None;
+        self.tx_state.hashing_inputs = None;
+        self.tx_state.findings = TxFindings::default();
         self.tx_state.fetched_tx = None;
         self.tx_state.fetched_txs.clear();
+        self.tx_state.fetched_range = None;
+        self.tx_state.range_errors.clear();
         self.tx_state.selected_tx_index = None;
         self.tx_state.decode = None;
+        self.tx_state.web_ui_decode = None;
         self.tx_state.warnings_error = None;
+        self.tx_state.nonce_status = None;
         self.tx_state.show_full_data = false;
 
         let chain_name = self.safe_context.chain_name.clone();
         let safe_address = self.safe_context.safe_address.clone();
+        self.tx_state.service_url = safe_api_url(&chain_name).ok();
         let nonce: u64 = match self.tx_state.nonce.trim().parse() {
             Ok(n) => n,
             Err(_) => {
@@ -1231,36 +2845,281 @@ impl App {
             }
         };
 
+        if self.tx_state.check_counterparties {
+            self.trigger_history_fetch(ctx);
+        }
+        self.trigger_nonce_check(ctx, nonce);
+
         let result = Arc::clone(&self.fetch_result);
         let ctx = ctx.clone();
+        self.in_flight.fetch += 1;
 
         // Spawn async task
-        #[cfg(target_arch = "wasm32")]
-        {
-            wasm_bindgen_futures::spawn_local(async move {
-                let fetch_result = fetch_transactions(&chain_name, &safe_address, nonce).await;
-                let mut result_guard = lock_or_recover!(result);
-                *result_guard = Some(match fetch_result {
-                    Ok(txs) => FetchResult::Success(txs),
-                    Err(e) => FetchResult::Error(format!("{:#}", e)),
-                });
-                ctx.request_repaint();
+        let task_result = Arc::clone(&result);
+        let spawned = self.spawn(async move {
+            let fetch_result = fetch_transactions(&chain_name, &safe_address, nonce).await;
+            let mut result_guard = lock_or_recover!(task_result);
+            *result_guard = Some(match fetch_result {
+                Ok(txs) => FetchResult::Success(txs),
+                Err(e) => FetchResult::Error(format!("{:#}", e)),
             });
+            ctx.request_repaint();
+        });
+        if let Err(e) = spawned {
+            *lock_or_recover!(result) = Some(FetchResult::Error(e));
         }
+    }
 
-        #[cfg(not(target_arch = "wasm32"))]
+    /// Place the fetched nonce in the Safe's queue: executable, blocked by
+    /// earlier nonces, or competing with other proposals
+    fn trigger_nonce_check(&mut self, ctx: &egui::Context, nonce: u64) {
+        let chain_name = self.safe_context.chain_name.clone();
+        let safe_address = self.safe_context.safe_address.clone();
+        let result = Arc::clone(&self.nonce_check_result);
+        let ctx = ctx.clone();
+
+        let task_result = Arc::clone(&result);
+        let spawned = self.spawn(async move {
+            let status = check_nonce_position(&chain_name, &safe_address, nonce).await;
+            *lock_or_recover!(task_result) = Some(match status {
+                Ok(status) => NonceCheckResult::Success(nonce, status),
+                Err(e) => NonceCheckResult::Error(nonce, format!("{:#}", e)),
+            });
+            ctx.request_repaint();
+        });
+        if let Err(e) = spawned {
+            *lock_or_recover!(result) = Some(NonceCheckResult::Error(nonce, e));
+        }
+    }
+
+    /// Apply a nonce position, unless the nonce field moved on meanwhile
+    fn check_nonce_check_result(&mut self) {
+        let result = lock_or_recover!(self.nonce_check_result).take();
+        let (nonce, status) = match result {
+            Some(NonceCheckResult::Success(nonce, status)) => (nonce, Ok(status)),
+            Some(NonceCheckResult::Error(nonce, e)) => (nonce, Err(e)),
+            None => return,
+        };
+        if self.tx_state.nonce.trim().parse() == Ok(nonce) && self.tx_state.fetched_range.is_none()
         {
-            std::thread::spawn(move || {
-                let rt = tokio::runtime::Runtime::new().unwrap();
-                let fetch_result =
-                    rt.block_on(fetch_transactions(&chain_name, &safe_address, nonce));
-                let mut result_guard = lock_or_recover!(result);
-                *result_guard = Some(match fetch_result {
-                    Ok(txs) => FetchResult::Success(txs),
-                    Err(e) => FetchResult::Error(format!("{:#}", e)),
-                });
-                ctx.request_repaint();
+            self.tx_state.nonce_status = Some(status);
+        }
+    }
+
+    /// Compare the computed safeTxHash against the one the user expects, and
+    /// on mismatch look for the setting that would explain it
+    fn check_expected_hash(&self, tx: &SafeTransaction) -> Option<expected::HashCheck> {
+        let wanted = self.tx_state.expected.safe_tx_hash.trim();
+        let hashes = self.tx_state.hashes.as_ref()?;
+        if wanted.is_empty() {
+            return None;
+        }
+        let mut check = expected::compare_safe_tx_hash(wanted, &hashes.safe_tx_hash);
+        if let expected::HashCheck::Mismatch { hints, .. } = &mut check {
+            *hints = diagnose_hash_mismatch(
+                wanted,
+                &self.safe_context.chain_name,
+                &self.safe_context.safe_address,
+                &self.safe_context.safe_version,
+                tx,
+            );
+        }
+        Some(check)
+    }
+
+    fn render_nonce_status(&self, ui: &mut egui::Ui, status: &Result<NonceStatus, String>) {
+        let nonce = self.tx_state.nonce.trim();
+        match status {
+            Ok(NonceStatus::Executable) => {
+                ui::success_message(ui, &format!("Nonce {} is executable now", nonce));
+            }
+            Ok(NonceStatus::Executed { current }) => ui::warning_message(
+                ui,
+                &format!(
+                    "Nonce {} is already used: the Safe is at nonce {}. This transaction can \
+                     no longer execute unless it is the one that did.",
+                    nonce, current
+                ),
+                egui::Color32::from_rgb(220, 180, 50),
+            ),
+            Ok(NonceStatus::Blocked { pending, missing }) => {
+                let list = |nonces: &[u64]| {
+                    nonces
+                        .iter()
+                        .map(u64::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                };
+                if !pending.is_empty() {
+                    ui::warning_message(
+                        ui,
+                        &format!("Blocked: nonce {} pending first", list(pending)),
+                        egui::Color32::from_rgb(220, 180, 50),
+                    );
+                }
+                if !missing.is_empty() {
+                    ui::warning_message(
+                        ui,
+                        &format!(
+                            "Blocked: nonce {} has no proposal, so nothing after it can execute",
+                            list(missing)
+                        ),
+                        egui::Color32::from_rgb(220, 180, 50),
+                    );
+                }
+            }
+            Ok(NonceStatus::Conflict { count }) => ui::warning_message(
+                ui,
+                &format!(
+                    "Conflict: {} transactions share nonce {}. Executing one invalidates \
+                     the others.",
+                    count, nonce
+                ),
+                egui::Color32::from_rgb(220, 180, 50),
+            ),
+            Err(e) => ui::error_message(ui, &format!("Nonce check failed: {}", e)),
+        }
+    }
+
+    /// Fetch every nonce from the nonce field through the range end into the
+    /// transaction list; each entry is then verified on selection
+    fn fetch_range(&mut self, ctx: &egui::Context) {
+        let range = match (
+            self.tx_state.nonce.trim().parse::<u64>(),
+            self.tx_state.range_end.trim().parse::<u64>(),
+        ) {
+            (Ok(start), Ok(end)) => (start, end),
+            _ => {
+                self.tx_state.error = Some("Invalid nonce range".to_string());
+                return;
+            }
+        };
+        if let Err(e) = check_nonce_range(range.0, range.1) {
+            self.tx_state.error = Some(format!("{:#}", e));
+            return;
+        }
+
+        self.tx_state.clear_results();
+        self.tx_state.is_loading = true;
+        self.tx_state.show_full_data = false;
+        self.tx_state.fetched_range = Some(range);
+
+        if self.tx_state.check_counterparties {
+            self.trigger_history_fetch(ctx);
+        }
+
+        let chain_name = self.safe_context.chain_name.clone();
+        self.tx_state.service_url = safe_api_url(&chain_name).ok();
+        let safe_address = self.safe_context.safe_address.clone();
+        let result = Arc::clone(&self.fetch_result);
+        let ctx = ctx.clone();
+        self.in_flight.fetch += 1;
+
+        let task_result = Arc::clone(&result);
+        let spawned = self.spawn(async move {
+            let fetch_result =
+                fetch_transaction_range(&chain_name, &safe_address, range.0, range.1).await;
+            *lock_or_recover!(task_result) = Some(match fetch_result {
+                Ok(range) => FetchResult::Range(range),
+                Err(e) => FetchResult::Error(format!("{:#}", e)),
+            });
+            ctx.request_repaint();
+        });
+        if let Err(e) = spawned {
+            *lock_or_recover!(result) = Some(FetchResult::Error(e));
+        }
+    }
+
+    /// Point the sidebar at the Safe in a pasted Safe{Wallet} link, then load
+    /// the linked transaction, or the next queued one for queue links
+    fn open_share_link(&mut self) {
+        let link = match parse_safe_share_url(&self.tx_state.share_link_input) {
+            Ok(link) => link,
+            Err(e) => {
+                self.tx_state.error = Some(e);
+                return;
+            }
+        };
+        self.safe_context.chain_name = link.chain_name;
+        self.safe_context.safe_address = link.safe_address;
+        crate::state::add_recent_address(
+            &mut self.safe_context.recent_addresses,
+            &self.safe_context.safe_address,
+        );
+        self.tx_state.share_link_input.clear();
+
+        // The linked transaction is fetched once the Safe info has set the
+        // version it hashes with; queue links load the next queued one
+        if let Some(hash) = link.safe_tx_hash {
+            self.tx_state.clear_results();
+            self.tx_state.is_loading = true;
+            self.tx_state.share_link_pending = Some(hash);
+        }
+        self.trigger_safe_info_fetch();
+    }
+
+    /// Verify a pasted transaction through the same pipeline as a fetched
+    /// one. The Safe comes from the JSON when none is selected.
+    fn verify_pasted_tx(&mut self, ctx: &egui::Context) {
+        let (tx, raw) = match parse_transaction_json(&self.tx_state.tx_json_input) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                self.tx_state.error = Some(e);
+                return;
+            }
+        };
+        let tx_safe = tx.safe.to_string();
+        if self.safe_context.safe_address.trim().is_empty() {
+            self.safe_context.safe_address = tx_safe;
+        } else if !tx_safe.eq_ignore_ascii_case(self.safe_context.safe_address.trim()) {
+            self.tx_state.error = Some(format!(
+                "Transaction belongs to Safe {}, not {}",
+                tx_safe,
+                self.safe_context.safe_address.trim()
+            ));
+            return;
+        }
+
+        self.tx_state.clear_results();
+        self.tx_state.nonce = tx.nonce.to_string();
+        self.tx_state
+            .raw_responses
+            .insert(tx.safe_tx_hash.to_lowercase(), raw);
+        self.tx_state.fetched_txs = vec![tx.clone()];
+        self.tx_state.selected_tx_index = Some(0);
+        self.apply_fetched_tx(ctx, tx);
+    }
+
+    /// Fetch every proposal at the nonce of `safe_tx_hash`, selecting that one
+    fn fetch_shared_transaction(&mut self, ctx: &egui::Context, safe_tx_hash: String) {
+        self.tx_state.clear_results();
+        self.tx_state.is_loading = true;
+        self.tx_state.show_full_data = false;
+        self.tx_state.share_link_hash = Some(safe_tx_hash.clone());
+
+        if self.tx_state.check_counterparties {
+            self.trigger_history_fetch(ctx);
+        }
+
+        let chain_name = self.safe_context.chain_name.clone();
+        self.tx_state.service_url = safe_api_url(&chain_name).ok();
+        let safe_address = self.safe_context.safe_address.clone();
+        let result = Arc::clone(&self.fetch_result);
+        let ctx = ctx.clone();
+        self.in_flight.fetch += 1;
+
+        let task_result = Arc::clone(&result);
+        let spawned = self.spawn(async move {
+            let fetch_result =
+                fetch_transactions_for_hash(&chain_name, &safe_address, &safe_tx_hash).await;
+            *lock_or_recover!(task_result) = Some(match fetch_result {
+                Ok(txs) => FetchResult::Success(txs),
+                Err(e) => FetchResult::Error(format!("{:#}", e)),
             });
+            ctx.request_repaint();
+        });
+        if let Err(e) = spawned {
+            *lock_or_recover!(result) = Some(FetchResult::Error(e));
         }
     }
 
@@ -1272,32 +3131,56 @@ impl App {
 
         if let Some(result) = result {
             self.tx_state.is_loading = false;
-
-            match result {
-                FetchResult::Success(txs) => {
-                    if txs.is_empty() {
-                        self.tx_state.error =
-                            Some("No transaction found for the specified nonce".to_string());
-                        return;
-                    }
-
-                    let mut sorted = txs;
-                    sorted.sort_by(|a, b| b.submission_date.cmp(&a.submission_date));
-                    self.tx_state.fetched_txs = sorted;
-                    let selected_index = self
-                        .tx_state
-                        .selected_tx_index
-                        .unwrap_or(0)
-                        .min(self.tx_state.fetched_txs.len() - 1);
-                    self.tx_state.selected_tx_index = Some(selected_index);
-
-                    if let Some(tx) = self.tx_state.fetched_txs.get(selected_index).cloned() {
-                        self.apply_fetched_tx(ctx, tx);
-                    }
+            self.in_flight.fetch = self.in_flight.fetch.saturating_sub(1);
+            let share_link_hash = self.tx_state.share_link_hash.take();
+
+            let fetched = match result {
+                FetchResult::Success(fetched) => fetched,
+                FetchResult::Range(range) => {
+                    self.tx_state.range_errors = range.errors;
+                    range.fetched
                 }
                 FetchResult::Error(e) => {
                     self.tx_state.error = Some(e);
+                    return;
                 }
+            };
+
+            let txs = fetched.transactions;
+            self.tx_state.proposals = fetched.proposals;
+            self.tx_state.raw_responses = fetched.raw;
+            if txs.is_empty() {
+                self.tx_state.error = Some(match self.tx_state.fetched_range {
+                    Some(_) => "No transactions found in the nonce range".to_string(),
+                    None => "No transaction found for the specified nonce".to_string(),
+                });
+                return;
+            }
+
+            // Lowest nonce first, newest proposal first within a nonce
+            let mut sorted = txs;
+            sorted.sort_by(|a, b| {
+                a.nonce
+                    .cmp(&b.nonce)
+                    .then_with(|| b.submission_date.cmp(&a.submission_date))
+            });
+            self.tx_state.fetched_txs = sorted;
+            if let Some(hash) = share_link_hash {
+                let txs = &self.tx_state.fetched_txs;
+                self.tx_state.selected_tx_index = txs
+                    .iter()
+                    .position(|tx| tx.safe_tx_hash.eq_ignore_ascii_case(&hash));
+                self.tx_state.nonce = txs[0].nonce.to_string();
+            }
+            let selected_index = self
+                .tx_state
+                .selected_tx_index
+                .unwrap_or(0)
+                .min(self.tx_state.fetched_txs.len() - 1);
+            self.tx_state.selected_tx_index = Some(selected_index);
+
+            if let Some(tx) = self.tx_state.fetched_txs.get(selected_index).cloned() {
+                self.apply_fetched_tx(ctx, tx);
             }
         }
     }
@@ -1305,56 +3188,66 @@ impl App {
     fn apply_fetched_tx(&mut self, ctx: &egui::Context, tx: SafeTransaction) {
         self.tx_state.error = None;
         self.tx_state.hashes = None;
+        self.tx_state.hashing_inputs = None;
         self.tx_state.warnings = SafeWarnings::new();
         self.tx_state.warnings_error = None;
         self.tx_state.decode = None;
+        self.tx_state.web_ui_decode = None;
         self.tx_state.show_full_data = false;
         self.tx_state.expected.clear_result();
 
-        // Compute hashes from the fetched transaction using validate_safe_tx_hash
-        match compute_hashes_from_api_tx(
+        // Same checks as headless verification
+        let checks = verify::check_transaction(
             &self.safe_context.chain_name,
             &self.safe_context.safe_address,
             &self.safe_context.safe_version,
             &tx,
-        ) {
-            Ok((hashes, mismatch)) => {
-                // Add hash mismatch to warnings if present
-                if let Some(m) = mismatch {
-                    self.tx_state.warnings.argument_mismatches.push(m);
-                }
-                self.tx_state.hashes = Some(hashes);
-            }
-            Err(e) => {
-                self.tx_state.error = Some(format!("Hash computation failed: {:#}", e));
-            }
+            self.tx_state.trustless,
+        );
+        if let Some(e) = checks.hash_error {
+            self.tx_state.error = Some(e);
         }
-
-        // Get warnings using check_suspicious_content (via get_warnings_from_api_tx)
-        let chain_id = ChainId::of(&self.safe_context.chain_name).ok();
-        match get_warnings_from_api_tx(&tx, chain_id) {
-            Ok(warnings) => self.tx_state.warnings.union(warnings),
-            Err(e) => {
-                debug_log!("Warning computation failed: {:#}", e);
-                self.tx_state.warnings_error = Some(format!("{:#}", e));
-            }
+        if let Some(e) = &checks.warnings_error {
+            debug_log!("Warning computation failed: {}", e);
         }
+        self.tx_state.hashes = checks.hashes;
+        self.tx_state.hashing_inputs = checks.inputs;
+        self.tx_state.warnings = checks.warnings;
+        self.tx_state.warnings_error = checks.warnings_error;
+        self.tx_state.findings = TxFindings::detect(
+            &self.safe_context.safe_address,
+            &tx.to.to_string(),
+            &tx.data,
+            tx.operation,
+        );
+        self.tx_state
+            .findings
+            .reconcile(&self.tx_state.roster.entries);
+        self.resolve_contracts(ctx, vec![tx.to.to_string()]);
+
+        // In trustless mode the decode runs on the raw fields alone
+        let api_decoded = tx
+            .data_decoded
+            .as_ref()
+            .filter(|_| !self.tx_state.trustless);
 
         // Validate against expected values if any were provided
         if self.tx_state.expected.has_values() {
             self.tx_state.expected.result =
                 Some(expected::validate_against_api(&tx, &self.tx_state.expected));
         }
+        self.tx_state.expected.hash_result = self.check_expected_hash(&tx);
 
         // Initialize calldata decode
         debug_log!("Parsing calldata: {} bytes", tx.data.len());
-        let decode_state = decode::parse_initial(&tx.data, tx.data_decoded.as_ref());
+        let decode_state = decode::parse_initial(&tx.data, api_decoded);
         debug_log!(
             "Decode kind: {:?}, selector: {}",
             match &decode_state.kind {
                 TransactionKind::Empty => "Empty",
                 TransactionKind::Single(_) => "Single",
                 TransactionKind::MultiSend(_) => "MultiSend",
+                TransactionKind::UserOp(_) => "UserOp",
                 TransactionKind::Unknown => "Unknown",
             },
             decode_state.selector
@@ -1364,13 +3257,21 @@ impl App {
             TransactionKind::Single(_) if !decode_state.selector.is_empty() => {
                 Some(("single", decode_state.selector.clone(), tx.data.clone(), 0))
             }
-            TransactionKind::MultiSend(multi) => {
-                Some(("multi", String::new(), String::new(), multi.transactions.len()))
+            TransactionKind::MultiSend(multi) => Some((
+                "multi",
+                String::new(),
+                String::new(),
+                multi.transactions.len(),
+            )),
+            TransactionKind::UserOp(bundle) => {
+                Some(("userop", String::new(), String::new(), bundle.ops.len()))
             }
             _ => None,
         };
+        let parsing = matches!(&decode_state.kind, TransactionKind::MultiSend(m) if m.is_parsing());
 
         self.tx_state.decode = Some(decode_state);
+        self.tx_state.web_ui_decode = api_decoded.map(decode::parse_web_ui_decode);
 
         // Trigger verification based on transaction type
         if let Some((kind, selector, data, tx_count)) = verification_action {
@@ -1378,33 +3279,74 @@ impl App {
                 "single" => {
                     debug_log!("Triggering 4byte lookup for selector: {}", selector);
                     self.trigger_decode_lookup(ctx, &selector, &data);
-                }
-                "multi" => {
-                    debug_log!("Triggering bulk verification for {} transactions", tx_count);
-                    // Update verification state
-                    if let Some(ref mut decode) = self.tx_state.decode {
-                        if let TransactionKind::MultiSend(ref mut multi) = decode.kind {
-                            multi.verification_state =
-                                decode::VerificationState::InProgress { total: tx_count };
-                        }
+                    if !self.safe_context.api.explorer_api_key.trim().is_empty() {
+                        self.trigger_explorer_decode(ctx, &tx.to.to_string(), &data);
                     }
-                    self.trigger_multisend_bulk_verify(ctx);
+                }
+                "multi" if parsing => {
+                    debug_log!("Parsed first {} transactions, continuing", tx_count);
+                    self.in_flight.parsing = true;
+                    ctx.request_repaint();
+                }
+                "multi" => self.start_multisend_verification(ctx),
+                "userop" => {
+                    debug_log!("Triggering UserOperation verification for {} ops", tx_count);
+                    self.trigger_user_op_verify(ctx);
                 }
                 _ => {}
             }
         }
 
         self.tx_state.fetched_tx = Some(tx);
+        self.tx_state.audit_pending = true;
+        self.refresh_decoded_findings();
     }
 
-    fn check_decode_result(&mut self) {
-        let result = {
-            let mut guard = lock_or_recover!(self.decode_result);
-            guard.take()
-        };
+    /// Refresh the findings read from the decode after it changes
+    fn refresh_decoded_findings(&mut self) {
+        if let (Some(decode), Some(tx)) = (&self.tx_state.decode, &self.tx_state.fetched_tx) {
+            self.tx_state
+                .findings
+                .update_decoded(decode, &tx.to.to_string());
+        }
+    }
+
+    /// Record the verification shown in the audit log, once its decode has
+    /// settled so the severity reflects the finished checks
+    fn record_verification(&mut self) {
+        if !self.tx_state.audit_pending || self.in_flight.verifying() {
+            return;
+        }
+        self.tx_state.audit_pending = false;
+        let Some(tx) = &self.tx_state.fetched_tx else {
+            return;
+        };
+        let timestamp = web_time::SystemTime::now()
+            .duration_since(web_time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        let hashes = self.tx_state.hashes.as_ref();
+        let entry = AuditLogEntry {
+            timestamp,
+            chain: self.safe_context.chain_name.clone(),
+            safe_address: self.safe_context.safe_address.trim().to_string(),
+            nonce: tx.nonce,
+            safe_tx_hash: hashes.map(|h| h.safe_tx_hash.clone()).unwrap_or_default(),
+            matches_api: hashes.and_then(|h| h.matches_api),
+            top_severity: self.verify_risk().map(|risk| risk.level),
+        };
+        self.safe_context.audit_log.record(entry);
+    }
+
+    fn check_decode_result(&mut self) {
+        let result = {
+            let mut guard = lock_or_recover!(self.decode_result);
+            guard.take()
+        };
 
         if let Some(result) = result {
             debug_log!("Received decode result");
+            self.in_flight.decode = self.in_flight.decode.saturating_sub(1);
             match result {
                 DecodeResult::Single {
                     selector: _,
@@ -1425,21 +3367,20 @@ impl App {
                                         single.local.as_ref(),
                                     );
                                     debug_log!("Comparison result: {:?}", single.comparison);
+                                    // The explorer may have answered first
+                                    if let Some(check) = single.explorer.take() {
+                                        single.explorer = Some(decode::compare_with_explorer(
+                                            single.api.as_ref(),
+                                            single.local.as_ref(),
+                                            check.decode,
+                                        ));
+                                    }
                                 }
                                 Err(e) => {
                                     single.comparison = ComparisonResult::Failed(e);
                                 }
                             }
-
-                            // Update overall status
-                            decode.status = match &single.comparison {
-                                ComparisonResult::Match => decode::OverallStatus::AllMatch,
-                                ComparisonResult::MethodMismatch { .. }
-                                | ComparisonResult::ParamMismatch(_) => {
-                                    decode::OverallStatus::HasMismatches
-                                }
-                                _ => decode::OverallStatus::PartiallyVerified,
-                            };
+                            decode.status = verify::overall_status(decode);
                         }
                     }
                 }
@@ -1451,60 +3392,143 @@ impl App {
                         if let TransactionKind::MultiSend(ref mut multi) = decode.kind {
                             // Replace with the verified MultiSend
                             *multi = verified_multi;
-
-                            // Update overall status based on summary
-                            decode.status = if multi.summary.mismatched > 0 {
-                                decode::OverallStatus::HasMismatches
-                            } else if multi.summary.verified == multi.summary.total {
-                                decode::OverallStatus::AllMatch
-                            } else if multi.summary.verified > 0 {
-                                decode::OverallStatus::PartiallyVerified
-                            } else {
-                                decode::OverallStatus::Pending
-                            };
+                            decode.status = verify::overall_status(decode);
+                        }
+                    }
+                }
+                DecodeResult::UserOpBulk {
+                    bundle: verified_bundle,
+                } => {
+                    if let Some(ref mut decode) = self.tx_state.decode {
+                        if let TransactionKind::UserOp(ref mut bundle) = decode.kind {
+                            *bundle = verified_bundle;
+                            decode.status = verify::overall_status(decode);
                         }
                     }
                 }
             }
+            self.refresh_decoded_findings();
         }
     }
 
-    fn trigger_decode_lookup(&self, ctx: &egui::Context, selector: &str, data: &str) {
+    fn trigger_decode_lookup(&mut self, ctx: &egui::Context, selector: &str, data: &str) {
         let lookup = self.signature_lookup.clone();
         let selector = selector.to_string();
         let data = data.to_string();
         let result = Arc::clone(&self.decode_result);
         let ctx = ctx.clone();
-
-        #[cfg(target_arch = "wasm32")]
-        {
-            use wasm_bindgen_futures::spawn_local;
-            spawn_local(async move {
-                let local_decode = Self::do_decode_lookup(&lookup, &selector, &data).await;
-                let mut guard = lock_or_recover!(result);
-                *guard = Some(DecodeResult::Single {
-                    selector,
-                    local_decode,
-                });
-                ctx.request_repaint();
+        self.in_flight.decode += 1;
+
+        let task_result = Arc::clone(&result);
+        let task_selector = selector.clone();
+        let spawned = self.spawn(async move {
+            let local_decode = verify::decode_single(&lookup, &task_selector, &data).await;
+            let mut guard = lock_or_recover!(task_result);
+            *guard = Some(DecodeResult::Single {
+                selector: task_selector,
+                local_decode,
+            });
+            ctx.request_repaint();
+        });
+        if let Err(e) = spawned {
+            *lock_or_recover!(result) = Some(DecodeResult::Single {
+                selector,
+                local_decode: Err(e),
             });
         }
+    }
 
-        #[cfg(not(target_arch = "wasm32"))]
-        {
-            std::thread::spawn(move || {
-                let rt = tokio::runtime::Runtime::new().unwrap();
-                let local_decode = rt.block_on(Self::do_decode_lookup(&lookup, &selector, &data));
-                let mut guard = lock_or_recover!(result);
-                *guard = Some(DecodeResult::Single {
-                    selector,
-                    local_decode,
-                });
-                ctx.request_repaint();
+    /// Decode `data` with the verified ABI of `to` from the block explorer
+    fn trigger_explorer_decode(&mut self, ctx: &egui::Context, to: &str, data: &str) {
+        let Ok(chain_id) = chain_id_of(&self.safe_context.chain_name) else {
+            return;
+        };
+        let api_key = self.safe_context.api.explorer_api_key.clone();
+        let chain_name = self.safe_context.chain_name.clone();
+        let to = to.to_string();
+        let data = data.to_string();
+        let result = Arc::clone(&self.explorer_result);
+        let ctx = ctx.clone();
+
+        let spawned = self.spawn(async move {
+            let decode = decode::explorer_decode(chain_id, &to, &data, &api_key).await;
+            *lock_or_recover!(result) = Some(ExplorerResult {
+                chain_name,
+                to,
+                data,
+                decode,
             });
+            ctx.request_repaint();
+        });
+        match spawned {
+            Ok(()) => self.in_flight.decode += 1,
+            Err(e) => {
+                debug_log!("Explorer decode not started: {}", e);
+            }
         }
     }
 
+    fn check_explorer_result(&mut self) {
+        let Some(result) = lock_or_recover!(self.explorer_result).take() else {
+            return;
+        };
+        self.in_flight.decode = self.in_flight.decode.saturating_sub(1);
+        let current = self.tx_state.fetched_tx.as_ref().is_some_and(|tx| {
+            self.safe_context.chain_name == result.chain_name
+                && tx.to.to_string().eq_ignore_ascii_case(&result.to)
+                && tx.data == result.data
+        });
+        if !current {
+            debug_log!("Dropping explorer decode for a previous transaction");
+            return;
+        }
+        if let Some(ref mut decode) = self.tx_state.decode {
+            if let TransactionKind::Single(ref mut single) = decode.kind {
+                single.explorer = Some(decode::compare_with_explorer(
+                    single.api.as_ref(),
+                    single.local.as_ref(),
+                    result.decode,
+                ));
+                decode.status = verify::overall_status(decode);
+            }
+        }
+    }
+
+    /// Parse the next chunk of a large MultiSend, starting bulk verification
+    /// once the whole batch is in
+    fn advance_multisend_parse(&mut self, ctx: &egui::Context) {
+        if !self.in_flight.parsing {
+            return;
+        }
+        let Some(decode) = &mut self.tx_state.decode else {
+            self.in_flight.parsing = false;
+            return;
+        };
+
+        decode::continue_parse(decode);
+        match &decode.kind {
+            TransactionKind::MultiSend(multi) if multi.is_parsing() => ctx.request_repaint(),
+            TransactionKind::MultiSend(_) => {
+                self.in_flight.parsing = false;
+                self.start_multisend_verification(ctx);
+            }
+            _ => self.in_flight.parsing = false,
+        }
+        self.refresh_decoded_findings();
+    }
+
+    fn start_multisend_verification(&mut self, ctx: &egui::Context) {
+        let Some(TransactionKind::MultiSend(multi)) =
+            self.tx_state.decode.as_mut().map(|d| &mut d.kind)
+        else {
+            return;
+        };
+        let total = multi.transactions.len();
+        debug_log!("Triggering bulk verification for {} transactions", total);
+        multi.verification_state = decode::VerificationState::InProgress { total };
+        self.trigger_multisend_bulk_verify(ctx);
+    }
+
     fn trigger_multisend_bulk_verify(&mut self, ctx: &egui::Context) {
         // Get the MultiSend data
         let multi = if let Some(ref decode) = self.tx_state.decode {
@@ -1522,64 +3546,119 @@ impl App {
         };
 
         let lookup = self.signature_lookup.clone();
+        let contracts = self.contract_lookup.clone();
+        let chain_id = chain_id_of(&self.safe_context.chain_name).ok();
+        let safe_address = self.safe_context.safe_address.clone();
         let result = Arc::clone(&self.decode_result);
         let ctx = ctx.clone();
 
-        #[cfg(target_arch = "wasm32")]
-        {
-            use wasm_bindgen_futures::spawn_local;
-            spawn_local(async move {
-                decode::verify_multisend_batch(&mut multi, &lookup).await;
-                let mut guard = lock_or_recover!(result);
-                *guard = Some(DecodeResult::MultiSendBulk { multi });
+        let spawned = self.spawn(async move {
+            decode::verify_multisend_batch(&mut multi, &lookup, &safe_address).await;
+            let targets: Vec<String> = multi.transactions.iter().map(|tx| tx.to.clone()).collect();
+            *lock_or_recover!(result) = Some(DecodeResult::MultiSendBulk { multi });
+            ctx.request_repaint();
+
+            // Names are cosmetic, so they fill in after the decode is shown
+            if let Some(chain_id) = chain_id {
+                contracts.resolve_all(chain_id, &targets).await;
                 ctx.request_repaint();
-            });
+            }
+        });
+        match spawned {
+            Ok(()) => self.in_flight.decode += 1,
+            Err(e) => self.fail_bulk_verify(format!("Verification not started: {}", e)),
         }
+    }
 
-        #[cfg(not(target_arch = "wasm32"))]
-        {
-            std::thread::spawn(move || {
-                let rt = tokio::runtime::Runtime::new().unwrap();
-                rt.block_on(decode::verify_multisend_batch(&mut multi, &lookup));
-                let mut guard = lock_or_recover!(result);
-                *guard = Some(DecodeResult::MultiSendBulk { multi });
-                ctx.request_repaint();
-            });
+    /// Look up Sourcify names for `addresses` in the background. Lookup
+    /// failures just leave the addresses unannotated; a lookup that can't
+    /// start is shown, unless an error is already showing.
+    fn resolve_contracts(&mut self, ctx: &egui::Context, addresses: Vec<String>) {
+        let Ok(chain_id) = chain_id_of(&self.safe_context.chain_name) else {
+            return;
+        };
+        let contracts = self.contract_lookup.clone();
+        let ctx = ctx.clone();
+        let spawned = self.spawn(async move {
+            contracts.resolve_all(chain_id, &addresses).await;
+            ctx.request_repaint();
+        });
+        if let Err(e) = spawned {
+            self.tx_state
+                .error
+                .get_or_insert_with(|| format!("Contract name lookup not started: {}", e));
         }
     }
 
-    async fn do_decode_lookup(
-        lookup: &SignatureLookup,
-        selector: &str,
-        data: &str,
-    ) -> Result<decode::LocalDecode, String> {
-        // Lookup signatures for selector (convert eyre error to String)
-        let signatures = lookup
-            .lookup(selector)
-            .await
-            .map_err(|e| format!("{:#}", e))?;
+    fn trigger_user_op_verify(&mut self, ctx: &egui::Context) {
+        let Some(TransactionKind::UserOp(bundle)) =
+            self.tx_state.decode.as_mut().map(|d| &mut d.kind)
+        else {
+            return;
+        };
+        bundle.verification_state = decode::VerificationState::InProgress {
+            total: bundle.ops.len(),
+        };
+        let mut bundle = bundle.clone();
 
-        if signatures.is_empty() {
-            return Err("No signatures found for selector".into());
+        let lookup = self.signature_lookup.clone();
+        let result = Arc::clone(&self.decode_result);
+        let ctx = ctx.clone();
+
+        let spawned = self.spawn(async move {
+            decode::verify_user_ops(&mut bundle, &lookup).await;
+            let mut guard = lock_or_recover!(result);
+            *guard = Some(DecodeResult::UserOpBulk { bundle });
+            ctx.request_repaint();
+        });
+        match spawned {
+            Ok(()) => self.in_flight.decode += 1,
+            Err(e) => self.fail_bulk_verify(format!("Verification not started: {}", e)),
         }
+    }
 
-        // Try each signature until one decodes successfully
-        // Signatures are sorted with verified first, so we prefer verified decodes
-        for sig_info in &signatures {
-            match decode::decode_with_signature(data, &sig_info.signature, sig_info.verified) {
-                Ok(decoded) => return Ok(decoded),
-                Err(_) => continue,
+    /// Mark every call of a bulk verification that couldn't start as
+    /// failed, so the error shows instead of a verification that never ends
+    fn fail_bulk_verify(&mut self, error: String) {
+        let Some(decode) = self.tx_state.decode.as_mut() else {
+            return;
+        };
+        let failed = |api: Option<decode::ApiDecode>| {
+            Some(decode::SingleDecode {
+                api,
+                local: None,
+                comparison: ComparisonResult::Failed(error.clone()),
+                explorer: None,
+            })
+        };
+        match &mut decode.kind {
+            TransactionKind::UserOp(bundle) => {
+                for op in bundle.ops.iter_mut().filter(|op| op.call_data.len() >= 10) {
+                    op.decode = failed(None);
+                }
+                bundle
+                    .summary
+                    .update_from(bundle.ops.iter().map(|op| op.decode.as_ref()));
+                bundle.verification_state = decode::VerificationState::Complete;
+            }
+            TransactionKind::MultiSend(multi) => {
+                for tx in multi
+                    .transactions
+                    .iter_mut()
+                    .filter(|tx| tx.data.len() >= 10)
+                {
+                    tx.decode = failed(tx.api_decode.clone());
+                }
+                multi.summary.update(&multi.transactions);
+                multi.verification_state = decode::VerificationState::Complete;
             }
+            _ => return,
         }
-
-        Err(format!(
-            "None of {} signatures decoded successfully",
-            signatures.len()
-        ))
+        decode.status = verify::overall_status(decode);
     }
 
     /// Check for safe info result and schedule auto-fetch if successful
-    fn check_safe_info_result(&mut self) {
+    fn check_safe_info_result(&mut self, ctx: &egui::Context) {
         let result = {
             let mut guard = lock_or_recover!(self.safe_info_result);
             guard.take()
@@ -1610,8 +3689,16 @@ impl App {
                     );
 
                     // If we have a pre-fetched pending transaction, use it directly
-                    // instead of making another API call
-                    if let Some(pending_tx) = info.pending_transaction.clone() {
+                    // instead of making another API call. Skipped while a
+                    // verification is running so it doesn't clobber the results.
+                    let share_link = self.tx_state.share_link_pending.take();
+                    let pending = info
+                        .pending_transaction
+                        .clone()
+                        .filter(|_| !self.in_flight.verifying());
+                    if let Some(hash) = share_link {
+                        self.fetch_shared_transaction(ctx, hash);
+                    } else if let Some(pending_tx) = pending {
                         // Set nonce from the pending transaction
                         self.tx_state.nonce = pending_tx.nonce.to_string();
 
@@ -1620,19 +3707,38 @@ impl App {
                         self.tx_state.error = None;
                         self.tx_state.warnings = SafeWarnings::new();
                         self.tx_state.hashes = None;
+                        self.tx_state.hashing_inputs = None;
+                        self.tx_state.findings = TxFindings::default();
                         self.tx_state.fetched_tx = None;
                         self.tx_state.fetched_txs.clear();
+                        self.tx_state.fetched_range = None;
+                        self.tx_state.range_errors.clear();
                         self.tx_state.selected_tx_index = None;
                         self.tx_state.decode = None;
+                        self.tx_state.web_ui_decode = None;
                         self.tx_state.warnings_error = None;
                         self.tx_state.show_full_data = false;
 
                         // Populate fetch_result with the pre-fetched transaction
                         {
+                            let key = pending_tx.safe_tx_hash.to_lowercase();
+                            let mut proposals = std::collections::HashMap::new();
+                            if let Some(proposal) = info.pending_proposal.clone() {
+                                proposals.insert(key.clone(), proposal);
+                            }
+                            let mut raw = std::collections::HashMap::new();
+                            if let Some(response) = info.pending_raw.clone() {
+                                raw.insert(key, response);
+                            }
                             let mut guard = lock_or_recover!(self.fetch_result);
-                            *guard = Some(FetchResult::Success(vec![pending_tx]));
+                            *guard = Some(FetchResult::Success(FetchedTransactions {
+                                transactions: vec![pending_tx],
+                                proposals,
+                                raw,
+                            }));
                         }
-                    } else {
+                        self.in_flight.fetch += 1;
+                    } else if !self.in_flight.verifying() {
                         // No pending transaction, set nonce to latest - 1 for manual fetch
                         let latest_nonce = if info.nonce > 0 {
                             info.nonce - 1
@@ -1646,12 +3752,66 @@ impl App {
                 }
                 SafeInfoResult::Error(e) => {
                     debug_log!("Failed to fetch Safe info: {}", e);
+                    if self.tx_state.share_link_pending.take().is_some() {
+                        self.tx_state.is_loading = false;
+                        self.tx_state.error =
+                            Some(format!("Failed to load the linked Safe: {}", e));
+                    }
                     // Don't clear safe_info on error, keep previous value
                 }
             }
         }
     }
 
+    fn trigger_version_detect(&mut self, ctx: &egui::Context) {
+        if self.sidebar_state.version_detecting {
+            return;
+        }
+
+        self.sidebar_state.version_detecting = true;
+        self.sidebar_state.version_detection = None;
+        let chain_name = self.safe_context.chain_name.clone();
+        let safe_address = self.safe_context.safe_address.clone();
+        let result = Arc::clone(&self.version_result);
+        let ctx = ctx.clone();
+
+        let task_result = Arc::clone(&result);
+        let spawned = self.spawn(async move {
+            let detected = crate::hasher::infer_safe_version(&chain_name, &safe_address).await;
+            let mut guard = lock_or_recover!(task_result);
+            *guard = Some(match detected {
+                Ok(version) => VersionResult::Detected(version),
+                Err(e) => VersionResult::Error(format!("{:#}", e)),
+            });
+            ctx.request_repaint();
+        });
+        if let Err(e) = spawned {
+            *lock_or_recover!(result) = Some(VersionResult::Error(e));
+        }
+    }
+
+    /// Apply a detected version. On failure the selected version is kept.
+    fn check_version_result(&mut self) {
+        let result = {
+            let mut guard = lock_or_recover!(self.version_result);
+            guard.take()
+        };
+
+        if let Some(result) = result {
+            self.sidebar_state.version_detecting = false;
+            self.sidebar_state.version_detection = Some(match result {
+                VersionResult::Detected(version) => {
+                    self.safe_context.safe_version = version.clone();
+                    Ok(format!("Detected {} from the singleton", version))
+                }
+                VersionResult::Error(e) => Err(format!(
+                    "Detection failed, keeping {}: {}",
+                    self.safe_context.safe_version, e
+                )),
+            });
+        }
+    }
+
     fn trigger_safe_info_fetch(&mut self) {
         if self.safe_info_loading {
             return;
@@ -1662,31 +3822,203 @@ impl App {
         let safe_address = self.safe_context.safe_address.clone();
         let result = Arc::clone(&self.safe_info_result);
 
-        #[cfg(target_arch = "wasm32")]
-        {
-            use wasm_bindgen_futures::spawn_local;
-            spawn_local(async move {
-                let fetch_result = crate::hasher::fetch_safe_info(&chain_name, &safe_address).await;
-                let mut guard = lock_or_recover!(result);
-                *guard = Some(match fetch_result {
-                    Ok(info) => SafeInfoResult::Success(info),
-                    Err(e) => SafeInfoResult::Error(format!("{:#}", e)),
-                });
+        let task_result = Arc::clone(&result);
+        let spawned = self.spawn(async move {
+            let fetch_result = crate::hasher::fetch_safe_info(&chain_name, &safe_address).await;
+            let mut guard = lock_or_recover!(task_result);
+            *guard = Some(match fetch_result {
+                Ok(info) => SafeInfoResult::Success(info),
+                Err(e) => SafeInfoResult::Error(format!("{:#}", e)),
             });
+        });
+        if let Err(e) = spawned {
+            *lock_or_recover!(result) = Some(SafeInfoResult::Error(e));
         }
+    }
 
-        #[cfg(not(target_arch = "wasm32"))]
-        {
-            std::thread::spawn(move || {
-                let rt = tokio::runtime::Runtime::new().unwrap();
-                let fetch_result =
-                    rt.block_on(crate::hasher::fetch_safe_info(&chain_name, &safe_address));
-                let mut guard = lock_or_recover!(result);
-                *guard = Some(match fetch_result {
-                    Ok(info) => SafeInfoResult::Success(info),
-                    Err(e) => SafeInfoResult::Error(format!("{:#}", e)),
-                });
+    /// Look the current address up on every configured chain. At most
+    /// `MAX_CONCURRENT_CHAIN_LOOKUPS` requests are in flight at once.
+    fn trigger_chain_scan(&mut self, ctx: &egui::Context) {
+        let address = self.safe_context.safe_address.trim().to_string();
+        let chains: Vec<String> = self
+            .safe_context
+            .chain_scan
+            .chains
+            .iter()
+            .filter(|c| self.chain_names.contains(c))
+            .cloned()
+            .collect();
+
+        let run = {
+            let mut scan = lock_or_recover!(self.chain_scan);
+            let run = scan.run + 1;
+            *scan = ChainScan {
+                run,
+                address: address.clone(),
+                pending: chains.len(),
+                found: Vec::new(),
+            };
+            run
+        };
+
+        let order = chains.clone();
+        let queue = Arc::new(Mutex::new(std::collections::VecDeque::from(chains)));
+        for _ in 0..MAX_CONCURRENT_CHAIN_LOOKUPS {
+            let queue = Arc::clone(&queue);
+            let scan = Arc::clone(&self.chain_scan);
+            let order = order.clone();
+            let address = address.clone();
+            let ctx = ctx.clone();
+            let spawned = self.spawn(async move {
+                loop {
+                    let next = lock_or_recover!(queue).pop_front();
+                    let Some(chain_name) = next else {
+                        break;
+                    };
+                    let result = crate::hasher::fetch_safe_presence(&chain_name, &address).await;
+
+                    let mut scan = lock_or_recover!(scan);
+                    if scan.run != run {
+                        return;
+                    }
+                    scan.pending = scan.pending.saturating_sub(1);
+                    match result {
+                        Ok(Some(presence)) => {
+                            scan.found.push(presence);
+                            let position = |name: &str| order.iter().position(|c| c == name);
+                            scan.found.sort_by_key(|p| position(&p.chain_name));
+                        }
+                        // Not a Safe on this chain, or the service is unreachable
+                        Ok(None) => {}
+                        Err(e) => {
+                            debug_log!("Safe lookup on {} failed: {:#}", chain_name, e);
+                        }
+                    }
+                    drop(scan);
+                    ctx.request_repaint();
+                }
+            });
+            if let Err(e) = spawned {
+                debug_log!("Failed to start chain lookup: {}", e);
+                lock_or_recover!(self.chain_scan).pending = 0;
+                break;
+            }
+        }
+    }
+
+    /// Fetch the Safe's executed history for the first-interaction check,
+    /// unless it's already loaded (or loading) for the current Safe
+    fn trigger_history_fetch(&mut self, ctx: &egui::Context) {
+        let chain_name = self.safe_context.chain_name.clone();
+        let safe_address = self.safe_context.safe_address.clone();
+        let loaded = self
+            .tx_state
+            .counterparty_history
+            .as_ref()
+            .is_some_and(|h| h.is_for(&chain_name, &safe_address));
+        if self.tx_state.counterparty_loading || loaded || safe_address.is_empty() {
+            return;
+        }
+
+        self.tx_state.counterparty_loading = true;
+        self.tx_state.counterparty_error = None;
+        let result = Arc::clone(&self.history_result);
+        let ctx = ctx.clone();
+
+        let task_result = Arc::clone(&result);
+        let spawned = self.spawn(async move {
+            let fetch_result =
+                crate::hasher::fetch_counterparty_history(&chain_name, &safe_address).await;
+            let mut guard = lock_or_recover!(task_result);
+            *guard = Some(match fetch_result {
+                Ok(history) => HistoryResult::Success(history),
+                Err(e) => HistoryResult::Error(format!("{:#}", e)),
+            });
+            ctx.request_repaint();
+        });
+        if let Err(e) = spawned {
+            *lock_or_recover!(result) = Some(HistoryResult::Error(e));
+        }
+    }
+
+    fn fetch_safe_message(&mut self, ctx: &egui::Context) {
+        self.msg_state.fetch_loading = true;
+        self.msg_state.fetch_error = None;
+        self.msg_state.fetched = None;
+        self.msg_state.local_hash = None;
+
+        let chain_name = self.safe_context.chain_name.clone();
+        let safe_address = self.safe_context.safe_address.clone();
+        let message_hash = self.msg_state.fetch_hash.clone();
+        let result = Arc::clone(&self.message_result);
+        let ctx = ctx.clone();
+
+        let task_result = Arc::clone(&result);
+        let spawned = self.spawn(async move {
+            let fetch_result =
+                crate::hasher::fetch_safe_message(&chain_name, &safe_address, &message_hash).await;
+            let mut guard = lock_or_recover!(task_result);
+            *guard = Some(match fetch_result {
+                Ok(message) => SafeMessageResult::Success(message),
+                Err(e) => SafeMessageResult::Error(format!("{:#}", e)),
             });
+            ctx.request_repaint();
+        });
+        if let Err(e) = spawned {
+            *lock_or_recover!(result) = Some(SafeMessageResult::Error(e));
+        }
+    }
+
+    fn check_message_result(&mut self) {
+        let result = {
+            let mut guard = lock_or_recover!(self.message_result);
+            guard.take()
+        };
+
+        if let Some(result) = result {
+            self.msg_state.fetch_loading = false;
+            match result {
+                SafeMessageResult::Success(message) => {
+                    self.msg_state.local_hash = Some(
+                        safe_message_hash(
+                            &self.safe_context.chain_name,
+                            &self.safe_context.safe_address,
+                            &self.safe_context.safe_version,
+                            &message.body,
+                        )
+                        .map(|hash| format!("{:?}", hash))
+                        .map_err(|e| format!("{:#}", e)),
+                    );
+                    self.msg_state.fetched = Some(message);
+                }
+                SafeMessageResult::Error(e) => {
+                    self.msg_state.fetch_error = Some(e);
+                }
+            }
+        }
+    }
+
+    fn check_history_result(&mut self) {
+        let result = {
+            let mut guard = lock_or_recover!(self.history_result);
+            guard.take()
+        };
+
+        if let Some(result) = result {
+            self.tx_state.counterparty_loading = false;
+            match result {
+                HistoryResult::Success(history) => {
+                    debug_log!(
+                        "Loaded {} counterparties from {} transactions",
+                        history.known.len(),
+                        history.scanned
+                    );
+                    self.tx_state.counterparty_history = Some(history);
+                }
+                HistoryResult::Error(e) => {
+                    self.tx_state.counterparty_error = Some(e);
+                }
+            }
         }
     }
 
@@ -1699,35 +4031,83 @@ impl App {
         ui.label("Manually input transaction data for offline verification (uses 4byte signature lookup).");
         ui.add_space(15.0);
 
+        // Keyboard undo/redo only when no field has focus, so Ctrl+Z inside
+        // a text field keeps editing that field
+        let inputs = self.offline_state.inputs();
+        self.offline_state.history.init(inputs);
+        if ctx.memory(|m| m.focused().is_none()) {
+            let redo_shift = egui::KeyboardShortcut::new(
+                egui::Modifiers::COMMAND | egui::Modifiers::SHIFT,
+                egui::Key::Z,
+            );
+            let redo = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::Y);
+            let undo = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::Z);
+            if ctx.input_mut(|i| i.consume_shortcut(&redo_shift) || i.consume_shortcut(&redo)) {
+                self.offline_state.redo();
+            } else if ctx.input_mut(|i| i.consume_shortcut(&undo)) {
+                self.offline_state.undo();
+            }
+        }
+
         // Transaction inputs
-        ui::section_header(ui, "Transaction Details");
+        ui.horizontal(|ui| {
+            ui::section_header(ui, "Transaction Details");
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                let redo = ui
+                    .add_enabled(
+                        self.offline_state.history.can_redo(),
+                        egui::Button::new("↷").small(),
+                    )
+                    .on_hover_text("Redo (Ctrl+Y)");
+                if redo.clicked() {
+                    self.offline_state.redo();
+                }
+                let undo = ui
+                    .add_enabled(
+                        self.offline_state.history.can_undo(),
+                        egui::Button::new("↶").small(),
+                    )
+                    .on_hover_text("Undo (Ctrl+Z)");
+                if undo.clicked() {
+                    self.offline_state.undo();
+                }
+            });
+        });
         ui.add_space(5.0);
 
+        // Inputs are snapshotted when a field is committed, not per keystroke
+        let mut committed = false;
+
         egui::Grid::new("offline_tx_inputs")
             .num_columns(2)
             .spacing([10.0, 8.0])
             .show(ui, |ui| {
                 ui.label("To:");
-                ui::address_input(ui, &mut self.offline_state.to);
+                committed |= ui::address_input(ui, &mut self.offline_state.to).lost_focus();
                 ui.end_row();
 
                 ui.label("Value (wei):");
-                ui::number_input(ui, &mut self.offline_state.value, "0");
+                committed |= ui::number_input(ui, &mut self.offline_state.value, "0").lost_focus();
                 ui.end_row();
 
                 ui.label("Data (hex):");
-                ui::multiline_input(ui, &mut self.offline_state.data, "0x...", 10);
+                committed |=
+                    ui::multiline_input(ui, &mut self.offline_state.data, "0x...", 10).lost_focus();
                 ui.end_row();
 
                 ui.label("Operation:");
                 ui.horizontal(|ui| {
-                    ui.selectable_value(&mut self.offline_state.operation, 0, "Call (0)");
-                    ui.selectable_value(&mut self.offline_state.operation, 1, "DelegateCall (1)");
+                    committed |= ui
+                        .selectable_value(&mut self.offline_state.operation, 0, "Call (0)")
+                        .changed();
+                    committed |= ui
+                        .selectable_value(&mut self.offline_state.operation, 1, "DelegateCall (1)")
+                        .changed();
                 });
                 ui.end_row();
 
                 ui.label("Nonce:");
-                ui::number_input(ui, &mut self.offline_state.nonce, "0");
+                committed |= ui::number_input(ui, &mut self.offline_state.nonce, "0").lost_focus();
                 ui.end_row();
             });
 
@@ -1744,147 +4124,701 @@ impl App {
                     .spacing([10.0, 8.0])
                     .show(ui, |ui| {
                         ui.label("SafeTxGas:");
-                        ui::number_input(ui, &mut self.offline_state.safe_tx_gas, "0");
+                        committed |= ui::number_input(ui, &mut self.offline_state.safe_tx_gas, "0")
+                            .lost_focus();
                         ui.end_row();
 
                         ui.label("BaseGas:");
-                        ui::number_input(ui, &mut self.offline_state.base_gas, "0");
+                        committed |= ui::number_input(ui, &mut self.offline_state.base_gas, "0")
+                            .lost_focus();
+                        ui.end_row();
+
+                        ui.label("GasPrice:");
+                        committed |= ui::number_input(ui, &mut self.offline_state.gas_price, "0")
+                            .lost_focus();
+                        ui.end_row();
+
+                        ui.label("GasToken:");
+                        committed |=
+                            ui::address_input(ui, &mut self.offline_state.gas_token).lost_focus();
+                        ui.end_row();
+
+                        ui.label("RefundReceiver:");
+                        committed |= ui::address_input(ui, &mut self.offline_state.refund_receiver)
+                            .lost_focus();
+                        ui.end_row();
+                    });
+
+                ui.add_space(5.0);
+                ui.label(
+                    egui::RichText::new("Most transactions use default values (all zeros)").weak(),
+                );
+            });
+
+        if committed {
+            self.offline_state.commit_inputs();
+        }
+
+        ui.add_space(15.0);
+
+        ui.checkbox(
+            &mut self.offline_state.decode_only,
+            "Decode only (skip hashing)",
+        )
+        .on_hover_text("Inspect calldata without a Safe address or target");
+        ui.add_space(10.0);
+
+        // Compute button
+        let can_compute = !self.offline_state.is_loading
+            && if self.offline_state.decode_only {
+                !self.offline_state.data.trim().is_empty()
+            } else {
+                !self.safe_context.safe_address.is_empty() && !self.offline_state.to.is_empty()
+            };
+        let label = if self.offline_state.decode_only {
+            "🔍 Decode"
+        } else {
+            "🔐 Compute Hash & Decode"
+        };
+
+        ui.horizontal(|ui| {
+            if ui::primary_button_enabled(ui, label, can_compute).clicked() {
+                self.trigger_offline_compute(ctx.clone());
+            }
+
+            if self.offline_state.is_loading {
+                ui.spinner();
+                ui.label("Computing...");
+            }
+        });
+
+        // Error display
+        if let Some(ref error) = self.offline_state.error {
+            ui.add_space(10.0);
+            ui.label(
+                egui::RichText::new(format!("❌ {}", error))
+                    .color(egui::Color32::from_rgb(220, 80, 80)),
+            );
+        }
+
+        // Results
+        if self.offline_state.hashes.is_some() || self.offline_state.decode_result.is_some() {
+            ui.add_space(20.0);
+            ui.separator();
+            ui.add_space(10.0);
+
+            // Warnings
+            let warnings_error = self.offline_state.warnings_error.as_deref();
+            let admin_calls = &self.offline_state.findings.admin_calls;
+            let config_changes = &self.offline_state.findings.config_changes;
+            let owner_changes = &self.offline_state.findings.owner_changes;
+            let refund = estimate_refund(
+                &self.offline_state.safe_tx_gas,
+                &self.offline_state.base_gas,
+                &self.offline_state.gas_price,
+                &self.offline_state.gas_token,
+                &self.offline_state.refund_receiver,
+            );
+            let approvals = &self.offline_state.findings.approvals;
+            let permits = &self.offline_state.findings.permits;
+            let chain_id = self.safe_context.chain_id();
+            let trusted = chain_id.and_then(|id| {
+                trust::lookup(
+                    self.offline_state.to.trim(),
+                    id,
+                    &self.safe_context.address_book,
+                )
+            });
+            let flags = self
+                .offline_state
+                .findings
+                .risk_flags(chain_id, &self.safe_context.address_book);
+            let kinds = WarningKind::active(&self.offline_state.warnings, &flags);
+            let risk = self.safe_context.warning_policy.assess(&kinds);
+            if let Some(risk) = &risk {
+                ui::risk_banner(ui, risk);
+                ui.add_space(10.0);
+            }
+            if self.offline_state.warnings.has_warnings()
+                || warnings_error.is_some()
+                || refund.is_some()
+                || !config_changes.is_empty()
+                || !owner_changes.is_empty()
+                || !admin_calls.is_empty()
+                || !approvals.is_empty()
+                || !permits.is_empty()
+            {
+                ui::section_header(ui, "⚠️ Warnings");
+
+                if let Some(error) = warnings_error {
+                    ui::error_message(ui, &format!("Warning computation failed: {}", error));
+                }
+
+                if let Some(t) = &trusted {
+                    ui::success_message(
+                        ui,
+                        &format!(
+                            "Target is a known contract: {} ({})",
+                            t.label,
+                            t.category.label()
+                        ),
+                    );
+                }
+
+                let w = &self.offline_state.warnings;
+                if w.delegatecall {
+                    Self::render_delegatecall_banner(ui, trusted.as_ref(), flags.delegatecall);
+                    Self::render_warning_explanation(ui, WarningKind::Delegatecall, "offline");
+                }
+                if w.non_zero_gas_token {
+                    ui::warning_banner(ui, "Non-zero gas token");
+                    Self::render_warning_explanation(ui, WarningKind::NonZeroGasToken, "offline");
+                }
+                if w.non_zero_refund_receiver {
+                    ui::warning_banner(ui, "Non-zero refund receiver");
+                    Self::render_warning_explanation(
+                        ui,
+                        WarningKind::NonZeroRefundReceiver,
+                        "offline",
+                    );
+                }
+                if let Some(refund) = &refund {
+                    self.render_refund_estimate(ui, refund);
+                }
+                self.render_config_changes(ui, config_changes);
+                if !config_changes.is_empty() {
+                    Self::render_warning_explanation(ui, WarningKind::ConfigChange, "offline");
+                }
+                self.render_owner_management(ui, owner_changes);
+                decode::render_admin_warnings(ui, admin_calls, &self.safe_context);
+                decode::render_approval_warnings(ui, approvals, &self.safe_context);
+                decode::render_permit_warnings(ui, permits, &self.safe_context);
+                if flags.unlimited_approval {
+                    Self::render_warning_explanation(ui, WarningKind::UnlimitedApproval, "offline");
+                }
+
+                ui.add_space(10.0);
+            }
+
+            // Calldata Decoding (before hashes, like Verify Safe API tab)
+            if let Some(ref mut decode) = self.offline_state.decode_result {
+                ui::section_header(ui, "Calldata Decoding");
+                if !matches!(decode, decode::OfflineDecodeResult::MultiSend { .. }) {
+                    decode::render_wrap_summary(
+                        ui,
+                        &self.offline_state.to,
+                        &self.offline_state.value,
+                        &self.offline_state.data,
+                        &self.safe_context,
+                    );
+                    if let Some(relay) = &self.offline_state.findings.relay {
+                        decode::render_relay_summary(
+                            ui,
+                            &self.offline_state.to,
+                            relay,
+                            &self.safe_context,
+                        );
+                    }
+                    if let Some(nested) = &self.offline_state.findings.nested_safe {
+                        decode::render_nested_safe_summary(
+                            ui,
+                            &self.offline_state.to,
+                            nested,
+                            &self.safe_context,
+                        );
+                    }
+                }
+                decode::render_offline_decode_section(
+                    ui,
+                    decode,
+                    &self.safe_context,
+                    &self.contract_lookup,
+                );
+                ui.add_space(10.0);
+            }
+
+            // Hashes
+            if let Some(ref hashes) = self.offline_state.hashes {
+                ui::section_header(ui, "Hash Results");
+                let copy_allowed =
+                    ui::copy_gate(ui, risk.as_ref(), &mut self.offline_state.copy_override);
+
+                egui::Grid::new("offline_hash_results")
+                    .num_columns(3)
+                    .spacing([10.0, 8.0])
+                    .show(ui, |ui| {
+                        ui.label(egui::RichText::new("Domain Hash:").strong());
+                        ui.label(
+                            egui::RichText::new(&hashes.domain_hash)
+                                .monospace()
+                                .size(12.0),
+                        );
+                        ui::hash_copy_button(ui, &hashes.domain_hash, copy_allowed);
                         ui.end_row();
 
-                        ui.label("GasPrice:");
-                        ui::number_input(ui, &mut self.offline_state.gas_price, "0");
-                        ui.end_row();
+                        ui.label(egui::RichText::new("Message Hash:").strong());
+                        ui.label(
+                            egui::RichText::new(&hashes.message_hash)
+                                .monospace()
+                                .size(12.0),
+                        );
+                        ui::hash_copy_button(ui, &hashes.message_hash, copy_allowed);
+                        ui.end_row();
+
+                        ui.label(egui::RichText::new("Safe Tx Hash:").strong());
+                        ui.label(
+                            egui::RichText::new(&hashes.safe_tx_hash)
+                                .monospace()
+                                .size(12.0),
+                        );
+                        ui::hash_copy_button(ui, &hashes.safe_tx_hash, copy_allowed);
+                        ui.end_row();
+
+                        // Hardware wallet display format
+                        ui::render_device_hash_row(ui, &hashes.safe_tx_hash, copy_allowed);
+                    });
+
+                self.render_domain_breakdown(ui, "offline_domain_breakdown");
+            }
+        }
+    }
+
+    /// The normalized SafeTx payload and intermediate hashes, so a hash
+    /// mismatch can be reproduced by hand or in another tool
+    fn render_hashing_inputs(
+        &self,
+        ui: &mut egui::Ui,
+        inputs: &HashingInputs,
+        hashes: &ComputedHashes,
+        copy_allowed: bool,
+    ) {
+        egui::CollapsingHeader::new("🧮 Show hashing inputs")
+            .id_salt("verify_hashing_inputs")
+            .show(ui, |ui| {
+                let json = inputs.to_json();
+
+                ui.label(
+                    egui::RichText::new(
+                        "SafeTx fields and domain exactly as fed into the hashers. \
+                         Numbers are decimal.",
+                    )
+                    .small(),
+                );
+                ui.add_space(5.0);
+                ui.add(
+                    egui::Label::new(egui::RichText::new(&json).monospace().size(12.0))
+                        .wrap()
+                        .selectable(true),
+                );
+                ui.add_space(5.0);
+
+                egui::Grid::new("verify_hashing_intermediate")
+                    .num_columns(3)
+                    .spacing([10.0, 6.0])
+                    .show(ui, |ui| {
+                        for (label, value) in [
+                            ("Domain Hash", &hashes.domain_hash),
+                            ("SafeTx Struct Hash", &hashes.message_hash),
+                            ("Safe Tx Hash", &hashes.safe_tx_hash),
+                        ] {
+                            ui.label(egui::RichText::new(format!("{}:", label)).strong());
+                            ui.label(egui::RichText::new(value).monospace().size(12.0));
+                            ui::hash_copy_button(ui, value, copy_allowed);
+                            ui.end_row();
+                        }
+                    });
+
+                ui.add_space(5.0);
+                if ui.small_button("📋 Copy JSON").clicked() {
+                    ui::copy_to_clipboard(&json);
+                }
+            });
+    }
+
+    /// Collapsible breakdown of the Safe domain separator's preimage components
+    fn render_domain_breakdown(&self, ui: &mut egui::Ui, id_salt: &str) {
+        let components = match domain_components(
+            &self.safe_context.chain_name,
+            &self.safe_context.safe_address,
+            &self.safe_context.safe_version,
+        ) {
+            Ok(c) => c,
+            Err(_) => return,
+        };
 
-                        ui.label("GasToken:");
-                        ui::address_input(ui, &mut self.offline_state.gas_token);
-                        ui.end_row();
+        ui.add_space(5.0);
+        egui::CollapsingHeader::new("🔬 Domain Separator Breakdown")
+            .id_salt(id_salt)
+            .show(ui, |ui| {
+                ui.label(
+                    egui::RichText::new(format!(
+                        "Safe {} domain. Safe domains have no name or version fields.",
+                        self.safe_context.safe_version
+                    ))
+                    .small(),
+                );
+                ui.add_space(5.0);
 
-                        ui.label("RefundReceiver:");
-                        ui::address_input(ui, &mut self.offline_state.refund_receiver);
-                        ui.end_row();
+                let word = |bytes: &[u8]| format!("0x{}", hex::encode(bytes));
+                let mut rows = vec![
+                    ("Type String", components.type_string.to_string()),
+                    ("Type Hash", word(components.type_hash.as_slice())),
+                ];
+                if let Some(chain_id) = components.chain_id {
+                    rows.push(("Chain ID", chain_id.to_string()));
+                    rows.push((
+                        "Chain ID (word)",
+                        word(&alloy::primitives::U256::from(chain_id).to_be_bytes::<32>()),
+                    ));
+                }
+                rows.push((
+                    "Verifying Contract",
+                    components.verifying_contract.to_string(),
+                ));
+                rows.push((
+                    "Verifying Contract (word)",
+                    word(components.verifying_contract.into_word().as_slice()),
+                ));
+                rows.push(("Encoded Preimage", word(&components.encoded)));
+                rows.push((
+                    "Domain Separator",
+                    word(components.domain_separator.as_slice()),
+                ));
+
+                egui::Grid::new(id_salt)
+                    .num_columns(3)
+                    .spacing([10.0, 6.0])
+                    .show(ui, |ui| {
+                        for (label, value) in &rows {
+                            ui.label(egui::RichText::new(format!("{}:", label)).strong());
+                            ui.add(
+                                egui::Label::new(egui::RichText::new(value).monospace().size(12.0))
+                                    .wrap(),
+                            );
+                            if ui.small_button("📋").on_hover_text("Copy").clicked() {
+                                ui::copy_to_clipboard(value);
+                            }
+                            ui.end_row();
+                        }
                     });
 
                 ui.add_space(5.0);
-                ui.label(
-                    egui::RichText::new("Most transactions use default values (all zeros)").weak(),
-                );
+                if components.matches_library {
+                    ui::success_message(ui, "Matches safe-utils DomainHasher");
+                } else {
+                    ui::error_message(ui, "Does NOT match safe-utils DomainHasher");
+                }
             });
+    }
 
+    fn render_audit_tab(&mut self, ui: &mut egui::Ui) {
+        ui::styled_heading(ui, "Bulk Audit");
+        ui.label("Re-verify every safeTxHash in a Safe Transaction Service export.");
         ui.add_space(15.0);
 
-        // Compute button
-        let can_compute = !self.safe_context.safe_address.is_empty()
-            && !self.offline_state.to.is_empty()
-            && !self.offline_state.is_loading;
+        ui.label("Transaction list JSON (array or API response with \"results\"):");
+        ui::multiline_input(
+            ui,
+            &mut self.audit_state.json_input,
+            r#"[{"to": "0x...", "value": "0", "data": "0x...", "nonce": 0, "safeTxHash": "0x...", ...}]"#,
+            10,
+        );
 
-        ui.horizontal(|ui| {
-            if ui::primary_button_enabled(ui, "🔐 Compute Hash & Decode", can_compute).clicked() {
-                self.trigger_offline_compute(ctx.clone());
-            }
+        ui.add_space(15.0);
 
-            if self.offline_state.is_loading {
-                ui.spinner();
-                ui.label("Computing...");
-            }
-        });
+        if ui::primary_button(ui, "🔐 Audit Hashes").clicked() {
+            self.run_audit();
+        }
 
-        // Error display
-        if let Some(ref error) = self.offline_state.error {
+        if let Some(error) = &self.audit_state.error {
             ui.add_space(10.0);
-            ui.label(
-                egui::RichText::new(format!("❌ {}", error))
-                    .color(egui::Color32::from_rgb(220, 80, 80)),
-            );
+            ui::error_message(ui, error);
         }
 
-        // Results
-        if self.offline_state.hashes.is_some() || self.offline_state.decode_result.is_some() {
-            ui.add_space(20.0);
-            ui.separator();
-            ui.add_space(10.0);
+        if self.audit_state.entries.is_empty() {
+            return;
+        }
 
-            // Warnings
-            let warnings_error = self.offline_state.warnings_error.as_deref();
-            if self.offline_state.warnings.has_warnings() || warnings_error.is_some() {
-                ui::section_header(ui, "⚠️ Warnings");
+        let total = self.audit_state.entries.len();
+        let passed = self
+            .audit_state
+            .entries
+            .iter()
+            .filter(|e| e.passed())
+            .count();
 
-                if let Some(error) = warnings_error {
-                    ui::error_message(ui, &format!("Warning computation failed: {}", error));
-                }
+        ui.add_space(15.0);
+        ui::section_header(ui, "Audit Results");
+        if passed == total {
+            ui::success_banner(ui, &format!("All {} transaction hashes match", total));
+        } else {
+            ui::error_banner(
+                ui,
+                &format!(
+                    "{} of {} transaction hashes failed verification",
+                    total - passed,
+                    total
+                ),
+            );
+        }
+        ui.add_space(8.0);
 
-                let w = &self.offline_state.warnings;
-                if w.delegatecall {
-                    ui::error_banner(ui, "DELEGATECALL - can modify Safe state!");
-                }
-                if w.non_zero_gas_token {
-                    ui::warning_banner(ui, "Non-zero gas token");
-                }
-                if w.non_zero_refund_receiver {
-                    ui::warning_banner(ui, "Non-zero refund receiver");
-                }
+        let mut toggle = None;
+        egui::Grid::new("audit_results")
+            .num_columns(4)
+            .spacing([10.0, 6.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label(egui::RichText::new("#").strong().small());
+                ui.label(egui::RichText::new("NONCE").strong().small());
+                ui.label(
+                    egui::RichText::new("REPORTED SAFE TX HASH")
+                        .strong()
+                        .small(),
+                );
+                ui.label(egui::RichText::new("RESULT").strong().small());
+                ui.end_row();
 
-                ui.add_space(10.0);
-            }
+                for (i, entry) in self.audit_state.entries.iter().enumerate() {
+                    ui.label((entry.index + 1).to_string());
+                    ui.label(
+                        entry
+                            .nonce
+                            .map(|n| n.to_string())
+                            .unwrap_or_else(|| "—".to_string()),
+                    );
+                    ui.label(
+                        egui::RichText::new(Self::shorten_middle(&entry.reported_hash, 10, 8))
+                            .monospace(),
+                    );
 
-            // Calldata Decoding (before hashes, like Verify Safe API tab)
-            if let Some(ref mut decode) = self.offline_state.decode_result {
-                ui::section_header(ui, "Calldata Decoding");
-                decode::render_offline_decode_section(ui, decode, &self.safe_context);
-                ui.add_space(10.0);
-            }
+                    let (text, color) = match &entry.result {
+                        Ok((_, None)) => ("✅ Match", egui::Color32::from_rgb(100, 200, 100)),
+                        Ok((_, Some(_))) => ("❌ Mismatch", egui::Color32::from_rgb(220, 80, 80)),
+                        Err(_) => ("⚠️ Error", egui::Color32::from_rgb(220, 180, 50)),
+                    };
+                    let is_selected = self.audit_state.selected == Some(i);
+                    if ui
+                        .selectable_label(is_selected, egui::RichText::new(text).color(color))
+                        .on_hover_text("Show details")
+                        .clicked()
+                    {
+                        toggle = Some(i);
+                    }
+                    ui.end_row();
+                }
+            });
 
-            // Hashes
-            if let Some(ref hashes) = self.offline_state.hashes {
-                ui::section_header(ui, "Hash Results");
+        if let Some(i) = toggle {
+            self.audit_state.selected = if self.audit_state.selected == Some(i) {
+                None
+            } else {
+                Some(i)
+            };
+        }
 
-                egui::Grid::new("offline_hash_results")
-                    .num_columns(3)
-                    .spacing([10.0, 8.0])
+        let Some(entry) = self
+            .audit_state
+            .selected
+            .and_then(|i| self.audit_state.entries.get(i))
+        else {
+            return;
+        };
+
+        ui.add_space(15.0);
+        ui::section_header(ui, &format!("Entry #{} Details", entry.index + 1));
+        match &entry.result {
+            Ok((hashes, mismatch)) => {
+                egui::Grid::new("audit_entry_details")
+                    .num_columns(2)
+                    .spacing([10.0, 6.0])
                     .show(ui, |ui| {
-                        ui.label(egui::RichText::new("Domain Hash:").strong());
+                        ui.label(egui::RichText::new("Reported:").strong());
                         ui.label(
-                            egui::RichText::new(&hashes.domain_hash)
+                            egui::RichText::new(&entry.reported_hash)
                                 .monospace()
-                                .size(12.0),
+                                .size(11.0),
                         );
-                        if ui.small_button("📋").on_hover_text("Copy").clicked() {
-                            ui::copy_to_clipboard(&hashes.domain_hash);
-                        }
                         ui.end_row();
 
-                        ui.label(egui::RichText::new("Message Hash:").strong());
+                        ui.label(egui::RichText::new("Computed:").strong());
                         ui.label(
-                            egui::RichText::new(&hashes.message_hash)
+                            egui::RichText::new(&hashes.safe_tx_hash)
                                 .monospace()
-                                .size(12.0),
+                                .size(11.0),
                         );
-                        if ui.small_button("📋").on_hover_text("Copy").clicked() {
-                            ui::copy_to_clipboard(&hashes.message_hash);
-                        }
                         ui.end_row();
 
-                        ui.label(egui::RichText::new("Safe Tx Hash:").strong());
+                        ui.label(egui::RichText::new("Domain Hash:").strong());
                         ui.label(
-                            egui::RichText::new(&hashes.safe_tx_hash)
+                            egui::RichText::new(&hashes.domain_hash)
                                 .monospace()
-                                .size(12.0),
+                                .size(11.0),
                         );
-                        if ui.small_button("📋").on_hover_text("Copy").clicked() {
-                            ui::copy_to_clipboard(&hashes.safe_tx_hash);
-                        }
                         ui.end_row();
 
-                        // Ledger binary format
-                        let binary_literal = ui::hash_to_binary_literal(&hashes.safe_tx_hash);
-                        ui.label(egui::RichText::new("Ledger Binary:").strong());
-                        ui.label(egui::RichText::new(&binary_literal).monospace().size(12.0));
-                        if ui.small_button("📋").on_hover_text("Copy").clicked() {
-                            ui::copy_to_clipboard(&binary_literal);
-                        }
+                        ui.label(egui::RichText::new("Message Hash:").strong());
+                        ui.label(
+                            egui::RichText::new(&hashes.message_hash)
+                                .monospace()
+                                .size(11.0),
+                        );
                         ui.end_row();
                     });
+                if let Some(m) = mismatch {
+                    ui.add_space(8.0);
+                    ui::error_banner(
+                        ui,
+                        &format!(
+                            "Mismatch in {}: API={}, computed={}",
+                            m.field, m.api_value, m.user_value
+                        ),
+                    );
+                }
+            }
+            Err(e) => ui::error_message(ui, e),
+        }
+    }
+
+    fn run_audit(&mut self) {
+        self.audit_state.entries.clear();
+        self.audit_state.selected = None;
+        self.audit_state.error = None;
+
+        if self.safe_context.safe_address.trim().is_empty() {
+            self.audit_state.error =
+                Some("Enter the Safe address in the sidebar first".to_string());
+            return;
+        }
+
+        match audit_transaction_export(
+            &self.safe_context.chain_name,
+            &self.safe_context.safe_address,
+            &self.safe_context.safe_version,
+            &self.audit_state.json_input,
+        ) {
+            Ok(entries) if entries.is_empty() => {
+                self.audit_state.error = Some("No transactions found in export".to_string());
+            }
+            Ok(entries) => self.audit_state.entries = entries,
+            Err(e) => self.audit_state.error = Some(format!("{:#}", e)),
+        }
+    }
+
+    /// Read-only view of the audit log, newest first
+    fn render_history_tab(&mut self, ui: &mut egui::Ui) {
+        ui::styled_heading(ui, "History");
+        ui.label(
+            "Every verification in the Verify tab, recorded locally. Delete Data keeps \
+             this log; only Clear audit log removes it.",
+        );
+        ui.add_space(15.0);
+
+        let entries = self.safe_context.audit_log.entries();
+        let mut clear = false;
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(!entries.is_empty(), egui::Button::new("💾 Export JSONL"))
+                .on_hover_text("Save every entry, one JSON object per line")
+                .clicked()
+            {
+                let file_name = format!(
+                    "rusty-safe-audit-log-{}.jsonl",
+                    entries.last().map(|e| e.timestamp).unwrap_or_default()
+                );
+                self.history_export = Some(ui::save_file(
+                    &file_name,
+                    &self.safe_context.audit_log.to_jsonl(),
+                ));
+            }
+            ui.label(egui::RichText::new(format!("{} entries", entries.len())).weak());
+
+            if self.history_confirm_clear {
+                ui.label(
+                    egui::RichText::new(format!(
+                        "Permanently remove all {} entries?",
+                        entries.len()
+                    ))
+                    .color(egui::Color32::from_rgb(220, 80, 80)),
+                );
+                if ui.button("Clear").clicked() {
+                    clear = true;
+                }
+                if ui.button("Cancel").clicked() {
+                    self.history_confirm_clear = false;
+                }
+            } else if ui
+                .add_enabled(!entries.is_empty(), egui::Button::new("🗑 Clear audit log"))
+                .on_hover_text("Remove every entry; asks for confirmation")
+                .clicked()
+            {
+                self.history_confirm_clear = true;
+            }
+        });
+        if clear {
+            self.safe_context.clear_audit_log();
+            self.history_confirm_clear = false;
+            self.history_export = None;
+            return;
+        }
+        match &self.history_export {
+            Some(Ok(saved)) => {
+                ui.label(egui::RichText::new(saved).small().weak());
             }
+            Some(Err(e)) => ui::error_message(ui, e),
+            None => {}
+        }
+
+        if entries.is_empty() {
+            ui.add_space(10.0);
+            ui.label(egui::RichText::new("No verifications yet").weak());
+            return;
         }
+
+        ui.add_space(10.0);
+        egui::Grid::new("history_entries")
+            .num_columns(6)
+            .spacing([10.0, 6.0])
+            .striped(true)
+            .show(ui, |ui| {
+                for header in ["TIME", "CHAIN", "SAFE", "NONCE", "SAFE TX HASH", "RESULT"] {
+                    ui.label(egui::RichText::new(header).strong().small());
+                }
+                ui.end_row();
+
+                for entry in entries.iter().rev() {
+                    ui.label(egui::RichText::new(entry.time_utc()).small());
+                    ui.label(&entry.chain);
+                    ui.label(
+                        egui::RichText::new(Self::shorten_middle(&entry.safe_address, 6, 4))
+                            .monospace(),
+                    )
+                    .on_hover_text(&entry.safe_address);
+                    ui.label(entry.nonce.to_string());
+                    if entry.safe_tx_hash.is_empty() {
+                        ui.label(egui::RichText::new("not computed").weak());
+                    } else {
+                        ui.label(
+                            egui::RichText::new(Self::shorten_middle(&entry.safe_tx_hash, 10, 8))
+                                .monospace(),
+                        )
+                        .on_hover_text(&entry.safe_tx_hash);
+                    }
+
+                    ui.horizontal(|ui| {
+                        let (text, color) = match entry.matches_api {
+                            Some(true) => ("✅ Match", egui::Color32::from_rgb(100, 200, 100)),
+                            Some(false) => ("❌ Mismatch", egui::Color32::from_rgb(220, 80, 80)),
+                            None => ("— Not compared", egui::Color32::GRAY),
+                        };
+                        ui.label(egui::RichText::new(text).color(color));
+                        if let Some(level) = entry.top_severity {
+                            ui.label(egui::RichText::new(level.label()).small().strong());
+                        }
+                    });
+                    ui.end_row();
+                }
+            });
     }
 
     fn render_address_book_window(&mut self, ctx: &egui::Context) {
@@ -1932,6 +4866,8 @@ impl App {
 
                         let filtered_is_empty = filtered_entries.is_empty();
                         let mut to_remove = None;
+                        let mut to_toggle_trust = None;
+                        let mut to_toggle_multisend = None;
 
                         let available_width = ui.available_width();
                         egui::Grid::new("address_book_entries_v2")
@@ -2005,9 +4941,37 @@ impl App {
                                     ui.label(egui::RichText::new(chain_name).weak());
 
                                     // Actions Column
-                                    if ui.button("🗑").on_hover_text("Remove").clicked() {
-                                        to_remove = Some(*original_idx);
-                                    }
+                                    ui.horizontal(|ui| {
+                                        let (icon, hover) = if entry.trusted {
+                                            ("🛡", "Trusted - click to untrust")
+                                        } else {
+                                            ("◻", "Mark as trusted contract")
+                                        };
+                                        if ui.button(icon).on_hover_text(hover).clicked() {
+                                            to_toggle_trust = Some(*original_idx);
+                                        }
+                                        if entry.trusted {
+                                            let (icon, hover) = if entry.multisend {
+                                                (
+                                                    "🔀",
+                                                    "Trusted MultiSend - DELEGATECALLs to it \
+                                                     aren't flagged. Click to unset",
+                                                )
+                                            } else {
+                                                (
+                                                    "➡",
+                                                    "Mark as a MultiSend deployment, so \
+                                                     DELEGATECALLs to it are expected",
+                                                )
+                                            };
+                                            if ui.button(icon).on_hover_text(hover).clicked() {
+                                                to_toggle_multisend = Some(*original_idx);
+                                            }
+                                        }
+                                        if ui.button("🗑").on_hover_text("Remove").clicked() {
+                                            to_remove = Some(*original_idx);
+                                        }
+                                    });
                                     ui.end_row();
                                 }
                             });
@@ -2015,6 +4979,18 @@ impl App {
                         // Drop filtered_entries borrow before mutable operation
                         drop(filtered_entries);
 
+                        if let Some(idx) = to_toggle_trust {
+                            if let Some(entry) = self.safe_context.address_book.entries.get_mut(idx)
+                            {
+                                entry.trusted = !entry.trusted;
+                            }
+                        }
+                        if let Some(idx) = to_toggle_multisend {
+                            if let Some(entry) = self.safe_context.address_book.entries.get_mut(idx)
+                            {
+                                entry.multisend = !entry.multisend;
+                            }
+                        }
                         if let Some(idx) = to_remove {
                             self.safe_context.address_book.entries.remove(idx);
                         }
@@ -2086,12 +5062,23 @@ impl App {
                                         }
                                     });
                                 ui.end_row();
+
+                                ui.label("Decimals:");
+                                ui.add(
+                                    egui::TextEdit::singleline(&mut self.address_book_add_decimals)
+                                        .hint_text("Optional, for tokens")
+                                        .desired_width(280.0),
+                                );
+                                ui.end_row();
                             });
 
                         ui.add_space(8.0);
 
+                        let decimals = self.address_book_add_decimals.trim();
+                        let parsed_decimals = decimals.parse::<u8>().ok();
                         let can_add = !self.address_book_add_name.is_empty()
-                            && !self.address_book_add_addr.is_empty();
+                            && !self.address_book_add_addr.is_empty()
+                            && (decimals.is_empty() || parsed_decimals.is_some());
                         if ui::primary_button_enabled(ui, "➕ Add Entry", can_add).clicked() {
                             if let Ok(chain_id) =
                                 alloy::primitives::ChainId::of(&self.address_book_add_chain)
@@ -2101,10 +5088,14 @@ impl App {
                                         address: self.address_book_add_addr.clone(),
                                         name: self.address_book_add_name.clone(),
                                         chain_id: u64::from(chain_id),
+                                        trusted: false,
+                                        multisend: false,
+                                        decimals: parsed_decimals,
                                     },
                                 );
                                 self.address_book_add_addr.clear();
                                 self.address_book_add_name.clear();
+                                self.address_book_add_decimals.clear();
                                 self.address_book_error = Some("✓ Entry added".to_string());
                             }
                         }
@@ -2198,6 +5189,9 @@ impl App {
             self.offline_state.is_loading = false;
             match result {
                 OfflineDecodeResult::Success(decode) => {
+                    self.offline_state
+                        .findings
+                        .update_offline_decoded(&decode, self.offline_state.to.trim());
                     self.offline_state.decode_result = Some(decode);
                 }
                 OfflineDecodeResult::Error(e) => {
@@ -2207,13 +5201,8 @@ impl App {
         }
     }
 
-    fn trigger_offline_compute(&mut self, ctx: egui::Context) {
-        self.offline_state.is_loading = true;
-        self.offline_state.error = None;
-        self.offline_state.hashes = None;
-        self.offline_state.decode_result = None;
-
-        // Compute hashes synchronously (fast, doesn't need async)
+    /// Hashes and warnings for the offline inputs; sets the error on failure
+    fn compute_offline_hashes(&mut self) {
         match crate::hasher::compute_hashes(
             &self.safe_context.chain_name,
             &self.safe_context.safe_address,
@@ -2253,9 +5242,29 @@ impl App {
                     }
                 }
             }
-            Err(e) => {
+            Err(e) => self.offline_state.error = Some(format!("{:#}", e)),
+        }
+    }
+
+    fn trigger_offline_compute(&mut self, ctx: egui::Context) {
+        self.offline_state.is_loading = true;
+        self.offline_state.error = None;
+        self.offline_state.hashes = None;
+        self.offline_state.decode_result = None;
+        self.offline_state.warnings = SafeWarnings::new();
+        self.offline_state.warnings_error = None;
+        self.offline_state.findings = TxFindings::detect(
+            &self.safe_context.safe_address,
+            self.offline_state.to.trim(),
+            &self.offline_state.data,
+            self.offline_state.operation,
+        );
+
+        // Compute hashes synchronously (fast, doesn't need async)
+        if !self.offline_state.decode_only {
+            self.compute_offline_hashes();
+            if self.offline_state.error.is_some() {
                 self.offline_state.is_loading = false;
-                self.offline_state.error = Some(format!("{:#}", e));
                 return;
             }
         }
@@ -2263,28 +5272,59 @@ impl App {
         // Decode async (uses 4byte API)
         let data = self.offline_state.data.clone();
         let lookup = self.signature_lookup.clone();
+        let contracts = self.contract_lookup.clone();
+        let chain_id = chain_id_of(&self.safe_context.chain_name).ok();
         let result = Arc::clone(&self.offline_decode_result);
 
-        #[cfg(target_arch = "wasm32")]
-        {
-            use wasm_bindgen_futures::spawn_local;
-            spawn_local(async move {
-                let decode = decode::decode_offline(&data, &lookup).await;
-                let mut guard = lock_or_recover!(result);
-                *guard = Some(OfflineDecodeResult::Success(decode));
+        let task_result = Arc::clone(&result);
+        let spawned = self.spawn(async move {
+            let decode = decode::decode_offline(&data, &lookup).await;
+            let targets: Vec<String> = match &decode {
+                decode::OfflineDecodeResult::MultiSend { txs, .. } => {
+                    txs.iter().map(|tx| tx.to.clone()).collect()
+                }
+                _ => vec![],
+            };
+            *lock_or_recover!(task_result) = Some(OfflineDecodeResult::Success(decode));
+            ctx.request_repaint();
+
+            if let Some(chain_id) = chain_id {
+                contracts.resolve_all(chain_id, &targets).await;
                 ctx.request_repaint();
-            });
+            }
+        });
+        if let Err(e) = spawned {
+            *lock_or_recover!(result) = Some(OfflineDecodeResult::Error(e));
         }
+    }
 
-        #[cfg(not(target_arch = "wasm32"))]
-        {
-            std::thread::spawn(move || {
-                let rt = tokio::runtime::Runtime::new().unwrap();
-                let decode = rt.block_on(decode::decode_offline(&data, &lookup));
-                let mut guard = lock_or_recover!(result);
-                *guard = Some(OfflineDecodeResult::Success(decode));
-                ctx.request_repaint();
-            });
+    // =========================================================================
+    // ASYNC EXECUTION
+    // =========================================================================
+
+    /// Spawn async work onto the shared tokio runtime.
+    /// Fails if the runtime couldn't be created at startup.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn spawn<F>(&self, future: F) -> Result<(), String>
+    where
+        F: std::future::Future<Output = ()> + Send + 'static,
+    {
+        match &self.runtime {
+            Ok(rt) => {
+                rt.spawn(future);
+                Ok(())
+            }
+            Err(e) => Err(e.clone()),
         }
     }
+
+    /// Spawn async work on the browser event loop
+    #[cfg(target_arch = "wasm32")]
+    fn spawn<F>(&self, future: F) -> Result<(), String>
+    where
+        F: std::future::Future<Output = ()> + 'static,
+    {
+        wasm_bindgen_futures::spawn_local(future);
+        Ok(())
+    }
 }
@@ -0,0 +1,256 @@
+//! First-interaction counterparty heuristic
+//!
+//! Builds the set of addresses a Safe has paid or approved before from its
+//! executed transaction history, and flags transfers/approvals to addresses
+//! outside that set. A lookalike swapped in for a familiar recipient shows
+//! up as a brand-new counterparty.
+
+use alloy::primitives::U256;
+use eframe::egui;
+use std::collections::HashSet;
+
+use crate::decode::{self, MULTISEND_SELECTOR};
+use crate::state::SafeContext;
+use crate::ui;
+
+//─────────────────────────────────────────────────────────────────────────────
+// EXTRACTION
+//─────────────────────────────────────────────────────────────────────────────
+
+/// How value reaches a counterparty
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CounterpartyKind {
+    /// Native currency sent directly
+    Native,
+    /// Token transfer recipient
+    Transfer,
+    /// Token spender or operator
+    Approval,
+}
+
+impl CounterpartyKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            CounterpartyKind::Native => "native transfer",
+            CounterpartyKind::Transfer => "token transfer",
+            CounterpartyKind::Approval => "approval",
+        }
+    }
+}
+
+/// An address that receives value or an allowance from a transaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counterparty {
+    /// MultiSend index, `None` for a single call
+    pub index: Option<usize>,
+    /// Lowercase address
+    pub address: String,
+    pub kind: CounterpartyKind,
+}
+
+/// Selector, counterparty argument position and kind. Read straight from the
+/// calldata so history analysis needs no signature lookups.
+const COUNTERPARTY_SELECTORS: &[(&str, usize, CounterpartyKind)] = &[
+    // transfer(address,uint256)
+    ("0xa9059cbb", 0, CounterpartyKind::Transfer),
+    // transferFrom(address,address,uint256)
+    ("0x23b872dd", 1, CounterpartyKind::Transfer),
+    // safeTransferFrom(address,address,uint256)
+    ("0x42842e0e", 1, CounterpartyKind::Transfer),
+    // safeTransferFrom(address,address,uint256,bytes)
+    ("0xb88d4fde", 1, CounterpartyKind::Transfer),
+    // safeTransferFrom(address,address,uint256,uint256,bytes)
+    ("0xf242432a", 1, CounterpartyKind::Transfer),
+    // safeBatchTransferFrom(address,address,uint256[],uint256[],bytes)
+    ("0x2eb2c2d6", 1, CounterpartyKind::Transfer),
+    // approve(address,uint256)
+    ("0x095ea7b3", 0, CounterpartyKind::Approval),
+    // increaseAllowance(address,uint256)
+    ("0x39509351", 0, CounterpartyKind::Approval),
+    // setApprovalForAll(address,bool)
+    ("0xa22cb465", 0, CounterpartyKind::Approval),
+];
+
+/// Address in the `arg`-th static calldata word, lowercase
+fn address_arg(data: &[u8], arg: usize) -> Option<String> {
+    let start = 4 + arg * 32;
+    let word = data.get(start..start + 32)?;
+    Some(format!("0x{}", alloy::hex::encode(&word[12..])))
+}
+
+fn call_counterparty(
+    index: Option<usize>,
+    to: &str,
+    value: U256,
+    data: &str,
+) -> Option<Counterparty> {
+    let data = data.trim();
+    if data.is_empty() || data == "0x" {
+        return (!value.is_zero()).then(|| Counterparty {
+            index,
+            address: to.trim().to_lowercase(),
+            kind: CounterpartyKind::Native,
+        });
+    }
+
+    let selector = data.get(..10)?.to_lowercase();
+    let (_, arg, kind) = COUNTERPARTY_SELECTORS
+        .iter()
+        .find(|(sel, _, _)| *sel == selector)?;
+    let bytes = alloy::hex::decode(data.strip_prefix("0x").unwrap_or(data)).ok()?;
+    Some(Counterparty {
+        index,
+        address: address_arg(&bytes, *arg)?,
+        kind: *kind,
+    })
+}
+
+/// Counterparties of a transaction. `value` is decimal wei, as the Safe
+/// service reports it. MultiSend batches are unpacked one level.
+pub fn extract(to: &str, value: &str, data: &str) -> Vec<Counterparty> {
+    let value = U256::from_str_radix(value.trim(), 10).unwrap_or_default();
+    let is_multisend = data
+        .trim()
+        .get(..10)
+        .is_some_and(|s| s.eq_ignore_ascii_case(MULTISEND_SELECTOR));
+    if !is_multisend {
+        return call_counterparty(None, to, value, data)
+            .into_iter()
+            .collect();
+    }
+
+    let Ok(txs) = decode::decode_multisend_bytes(data.trim())
+        .and_then(|packed| decode::unpack_multisend_transactions(&packed))
+    else {
+        return vec![];
+    };
+    txs.iter()
+        .filter_map(|tx| {
+            let value = U256::from_str_radix(&tx.value, 10).unwrap_or_default();
+            call_counterparty(Some(tx.index), &tx.to, value, &tx.data)
+        })
+        .collect()
+}
+
+//─────────────────────────────────────────────────────────────────────────────
+// HISTORY
+//─────────────────────────────────────────────────────────────────────────────
+
+/// Addresses a Safe has interacted with, from its executed transactions
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CounterpartyHistory {
+    pub chain_name: String,
+    /// Lowercase Safe address the history belongs to
+    pub safe_address: String,
+    /// Lowercase counterparty addresses
+    pub known: HashSet<String>,
+    /// Number of executed transactions analyzed
+    pub scanned: usize,
+}
+
+impl CounterpartyHistory {
+    pub fn new(chain_name: &str, safe_address: &str) -> Self {
+        Self {
+            chain_name: chain_name.to_string(),
+            safe_address: safe_address.trim().to_lowercase(),
+            ..Default::default()
+        }
+    }
+
+    /// Record the counterparties of one executed transaction
+    pub fn add_transaction(&mut self, to: &str, value: &str, data: &str) {
+        self.scanned += 1;
+        self.known
+            .extend(extract(to, value, data).into_iter().map(|c| c.address));
+    }
+
+    /// Whether this history was built for the given Safe
+    pub fn is_for(&self, chain_name: &str, safe_address: &str) -> bool {
+        self.chain_name == chain_name && self.safe_address == safe_address.trim().to_lowercase()
+    }
+
+    /// Counterparties of a transaction this Safe has never paid or approved.
+    /// The Safe itself is never a new counterparty.
+    pub fn first_interactions(&self, to: &str, value: &str, data: &str) -> Vec<Counterparty> {
+        extract(to, value, data)
+            .into_iter()
+            .filter(|c| c.address != self.safe_address && !self.known.contains(&c.address))
+            .collect()
+    }
+}
+
+//─────────────────────────────────────────────────────────────────────────────
+// UI
+//─────────────────────────────────────────────────────────────────────────────
+
+/// Informational notes for first-time counterparties
+pub fn render_first_interactions(ui: &mut egui::Ui, found: &[Counterparty], ctx: &SafeContext) {
+    for counterparty in found {
+        let prefix = counterparty
+            .index
+            .map(|i| format!("Tx {}: ", i + 1))
+            .unwrap_or_default();
+        ui.horizontal(|ui| {
+            ui.label(
+                egui::RichText::new(format!(
+                    "ℹ {}First interaction with this address from this Safe ({})",
+                    prefix,
+                    counterparty.kind.label()
+                ))
+                .color(egui::Color32::from_rgb(120, 170, 230)),
+            );
+            let name = ctx.name_of(&counterparty.address);
+            ui::address_link(ui, &ctx.chain_name, &counterparty.address, name);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAFE: &str = "0x1111111111111111111111111111111111111111";
+    const TOKEN: &str = "0x2222222222222222222222222222222222222222";
+    const ALICE: &str = "0x3333333333333333333333333333333333333333";
+    const MALLORY: &str = "0x4444444444444444444444444444444444444444";
+
+    fn transfer_data(to: &str) -> String {
+        format!(
+            "0xa9059cbb000000000000000000000000{}{:064x}",
+            to.trim_start_matches("0x"),
+            1000
+        )
+    }
+
+    #[test]
+    fn test_extract() {
+        let transfer = extract(TOKEN, "0", &transfer_data(ALICE));
+        assert_eq!(transfer.len(), 1);
+        assert_eq!(transfer[0].address, ALICE);
+        assert_eq!(transfer[0].kind, CounterpartyKind::Transfer);
+
+        let native = extract(ALICE, "5", "0x");
+        assert_eq!(native[0].kind, CounterpartyKind::Native);
+
+        // Zero-value call to an unknown method has no counterparty
+        assert!(extract(ALICE, "0", "0x12345678").is_empty());
+    }
+
+    #[test]
+    fn test_first_interactions() {
+        let mut history = CounterpartyHistory::new("ethereum", SAFE);
+        history.add_transaction(TOKEN, "0", &transfer_data(ALICE));
+        history.add_transaction(SAFE, "0", "0x");
+        assert_eq!(history.scanned, 2);
+
+        assert!(history
+            .first_interactions(TOKEN, "0", &transfer_data(ALICE))
+            .is_empty());
+        let new = history.first_interactions(TOKEN, "0", &transfer_data(MALLORY));
+        assert_eq!(new.len(), 1);
+        assert_eq!(new[0].address, MALLORY);
+
+        // Paying the Safe itself isn't a new counterparty
+        assert!(history.first_interactions(SAFE, "1", "0x").is_empty());
+    }
+}
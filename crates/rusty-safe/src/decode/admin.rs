@@ -0,0 +1,215 @@
+//! Emergency and admin call recognition
+//!
+//! Flags protocol-admin operations a Safe might execute as a protocol owner:
+//! fund sweeps/rescues, emergency withdrawals, pausing and implementation
+//! upgrades. Recognition is by method name only; anything else keeps decoding
+//! generically.
+
+use super::types::*;
+
+/// Kind of admin operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdminKind {
+    /// Moves the contract's whole native balance out
+    Sweep,
+    /// Moves tokens held by the contract out
+    Rescue,
+    /// Bypasses normal withdrawal logic
+    EmergencyWithdraw,
+    Pause,
+    Unpause,
+    /// Proxy or beacon implementation change
+    Upgrade,
+}
+
+impl AdminKind {
+    /// What the call does, for the warning text
+    pub fn effect(&self) -> &'static str {
+        match self {
+            AdminKind::Sweep => "sweeps the contract's balance out",
+            AdminKind::Rescue => "moves tokens held by the contract out",
+            AdminKind::EmergencyWithdraw => "withdraws funds bypassing normal withdrawal logic",
+            AdminKind::Pause => "halts the protocol's operations until unpaused",
+            AdminKind::Unpause => "resumes the protocol's operations",
+            AdminKind::Upgrade => "replaces the contract's implementation",
+        }
+    }
+
+    /// Whether the call moves funds (and so has a destination worth checking)
+    pub fn moves_funds(&self) -> bool {
+        matches!(
+            self,
+            AdminKind::Sweep | AdminKind::Rescue | AdminKind::EmergencyWithdraw
+        )
+    }
+}
+
+/// A recognized admin call
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdminCall {
+    /// MultiSend index, `None` for a single call
+    pub index: Option<usize>,
+    pub method: String,
+    pub kind: AdminKind,
+    /// Contract being called
+    pub target: String,
+    /// Where moved funds go, if an address argument is present
+    pub destination: Option<String>,
+    /// New implementation for upgrades
+    pub implementation: Option<String>,
+}
+
+/// Classify a method name (case-insensitive, ignoring underscores)
+fn classify_method(method: &str) -> Option<AdminKind> {
+    let name: String = method
+        .chars()
+        .filter(|c| *c != '_')
+        .collect::<String>()
+        .to_lowercase();
+    let kind = match name.as_str() {
+        "pause" => AdminKind::Pause,
+        "unpause" => AdminKind::Unpause,
+        "upgradeto" | "upgradetoandcall" | "setimplementation" => AdminKind::Upgrade,
+        n if n.starts_with("sweep") => AdminKind::Sweep,
+        n if n.starts_with("rescue")
+            || n.starts_with("recovererc")
+            || n.starts_with("recovertoken") =>
+        {
+            AdminKind::Rescue
+        }
+        n if n.starts_with("emergency") => AdminKind::EmergencyWithdraw,
+        _ => return None,
+    };
+    Some(kind)
+}
+
+/// Classify one decoded call against `target`. The destination is the last
+/// address argument, which fits `sweepETH(to)`, `rescueTokens(token, to, amount)`
+/// and `sweepToken(token, amountMin, recipient)`. For upgrades the new
+/// implementation is the first address argument.
+pub fn classify_admin_call(
+    index: Option<usize>,
+    target: &str,
+    call: CallParams<'_>,
+) -> Option<AdminCall> {
+    let (method, params) = call;
+    let kind = classify_method(method)?;
+    let mut addresses = params
+        .iter()
+        .filter(|(typ, _)| *typ == "address")
+        .map(|(_, value)| value.to_string());
+    let (destination, implementation) = match kind {
+        AdminKind::Upgrade => (None, addresses.next()),
+        k if k.moves_funds() => (addresses.last(), None),
+        _ => (None, None),
+    };
+
+    Some(AdminCall {
+        index,
+        method: method.to_string(),
+        kind,
+        target: target.to_string(),
+        destination,
+        implementation,
+    })
+}
+
+/// Find admin calls in a Verify-tab decode. `target` is the transaction's `to`.
+pub fn detect_admin_calls(decode: &DecodedTransaction, target: &str) -> Vec<AdminCall> {
+    match &decode.kind {
+        TransactionKind::Single(single) => single
+            .call()
+            .and_then(|call| classify_admin_call(None, target, call))
+            .into_iter()
+            .collect(),
+        TransactionKind::MultiSend(multi) => multi
+            .transactions
+            .iter()
+            .filter_map(|tx| classify_admin_call(Some(tx.index), &tx.to, tx.call()?))
+            .collect(),
+        TransactionKind::UserOp(bundle) => bundle
+            .ops
+            .iter()
+            .filter_map(|op| {
+                let call = op.decode.as_ref()?.call()?;
+                classify_admin_call(Some(op.index), &op.sender, call)
+            })
+            .collect(),
+        TransactionKind::Empty | TransactionKind::Unknown => vec![],
+    }
+}
+
+/// Find admin calls in an offline decode
+pub fn detect_offline_admin_calls(decode: &OfflineDecodeResult, target: &str) -> Vec<AdminCall> {
+    match decode {
+        OfflineDecodeResult::Single { local, .. } => {
+            classify_admin_call(None, target, local.call())
+                .into_iter()
+                .collect()
+        }
+        OfflineDecodeResult::MultiSend { txs, .. } => txs
+            .iter()
+            .filter_map(|tx| {
+                let local = tx.local_decode.as_ref()?;
+                classify_admin_call(Some(tx.index), &tx.to, local.call())
+            })
+            .collect(),
+        OfflineDecodeResult::Empty | OfflineDecodeResult::RawHex(_) => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROTOCOL: &str = "0x1111111111111111111111111111111111111111";
+    const TOKEN: &str = "0x2222222222222222222222222222222222222222";
+    const DEST: &str = "0x3333333333333333333333333333333333333333";
+
+    #[test]
+    fn test_classify_method() {
+        assert_eq!(classify_method("sweepETH"), Some(AdminKind::Sweep));
+        assert_eq!(classify_method("rescueTokens"), Some(AdminKind::Rescue));
+        assert_eq!(classify_method("recoverERC20"), Some(AdminKind::Rescue));
+        assert_eq!(
+            classify_method("emergencyWithdraw"),
+            Some(AdminKind::EmergencyWithdraw)
+        );
+        assert_eq!(classify_method("_pause"), Some(AdminKind::Pause));
+        assert_eq!(classify_method("unpause"), Some(AdminKind::Unpause));
+        assert_eq!(classify_method("transfer"), None);
+        assert_eq!(classify_method("pauseFor"), None);
+        assert_eq!(classify_method("upgradeTo"), Some(AdminKind::Upgrade));
+        assert_eq!(
+            classify_method("upgradeToAndCall"),
+            Some(AdminKind::Upgrade)
+        );
+    }
+
+    #[test]
+    fn test_destination() {
+        let rescue = classify_admin_call(
+            None,
+            PROTOCOL,
+            (
+                "rescueTokens",
+                vec![("address", TOKEN), ("address", DEST), ("uint256", "5")],
+            ),
+        )
+        .unwrap();
+        assert_eq!(rescue.destination.as_deref(), Some(DEST));
+
+        // pause() doesn't move funds
+        let pause = classify_admin_call(None, PROTOCOL, ("pause", vec![])).unwrap();
+        assert_eq!(pause.destination, None);
+
+        let upgrade = classify_admin_call(
+            None,
+            PROTOCOL,
+            ("upgradeToAndCall", vec![("address", DEST), ("bytes", "0x")]),
+        )
+        .unwrap();
+        assert_eq!(upgrade.implementation.as_deref(), Some(DEST));
+        assert_eq!(upgrade.destination, None);
+    }
+}
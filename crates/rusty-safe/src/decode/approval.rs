@@ -0,0 +1,142 @@
+//! ERC-20 allowance grants
+//!
+//! Flags `approve(address,uint256)` calls. An unlimited allowance lets the
+//! spender move every current and future token balance of the Safe, so it is
+//! warned about; finite allowances are listed with their amount so it can be
+//! sanity-checked against what the transaction is meant to do.
+
+use alloy::primitives::U256;
+
+use super::types::*;
+
+/// Allowances at or above `type(uint96).max` base units are treated as
+/// unlimited. That is tens of billions of tokens even at 18 decimals, and
+/// covers the common "max" sentinels below `type(uint256).max` (uint96,
+/// uint128, uint160).
+pub(super) const UNLIMITED_MIN: U256 = U256::from_limbs([u64::MAX, u32::MAX as u64, 0, 0]);
+
+/// A recognized `approve` call
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Approval {
+    /// MultiSend index, `None` for a single call
+    pub index: Option<usize>,
+    /// Token contract being called
+    pub token: String,
+    pub spender: String,
+    /// Allowance in the token's base units
+    pub amount: U256,
+}
+
+impl Approval {
+    /// `type(uint256).max` or large enough to be unlimited in practice
+    pub fn is_unlimited(&self) -> bool {
+        self.amount >= UNLIMITED_MIN
+    }
+}
+
+/// Classify one decoded call against `token`. ERC-721 `approve` has the
+/// same signature; its token id comes through as a finite amount.
+pub fn classify_approval(
+    index: Option<usize>,
+    token: &str,
+    call: CallParams<'_>,
+) -> Option<Approval> {
+    let (method, params) = call;
+    let [("address", spender), ("uint256", amount)] = params.as_slice() else {
+        return None;
+    };
+    if method != "approve" {
+        return None;
+    }
+
+    Some(Approval {
+        index,
+        token: token.to_string(),
+        spender: spender.to_string(),
+        amount: amount.trim().parse().ok()?,
+    })
+}
+
+/// Find approvals in a Verify-tab decode. `target` is the transaction's `to`.
+pub fn detect_approvals(decode: &DecodedTransaction, target: &str) -> Vec<Approval> {
+    match &decode.kind {
+        TransactionKind::Single(single) => single
+            .call()
+            .and_then(|call| classify_approval(None, target, call))
+            .into_iter()
+            .collect(),
+        TransactionKind::MultiSend(multi) => multi
+            .transactions
+            .iter()
+            .filter_map(|tx| classify_approval(Some(tx.index), &tx.to, tx.call()?))
+            .collect(),
+        // UserOperations approve from the sender account, not this Safe
+        TransactionKind::UserOp(_) | TransactionKind::Empty | TransactionKind::Unknown => {
+            vec![]
+        }
+    }
+}
+
+/// Find approvals in an offline decode
+pub fn detect_offline_approvals(decode: &OfflineDecodeResult, target: &str) -> Vec<Approval> {
+    match decode {
+        OfflineDecodeResult::Single { local, .. } => classify_approval(None, target, local.call())
+            .into_iter()
+            .collect(),
+        OfflineDecodeResult::MultiSend { txs, .. } => txs
+            .iter()
+            .filter_map(|tx| {
+                let local = tx.local_decode.as_ref()?;
+                classify_approval(Some(tx.index), &tx.to, local.call())
+            })
+            .collect(),
+        OfflineDecodeResult::Empty | OfflineDecodeResult::RawHex(_) => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOKEN: &str = "0x1111111111111111111111111111111111111111";
+    const SPENDER: &str = "0x2222222222222222222222222222222222222222";
+
+    fn approve(amount: &str) -> Option<Approval> {
+        classify_approval(
+            None,
+            TOKEN,
+            ("approve", vec![("address", SPENDER), ("uint256", amount)]),
+        )
+    }
+
+    #[test]
+    fn test_unlimited() {
+        let max = U256::MAX.to_string();
+        assert!(approve(&max).unwrap().is_unlimited());
+        // uint160 max, the Permit2 sentinel
+        let uint160_max = (U256::MAX >> 96usize).to_string();
+        assert!(approve(&uint160_max).unwrap().is_unlimited());
+        // uint96 max, used by tokens with 96-bit balances (COMP, UNI)
+        let uint96_max = ((U256::from(1) << 96usize) - U256::from(1)).to_string();
+        assert!(approve(&uint96_max).unwrap().is_unlimited());
+        assert_eq!(UNLIMITED_MIN, (U256::from(1) << 96usize) - U256::from(1));
+        let below = ((U256::from(1) << 96usize) - U256::from(2)).to_string();
+        assert!(!approve(&below).unwrap().is_unlimited());
+    }
+
+    #[test]
+    fn test_finite() {
+        let approval = approve("1000000").unwrap();
+        assert!(!approval.is_unlimited());
+        assert_eq!(approval.amount, U256::from(1_000_000));
+        assert_eq!(approval.spender, SPENDER);
+    }
+
+    #[test]
+    fn test_not_an_approval() {
+        let transfer = ("transfer", vec![("address", SPENDER), ("uint256", "5")]);
+        assert!(classify_approval(None, TOKEN, transfer).is_none());
+        let malformed = ("approve", vec![("address", SPENDER)]);
+        assert!(classify_approval(None, TOKEN, malformed).is_none());
+    }
+}
@@ -14,6 +14,38 @@ pub fn compare_decodes(api: Option<&ApiDecode>, local: Option<&LocalDecode>) ->
     }
 }
 
+/// Check the explorer's verified-ABI decode against the API and signature
+/// decodes. Sources that didn't decode are left out of the agreement.
+pub fn compare_with_explorer(
+    api: Option<&ApiDecode>,
+    local: Option<&LocalDecode>,
+    explorer: Result<LocalDecode, String>,
+) -> ExplorerCheck {
+    let mut agreement = Vec::new();
+    if let Ok(verified) = &explorer {
+        if let Some(api) = api {
+            agreement.push((DecodeSource::Api, compare_both(api, verified).is_match()));
+        }
+        if let Some(local) = local {
+            agreement.push((DecodeSource::Signature, locals_agree(local, verified)));
+        }
+    }
+    ExplorerCheck {
+        decode: explorer,
+        agreement,
+    }
+}
+
+/// Whether two independent decodes read the same method and values
+fn locals_agree(a: &LocalDecode, b: &LocalDecode) -> bool {
+    normalize_method(&a.method) == normalize_method(&b.method)
+        && a.params.len() == b.params.len()
+        && a.params
+            .iter()
+            .zip(&b.params)
+            .all(|(pa, pb)| pa.typ == pb.typ && values_match(&pa.value, &pb.value, &pb.typ))
+}
+
 /// Compare when both decodes are available
 fn compare_both(api: &ApiDecode, local: &LocalDecode) -> ComparisonResult {
     // Compare method names (normalize)
@@ -172,6 +204,55 @@ mod tests {
         assert_eq!(normalize_int(max_u256), max_u256);
     }
 
+    fn local(method: &str, value: &str) -> LocalDecode {
+        LocalDecode {
+            signature: format!("{}(uint256)", method),
+            method: method.to_string(),
+            params: vec![LocalParam {
+                typ: "uint256".to_string(),
+                value: value.to_string(),
+                children: vec![],
+            }],
+            verified: true,
+            alternatives: vec![],
+        }
+    }
+
+    #[test]
+    fn test_compare_with_explorer() {
+        let api = ApiDecode {
+            method: "burn".to_string(),
+            params: vec![ApiParam {
+                name: "amount".to_string(),
+                typ: "uint256".to_string(),
+                value: "1000".to_string(),
+            }],
+        };
+
+        let check = compare_with_explorer(
+            Some(&api),
+            Some(&local("burn", "0x3e8")),
+            Ok(local("burn", "1000")),
+        );
+        assert_eq!(
+            check.agreement,
+            vec![(DecodeSource::Api, true), (DecodeSource::Signature, true)]
+        );
+        assert!(!check.is_mismatch());
+
+        // The API and 4byte agree with each other but not with the verified ABI
+        let check = compare_with_explorer(
+            Some(&api),
+            Some(&local("burn", "1000")),
+            Ok(local("mint", "1000")),
+        );
+        assert!(check.disagrees_with_all());
+
+        let check = compare_with_explorer(Some(&api), None, Err("not verified".into()));
+        assert!(check.agreement.is_empty());
+        assert!(!check.disagrees_with_all());
+    }
+
     #[test]
     fn test_values_match() {
         assert!(values_match("0xAbCd", "0xabcd", "address"));
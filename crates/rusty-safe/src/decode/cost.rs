@@ -0,0 +1,85 @@
+//! Calldata size and gas cost estimation
+//!
+//! Uses the EIP-2028 intrinsic calldata pricing (16 gas per non-zero byte,
+//! 4 per zero byte). On rollups the same bytes are also posted to L1, and
+//! the L1 data fee that pays for that, usually the dominant cost there, is
+//! not estimated.
+
+use super::parser;
+
+/// Gas per zero calldata byte
+const ZERO_BYTE_GAS: u64 = 4;
+/// Gas per non-zero calldata byte (EIP-2028)
+const NONZERO_BYTE_GAS: u64 = 16;
+
+/// Chains that post transaction data to Ethereum L1
+const L1_DATA_CHAINS: &[&str] = &[
+    "arbitrum",
+    "base",
+    "base-sepolia",
+    "blast",
+    "linea",
+    "mantle",
+    "optimism",
+    "polygon-zkevm",
+    "scroll",
+    "worldchain",
+    "xlayer",
+    "zksync",
+];
+
+/// Size and intrinsic gas of a calldata blob
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CalldataCost {
+    pub bytes: usize,
+    pub zero_bytes: usize,
+    /// Intrinsic calldata gas on the execution chain
+    pub gas: u64,
+}
+
+/// Compute calldata size and gas for `data`.
+/// Returns `None` if `data` isn't valid hex.
+pub fn calldata_cost(data: &str) -> Option<CalldataCost> {
+    let hex_data = data.trim().strip_prefix("0x").unwrap_or(data.trim());
+    let bytes = alloy::hex::decode(hex_data).ok()?;
+
+    let zero_bytes = bytes.iter().filter(|&&b| b == 0).count();
+    let nonzero_bytes = bytes.len() - zero_bytes;
+    let gas = zero_bytes as u64 * ZERO_BYTE_GAS + nonzero_bytes as u64 * NONZERO_BYTE_GAS;
+
+    Some(CalldataCost {
+        bytes: bytes.len(),
+        zero_bytes,
+        gas,
+    })
+}
+
+/// Whether the chain is a rollup that pays an L1 data fee
+pub fn is_l1_data_chain(chain_name: &str) -> bool {
+    L1_DATA_CHAINS.contains(&chain_name.to_lowercase().as_str())
+}
+
+/// Size of the packed `transactions` bytes inside a MultiSend call
+pub fn multisend_packed_size(data: &str) -> Option<usize> {
+    parser::decode_multisend_bytes(data).ok().map(|b| b.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calldata_cost() {
+        // selector (4 non-zero) + 4 zero bytes
+        let cost = calldata_cost("0xa9059cbb00000000").unwrap();
+        assert_eq!(cost.bytes, 8);
+        assert_eq!(cost.zero_bytes, 4);
+        assert_eq!(cost.gas, 4 * 16 + 4 * 4);
+
+        assert_eq!(calldata_cost("0x").unwrap().bytes, 0);
+        assert!(calldata_cost("0xzz").is_none());
+
+        assert!(is_l1_data_chain("Base"));
+        assert!(!is_l1_data_chain("ethereum"));
+    }
+}
@@ -0,0 +1,115 @@
+//! Decode with the block explorer's verified ABI
+//!
+//! A third, independent decode source: the ABI the target contract was
+//! verified with on Etherscan (via its multichain v2 API). Opt-in, since it
+//! needs an API key. For a proxy the explorer returns the proxy's own ABI,
+//! so implementation calls usually come back as a missing selector rather
+//! than a mismatch.
+
+use std::time::Duration;
+
+use alloy::json_abi::JsonAbi;
+use serde::Deserialize;
+
+use super::parser::decode_with_signature;
+use super::types::LocalDecode;
+use crate::hasher::with_timeout;
+
+const ETHERSCAN_V2_API: &str = "https://api.etherscan.io/v2/api";
+/// The decode counts as in-flight work, so a stalled explorer mustn't hold
+/// up the verification
+const EXPLORER_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Etherscan wraps every result; `status` is "1" on success, otherwise
+/// `result` holds the error message
+#[derive(Debug, Deserialize)]
+struct EtherscanResponse {
+    status: String,
+    result: String,
+}
+
+/// Verified ABI of a contract. The URL carries the API key, so errors
+/// never include it.
+async fn fetch_verified_abi(
+    chain_id: u64,
+    address: &str,
+    api_key: &str,
+) -> Result<JsonAbi, String> {
+    let url = format!(
+        "{}?chainid={}&module=contract&action=getabi&address={}&apikey={}",
+        ETHERSCAN_V2_API,
+        chain_id,
+        address.trim(),
+        api_key.trim()
+    );
+    let response = reqwest::get(&url)
+        .await
+        .map_err(|e| format!("Explorer request failed: {}", e.without_url()))?;
+    if !response.status().is_success() {
+        return Err(format!("Explorer request failed: {}", response.status()));
+    }
+    let body: EtherscanResponse = response
+        .json()
+        .await
+        .map_err(|e| format!("Unexpected explorer response: {}", e.without_url()))?;
+    if body.status != "1" {
+        return Err(body.result);
+    }
+    serde_json::from_str(&body.result).map_err(|e| format!("Invalid verified ABI: {}", e))
+}
+
+/// Decode calldata with the function its selector names in `abi`
+pub fn decode_with_abi(abi: &JsonAbi, data: &str) -> Result<LocalDecode, String> {
+    let hex = data.trim().trim_start_matches("0x");
+    let selector = hex.get(..8).ok_or("Calldata is too short for a selector")?;
+    let function = abi
+        .functions()
+        .find(|f| alloy::hex::encode(f.selector()).eq_ignore_ascii_case(selector))
+        .ok_or_else(|| format!("Selector 0x{} isn't in the verified ABI", selector))?;
+    decode_with_signature(data, &function.signature(), true).map_err(|e| e.to_string())
+}
+
+/// Fetch the verified ABI of `address` and decode `data` with it
+pub async fn explorer_decode(
+    chain_id: u64,
+    address: &str,
+    data: &str,
+    api_key: &str,
+) -> Result<LocalDecode, String> {
+    let fetch = async {
+        fetch_verified_abi(chain_id, address, api_key)
+            .await
+            .map_err(|e| eyre::eyre!(e))
+    };
+    let abi = with_timeout(EXPLORER_TIMEOUT, fetch)
+        .await
+        .map_err(|e| format!("Explorer request failed: {}", e))?;
+    decode_with_abi(&abi, data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ERC20_ABI: &str = r#"[
+        {"type":"function","name":"transfer","stateMutability":"nonpayable",
+         "inputs":[{"name":"to","type":"address"},{"name":"amount","type":"uint256"}],
+         "outputs":[{"name":"","type":"bool"}]}
+    ]"#;
+
+    #[test]
+    fn test_decode_with_abi() {
+        let abi: JsonAbi = serde_json::from_str(ERC20_ABI).unwrap();
+        let data = "0xa9059cbb\
+            000000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa96045\
+            00000000000000000000000000000000000000000000000000000000000003e8";
+
+        let decode = decode_with_abi(&abi, data).unwrap();
+        assert_eq!(decode.method, "transfer");
+        assert_eq!(decode.params.len(), 2);
+        assert!(decode.verified);
+
+        let err = decode_with_abi(&abi, "0x095ea7b3").unwrap_err();
+        assert!(err.contains("0x095ea7b3"));
+    }
+}
@@ -2,29 +2,55 @@
 //!
 //! Provides side-by-side comparison of:
 //! - Safe API's decoded calldata
-//! - Independent decode via 4byte signature lookup
+//! - Independent decode via 4byte signature lookup, with bundled standard
+//!   ABIs as a fallback
+//! - Optionally, the block explorer's verified ABI for the target contract
 //!
-//! Supports nested calls (MultiSend batches).
+//! Supports nested calls (MultiSend batches, ERC-4337 UserOperations, Safe
+//! transactions executed on another Safe).
 
+mod admin;
+mod approval;
 mod compare;
+pub mod cost;
+mod explorer;
+mod nested_safe;
 mod offline;
 pub mod parser;
+#[cfg(test)]
+mod perf;
+mod permit;
+mod relay;
 mod sourcify;
+mod standard;
 pub mod types;
 pub mod ui;
+mod userop;
 mod verify;
+mod wrapped;
 
 // Re-exports
-pub use compare::compare_decodes;
+pub use admin::{detect_admin_calls, detect_offline_admin_calls, AdminCall};
+pub use approval::{detect_approvals, detect_offline_approvals, Approval};
+pub use compare::{compare_decodes, compare_with_explorer};
+pub use explorer::explorer_decode;
+pub use nested_safe::{classify_nested_safe, NestedSafeTx};
 pub use offline::decode_offline;
 pub use parser::{
-    decode_multisend_bytes, decode_with_signature, get_selector, parse_initial,
-    unpack_multisend_transactions, MULTISEND_SELECTOR,
+    continue_parse, decode_multisend_bytes, decode_with_candidates, decode_with_signature,
+    get_selector, parse_initial, parse_web_ui_decode, unpack_multisend_transactions,
+    MULTISEND_SELECTOR,
 };
-pub use sourcify::{SignatureInfo, SignatureLookup};
+pub use permit::{decode_permit, detect_permits, DeadlineStatus, PermitInfo};
+pub use relay::{classify_relay, RelayCall};
+pub use sourcify::{ContractLookup, ContractMeta, SignatureInfo, SignatureLookup};
 pub use types::*;
-pub use ui::{render_decode_section, render_offline_decode_section, render_single_comparison};
-pub use verify::verify_multisend_batch;
+pub use ui::{
+    render_admin_warnings, render_approval_warnings, render_contract_name, render_decode_section,
+    render_nested_safe_summary, render_offline_decode_section, render_permit_warnings,
+    render_relay_summary, render_single_comparison, render_web_ui_decode, render_wrap_summary,
+};
+pub use verify::{verify_multisend_batch, verify_user_ops};
 
 /// Log to console (works in both WASM and native)
 ///
@@ -44,3 +70,146 @@ macro_rules! decode_log {
 
 // Make macro available to submodules
 pub(crate) use decode_log;
+
+/// One spreadsheet row per MultiSend sub-transaction. Entries of a nested
+/// batch follow the entry carrying it, numbered by position (e.g. `2.1`).
+pub fn multisend_to_csv(multi: &MultiSendDecode) -> String {
+    let mut csv = String::from("index,to,value_wei,value_eth,operation,method,params,status\n");
+    write_csv_rows(&mut csv, &multi.transactions, "");
+    csv
+}
+
+fn write_csv_rows(csv: &mut String, transactions: &[MultiSendTx], prefix: &str) {
+    for tx in transactions {
+        let index = format!("{}{}", prefix, tx.index + 1);
+        let (method, params) = match tx.call() {
+            Some((method, params)) => (
+                method.to_string(),
+                params
+                    .iter()
+                    .map(|(typ, value)| format!("{} {}", typ, value))
+                    .collect::<Vec<_>>()
+                    .join("; "),
+            ),
+            None => (String::new(), String::new()),
+        };
+        let operation = match tx.operation {
+            0 => "Call".to_string(),
+            1 => "DelegateCall".to_string(),
+            n => format!("Invalid ({})", n),
+        };
+        let status = match tx.decode.as_ref().map(|d| &d.comparison) {
+            Some(c) if c.is_match() => "verified",
+            Some(c) if c.is_mismatch() => "mismatch",
+            Some(ComparisonResult::Pending) | None => "pending",
+            Some(_) => "unverified",
+        };
+
+        let fields = [
+            index.as_str(),
+            &tx.to,
+            &tx.value,
+            &wei_to_eth(&tx.value),
+            &operation,
+            &method,
+            &params,
+            status,
+        ];
+        let row: Vec<String> = fields.iter().map(|f| csv_field(f)).collect();
+        csv.push_str(&row.join(","));
+        csv.push('\n');
+
+        if let Some(nested) = &tx.nested {
+            write_csv_rows(csv, &nested.transactions, &format!("{}.", index));
+        }
+    }
+}
+
+/// Wei amount in ETH without trailing zeros, empty if it doesn't parse
+fn wei_to_eth(wei: &str) -> String {
+    wei.trim()
+        .parse::<alloy::primitives::U256>()
+        .ok()
+        .and_then(|v| alloy::primitives::utils::format_units(v, "ether").ok())
+        .map(|eth| eth.trim_end_matches('0').trim_end_matches('.').to_string())
+        .unwrap_or_default()
+}
+
+/// Quote a field containing a delimiter, quote or line break (RFC 4180).
+/// Cells a spreadsheet would read as a formula get a leading `'`.
+fn csv_field(value: &str) -> String {
+    let value = if value.starts_with(['=', '+', '-', '@']) {
+        format!("'{}", value)
+    } else {
+        value.to_string()
+    };
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("two\nlines"), "\"two\nlines\"");
+    }
+
+    #[test]
+    fn test_csv_field_formula() {
+        assert_eq!(csv_field("=HYPERLINK(\"x\")"), "\"'=HYPERLINK(\"\"x\"\")\"");
+        assert_eq!(csv_field("+1"), "'+1");
+        assert_eq!(csv_field("-1"), "'-1");
+        assert_eq!(csv_field("@SUM(A1)"), "'@SUM(A1)");
+        assert_eq!(csv_field("0x12"), "0x12");
+    }
+
+    #[test]
+    fn test_multisend_to_csv() {
+        let tx = MultiSendTx {
+            index: 0,
+            operation: 0,
+            to: "0x1111111111111111111111111111111111111111".to_string(),
+            value: "1500000000000000000".to_string(),
+            data: "0x".to_string(),
+            api_decode: Some(ApiDecode {
+                method: "setNames".to_string(),
+                params: vec![ApiParam {
+                    name: "names".to_string(),
+                    typ: "string[]".to_string(),
+                    value: "[\"a,b\", \"c\"]".to_string(),
+                }],
+            }),
+            decode: None,
+            nested: None,
+            is_expanded: false,
+        };
+        let multi = MultiSendDecode {
+            transactions: vec![tx],
+            ..Default::default()
+        };
+
+        let csv = multisend_to_csv(&multi);
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next(),
+            Some("index,to,value_wei,value_eth,operation,method,params,status")
+        );
+        assert_eq!(
+            lines.next(),
+            Some(
+                "1,0x1111111111111111111111111111111111111111,1500000000000000000,1.5,Call,\
+                 setNames,\"string[] [\"\"a,b\"\", \"\"c\"\"]\",pending"
+            )
+        );
+        assert_eq!(lines.next(), None);
+        assert_eq!(wei_to_eth("0"), "0");
+    }
+}
@@ -0,0 +1,150 @@
+//! Safe-to-Safe calls
+//!
+//! A parent Safe acting on a child Safe calls the child's `execTransaction`
+//! with a complete Safe transaction and the child owners' signatures. That
+//! inner transaction is what runs, from the child Safe, so it is unpacked
+//! and decoded like a top-level one.
+
+use alloy::dyn_abi::{DynSolValue, JsonAbiExt};
+use alloy::json_abi::Function;
+
+use super::relay::decode_inner;
+use super::types::LocalDecode;
+
+/// `execTransaction` on a Safe
+pub const EXEC_TRANSACTION_SELECTOR: &str = "0x6a761202";
+
+const EXEC_TRANSACTION: &str =
+    "execTransaction(address,uint256,bytes,uint8,uint256,uint256,uint256,address,address,bytes)";
+
+/// Safe transaction carried by an `execTransaction` call. Its nonce isn't
+/// part of the call; the child Safe uses its current one.
+#[derive(Debug, Clone)]
+pub struct NestedSafeTx {
+    pub to: String,
+    /// Value in wei
+    pub value: String,
+    pub data: String,
+    pub operation: u8,
+    pub safe_tx_gas: String,
+    pub base_gas: String,
+    pub gas_price: String,
+    pub gas_token: String,
+    pub refund_receiver: String,
+    /// Packed owner signatures
+    pub signatures: String,
+    /// Inner call decoded with the bundled standard ABIs
+    pub inner: Option<LocalDecode>,
+}
+
+impl NestedSafeTx {
+    /// Selector of the inner call
+    pub fn inner_selector(&self) -> Option<&str> {
+        self.data.get(..10)
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    format!("0x{}", alloy::hex::encode(bytes))
+}
+
+/// Recognize an `execTransaction` call and extract the Safe transaction
+pub fn classify_nested_safe(data: &str) -> Option<NestedSafeTx> {
+    let data = data.trim();
+    if !data
+        .get(..10)?
+        .eq_ignore_ascii_case(EXEC_TRANSACTION_SELECTOR)
+    {
+        return None;
+    }
+
+    let func = Function::parse(EXEC_TRANSACTION).ok()?;
+    let bytes = alloy::hex::decode(data.strip_prefix("0x").unwrap_or(data)).ok()?;
+    let values = func.abi_decode_input(bytes.get(4..)?, true).ok()?;
+
+    let address = |i: usize| match values.get(i)? {
+        DynSolValue::Address(a) => Some(a.to_string()),
+        _ => None,
+    };
+    let uint = |i: usize| match values.get(i)? {
+        DynSolValue::Uint(u, _) => Some(*u),
+        _ => None,
+    };
+    let bytes_hex = |i: usize| match values.get(i)? {
+        DynSolValue::Bytes(b) => Some(hex(b)),
+        _ => None,
+    };
+
+    let inner_data = bytes_hex(2)?;
+    Some(NestedSafeTx {
+        to: address(0)?,
+        value: uint(1)?.to_string(),
+        operation: uint(3)?.try_into().ok()?,
+        safe_tx_gas: uint(4)?.to_string(),
+        base_gas: uint(5)?.to_string(),
+        gas_price: uint(6)?.to_string(),
+        gas_token: address(7)?,
+        refund_receiver: address(8)?,
+        signatures: bytes_hex(9)?,
+        inner: decode_inner(&inner_data),
+        data: inner_data,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::{Address, U256};
+
+    const TOKEN: &str = "0x2222222222222222222222222222222222222222";
+    const RECIPIENT: &str = "0x3333333333333333333333333333333333333333";
+
+    fn exec_transaction(to: &str, data: Vec<u8>) -> String {
+        let func = Function::parse(EXEC_TRANSACTION).unwrap();
+        let calldata = func
+            .abi_encode_input(&[
+                DynSolValue::Address(to.parse().unwrap()),
+                DynSolValue::Uint(U256::ZERO, 256),
+                DynSolValue::Bytes(data),
+                DynSolValue::Uint(U256::ZERO, 8),
+                DynSolValue::Uint(U256::ZERO, 256),
+                DynSolValue::Uint(U256::ZERO, 256),
+                DynSolValue::Uint(U256::ZERO, 256),
+                DynSolValue::Address(Address::ZERO),
+                DynSolValue::Address(Address::ZERO),
+                DynSolValue::Bytes(vec![0xcc; 65]),
+            ])
+            .unwrap();
+        hex(&calldata)
+    }
+
+    #[test]
+    fn test_selector_matches_signature() {
+        let func = Function::parse(EXEC_TRANSACTION).unwrap();
+        assert_eq!(hex(func.selector().as_slice()), EXEC_TRANSACTION_SELECTOR);
+    }
+
+    #[test]
+    fn test_exec_transaction() {
+        let mut transfer = alloy::hex::decode("a9059cbb").unwrap();
+        transfer.extend_from_slice(&[0u8; 12]);
+        transfer.extend_from_slice(RECIPIENT.parse::<Address>().unwrap().as_slice());
+        transfer.extend_from_slice(&U256::from(1000).to_be_bytes::<32>());
+
+        let nested = classify_nested_safe(&exec_transaction(TOKEN, transfer)).unwrap();
+        assert_eq!(nested.to.to_lowercase(), TOKEN);
+        assert_eq!(nested.value, "0");
+        assert_eq!(nested.operation, 0);
+        assert_eq!(nested.gas_token, Address::ZERO.to_string());
+        assert_eq!(nested.inner_selector(), Some("0xa9059cbb"));
+        assert_eq!(nested.inner.unwrap().method, "transfer");
+        assert_eq!(nested.signatures.len(), 2 + 65 * 2);
+    }
+
+    #[test]
+    fn test_not_exec_transaction() {
+        assert!(classify_nested_safe("0xa9059cbb").is_none());
+        // Right selector, malformed arguments
+        assert!(classify_nested_safe("0x6a7612021234").is_none());
+    }
+}
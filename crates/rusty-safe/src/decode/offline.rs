@@ -28,6 +28,7 @@ pub async fn decode_offline(raw_data: &str, lookup: &SignatureLookup) -> Offline
                 method: "Invalid data".to_string(),
                 params: vec![],
                 verified: false,
+                alternatives: vec![],
             },
             status: OfflineDecodeStatus::Failed("Data contains non-hex characters".to_string()),
         };
@@ -43,7 +44,13 @@ pub async fn decode_offline(raw_data: &str, lookup: &SignatureLookup) -> Offline
     // Check if MultiSend
     if selector == parser::MULTISEND_SELECTOR {
         match decode_offline_multisend(raw_data, lookup).await {
-            Ok(txs) => return OfflineDecodeResult::MultiSend(txs),
+            Ok(txs) => {
+                let delegatecalls = parser::find_delegatecalls(
+                    txs.iter()
+                        .map(|tx| (tx.operation, tx.to.as_str(), tx.data.as_str())),
+                );
+                return OfflineDecodeResult::MultiSend { txs, delegatecalls };
+            }
             Err(e) => {
                 decode_log!("Failed to decode MultiSend: {}", e);
                 return OfflineDecodeResult::RawHex(raw_data.to_string());
@@ -74,25 +81,18 @@ async fn decode_offline_single(
                 method: format!("Unknown function {}", selector),
                 params: vec![],
                 verified: false,
+                alternatives: vec![],
             },
             status: OfflineDecodeStatus::Unknown(selector.to_string()),
         };
     }
 
-    // Try each signature until one decodes
     // Signatures are sorted with verified first, so we prefer verified decodes
-    for sig_info in &sigs {
-        match parser::decode_with_signature(raw_data, &sig_info.signature, sig_info.verified) {
-            Ok(decoded) => {
-                return OfflineDecodeResult::Single {
-                    local: decoded,
-                    status: OfflineDecodeStatus::Decoded,
-                };
-            }
-            Err(e) => {
-                decode_log!("Failed to decode with {}: {}", sig_info.signature, e);
-            }
-        }
+    if let Some(decoded) = parser::decode_with_candidates(raw_data, &sigs) {
+        return OfflineDecodeResult::Single {
+            local: decoded,
+            status: OfflineDecodeStatus::Decoded,
+        };
     }
 
     // All signatures failed
@@ -105,6 +105,7 @@ async fn decode_offline_single(
             method: format!("Failed to decode {}", selector),
             params: vec![],
             verified: false,
+            alternatives: vec![],
         },
         status: OfflineDecodeStatus::Failed("ABI decode failed".to_string()),
     }
@@ -143,21 +144,7 @@ async fn decode_offline_multisend(
 
             match signatures.get(&selector) {
                 Some(sigs) if !sigs.is_empty() => {
-                    // Try to decode with available signatures
-                    // Signatures are sorted with verified first
-                    let mut decoded = None;
-                    for sig_info in sigs {
-                        if let Ok(d) = parser::decode_with_signature(
-                            &tx.data,
-                            &sig_info.signature,
-                            sig_info.verified,
-                        ) {
-                            decoded = Some(d);
-                            break;
-                        }
-                    }
-
-                    match decoded {
+                    match parser::decode_with_candidates(&tx.data, sigs) {
                         Some(d) => (Some(d), OfflineDecodeStatus::Decoded),
                         None => (
                             None,
@@ -3,11 +3,12 @@
 //! Uses `alloy_json_abi::Function::parse()` for signature parsing,
 //! following the same pattern as Foundry's `abi_decode_calldata`.
 
-use alloy::dyn_abi::JsonAbiExt;
+use alloy::dyn_abi::{DynSolType, DynSolValue, JsonAbiExt, Specifier};
 use alloy::json_abi::Function;
 use alloy::primitives::{hex, U256};
 use eyre::{Result, WrapErr};
 
+use super::sourcify::SignatureInfo;
 use super::types::*;
 use crate::api::DataDecoded;
 
@@ -57,6 +58,25 @@ pub fn parse_initial(raw_data: &str, api_decoded: Option<&DataDecoded>) -> Decod
                 status: OverallStatus::Failed,
             },
         }
+    } else if super::userop::entry_point_version(&selector).is_some() {
+        // ERC-4337 bundle; a matching selector that doesn't unpack is Unknown
+        match super::userop::unpack_user_operations(raw_data) {
+            Ok(bundle) => DecodedTransaction {
+                raw_data: raw_data.to_string(),
+                selector,
+                kind: TransactionKind::UserOp(bundle),
+                status: OverallStatus::Pending,
+            },
+            Err(e) => {
+                decode_log!("handleOps unpack failed: {:#}", e);
+                DecodedTransaction {
+                    raw_data: raw_data.to_string(),
+                    selector,
+                    kind: TransactionKind::Unknown,
+                    status: OverallStatus::Failed,
+                }
+            }
+        }
     } else {
         // Single function call
         DecodedTransaction {
@@ -66,6 +86,7 @@ pub fn parse_initial(raw_data: &str, api_decoded: Option<&DataDecoded>) -> Decod
                 api: api_decode,
                 local: None,
                 comparison: ComparisonResult::Pending,
+                explorer: None,
             }),
             status: OverallStatus::Pending,
         }
@@ -88,44 +109,206 @@ fn convert_api_decode(decoded: &DataDecoded) -> ApiDecode {
     }
 }
 
+/// Convert Safe API DataDecoded to the hierarchical web UI view,
+/// following `valueDecoded` into nested actions
+pub fn parse_web_ui_decode(decoded: &DataDecoded) -> WebUiDecode {
+    WebUiDecode {
+        method: decoded.method.clone(),
+        params: decoded
+            .parameters
+            .iter()
+            .map(|p| WebUiParam {
+                name: p.name.clone(),
+                typ: p.r#type.clone(),
+                value: p.value_as_string(),
+                actions: p
+                    .value_decoded
+                    .as_ref()
+                    .and_then(|v| v.as_array())
+                    .map(|items| items.iter().map(parse_web_ui_action).collect())
+                    .unwrap_or_default(),
+            })
+            .collect(),
+    }
+}
+
+/// Parse one `valueDecoded` item: {operation, to, value, data, dataDecoded}
+fn parse_web_ui_action(item: &serde_json::Value) -> WebUiAction {
+    let str_field = |key: &str| {
+        item.get(key)
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string()
+    };
+
+    WebUiAction {
+        operation: item.get("operation").and_then(|v| v.as_u64()),
+        to: str_field("to"),
+        value: str_field("value"),
+        data: str_field("data"),
+        decoded: item
+            .get("dataDecoded")
+            .and_then(|dd| serde_json::from_value::<DataDecoded>(dd.clone()).ok())
+            .map(|d| parse_web_ui_decode(&d)),
+    }
+}
+
 /// Parse MultiSend calldata
 fn parse_multisend(raw_data: &str, api_decoded: Option<&DataDecoded>) -> Result<MultiSendDecode> {
     // Decode the outer multiSend(bytes) call
     let bytes_data = decode_multisend_bytes(raw_data)?;
 
-    // Get nested decodes from API if available
-    let api_nested_decodes: Vec<Option<ApiDecode>> = api_decoded
+    let mut multi = MultiSendDecode {
+        parse: Some(MultiSendParse {
+            packed: bytes_data,
+            offset: 0,
+            api_items: api_batch_items(api_decoded),
+        }),
+        ..Default::default()
+    };
+    continue_multisend_parse(&mut multi)?;
+
+    Ok(multi)
+}
+
+/// The service's `dataDecoded` for each transaction of a multiSend call
+fn api_batch_items(api_decoded: Option<&DataDecoded>) -> Vec<Option<serde_json::Value>> {
+    api_decoded
         .and_then(|d| d.parameters.first())
         .and_then(|p| p.value_decoded.as_ref())
         .and_then(|v| v.as_array())
         .map(|arr| {
             arr.iter()
-                .map(|item| {
-                    // Each item has dataDecoded which contains method + params
-                    item.get("dataDecoded")
-                        .and_then(|dd| serde_json::from_value::<DataDecoded>(dd.clone()).ok())
-                        .map(|d| convert_api_decode(&d))
-                })
+                .map(|item| item.get("dataDecoded").cloned())
                 .collect()
         })
-        .unwrap_or_default();
+        .unwrap_or_default()
+}
 
-    // Parse packed transactions and attach API decodes
-    let mut transactions = unpack_multisend_transactions(&bytes_data)?;
+/// Attach the service's decode to a batch entry at nesting `depth` (1 for
+/// the outermost batch) and unpack the batch it carries, if any
+fn attach_api_decode(tx: &mut MultiSendTx, item: Option<&serde_json::Value>, depth: usize) {
+    let api = item.and_then(|dd| serde_json::from_value::<DataDecoded>(dd.clone()).ok());
+    tx.api_decode = api.as_ref().map(convert_api_decode);
+    tx.nested = parse_nested_multisend(&tx.data, api.as_ref(), depth);
+}
 
-    // Attach API decode data to each transaction
-    for (i, tx) in transactions.iter_mut().enumerate() {
-        tx.api_decode = api_nested_decodes.get(i).cloned().flatten();
+/// Unpack a multiSend call made from inside a batch. Stops at
+/// `MAX_NESTING_DEPTH`; data that doesn't unpack stays a plain call.
+fn parse_nested_multisend(
+    data: &str,
+    api_decoded: Option<&DataDecoded>,
+    depth: usize,
+) -> Option<Box<MultiSendDecode>> {
+    if depth >= MAX_NESTING_DEPTH || get_selector(data) != MULTISEND_SELECTOR {
+        return None;
     }
+    let bytes = decode_multisend_bytes(data).ok()?;
+    let items = api_batch_items(api_decoded);
 
+    let mut transactions = unpack_multisend_transactions(&bytes).ok()?;
+    for tx in &mut transactions {
+        attach_api_decode(tx, items.get(tx.index).and_then(Option::as_ref), depth + 1);
+    }
     let mut multi = MultiSendDecode {
         transactions,
-        summary: MultiSendSummary::default(),
-        verification_state: VerificationState::Pending,
+        ..Default::default()
     };
     multi.summary.update(&multi.transactions);
+    Some(Box::new(multi))
+}
 
-    Ok(multi)
+/// Transactions parsed per step of an incremental MultiSend parse
+const MULTISEND_PARSE_CHUNK: usize = 100;
+
+/// Parse the next chunk of a batch that is still being parsed, attaching
+/// API decodes. Once the last transaction is in, the batch-level checks run
+/// and `multi.parse` is cleared. Errors leave the batch unusable.
+pub fn continue_multisend_parse(multi: &mut MultiSendDecode) -> Result<()> {
+    let Some(parse) = multi.parse.as_mut() else {
+        return Ok(());
+    };
+
+    let mut iter = MultiSendIter::resume(&parse.packed, parse.offset, multi.transactions.len());
+    for tx in iter.by_ref().take(MULTISEND_PARSE_CHUNK) {
+        let mut tx = tx?;
+        let item = parse.api_items.get(tx.index).and_then(Option::as_ref);
+        attach_api_decode(&mut tx, item, 1);
+        multi.transactions.push(tx);
+    }
+    parse.offset = iter.offset();
+    let done = iter.offset() >= parse.packed.len();
+
+    if done {
+        multi.parse = None;
+        multi.delegatecalls = find_delegatecalls(
+            multi
+                .transactions
+                .iter()
+                .map(|tx| (tx.operation, tx.to.as_str(), tx.data.as_str())),
+        );
+    }
+    multi.summary.update(&multi.transactions);
+
+    Ok(())
+}
+
+/// Advance an incremental MultiSend parse by one chunk. A malformed batch
+/// turns the decode into `Unknown`, as a one-shot parse would have.
+pub fn continue_parse(decoded: &mut DecodedTransaction) {
+    let TransactionKind::MultiSend(multi) = &mut decoded.kind else {
+        return;
+    };
+    if let Err(e) = continue_multisend_parse(multi) {
+        decode_log!("MultiSend parse failed: {:#}", e);
+        decoded.kind = TransactionKind::Unknown;
+        decoded.status = OverallStatus::Failed;
+    }
+}
+
+/// How deep to follow multiSend calls nested inside a batch
+const MAX_NESTING_DEPTH: usize = 8;
+
+/// Walk a batch's `(operation, to, data)` entries, descending into nested
+/// multiSend calls, and report every sub-transaction that isn't a plain call.
+/// A benign batch only contains operation 0 at every level.
+pub fn find_delegatecalls<'a>(
+    transactions: impl IntoIterator<Item = (u8, &'a str, &'a str)>,
+) -> Vec<NestedDelegatecall> {
+    let transactions: Vec<_> = transactions.into_iter().collect();
+    let mut found = Vec::new();
+    collect_delegatecalls(&transactions, &[], &mut found);
+    found
+}
+
+fn collect_delegatecalls(
+    transactions: &[(u8, &str, &str)],
+    prefix: &[usize],
+    found: &mut Vec<NestedDelegatecall>,
+) {
+    for (i, &(operation, to, data)) in transactions.iter().enumerate() {
+        let mut path = prefix.to_vec();
+        path.push(i);
+
+        if operation != 0 {
+            found.push(NestedDelegatecall {
+                path: path.clone(),
+                to: to.to_string(),
+            });
+        }
+
+        if path.len() < MAX_NESTING_DEPTH && get_selector(data) == MULTISEND_SELECTOR {
+            let nested = decode_multisend_bytes(data)
+                .and_then(|bytes| unpack_multisend_transactions(&bytes));
+            if let Ok(nested) = nested {
+                let entries: Vec<_> = nested
+                    .iter()
+                    .map(|tx| (tx.operation, tx.to.as_str(), tx.data.as_str()))
+                    .collect();
+                collect_delegatecalls(&entries, &path, found);
+            }
+        }
+    }
 }
 
 /// Decode multiSend(bytes) ABI encoding to get the packed bytes
@@ -164,14 +347,43 @@ pub fn decode_multisend_bytes(raw_data: &str) -> Result<Vec<u8>> {
 
 /// Unpack MultiSend packed transactions
 pub fn unpack_multisend_transactions(packed: &[u8]) -> Result<Vec<MultiSendTx>> {
-    let mut transactions = Vec::new();
-    let mut offset = 0;
+    MultiSendIter::new(packed).collect()
+}
 
-    while offset < packed.len() {
-        // operation: 1 byte
-        if offset >= packed.len() {
-            break;
+/// Iterator over packed MultiSend transactions. Stops after the first
+/// malformed entry.
+pub struct MultiSendIter<'a> {
+    packed: &'a [u8],
+    offset: usize,
+    index: usize,
+    failed: bool,
+}
+
+impl<'a> MultiSendIter<'a> {
+    pub fn new(packed: &'a [u8]) -> Self {
+        Self::resume(packed, 0, 0)
+    }
+
+    /// Continue from a byte offset, numbering from `index`
+    pub fn resume(packed: &'a [u8], offset: usize, index: usize) -> Self {
+        Self {
+            packed,
+            offset,
+            index,
+            failed: false,
         }
+    }
+
+    /// Bytes consumed so far
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    fn next_tx(&mut self) -> Result<MultiSendTx> {
+        let packed = self.packed;
+        let mut offset = self.offset;
+
+        // operation: 1 byte
         let operation = packed[offset];
         offset += 1;
 
@@ -215,19 +427,34 @@ pub fn unpack_multisend_transactions(packed: &[u8]) -> Result<Vec<MultiSendTx>>
         };
         offset += data_length_usize;
 
-        transactions.push(MultiSendTx {
-            index: transactions.len(),
+        let tx = MultiSendTx {
+            index: self.index,
             operation,
             to,
             value: value.to_string(),
             data,
             api_decode: None, // Will be filled in by parse_multisend
             decode: None,
+            nested: None,
             is_expanded: false,
-        });
+        };
+        self.offset = offset;
+        self.index += 1;
+        Ok(tx)
     }
+}
 
-    Ok(transactions)
+impl Iterator for MultiSendIter<'_> {
+    type Item = Result<MultiSendTx>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.offset >= self.packed.len() {
+            return None;
+        }
+        let tx = self.next_tx();
+        self.failed = tx.is_err();
+        Some(tx)
+    }
 }
 
 use super::decode_log;
@@ -273,6 +500,7 @@ pub fn decode_with_signature(data: &str, signature: &str, verified: bool) -> Res
             method: func.name.clone(),
             params: vec![],
             verified,
+            alternatives: vec![],
         });
     }
 
@@ -291,9 +519,13 @@ pub fn decode_with_signature(data: &str, signature: &str, verified: bool) -> Res
     let params = decoded
         .iter()
         .zip(func.inputs.iter())
-        .map(|(val, input)| LocalParam {
-            typ: input.ty.clone(),
-            value: format_value(val),
+        .map(|(val, input)| {
+            let ty = input.resolve().ok();
+            LocalParam {
+                typ: input.ty.clone(),
+                value: format_value(val),
+                children: param_children(ty.as_ref(), val),
+            }
         })
         .collect();
 
@@ -302,13 +534,81 @@ pub fn decode_with_signature(data: &str, signature: &str, verified: bool) -> Res
         method: func.name.clone(),
         params,
         verified,
+        alternatives: vec![],
     })
 }
 
-/// Format a decoded value for display
-fn format_value(val: &alloy::dyn_abi::DynSolValue) -> String {
-    use alloy::dyn_abi::DynSolValue;
+/// Decode `data` with the first signature that fits. Signatures are sorted
+/// with verified first, so a verified decode wins. Every other signature
+/// that also decodes is kept in `alternatives` rather than dropped, since a
+/// selector collision can make a wrong decode look plausible.
+pub fn decode_with_candidates(data: &str, sigs: &[SignatureInfo]) -> Option<LocalDecode> {
+    let mut decodes: Vec<LocalDecode> = Vec::new();
+    for sig_info in sigs {
+        if decodes.iter().any(|d| d.signature == sig_info.signature) {
+            continue;
+        }
+        match decode_with_signature(data, &sig_info.signature, sig_info.verified) {
+            Ok(decoded) => decodes.push(decoded),
+            Err(e) => {
+                decode_log!("Failed to decode with {}: {}", sig_info.signature, e);
+            }
+        }
+    }
 
+    let mut decodes = decodes.into_iter();
+    let mut chosen = decodes.next()?;
+    chosen.alternatives = decodes.collect();
+    if !chosen.alternatives.is_empty() {
+        decode_log!(
+            "{} signatures decode {}",
+            chosen.alternatives.len() + 1,
+            get_selector(data)
+        );
+    }
+    Some(chosen)
+}
+
+/// Tuple components or array elements of `val` as params of their own,
+/// recursively. `ty` supplies the element types; without it they are taken
+/// from the values, which can't name the type of an empty array.
+fn param_children(ty: Option<&DynSolType>, val: &DynSolValue) -> Vec<LocalParam> {
+    let (types, items): (Vec<Option<&DynSolType>>, &[DynSolValue]) = match (ty, val) {
+        (Some(DynSolType::Tuple(types)), DynSolValue::Tuple(items))
+        | (
+            Some(DynSolType::CustomStruct { tuple: types, .. }),
+            DynSolValue::CustomStruct { tuple: items, .. },
+        ) => (types.iter().map(Some).collect(), items),
+        (
+            Some(DynSolType::Array(inner) | DynSolType::FixedArray(inner, _)),
+            DynSolValue::Array(items) | DynSolValue::FixedArray(items),
+        ) => (vec![Some(&**inner); items.len()], items),
+        (
+            _,
+            DynSolValue::Tuple(items)
+            | DynSolValue::Array(items)
+            | DynSolValue::FixedArray(items)
+            | DynSolValue::CustomStruct { tuple: items, .. },
+        ) => (vec![None; items.len()], items),
+        _ => return vec![],
+    };
+
+    types
+        .into_iter()
+        .zip(items)
+        .map(|(ty, item)| LocalParam {
+            typ: ty
+                .map(|t| t.sol_type_name().into_owned())
+                .or_else(|| item.sol_type_name().map(|n| n.into_owned()))
+                .unwrap_or_default(),
+            value: format_value(item),
+            children: param_children(ty, item),
+        })
+        .collect()
+}
+
+/// Format a decoded value for display
+fn format_value(val: &DynSolValue) -> String {
     match val {
         DynSolValue::Bool(b) => b.to_string(),
         DynSolValue::Int(i, _) => i.to_string(),
@@ -373,6 +673,46 @@ mod tests {
         assert!(result.verified);
     }
 
+    #[test]
+    fn test_decode_nested_params() {
+        let sig = "submit((uint256,address[]),uint8[2])";
+        let func = Function::parse(sig).unwrap();
+        let owner = alloy::primitives::Address::repeat_byte(0x11);
+        let calldata = func
+            .abi_encode_input(&[
+                DynSolValue::Tuple(vec![
+                    DynSolValue::Uint(U256::from(7), 256),
+                    DynSolValue::Array(vec![
+                        DynSolValue::Address(owner),
+                        DynSolValue::Address(owner),
+                    ]),
+                ]),
+                DynSolValue::FixedArray(vec![
+                    DynSolValue::Uint(U256::from(1), 8),
+                    DynSolValue::Uint(U256::from(2), 8),
+                ]),
+            ])
+            .unwrap();
+        let data = format!("0x{}", hex::encode(calldata));
+
+        let result = decode_with_signature(&data, sig, true).unwrap();
+        let tuple = &result.params[0];
+        assert_eq!(tuple.children.len(), 2);
+        assert_eq!(tuple.children[0].typ, "uint256");
+        assert_eq!(tuple.children[0].value, "7");
+        let owners = &tuple.children[1];
+        assert_eq!(owners.typ, "address[]");
+        assert_eq!(owners.children.len(), 2);
+        assert_eq!(owners.children[1].typ, "address");
+        assert!(owners.children[1].children.is_empty());
+        // The flattened value is unchanged, so comparisons are unaffected
+        assert_eq!(tuple.value, format!("[7, [{}, {}]]", owner, owner));
+
+        let fixed = &result.params[1];
+        assert_eq!(fixed.children[1].typ, "uint8");
+        assert_eq!(fixed.children[1].value, "2");
+    }
+
     #[test]
     fn test_decode_no_params() {
         let sig = "pause()";
@@ -406,4 +746,229 @@ mod tests {
             }
         }
     }
+
+    /// Pack one MultiSend entry: operation, to, value (0), data length, data
+    fn pack(operation: u8, to: &str, data: &[u8]) -> Vec<u8> {
+        let mut out = vec![operation];
+        out.extend(hex::decode(to.trim_start_matches("0x")).unwrap());
+        out.extend([0u8; 32]);
+        out.extend(U256::from(data.len()).to_be_bytes::<32>());
+        out.extend(data);
+        out
+    }
+
+    /// ABI-encode multiSend(bytes)
+    fn multisend_call(packed: &[u8]) -> Vec<u8> {
+        let mut out = hex::decode(MULTISEND_SELECTOR.trim_start_matches("0x")).unwrap();
+        out.extend(U256::from(32).to_be_bytes::<32>());
+        out.extend(U256::from(packed.len()).to_be_bytes::<32>());
+        out.extend(packed);
+        out.resize(4 + (out.len() - 4).div_ceil(32) * 32, 0);
+        out
+    }
+
+    #[test]
+    fn test_decode_with_candidates() {
+        let sig = |signature: &str, verified| SignatureInfo {
+            signature: signature.to_string(),
+            verified,
+        };
+        // Selector plus one word: fits any single static argument
+        let data = format!("0x12345678{:064x}", 7);
+        let sigs = [
+            sig("foo(uint256)", true),
+            sig("foo(uint256)", false),
+            sig("bar(address,uint256)", false),
+            sig("baz(bytes32)", false),
+        ];
+
+        let decoded = decode_with_candidates(&data, &sigs).unwrap();
+        assert_eq!(decoded.signature, "foo(uint256)");
+        assert!(decoded.verified);
+        let alternatives: Vec<&str> = decoded
+            .alternatives
+            .iter()
+            .map(|d| d.signature.as_str())
+            .collect();
+        assert_eq!(alternatives, vec!["baz(bytes32)"]);
+
+        let single = decode_with_candidates(&data, &sigs[..1]).unwrap();
+        assert!(single.alternatives.is_empty());
+        assert!(decode_with_candidates(&data, &sigs[2..3]).is_none());
+    }
+
+    #[test]
+    fn test_parse_web_ui_decode() {
+        let decoded: DataDecoded = serde_json::from_value(serde_json::json!({
+            "method": "multiSend",
+            "parameters": [{
+                "name": "transactions",
+                "type": "bytes",
+                "value": "0x00",
+                "valueDecoded": [
+                    {
+                        "operation": 0,
+                        "to": "0x1111111111111111111111111111111111111111",
+                        "value": "1",
+                        "data": null,
+                        "dataDecoded": null,
+                    },
+                    {
+                        "operation": 1,
+                        "to": "0x2222222222222222222222222222222222222222",
+                        "value": "0",
+                        "data": "0xa9059cbb",
+                        "dataDecoded": {
+                            "method": "transfer",
+                            "parameters": [
+                                {
+                                    "name": "to",
+                                    "type": "address",
+                                    "value": "0x3333333333333333333333333333333333333333",
+                                },
+                                { "name": "value", "type": "uint256", "value": "5" },
+                            ],
+                        },
+                    },
+                    { "operation": 300, "dataDecoded": { "method": 1 } },
+                ],
+            }],
+        }))
+        .unwrap();
+
+        let web = parse_web_ui_decode(&decoded);
+        assert_eq!(web.method, "multiSend");
+        assert_eq!(web.params.len(), 1);
+        assert_eq!(web.params[0].typ, "bytes");
+        let actions = &web.params[0].actions;
+        assert_eq!(actions.len(), 3);
+
+        assert_eq!(actions[0].operation, Some(0));
+        assert_eq!(actions[0].value, "1");
+        assert_eq!(actions[0].data, "");
+        assert!(actions[0].decoded.is_none());
+
+        assert_eq!(actions[1].operation, Some(1));
+        assert_eq!(actions[1].to, "0x2222222222222222222222222222222222222222");
+        let transfer = actions[1].decoded.as_ref().unwrap();
+        assert_eq!(transfer.method, "transfer");
+        assert_eq!(transfer.params[1].value, "5");
+        assert!(transfer.params[1].actions.is_empty());
+
+        // Malformed items keep what was sent rather than failing the decode;
+        // an out-of-range operation must not pass for a Call
+        assert_eq!(actions[2].operation, Some(300));
+        assert_eq!(actions[2].to, "");
+        assert!(actions[2].decoded.is_none());
+    }
+
+    #[test]
+    fn test_find_delegatecalls() {
+        let target = "0x1111111111111111111111111111111111111111";
+        let multisend = "0x40a2accbd92bca938b02010e17a5b8929b49130d";
+
+        // Inner batch: a call, then a delegatecall at position 2
+        let inner = [pack(0, target, &[]), pack(1, target, &[])].concat();
+        let inner_data = format!("0x{}", hex::encode(multisend_call(&inner)));
+
+        let outer = [
+            (0u8, target, "0x"),
+            // The nested batch is itself reached through a delegatecall
+            (1u8, multisend, inner_data.as_str()),
+        ];
+        let found = find_delegatecalls(outer);
+        let positions: Vec<String> = found.iter().map(|d| d.position()).collect();
+        assert_eq!(positions, vec!["2", "2.2"]);
+        assert_eq!(found[1].to, target);
+
+        assert!(find_delegatecalls([(0u8, target, "0x")]).is_empty());
+    }
+
+    #[test]
+    fn test_nested_multisend_parse() {
+        let target = "0x1111111111111111111111111111111111111111";
+        let multisend = "0x40a2accbd92bca938b02010e17a5b8929b49130d";
+
+        let inner = [pack(0, target, &[]), pack(0, target, &[1, 2])].concat();
+        let outer = [
+            pack(0, target, &[]),
+            pack(1, multisend, &multisend_call(&inner)),
+        ]
+        .concat();
+        let data = format!("0x{}", hex::encode(multisend_call(&outer)));
+
+        let decoded = parse_initial(&data, None);
+        let TransactionKind::MultiSend(multi) = &decoded.kind else {
+            panic!("expected MultiSend");
+        };
+        assert!(multi.transactions[0].nested.is_none());
+        let nested = multi.transactions[1].nested.as_deref().unwrap();
+        assert_eq!(nested.transactions.len(), 2);
+        assert_eq!(nested.transactions[1].data, "0x0102");
+        // Nested entries count toward the outer summary
+        assert_eq!(multi.summary.total, 4);
+
+        // Nesting stops at the depth limit
+        let mut data = multisend_call(&pack(0, target, &[]));
+        for _ in 0..MAX_NESTING_DEPTH + 2 {
+            data = multisend_call(&pack(1, multisend, &data));
+        }
+        let decoded = parse_initial(&format!("0x{}", hex::encode(data)), None);
+        let TransactionKind::MultiSend(multi) = &decoded.kind else {
+            panic!("expected MultiSend");
+        };
+        let mut depth = 1;
+        let mut batch = multi;
+        while let Some(nested) = batch.transactions[0].nested.as_deref() {
+            batch = nested;
+            depth += 1;
+        }
+        assert_eq!(depth, MAX_NESTING_DEPTH);
+    }
+
+    #[test]
+    fn test_incremental_multisend_parse() {
+        let target = "0x1111111111111111111111111111111111111111";
+        let count = MULTISEND_PARSE_CHUNK * 2 + 5;
+        let packed: Vec<u8> = (0..count)
+            .flat_map(|i| pack((i == count - 1) as u8, target, &[i as u8]))
+            .collect();
+        let data = format!("0x{}", hex::encode(multisend_call(&packed)));
+
+        let mut decoded = parse_initial(&data, None);
+        let TransactionKind::MultiSend(multi) = &decoded.kind else {
+            panic!("expected MultiSend");
+        };
+        assert!(multi.is_parsing());
+        assert_eq!(multi.transactions.len(), MULTISEND_PARSE_CHUNK);
+        assert_eq!(multi.estimated_total(), count);
+
+        while matches!(&decoded.kind, TransactionKind::MultiSend(m) if m.is_parsing()) {
+            continue_parse(&mut decoded);
+        }
+        let TransactionKind::MultiSend(multi) = &decoded.kind else {
+            panic!("expected MultiSend");
+        };
+        assert_eq!(multi.transactions.len(), count);
+        assert_eq!(multi.transactions[count - 1].index, count - 1);
+        // Batch-level checks run once the whole batch is in
+        assert_eq!(multi.delegatecalls.len(), 1);
+        let all = unpack_multisend_transactions(&packed).unwrap();
+        assert_eq!(all.len(), count);
+    }
+
+    #[test]
+    fn test_truncated_multisend_fails_during_parse() {
+        let target = "0x1111111111111111111111111111111111111111";
+        let mut packed: Vec<u8> = (0..MULTISEND_PARSE_CHUNK + 1)
+            .flat_map(|_| pack(0, target, &[]))
+            .collect();
+        packed.truncate(packed.len() - 10);
+        let data = format!("0x{}", hex::encode(multisend_call(&packed)));
+
+        let mut decoded = parse_initial(&data, None);
+        assert!(matches!(decoded.kind, TransactionKind::MultiSend(_)));
+        continue_parse(&mut decoded);
+        assert!(matches!(decoded.kind, TransactionKind::Unknown));
+    }
 }
@@ -0,0 +1,225 @@
+//! Decode pipeline timings
+//!
+//! Runs `parse_initial`, `decode_with_signature` and `compare_decodes` over
+//! representative calldata with a fixed in-memory signature source, so the
+//! numbers are deterministic and need no network. Per-stage timings are
+//! printed (`cargo test perf -- --nocapture`) to localize a regression; the
+//! budget test fails CI if a large batch gets pathologically slow.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use alloy::dyn_abi::{DynSolValue, JsonAbiExt};
+use alloy::json_abi::Function;
+use alloy::primitives::{Address, U256};
+
+use super::compare::compare_decodes;
+use super::parser::{continue_parse, decode_with_signature, parse_initial, MULTISEND_SELECTOR};
+use super::types::*;
+
+const TRANSFER: &str = "transfer(address,uint256)";
+const APPROVE: &str = "approve(address,uint256)";
+const NESTED: &str = "submit(((uint256,(address,bytes)[]),uint8)[],bytes32)";
+
+/// Signature source standing in for the signature database
+fn signature_source() -> HashMap<String, &'static str> {
+    [TRANSFER, APPROVE, NESTED]
+        .into_iter()
+        .map(|sig| {
+            let func = Function::parse(sig).unwrap();
+            (format!("0x{}", alloy::hex::encode(func.selector())), sig)
+        })
+        .collect()
+}
+
+fn address(i: usize) -> Address {
+    Address::left_padding_from(&(i as u64 + 1).to_be_bytes())
+}
+
+fn encode(signature: &str, args: &[DynSolValue]) -> Vec<u8> {
+    Function::parse(signature)
+        .unwrap()
+        .abi_encode_input(args)
+        .unwrap()
+}
+
+fn transfer_call(i: usize) -> Vec<u8> {
+    let signature = if i % 2 == 0 { TRANSFER } else { APPROVE };
+    encode(
+        signature,
+        &[
+            DynSolValue::Address(address(i)),
+            DynSolValue::Uint(U256::from(i * 1000), 256),
+        ],
+    )
+}
+
+fn nested_tuple_call() -> Vec<u8> {
+    let entries = (0..8)
+        .map(|i| {
+            let actions = (0..4)
+                .map(|j| {
+                    DynSolValue::Tuple(vec![
+                        DynSolValue::Address(address(i * 4 + j)),
+                        DynSolValue::Bytes(vec![j as u8; 36]),
+                    ])
+                })
+                .collect();
+            DynSolValue::Tuple(vec![
+                DynSolValue::Tuple(vec![
+                    DynSolValue::Uint(U256::from(i), 256),
+                    DynSolValue::Array(actions),
+                ]),
+                DynSolValue::Uint(U256::from(i % 2), 8),
+            ])
+        })
+        .collect();
+    encode(
+        NESTED,
+        &[
+            DynSolValue::Array(entries),
+            DynSolValue::FixedBytes(Default::default(), 32),
+        ],
+    )
+}
+
+/// multiSend(bytes) over `count` token calls
+fn multisend_call(count: usize) -> Vec<u8> {
+    let token = address(9999);
+    let packed: Vec<u8> = (0..count)
+        .flat_map(|i| {
+            let data = transfer_call(i);
+            let mut entry = vec![0u8];
+            entry.extend_from_slice(token.as_slice());
+            entry.extend(U256::ZERO.to_be_bytes::<32>());
+            entry.extend(U256::from(data.len()).to_be_bytes::<32>());
+            entry.extend(data);
+            entry
+        })
+        .collect();
+    let mut out = alloy::hex::decode(MULTISEND_SELECTOR).unwrap();
+    out.extend(U256::from(32).to_be_bytes::<32>());
+    out.extend(U256::from(packed.len()).to_be_bytes::<32>());
+    out.extend(&packed);
+    out.resize(4 + (out.len() - 4).div_ceil(32) * 32, 0);
+    out
+}
+
+/// Per-stage wall time for one calldata
+#[derive(Debug, Default)]
+struct StageTimings {
+    parse: Duration,
+    decode: Duration,
+    compare: Duration,
+    calls: usize,
+}
+
+impl StageTimings {
+    fn total(&self) -> Duration {
+        self.parse + self.decode + self.compare
+    }
+
+    fn report(&self, name: &str) {
+        eprintln!(
+            "[perf] {:<22} calls={:<4} parse={:>10?} decode={:>10?} compare={:>10?} total={:>10?}",
+            name,
+            self.calls,
+            self.parse,
+            self.decode,
+            self.compare,
+            self.total()
+        );
+    }
+}
+
+/// Run the pipeline the way the Verify tab does: parse, decode every call
+/// with the first matching signature, compare against an API decode that
+/// agrees with it
+fn run_pipeline(data: &[u8], signatures: &HashMap<String, &'static str>) -> StageTimings {
+    let data = format!("0x{}", alloy::hex::encode(data));
+    let mut timings = StageTimings::default();
+
+    let start = Instant::now();
+    let mut decoded = parse_initial(&data, None);
+    while matches!(&decoded.kind, TransactionKind::MultiSend(m) if m.is_parsing()) {
+        continue_parse(&mut decoded);
+    }
+    timings.parse = start.elapsed();
+
+    let calls: Vec<String> = match &decoded.kind {
+        TransactionKind::Single(_) => vec![data.clone()],
+        TransactionKind::MultiSend(multi) => multi
+            .transactions
+            .iter()
+            .map(|tx| tx.data.clone())
+            .collect(),
+        _ => panic!("fixture did not parse"),
+    };
+    timings.calls = calls.len();
+
+    let start = Instant::now();
+    let locals: Vec<LocalDecode> = calls
+        .iter()
+        .map(|call| {
+            let signature = signatures[&call[..10]];
+            decode_with_signature(call, signature, true).unwrap()
+        })
+        .collect();
+    timings.decode = start.elapsed();
+
+    let apis: Vec<ApiDecode> = locals
+        .iter()
+        .map(|local| ApiDecode {
+            method: local.method.clone(),
+            params: local
+                .params
+                .iter()
+                .map(|p| ApiParam {
+                    name: String::new(),
+                    typ: p.typ.clone(),
+                    value: p.value.clone(),
+                })
+                .collect(),
+        })
+        .collect();
+
+    let start = Instant::now();
+    for (api, local) in apis.iter().zip(&locals) {
+        let result = compare_decodes(Some(api), Some(local));
+        assert!(result.is_match(), "{:?}", result);
+    }
+    timings.compare = start.elapsed();
+
+    timings
+}
+
+#[test]
+fn test_pipeline_stage_timings() {
+    let signatures = signature_source();
+    let fixtures = [
+        ("transfer", transfer_call(0)),
+        ("multisend_50", multisend_call(50)),
+        ("nested_tuples", nested_tuple_call()),
+    ];
+    for (name, data) in &fixtures {
+        run_pipeline(data, &signatures).report(name);
+    }
+}
+
+/// Generous enough for an unoptimized build on a slow CI runner; a
+/// regression that trips it is an algorithmic one, not noise
+const LARGE_BATCH_BUDGET: Duration = Duration::from_secs(5);
+
+#[test]
+fn test_large_batch_within_budget() {
+    let signatures = signature_source();
+    let timings = run_pipeline(&multisend_call(1000), &signatures);
+    timings.report("multisend_1000");
+    assert_eq!(timings.calls, 1000);
+    assert!(
+        timings.total() < LARGE_BATCH_BUDGET,
+        "decoding 1000 transactions took {:?} (budget {:?})",
+        timings.total(),
+        LARGE_BATCH_BUDGET
+    );
+}
@@ -0,0 +1,426 @@
+//! Signed token permits
+//!
+//! EIP-2612 `permit`, DAI's `permit` and Uniswap's Permit2 carry a token
+//! owner's off-chain signature granting an allowance or a one-off transfer.
+//! Batches often bundle them ahead of a swap or deposit; the amount and how
+//! long the signature stays usable are what deserve scrutiny. Each format is
+//! described by the argument paths of its fields, like the relay formats.
+
+use alloy::dyn_abi::{DynSolValue, JsonAbiExt};
+use alloy::json_abi::Function;
+use alloy::primitives::U256;
+
+use super::approval::UNLIMITED_MIN;
+use super::parser::{decode_multisend_bytes, unpack_multisend_transactions, MULTISEND_SELECTOR};
+
+/// Deadlines and expirations further out than this are flagged
+const FAR_FUTURE_SECS: u64 = 365 * 24 * 60 * 60;
+
+/// How the permit states its amount
+enum AmountLayout {
+    Value(&'static [usize]),
+    /// DAI: a bool, `true` for an unlimited allowance and `false` to revoke
+    Allowed(&'static [usize]),
+}
+
+/// A permit entry point. Paths index into the decoded arguments, descending
+/// into tuples.
+struct PermitFormat {
+    selector: &'static str,
+    signature: &'static str,
+    label: &'static str,
+    owner: &'static [usize],
+    /// `None`: the called contract is the token
+    token: Option<&'static [usize]>,
+    /// `None`: whoever submits the call
+    spender: Option<&'static [usize]>,
+    /// Transfer recipient, for one-off transfer permits
+    recipient: Option<&'static [usize]>,
+    amount: AmountLayout,
+    deadline: &'static [usize],
+    /// When the granted allowance itself lapses, if separate from the deadline
+    expiration: Option<&'static [usize]>,
+}
+
+const PERMIT_FORMATS: &[PermitFormat] = &[
+    PermitFormat {
+        selector: "0xd505accf",
+        signature: "permit(address,address,uint256,uint256,uint8,bytes32,bytes32)",
+        label: "EIP-2612 permit",
+        owner: &[0],
+        token: None,
+        spender: Some(&[1]),
+        recipient: None,
+        amount: AmountLayout::Value(&[2]),
+        deadline: &[3],
+        expiration: None,
+    },
+    PermitFormat {
+        selector: "0x8fcbaf0c",
+        signature: "permit(address,address,uint256,uint256,bool,uint8,bytes32,bytes32)",
+        label: "DAI permit",
+        owner: &[0],
+        token: None,
+        spender: Some(&[1]),
+        recipient: None,
+        amount: AmountLayout::Allowed(&[4]),
+        deadline: &[3],
+        expiration: None,
+    },
+    // permitTransferFrom(PermitTransferFrom, SignatureTransferDetails, owner, signature)
+    PermitFormat {
+        selector: "0x30f28b7a",
+        signature:
+            "permitTransferFrom(((address,uint256),uint256,uint256),(address,uint256),address,bytes)",
+        label: "Permit2 transfer",
+        owner: &[2],
+        token: Some(&[0, 0, 0]),
+        spender: None,
+        recipient: Some(&[1, 0]),
+        amount: AmountLayout::Value(&[0, 0, 1]),
+        deadline: &[0, 2],
+        expiration: None,
+    },
+    // permit(owner, PermitSingle, signature)
+    PermitFormat {
+        selector: "0x2b67b570",
+        signature: "permit(address,((address,uint160,uint48,uint48),address,uint256),bytes)",
+        label: "Permit2 allowance",
+        owner: &[0],
+        token: Some(&[1, 0, 0]),
+        spender: Some(&[1, 1]),
+        recipient: None,
+        amount: AmountLayout::Value(&[1, 0, 1]),
+        deadline: &[1, 2],
+        expiration: Some(&[1, 0, 2]),
+    },
+];
+
+/// When a permit's signature deadline falls
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeadlineStatus {
+    /// Already passed; the call will revert
+    Expired,
+    /// Within the next year
+    Valid,
+    /// More than a year out
+    FarFuture,
+    /// DAI permits with expiry 0 never expire
+    Never,
+}
+
+/// A decoded permit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PermitInfo {
+    /// MultiSend index, `None` for a single call
+    pub index: Option<usize>,
+    /// Permit kind, e.g. "EIP-2612 permit"
+    pub label: &'static str,
+    pub owner: String,
+    pub token: String,
+    /// `None`: whoever submits the call
+    pub spender: Option<String>,
+    pub recipient: Option<String>,
+    /// Amount in the token's base units
+    pub value: U256,
+    /// Unix seconds
+    pub deadline: U256,
+    /// Unix seconds the allowance lapses (Permit2 allowances)
+    pub expiration: Option<U256>,
+    /// DAI semantics: a zero deadline means no expiry
+    never_expires_on_zero: bool,
+}
+
+impl PermitInfo {
+    /// Same threshold as `approve`
+    pub fn is_unlimited(&self) -> bool {
+        self.value >= UNLIMITED_MIN
+    }
+
+    pub fn deadline_status(&self, now: u64) -> DeadlineStatus {
+        if self.never_expires_on_zero && self.deadline.is_zero() {
+            return DeadlineStatus::Never;
+        }
+        time_status(self.deadline, now)
+    }
+
+    /// When the granted allowance lapses, for permits with an expiration.
+    /// Permit2 treats 0 as the current block, so it never counts as far out.
+    pub fn expiration_status(&self, now: u64) -> Option<DeadlineStatus> {
+        self.expiration.map(|expiration| match expiration {
+            e if e.is_zero() => DeadlineStatus::Valid,
+            e => time_status(e, now),
+        })
+    }
+}
+
+fn time_status(timestamp: U256, now: u64) -> DeadlineStatus {
+    if timestamp < U256::from(now) {
+        DeadlineStatus::Expired
+    } else if timestamp > U256::from(now.saturating_add(FAR_FUTURE_SECS)) {
+        DeadlineStatus::FarFuture
+    } else {
+        DeadlineStatus::Valid
+    }
+}
+
+/// Argument at `path`, descending into tuples
+fn field<'a>(values: &'a [DynSolValue], path: &[usize]) -> Option<&'a DynSolValue> {
+    let (first, rest) = path.split_first()?;
+    let value = values.get(*first)?;
+    if rest.is_empty() {
+        return Some(value);
+    }
+    match value {
+        DynSolValue::Tuple(items) => field(items, rest),
+        _ => None,
+    }
+}
+
+fn address_field(values: &[DynSolValue], path: &[usize]) -> Option<String> {
+    match field(values, path)? {
+        DynSolValue::Address(a) => Some(a.to_string()),
+        _ => None,
+    }
+}
+
+fn uint_field(values: &[DynSolValue], path: &[usize]) -> Option<U256> {
+    match field(values, path)? {
+        DynSolValue::Uint(u, _) => Some(*u),
+        _ => None,
+    }
+}
+
+/// Decode a permit call to `to` from its calldata
+pub fn decode_permit(to: &str, data: &str) -> Option<PermitInfo> {
+    let data = data.trim();
+    let selector = data.get(..10)?;
+    let format = PERMIT_FORMATS
+        .iter()
+        .find(|f| f.selector.eq_ignore_ascii_case(selector))?;
+
+    let func = Function::parse(format.signature).ok()?;
+    let bytes = alloy::hex::decode(data.strip_prefix("0x").unwrap_or(data)).ok()?;
+    let values = func.abi_decode_input(bytes.get(4..)?, true).ok()?;
+
+    let value = match format.amount {
+        AmountLayout::Value(path) => uint_field(&values, path)?,
+        AmountLayout::Allowed(path) => match field(&values, path)? {
+            DynSolValue::Bool(true) => U256::MAX,
+            DynSolValue::Bool(false) => U256::ZERO,
+            _ => return None,
+        },
+    };
+    let token = match format.token {
+        Some(path) => address_field(&values, path)?,
+        None => to.to_string(),
+    };
+
+    Some(PermitInfo {
+        index: None,
+        label: format.label,
+        owner: address_field(&values, format.owner)?,
+        token,
+        spender: format.spender.and_then(|p| address_field(&values, p)),
+        recipient: format.recipient.and_then(|p| address_field(&values, p)),
+        value,
+        deadline: uint_field(&values, format.deadline)?,
+        expiration: format.expiration.and_then(|p| uint_field(&values, p)),
+        never_expires_on_zero: matches!(format.amount, AmountLayout::Allowed(_)),
+    })
+}
+
+/// Find permits in a transaction, including MultiSend sub-transactions.
+/// Works on the raw calldata so it doesn't depend on any decode.
+pub fn detect_permits(to: &str, data: &str) -> Vec<PermitInfo> {
+    let is_multisend = data
+        .trim()
+        .get(..10)
+        .is_some_and(|s| s.eq_ignore_ascii_case(MULTISEND_SELECTOR));
+    if is_multisend {
+        let txs = decode_multisend_bytes(data.trim())
+            .and_then(|packed| unpack_multisend_transactions(&packed));
+        if let Ok(txs) = txs {
+            return txs
+                .iter()
+                .filter_map(|tx| {
+                    let permit = decode_permit(&tx.to, &tx.data)?;
+                    Some(PermitInfo {
+                        index: Some(tx.index),
+                        ..permit
+                    })
+                })
+                .collect();
+        }
+    }
+    decode_permit(to, data).into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::{Address, B256};
+
+    const TOKEN: &str = "0x1111111111111111111111111111111111111111";
+    const OWNER: &str = "0x2222222222222222222222222222222222222222";
+    const SPENDER: &str = "0x3333333333333333333333333333333333333333";
+
+    fn encode(signature: &str, args: &[DynSolValue]) -> String {
+        let func = Function::parse(signature).unwrap();
+        format!(
+            "0x{}",
+            alloy::hex::encode(func.abi_encode_input(args).unwrap())
+        )
+    }
+
+    fn eip2612(value: U256, deadline: u64) -> String {
+        encode(
+            PERMIT_FORMATS[0].signature,
+            &[
+                DynSolValue::Address(OWNER.parse().unwrap()),
+                DynSolValue::Address(SPENDER.parse().unwrap()),
+                DynSolValue::Uint(value, 256),
+                DynSolValue::Uint(U256::from(deadline), 256),
+                DynSolValue::Uint(U256::from(27), 8),
+                DynSolValue::FixedBytes(B256::ZERO, 32),
+                DynSolValue::FixedBytes(B256::ZERO, 32),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_selectors_match_signatures() {
+        for format in PERMIT_FORMATS {
+            let func = Function::parse(format.signature).unwrap();
+            let selector = format!("0x{}", alloy::hex::encode(func.selector().as_slice()));
+            assert_eq!(selector, format.selector, "{}", format.signature);
+        }
+    }
+
+    #[test]
+    fn test_eip2612() {
+        let permit = decode_permit(TOKEN, &eip2612(U256::from(500), 1_000)).unwrap();
+        assert_eq!(permit.label, "EIP-2612 permit");
+        assert_eq!(permit.token, TOKEN);
+        assert_eq!(permit.owner.to_lowercase(), OWNER);
+        assert_eq!(permit.spender.unwrap().to_lowercase(), SPENDER);
+        assert_eq!(permit.value, U256::from(500));
+        assert!(!permit.is_unlimited());
+
+        let unlimited = decode_permit(TOKEN, &eip2612(U256::MAX, 1_000)).unwrap();
+        assert!(unlimited.is_unlimited());
+    }
+
+    #[test]
+    fn test_deadline_status() {
+        let permit = decode_permit(TOKEN, &eip2612(U256::from(1), 1_000)).unwrap();
+        assert_eq!(permit.deadline_status(2_000), DeadlineStatus::Expired);
+        assert_eq!(permit.deadline_status(500), DeadlineStatus::Valid);
+        assert_eq!(permit.deadline_status(0), DeadlineStatus::Valid);
+
+        let far = decode_permit(TOKEN, &eip2612(U256::from(1), u64::MAX)).unwrap();
+        assert_eq!(far.deadline_status(1_000), DeadlineStatus::FarFuture);
+    }
+
+    #[test]
+    fn test_dai_never_expires() {
+        let data = encode(
+            PERMIT_FORMATS[1].signature,
+            &[
+                DynSolValue::Address(OWNER.parse().unwrap()),
+                DynSolValue::Address(SPENDER.parse().unwrap()),
+                DynSolValue::Uint(U256::ZERO, 256),
+                DynSolValue::Uint(U256::ZERO, 256),
+                DynSolValue::Bool(true),
+                DynSolValue::Uint(U256::from(27), 8),
+                DynSolValue::FixedBytes(B256::ZERO, 32),
+                DynSolValue::FixedBytes(B256::ZERO, 32),
+            ],
+        );
+        let permit = decode_permit(TOKEN, &data).unwrap();
+        assert!(permit.is_unlimited());
+        assert_eq!(permit.deadline_status(1_000), DeadlineStatus::Never);
+    }
+
+    #[test]
+    fn test_permit2_transfer() {
+        let data = encode(
+            PERMIT_FORMATS[2].signature,
+            &[
+                DynSolValue::Tuple(vec![
+                    DynSolValue::Tuple(vec![
+                        DynSolValue::Address(TOKEN.parse().unwrap()),
+                        DynSolValue::Uint(U256::from(7), 256),
+                    ]),
+                    DynSolValue::Uint(U256::ZERO, 256),
+                    DynSolValue::Uint(U256::from(1_000), 256),
+                ]),
+                DynSolValue::Tuple(vec![
+                    DynSolValue::Address(SPENDER.parse().unwrap()),
+                    DynSolValue::Uint(U256::from(7), 256),
+                ]),
+                DynSolValue::Address(OWNER.parse().unwrap()),
+                DynSolValue::Bytes(vec![0xcc; 65]),
+            ],
+        );
+        let permit = decode_permit(&Address::ZERO.to_string(), &data).unwrap();
+        assert_eq!(permit.token.to_lowercase(), TOKEN);
+        assert_eq!(permit.recipient.unwrap().to_lowercase(), SPENDER);
+        assert!(permit.spender.is_none());
+        assert_eq!(permit.value, U256::from(7));
+        assert_eq!(permit.deadline, U256::from(1_000));
+    }
+
+    #[test]
+    fn test_permit2_allowance_expiration() {
+        let permit = |expiration: u64| {
+            let data = encode(
+                PERMIT_FORMATS[3].signature,
+                &[
+                    DynSolValue::Address(OWNER.parse().unwrap()),
+                    DynSolValue::Tuple(vec![
+                        DynSolValue::Tuple(vec![
+                            DynSolValue::Address(TOKEN.parse().unwrap()),
+                            DynSolValue::Uint(U256::from(7), 160),
+                            DynSolValue::Uint(U256::from(expiration), 48),
+                            DynSolValue::Uint(U256::ZERO, 48),
+                        ]),
+                        DynSolValue::Address(SPENDER.parse().unwrap()),
+                        DynSolValue::Uint(U256::from(1_000), 256),
+                    ]),
+                    DynSolValue::Bytes(vec![0xcc; 65]),
+                ],
+            );
+            decode_permit(&Address::ZERO.to_string(), &data).unwrap()
+        };
+
+        // type(uint48).max, the usual "never" value
+        let forever = permit((1 << 48) - 1);
+        assert_eq!(forever.deadline_status(500), DeadlineStatus::Valid);
+        assert_eq!(
+            forever.expiration_status(500),
+            Some(DeadlineStatus::FarFuture)
+        );
+        assert_eq!(
+            permit(900).expiration_status(500),
+            Some(DeadlineStatus::Valid)
+        );
+        assert_eq!(
+            permit(0).expiration_status(500),
+            Some(DeadlineStatus::Valid)
+        );
+        assert_eq!(
+            decode_permit(TOKEN, &eip2612(U256::from(1), 1_000))
+                .unwrap()
+                .expiration_status(500),
+            None
+        );
+    }
+
+    #[test]
+    fn test_not_a_permit() {
+        assert!(decode_permit(TOKEN, "0xa9059cbb").is_none());
+        // Right selector, malformed arguments
+        assert!(decode_permit(TOKEN, "0xd505accf1234").is_none());
+    }
+}
@@ -0,0 +1,279 @@
+//! Meta-transaction relays
+//!
+//! Recognizes calls that submit someone else's signed action on-chain
+//! (`executeMetaTransaction`, ERC-2771 forwarders). The outer call is just
+//! relay mechanics; what executes is the nested call embedded as a `bytes`
+//! argument, with the signer's authority. Each format is described by the
+//! argument paths of its signer, target, value, nested calldata and
+//! signature, so a new relay is one table entry.
+
+use alloy::dyn_abi::{DynSolValue, JsonAbiExt};
+use alloy::json_abi::Function;
+
+use super::parser::decode_with_signature;
+use super::standard::StandardAbiRegistry;
+use super::types::LocalDecode;
+
+/// Where the relay carries the signature
+enum SignatureLayout {
+    /// A single `bytes` argument
+    Bytes(&'static [usize]),
+    /// Separate `r`, `s`, `v` arguments
+    Split {
+        r: &'static [usize],
+        s: &'static [usize],
+        v: &'static [usize],
+    },
+}
+
+/// A relay entry point. Paths index into the decoded arguments, descending
+/// into tuples (e.g. `[0, 5]` is field 5 of the first argument).
+struct RelayFormat {
+    selector: &'static str,
+    signature: &'static str,
+    label: &'static str,
+    signer: &'static [usize],
+    /// `None`: the nested call targets the relay contract itself
+    target: Option<&'static [usize]>,
+    value: Option<&'static [usize]>,
+    data: &'static [usize],
+    relay_signature: SignatureLayout,
+}
+
+const RELAY_FORMATS: &[RelayFormat] = &[
+    // EIP-712 meta-transactions (Polygon PoS tokens, Biconomy)
+    RelayFormat {
+        selector: "0x0c53c51c",
+        signature: "executeMetaTransaction(address,bytes,bytes32,bytes32,uint8)",
+        label: "EIP-712 meta-transaction",
+        signer: &[0],
+        target: None,
+        value: None,
+        data: &[1],
+        relay_signature: SignatureLayout::Split {
+            r: &[2],
+            s: &[3],
+            v: &[4],
+        },
+    },
+    // OpenZeppelin MinimalForwarder: execute(ForwardRequest, signature)
+    RelayFormat {
+        selector: "0x47153f82",
+        signature: "execute((address,address,uint256,uint256,uint256,bytes),bytes)",
+        label: "ERC-2771 forwarder",
+        signer: &[0, 0],
+        target: Some(&[0, 1]),
+        value: Some(&[0, 2]),
+        data: &[0, 5],
+        relay_signature: SignatureLayout::Bytes(&[1]),
+    },
+    // OpenZeppelin ERC2771Forwarder: execute(ForwardRequestData)
+    RelayFormat {
+        selector: "0xdf905caf",
+        signature: "execute((address,address,uint256,uint256,uint48,bytes,bytes))",
+        label: "ERC-2771 forwarder",
+        signer: &[0, 0],
+        target: Some(&[0, 1]),
+        value: Some(&[0, 2]),
+        data: &[0, 5],
+        relay_signature: SignatureLayout::Bytes(&[0, 6]),
+    },
+];
+
+/// A relayed signed action
+#[derive(Debug, Clone)]
+pub struct RelayCall {
+    /// Relay kind, e.g. "ERC-2771 forwarder"
+    pub label: &'static str,
+    /// Outer method name
+    pub method: String,
+    /// Address whose signature authorizes the nested call
+    pub signer: String,
+    /// Nested call target, `None` if it's the relay contract itself
+    pub target: Option<String>,
+    /// Nested call value in wei, if the relay forwards one
+    pub value: Option<String>,
+    /// Nested calldata
+    pub data: String,
+    /// Embedded signature (`r ‖ s ‖ v` for split layouts)
+    pub signature: String,
+    /// Nested call decoded with the bundled standard ABIs
+    pub inner: Option<LocalDecode>,
+}
+
+impl RelayCall {
+    /// Selector of the nested call
+    pub fn inner_selector(&self) -> Option<&str> {
+        self.data.get(..10)
+    }
+}
+
+/// Argument at `path`, descending into tuples
+fn field<'a>(values: &'a [DynSolValue], path: &[usize]) -> Option<&'a DynSolValue> {
+    let (first, rest) = path.split_first()?;
+    let value = values.get(*first)?;
+    if rest.is_empty() {
+        return Some(value);
+    }
+    match value {
+        DynSolValue::Tuple(items) => field(items, rest),
+        _ => None,
+    }
+}
+
+fn bytes_field(values: &[DynSolValue], path: &[usize]) -> Option<Vec<u8>> {
+    match field(values, path)? {
+        DynSolValue::Bytes(b) => Some(b.clone()),
+        DynSolValue::FixedBytes(word, size) => Some(word[..*size].to_vec()),
+        DynSolValue::Uint(u, _) => Some(vec![u.to::<u8>()]),
+        _ => None,
+    }
+}
+
+fn address_field(values: &[DynSolValue], path: &[usize]) -> Option<String> {
+    match field(values, path)? {
+        DynSolValue::Address(a) => Some(a.to_string()),
+        _ => None,
+    }
+}
+
+fn uint_field(values: &[DynSolValue], path: &[usize]) -> Option<String> {
+    match field(values, path)? {
+        DynSolValue::Uint(u, _) => Some(u.to_string()),
+        _ => None,
+    }
+}
+
+/// Decode the nested call with the bundled standard ABIs. Other methods are
+/// shown by selector only, since the signature database is async.
+pub(super) fn decode_inner(data: &str) -> Option<LocalDecode> {
+    let signature = StandardAbiRegistry::lookup(data.get(..10)?)?;
+    decode_with_signature(data, signature, true).ok()
+}
+
+/// Recognize a relay call and extract its nested signed action
+pub fn classify_relay(data: &str) -> Option<RelayCall> {
+    let data = data.trim();
+    let selector = data.get(..10)?.to_lowercase();
+    let format = RELAY_FORMATS.iter().find(|f| f.selector == selector)?;
+
+    let func = Function::parse(format.signature).ok()?;
+    let bytes = alloy::hex::decode(data.strip_prefix("0x").unwrap_or(data)).ok()?;
+    let values = func.abi_decode_input(bytes.get(4..)?, true).ok()?;
+
+    let signature = match format.relay_signature {
+        SignatureLayout::Bytes(path) => bytes_field(&values, path)?,
+        SignatureLayout::Split { r, s, v } => [
+            bytes_field(&values, r)?,
+            bytes_field(&values, s)?,
+            bytes_field(&values, v)?,
+        ]
+        .concat(),
+    };
+    let inner_data = format!(
+        "0x{}",
+        alloy::hex::encode(bytes_field(&values, format.data)?)
+    );
+
+    Some(RelayCall {
+        label: format.label,
+        method: func.name.clone(),
+        signer: address_field(&values, format.signer)?,
+        target: match format.target {
+            Some(path) => Some(address_field(&values, path)?),
+            None => None,
+        },
+        value: match format.value {
+            Some(path) => Some(uint_field(&values, path)?),
+            None => None,
+        },
+        inner: decode_inner(&inner_data),
+        data: inner_data,
+        signature: format!("0x{}", alloy::hex::encode(signature)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::{Address, B256, U256};
+
+    const SIGNER: &str = "0x1111111111111111111111111111111111111111";
+    const TOKEN: &str = "0x2222222222222222222222222222222222222222";
+    const RECIPIENT: &str = "0x3333333333333333333333333333333333333333";
+
+    fn transfer_data() -> Vec<u8> {
+        let mut data = alloy::hex::decode("a9059cbb").unwrap();
+        data.extend_from_slice(&[0u8; 12]);
+        data.extend_from_slice(RECIPIENT.parse::<Address>().unwrap().as_slice());
+        data.extend_from_slice(&U256::from(1000).to_be_bytes::<32>());
+        data
+    }
+
+    #[test]
+    fn test_selectors_match_signatures() {
+        for format in RELAY_FORMATS {
+            let func = Function::parse(format.signature).unwrap();
+            let expected = format!("0x{}", alloy::hex::encode(func.selector()));
+            assert_eq!(
+                format.selector, expected,
+                "selector for {}",
+                format.signature
+            );
+        }
+    }
+
+    #[test]
+    fn test_execute_meta_transaction() {
+        let func = Function::parse(RELAY_FORMATS[0].signature).unwrap();
+        let calldata = func
+            .abi_encode_input(&[
+                DynSolValue::Address(SIGNER.parse().unwrap()),
+                DynSolValue::Bytes(transfer_data()),
+                DynSolValue::FixedBytes(B256::repeat_byte(0xaa), 32),
+                DynSolValue::FixedBytes(B256::repeat_byte(0xbb), 32),
+                DynSolValue::Uint(U256::from(27), 8),
+            ])
+            .unwrap();
+        let data = format!("0x{}", alloy::hex::encode(calldata));
+
+        let relay = classify_relay(&data).unwrap();
+        assert_eq!(relay.signer.to_lowercase(), SIGNER);
+        assert!(relay.target.is_none());
+        assert_eq!(relay.inner_selector(), Some("0xa9059cbb"));
+        assert_eq!(relay.inner.unwrap().method, "transfer");
+        // r ‖ s ‖ v
+        assert_eq!(relay.signature.len(), 2 + 65 * 2);
+        assert!(relay.signature.ends_with("1b"));
+    }
+
+    #[test]
+    fn test_forwarder_request() {
+        let func = Function::parse(RELAY_FORMATS[1].signature).unwrap();
+        let request = DynSolValue::Tuple(vec![
+            DynSolValue::Address(SIGNER.parse().unwrap()),
+            DynSolValue::Address(TOKEN.parse().unwrap()),
+            DynSolValue::Uint(U256::from(5), 256),
+            DynSolValue::Uint(U256::from(100_000), 256),
+            DynSolValue::Uint(U256::from(7), 256),
+            DynSolValue::Bytes(transfer_data()),
+        ]);
+        let calldata = func
+            .abi_encode_input(&[request, DynSolValue::Bytes(vec![0xcc; 65])])
+            .unwrap();
+        let data = format!("0x{}", alloy::hex::encode(calldata));
+
+        let relay = classify_relay(&data).unwrap();
+        assert_eq!(relay.label, "ERC-2771 forwarder");
+        assert_eq!(relay.target.unwrap().to_lowercase(), TOKEN);
+        assert_eq!(relay.value.as_deref(), Some("5"));
+        assert!(relay.inner.is_some());
+    }
+
+    #[test]
+    fn test_not_a_relay() {
+        assert!(classify_relay("0xa9059cbb").is_none());
+        // Right selector, malformed arguments
+        assert!(classify_relay("0x0c53c51c1234").is_none());
+    }
+}
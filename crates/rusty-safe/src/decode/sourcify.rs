@@ -1,9 +1,11 @@
-//! Sourcify 4byte signature lookup with caching
+//! Sourcify 4byte signature and contract lookups with caching
 //!
 //! Uses Sourcify's Signature Database API:
 //! https://docs.sourcify.dev/docs/api/#/Signature%20Database/get_signature_database_v1_lookup
+//! and its verified contract API for contract names:
+//! https://docs.sourcify.dev/docs/api/#/Contract%20Lookup/get-contract
 //!
-//! Cache is persisted via eframe storage (works on both WASM and native).
+//! Caches are persisted via eframe storage (works on both WASM and native).
 
 use eyre::{Result, WrapErr};
 use serde::{Deserialize, Serialize};
@@ -11,6 +13,9 @@ use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 
+use super::standard::StandardAbiRegistry;
+use crate::persist::{self, Schema};
+
 const SOURCIFY_API: &str = "https://api.4byte.sourcify.dev/signature-database/v1/lookup";
 
 /// How many requests can fail before we mark the connection as spurious
@@ -18,10 +23,30 @@ const MAX_FAILED_REQUESTS: usize = 3;
 
 /// Storage key for signature cache
 const SIGNATURES_STORAGE_KEY: &str = "signatures_cache";
+/// Versioned format of the persisted cache
+const SIGNATURES_SCHEMA: Schema = Schema {
+    key: SIGNATURES_STORAGE_KEY,
+    version: 1,
+    migrations: &[persist::unchanged],
+};
 
 /// Maximum cached selectors (to prevent unbounded storage growth)
 const MAX_CACHED_SELECTORS: usize = 1000;
 
+const SOURCIFY_CONTRACT_API: &str = "https://sourcify.dev/server/v2/contract";
+
+/// Storage key for contract metadata cache
+const CONTRACTS_STORAGE_KEY: &str = "contracts_cache";
+/// Versioned format of the persisted contract cache
+const CONTRACTS_SCHEMA: Schema = Schema {
+    key: CONTRACTS_STORAGE_KEY,
+    version: 1,
+    migrations: &[persist::unchanged],
+};
+
+/// Maximum cached contracts (to prevent unbounded storage growth)
+const MAX_CACHED_CONTRACTS: usize = 1000;
+
 /// Log to console (works in both WASM and native)
 macro_rules! debug_log {
     ($($arg:tt)*) => {
@@ -113,16 +138,15 @@ impl SignatureLookup {
         }
     }
 
-    /// Load cache from eframe storage
+    /// Load cache from eframe storage. The cache is rebuildable, so an
+    /// unreadable blob is logged and replaced on the next save.
     pub fn load(storage: Option<&dyn eframe::Storage>) -> Self {
-        let cache = if let Some(storage) = storage {
-            storage
-                .get_string(SIGNATURES_STORAGE_KEY)
-                .and_then(|s| serde_json::from_str::<StoredCache>(&s).ok())
-                .map(|c| c.signatures)
-                .unwrap_or_default()
-        } else {
-            HashMap::new()
+        let cache = match SIGNATURES_SCHEMA.load::<StoredCache>(storage) {
+            Ok(stored) => stored.map(|c| c.signatures).unwrap_or_default(),
+            Err(e) => {
+                debug_log!("Discarding signature cache: {}", e);
+                HashMap::new()
+            }
         };
 
         debug_log!("Loaded {} cached signatures from storage", cache.len());
@@ -151,10 +175,8 @@ impl SignatureLookup {
         };
 
         let stored = StoredCache { signatures };
-        if let Ok(json) = serde_json::to_string(&stored) {
-            storage.set_string(SIGNATURES_STORAGE_KEY, json);
-            debug_log!("Saved {} signatures to storage", stored.signatures.len());
-        }
+        SIGNATURES_SCHEMA.save(storage, &stored);
+        debug_log!("Saved {} signatures to storage", stored.signatures.len());
     }
 
     /// Check if the API appears to be down
@@ -197,15 +219,17 @@ impl SignatureLookup {
     }
 
     /// Lookup a single selector (checks cache first)
-    /// Returns signatures with verification status, sorted by verified first
+    /// Returns signatures with verification status, sorted by verified first.
+    /// Falls back to the bundled standard ABIs when the API has nothing.
     pub async fn lookup(&self, selector: &str) -> Result<Vec<SignatureInfo>> {
+        let selector = normalize_selector(selector);
+
         // Short-circuit if API is marked as down
         if self.is_spurious() {
             debug_log!("Skipping lookup - API marked as spurious");
-            return Ok(vec![]);
+            return Ok(StandardAbiRegistry::signature_infos(&selector));
         }
 
-        let selector = normalize_selector(selector);
         debug_log!("Looking up selector: {}", selector);
 
         // Check cache
@@ -219,15 +243,28 @@ impl SignatureLookup {
         debug_log!("Cache miss for {}, fetching from API...", selector);
 
         // Fetch from API
-        let sigs = self.fetch_single(&selector).await?;
+        let sigs = match self.fetch_single(&selector).await {
+            Ok(sigs) => sigs,
+            Err(e) => {
+                let standard = StandardAbiRegistry::signature_infos(&selector);
+                if standard.is_empty() {
+                    return Err(e);
+                }
+                debug_log!("Fetch failed for {}, using bundled ABI: {}", selector, e);
+                return Ok(standard);
+            }
+        };
         debug_log!("Fetched {} signatures for {}", sigs.len(), selector);
 
         // Cache result
         {
             let mut cache = lock_or_recover!(self.cache);
-            cache.insert(selector, sigs.clone());
+            cache.insert(selector.clone(), sigs.clone());
         }
 
+        if sigs.is_empty() {
+            return Ok(StandardAbiRegistry::signature_infos(&selector));
+        }
         Ok(sigs)
     }
 
@@ -275,6 +312,17 @@ impl SignatureLookup {
             }
         }
 
+        // Fill anything the API couldn't resolve from the bundled standard ABIs
+        for sel in to_fetch {
+            if results.get(&sel).map_or(true, |sigs| sigs.is_empty()) {
+                let standard = StandardAbiRegistry::signature_infos(&sel);
+                if !standard.is_empty() {
+                    debug_log!("Using bundled ABI for {}", sel);
+                    results.insert(sel, standard);
+                }
+            }
+        }
+
         results
     }
 
@@ -372,6 +420,122 @@ impl SignatureLookup {
     }
 }
 
+/// Identity of a contract verified on Sourcify
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContractMeta {
+    /// Contract name from the compilation target
+    pub name: String,
+    /// Whether the metadata hash matched too, not just the bytecode
+    pub exact_match: bool,
+}
+
+/// Cached Sourcify contract lookup. `None` entries record addresses that
+/// aren't verified, so they aren't asked for again.
+#[derive(Clone, Default)]
+pub struct ContractLookup {
+    cache: Arc<Mutex<HashMap<String, Option<ContractMeta>>>>,
+}
+
+impl ContractLookup {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load cache from eframe storage. The cache is rebuildable, so an
+    /// unreadable blob is logged and replaced on the next save.
+    pub fn load(storage: Option<&dyn eframe::Storage>) -> Self {
+        let cache = match CONTRACTS_SCHEMA.load::<HashMap<String, Option<ContractMeta>>>(storage) {
+            Ok(stored) => stored.unwrap_or_default(),
+            Err(e) => {
+                debug_log!("Discarding contract cache: {}", e);
+                HashMap::new()
+            }
+        };
+
+        Self {
+            cache: Arc::new(Mutex::new(cache)),
+        }
+    }
+
+    /// Save cache to eframe storage
+    pub fn save(&self, storage: &mut dyn eframe::Storage) {
+        let cache = lock_or_recover!(self.cache);
+        let contracts: HashMap<&String, &Option<ContractMeta>> =
+            cache.iter().take(MAX_CACHED_CONTRACTS).collect();
+        CONTRACTS_SCHEMA.save(storage, &contracts);
+    }
+
+    /// Cached result without touching the network: `None` if not looked up
+    /// yet, `Some(None)` if the contract isn't verified
+    pub fn cached(&self, chain_id: u64, address: &str) -> Option<Option<ContractMeta>> {
+        let cache = lock_or_recover!(self.cache);
+        cache.get(&contract_key(chain_id, address)).cloned()
+    }
+
+    /// Look up a contract (checks cache first). Network errors give `None`
+    /// and aren't cached, so the address is retried on the next lookup.
+    pub async fn lookup_contract(&self, chain_id: u64, address: &str) -> Option<ContractMeta> {
+        let key = contract_key(chain_id, address);
+        if let Some(cached) = lock_or_recover!(self.cache).get(&key) {
+            return cached.clone();
+        }
+
+        let url = format!(
+            "{}/{}/{}?fields=compilation",
+            SOURCIFY_CONTRACT_API,
+            chain_id,
+            address.trim()
+        );
+        let meta = match reqwest::get(&url).await {
+            Ok(response) if response.status() == reqwest::StatusCode::NOT_FOUND => None,
+            Ok(response) if response.status().is_success() => {
+                match response.json::<serde_json::Value>().await {
+                    Ok(body) => parse_contract_response(&body),
+                    Err(e) => {
+                        debug_log!("Failed to parse contract {}: {}", key, e);
+                        return None;
+                    }
+                }
+            }
+            Ok(response) => {
+                debug_log!("Contract lookup for {} failed: {}", key, response.status());
+                return None;
+            }
+            Err(e) => {
+                debug_log!("Contract lookup for {} failed: {}", key, e);
+                return None;
+            }
+        };
+
+        lock_or_recover!(self.cache).insert(key, meta.clone());
+        meta
+    }
+
+    /// Look up every address not cached yet, one at a time
+    pub async fn resolve_all(&self, chain_id: u64, addresses: &[String]) {
+        for address in addresses {
+            if self.cached(chain_id, address).is_none() {
+                self.lookup_contract(chain_id, address).await;
+            }
+        }
+    }
+}
+
+fn contract_key(chain_id: u64, address: &str) -> String {
+    format!("{}:{}", chain_id, address.trim().to_lowercase())
+}
+
+/// Contract name from a Sourcify v2 contract response. Responses without a
+/// match are treated as unverified.
+fn parse_contract_response(body: &serde_json::Value) -> Option<ContractMeta> {
+    let matched = body.get("match")?.as_str()?;
+    let name = body.get("compilation")?.get("name")?.as_str()?;
+    Some(ContractMeta {
+        name: name.to_string(),
+        exact_match: matched == "exact_match",
+    })
+}
+
 /// Normalize selector to lowercase with 0x prefix
 fn normalize_selector(selector: &str) -> String {
     let sel = selector.trim().to_lowercase();
@@ -392,4 +556,34 @@ mod tests {
         assert_eq!(normalize_selector("a9059cbb"), "0xa9059cbb");
         assert_eq!(normalize_selector("0xa9059cbb"), "0xa9059cbb");
     }
+
+    #[test]
+    fn test_parse_contract_response() {
+        let verified = serde_json::json!({
+            "match": "exact_match",
+            "chainId": "1",
+            "compilation": {"name": "SafeProxy", "language": "Solidity"}
+        });
+        assert_eq!(
+            parse_contract_response(&verified),
+            Some(ContractMeta {
+                name: "SafeProxy".to_string(),
+                exact_match: true,
+            })
+        );
+
+        let partial = serde_json::json!({"match": "match", "compilation": {"name": "Token"}});
+        assert!(!parse_contract_response(&partial).unwrap().exact_match);
+
+        let unmatched = serde_json::json!({"match": null, "compilation": {"name": "Token"}});
+        assert_eq!(parse_contract_response(&unmatched), None);
+    }
+
+    #[test]
+    fn test_contract_key() {
+        assert_eq!(
+            contract_key(1, " 0xABCdef0000000000000000000000000000000000 "),
+            "1:0xabcdef0000000000000000000000000000000000"
+        );
+    }
 }
@@ -0,0 +1,114 @@
+//! Bundled standard ABIs
+//!
+//! Canonical signatures for ERC20, ERC721, ERC1155, Ownable, AccessControl,
+//! proxy upgrade and Safe functions. Used as a zero-network fallback when
+//! the signature database has no entry (or is unreachable), so the most
+//! common calls always decode, and with signatures that can't be
+//! crowd-sourced wrong.
+
+use super::sourcify::SignatureInfo;
+
+/// (selector, canonical signature) for each bundled function
+const STANDARD_FUNCTIONS: &[(&str, &str)] = &[
+    // ERC20 (transfer/approve/transferFrom are shared with ERC721)
+    ("0xa9059cbb", "transfer(address,uint256)"),
+    ("0x23b872dd", "transferFrom(address,address,uint256)"),
+    ("0x095ea7b3", "approve(address,uint256)"),
+    ("0x39509351", "increaseAllowance(address,uint256)"),
+    ("0xa457c2d7", "decreaseAllowance(address,uint256)"),
+    (
+        "0xd505accf",
+        "permit(address,address,uint256,uint256,uint8,bytes32,bytes32)",
+    ),
+    // ERC721
+    ("0x42842e0e", "safeTransferFrom(address,address,uint256)"),
+    ("0xb88d4fde", "safeTransferFrom(address,address,uint256,bytes)"),
+    ("0xa22cb465", "setApprovalForAll(address,bool)"),
+    // ERC1155
+    (
+        "0xf242432a",
+        "safeTransferFrom(address,address,uint256,uint256,bytes)",
+    ),
+    (
+        "0x2eb2c2d6",
+        "safeBatchTransferFrom(address,address,uint256[],uint256[],bytes)",
+    ),
+    // Ownable / Ownable2Step
+    ("0xf2fde38b", "transferOwnership(address)"),
+    ("0x715018a6", "renounceOwnership()"),
+    ("0x79ba5097", "acceptOwnership()"),
+    // AccessControl
+    ("0x2f2ff15d", "grantRole(bytes32,address)"),
+    ("0xd547741f", "revokeRole(bytes32,address)"),
+    ("0x36568abe", "renounceRole(bytes32,address)"),
+    // Proxies (UUPS, transparent, beacon)
+    ("0x3659cfe6", "upgradeTo(address)"),
+    ("0x4f1ef286", "upgradeToAndCall(address,bytes)"),
+    // Safe
+    ("0x0d582f13", "addOwnerWithThreshold(address,uint256)"),
+    ("0xf8dc5dd9", "removeOwner(address,address,uint256)"),
+    ("0xe318b52b", "swapOwner(address,address,address)"),
+    ("0x694e80c3", "changeThreshold(uint256)"),
+    ("0x610b5925", "enableModule(address)"),
+    ("0xe009cfde", "disableModule(address,address)"),
+    ("0xe19a9dd9", "setGuard(address)"),
+    ("0xf08a0323", "setFallbackHandler(address)"),
+    ("0xd4d9bdcd", "approveHash(bytes32)"),
+    (
+        "0x6a761202",
+        "execTransaction(address,uint256,bytes,uint8,uint256,uint256,uint256,address,address,bytes)",
+    ),
+    ("0x8d80ff0a", "multiSend(bytes)"),
+    ("0x85a5affe", "signMessage(bytes)"),
+];
+
+/// Compile-time registry of standard interface signatures
+pub struct StandardAbiRegistry;
+
+impl StandardAbiRegistry {
+    /// Canonical signature for a selector, if it belongs to a bundled interface
+    pub fn lookup(selector: &str) -> Option<&'static str> {
+        let selector = selector.trim().to_lowercase();
+        STANDARD_FUNCTIONS
+            .iter()
+            .find(|(sel, _)| *sel == selector)
+            .map(|(_, sig)| *sig)
+    }
+
+    /// Lookup result in the same shape as the signature database.
+    /// Bundled signatures are canonical, so they count as verified.
+    pub fn signature_infos(selector: &str) -> Vec<SignatureInfo> {
+        Self::lookup(selector)
+            .map(|sig| {
+                vec![SignatureInfo {
+                    signature: sig.to_string(),
+                    verified: true,
+                }]
+            })
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_selectors_match_signatures() {
+        for (selector, sig) in STANDARD_FUNCTIONS {
+            let hash = alloy::primitives::keccak256(sig.as_bytes());
+            let expected = format!("0x{}", alloy::hex::encode(&hash[..4]));
+            assert_eq!(*selector, expected, "selector for {}", sig);
+        }
+    }
+
+    #[test]
+    fn test_lookup() {
+        assert_eq!(
+            StandardAbiRegistry::lookup("0xA9059CBB"),
+            Some("transfer(address,uint256)")
+        );
+        assert!(StandardAbiRegistry::lookup("0xdeadbeef").is_none());
+        assert!(StandardAbiRegistry::signature_infos("0xa9059cbb")[0].verified);
+    }
+}
@@ -3,7 +3,7 @@
 use std::collections::HashMap;
 
 /// Top-level decoded transaction
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, serde::Serialize, Default)]
 pub struct DecodedTransaction {
     pub raw_data: String,
     pub selector: String,
@@ -12,33 +12,124 @@ pub struct DecodedTransaction {
 }
 
 /// Type of transaction calldata
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, serde::Serialize, Default)]
+#[serde(rename_all = "snake_case")]
 pub enum TransactionKind {
     #[default]
     Empty,
     Single(SingleDecode),
     MultiSend(MultiSendDecode),
+    /// ERC-4337 `handleOps` on an EntryPoint
+    UserOp(UserOpBundle),
     Unknown,
 }
 
 /// Single function call decode (both sources)
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, serde::Serialize, Default)]
 pub struct SingleDecode {
     pub api: Option<ApiDecode>,
     pub local: Option<LocalDecode>,
     pub comparison: ComparisonResult,
+    /// Explorer verified-ABI decode, when an explorer API key is set
+    pub explorer: Option<ExplorerCheck>,
+}
+
+impl SingleDecode {
+    /// Whether more than one signature decodes the calldata
+    pub fn is_ambiguous(&self) -> bool {
+        self.local
+            .as_ref()
+            .is_some_and(|l| !l.alternatives.is_empty())
+    }
+
+    /// Method and params, preferring the independent local decode over the API's
+    pub fn call(&self) -> Option<CallParams<'_>> {
+        self.local
+            .as_ref()
+            .map(LocalDecode::call)
+            .or_else(|| self.api.as_ref().map(ApiDecode::call))
+    }
 }
 
 /// MultiSend batch decode
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, serde::Serialize, Default)]
 pub struct MultiSendDecode {
     pub transactions: Vec<MultiSendTx>,
     pub summary: MultiSendSummary,
     pub verification_state: VerificationState,
+    /// Sub-transactions that call back into the Safe's own execution methods
+    pub self_reentries: Vec<SelfReentry>,
+    /// DELEGATECALL sub-transactions, including inside nested batches
+    pub delegatecalls: Vec<NestedDelegatecall>,
+    /// Remaining input while the batch is still being parsed
+    #[serde(skip)]
+    pub parse: Option<MultiSendParse>,
+    /// UI-only: outcome of the last CSV export
+    #[serde(skip)]
+    pub csv_export: Option<Result<String, String>>,
+}
+
+impl MultiSendDecode {
+    /// Whether transactions are still being parsed
+    pub fn is_parsing(&self) -> bool {
+        self.parse.is_some()
+    }
+
+    /// Estimated total transaction count while parsing, from the average
+    /// size of the transactions parsed so far
+    pub fn estimated_total(&self) -> usize {
+        let parsed = self.transactions.len();
+        match &self.parse {
+            Some(parse) if parse.offset > 0 && parsed > 0 => {
+                let remaining = parse.packed.len() - parse.offset;
+                parsed + remaining.div_ceil((parse.offset / parsed).max(1))
+            }
+            _ => parsed,
+        }
+    }
+}
+
+/// Input of an incremental MultiSend parse
+#[derive(Debug, Clone)]
+pub struct MultiSendParse {
+    /// Packed transactions (the `multiSend` bytes argument)
+    pub packed: Vec<u8>,
+    /// Bytes parsed so far
+    pub offset: usize,
+    /// Service `dataDecoded` by transaction index
+    pub api_items: Vec<Option<serde_json::Value>>,
+}
+
+/// A MultiSend sub-transaction that re-enters the Safe's execution machinery
+#[derive(Debug, Clone, serde::Serialize, PartialEq, Eq)]
+pub struct SelfReentry {
+    pub index: usize,
+    pub method: String,
+}
+
+/// A DELEGATECALL inside a MultiSend batch, at any nesting depth
+#[derive(Debug, Clone, serde::Serialize, PartialEq, Eq)]
+pub struct NestedDelegatecall {
+    /// Index at each nesting level, outermost first
+    pub path: Vec<usize>,
+    pub to: String,
+}
+
+impl NestedDelegatecall {
+    /// 1-based batch position, e.g. "2.1" for the first call of the nested
+    /// batch at position 2
+    pub fn position(&self) -> String {
+        self.path
+            .iter()
+            .map(|i| (i + 1).to_string())
+            .collect::<Vec<_>>()
+            .join(".")
+    }
 }
 
 /// Verification state for bulk operations
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, serde::Serialize, Default)]
+#[serde(rename_all = "snake_case")]
 pub enum VerificationState {
     #[default]
     Pending,
@@ -49,7 +140,7 @@ pub enum VerificationState {
 }
 
 /// Single transaction within a MultiSend batch
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct MultiSendTx {
     pub index: usize,
     pub operation: u8,
@@ -60,12 +151,26 @@ pub struct MultiSendTx {
     pub api_decode: Option<ApiDecode>,
     /// Full decode comparison (populated after bulk verification)
     pub decode: Option<SingleDecode>,
+    /// Batch carried by a `multiSend` call in this transaction's data
+    pub nested: Option<Box<MultiSendDecode>>,
     /// UI-only: whether this item is expanded for viewing details
+    #[serde(skip)]
     pub is_expanded: bool,
 }
 
+impl MultiSendTx {
+    /// Method and params, preferring the independent local decode over the API's
+    pub fn call(&self) -> Option<CallParams<'_>> {
+        self.decode
+            .as_ref()
+            .and_then(|d| d.local.as_ref())
+            .map(LocalDecode::call)
+            .or_else(|| self.api_decode.as_ref().map(ApiDecode::call))
+    }
+}
+
 /// Summary counts for MultiSend
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, serde::Serialize, Default)]
 pub struct MultiSendSummary {
     pub total: usize,
     pub verified: usize,
@@ -74,14 +179,30 @@ pub struct MultiSendSummary {
 }
 
 impl MultiSendSummary {
+    /// Recount a batch, adding in the (already updated) summaries of its
+    /// nested batches
     pub fn update(&mut self, transactions: &[MultiSendTx]) {
-        self.total = transactions.len();
+        self.update_from(transactions.iter().map(|tx| tx.decode.as_ref()));
+        for nested in transactions.iter().filter_map(|tx| tx.nested.as_deref()) {
+            self.total += nested.summary.total;
+            self.verified += nested.summary.verified;
+            self.mismatched += nested.summary.mismatched;
+            self.pending += nested.summary.pending;
+        }
+    }
+
+    /// Recount from the per-call decodes of any batch
+    pub fn update_from<'a>(&mut self, decodes: impl IntoIterator<Item = Option<&'a SingleDecode>>) {
+        self.total = 0;
         self.verified = 0;
         self.mismatched = 0;
         self.pending = 0;
 
-        for tx in transactions {
-            match &tx.decode {
+        for decode in decodes {
+            self.total += 1;
+            match decode {
+                // Agreeing with the API proves little if another signature fits too
+                Some(d) if d.comparison.is_match() && d.is_ambiguous() => self.pending += 1,
                 Some(d) => match &d.comparison {
                     ComparisonResult::Match => self.verified += 1,
                     ComparisonResult::MethodMismatch { .. }
@@ -98,46 +219,164 @@ impl MultiSendSummary {
     }
 }
 
+// --- ERC-4337 UserOperations ---
+
+/// EntryPoint ABI layout of a `handleOps` call
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub enum EntryPointVersion {
+    /// `UserOperation` with separate gas fields
+    V06,
+    /// `PackedUserOperation` with packed gas limits and fees
+    V07,
+}
+
+impl EntryPointVersion {
+    pub fn label(&self) -> &'static str {
+        match self {
+            EntryPointVersion::V06 => "EntryPoint v0.6",
+            EntryPointVersion::V07 => "EntryPoint v0.7",
+        }
+    }
+}
+
+/// Decoded `handleOps(ops, beneficiary)` bundle
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct UserOpBundle {
+    pub entry_point: EntryPointVersion,
+    pub beneficiary: String,
+    pub ops: Vec<UserOperation>,
+    pub summary: MultiSendSummary,
+    pub verification_state: VerificationState,
+}
+
+/// One UserOperation from a bundle
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct UserOperation {
+    pub index: usize,
+    /// Smart account the operation runs as
+    pub sender: String,
+    pub nonce: String,
+    /// Account deployment code, `0x` for deployed accounts
+    pub init_code: String,
+    /// Call the account executes
+    pub call_data: String,
+    /// First 20 bytes of `paymasterAndData`
+    pub paymaster: Option<String>,
+    /// Rest of `paymasterAndData`
+    pub paymaster_data: String,
+    /// Independent decode of `call_data` (populated after bulk verification)
+    pub decode: Option<SingleDecode>,
+    /// UI-only: whether this item is expanded for viewing details
+    #[serde(skip)]
+    pub is_expanded: bool,
+}
+
 // --- API Decode (from Safe Transaction Service) ---
 
+/// Method name and `(type, value)` params of a decoded call
+pub type CallParams<'a> = (&'a str, Vec<(&'a str, &'a str)>);
+
 /// Decode provided by Safe API
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct ApiDecode {
     pub method: String,
     pub params: Vec<ApiParam>,
 }
 
 /// Parameter from API decode
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct ApiParam {
     pub name: String,
     pub typ: String,
     pub value: String,
 }
 
+impl ApiDecode {
+    pub fn call(&self) -> CallParams<'_> {
+        let params = self
+            .params
+            .iter()
+            .map(|p| (p.typ.as_str(), p.value.as_str()))
+            .collect();
+        (&self.method, params)
+    }
+}
+
+// --- Safe{Wallet} web UI view (API decode, hierarchical) ---
+
+/// API `dataDecoded` kept in the nested shape the Safe{Wallet} web UI renders
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct WebUiDecode {
+    pub method: String,
+    pub params: Vec<WebUiParam>,
+}
+
+/// Parameter with any `valueDecoded` actions attached
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct WebUiParam {
+    pub name: String,
+    pub typ: String,
+    pub value: String,
+    /// Nested actions (e.g. the transactions inside multiSend)
+    pub actions: Vec<WebUiAction>,
+}
+
+/// One nested action from `valueDecoded`
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct WebUiAction {
+    /// As the service sent it; `None` when missing or not a number. Only 0
+    /// and 1 are valid.
+    pub operation: Option<u64>,
+    pub to: String,
+    pub value: String,
+    pub data: String,
+    pub decoded: Option<WebUiDecode>,
+}
+
 // --- Local Decode (from 4byte + alloy) ---
 
 /// Decode from local 4byte lookup + ABI decoding
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct LocalDecode {
     pub signature: String,
     pub method: String,
     pub params: Vec<LocalParam>,
     /// Whether this signature comes from a verified contract on Sourcify
     pub verified: bool,
+    /// Other signatures for the selector that also decode the calldata. A
+    /// selector collision: the data alone can't tell which one is called.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub alternatives: Vec<LocalDecode>,
 }
 
 /// Parameter from local decode (no names, just types)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct LocalParam {
     pub typ: String,
+    /// Whole value, tuples and arrays flattened to `[a, b]` as the API does
     pub value: String,
+    /// Components of a tuple or elements of an array, each decoded the same
+    /// way; empty for scalar values
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<LocalParam>,
+}
+
+impl LocalDecode {
+    pub fn call(&self) -> CallParams<'_> {
+        let params = self
+            .params
+            .iter()
+            .map(|p| (p.typ.as_str(), p.value.as_str()))
+            .collect();
+        (&self.method, params)
+    }
 }
 
 // --- Comparison ---
 
 /// Result of comparing API vs Local decode
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, serde::Serialize, Default)]
+#[serde(rename_all = "snake_case")]
 pub enum ComparisonResult {
     #[default]
     Pending,
@@ -165,8 +404,51 @@ impl ComparisonResult {
     }
 }
 
+/// Where a decode came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DecodeSource {
+    /// Safe Transaction Service `dataDecoded`
+    Api,
+    /// Signature database lookup or bundled standard ABI
+    Signature,
+    /// The explorer's verified ABI for the called contract
+    Explorer,
+}
+
+impl DecodeSource {
+    pub fn label(&self) -> &'static str {
+        match self {
+            DecodeSource::Api => "Safe API",
+            DecodeSource::Signature => "4byte",
+            DecodeSource::Explorer => "Explorer ABI",
+        }
+    }
+}
+
+/// Explorer verified-ABI decode checked against the other sources
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ExplorerCheck {
+    /// Decode with the verified ABI, or why there is none
+    pub decode: Result<LocalDecode, String>,
+    /// Whether the explorer decode agrees with each source that decoded
+    pub agreement: Vec<(DecodeSource, bool)>,
+}
+
+impl ExplorerCheck {
+    pub fn is_mismatch(&self) -> bool {
+        self.agreement.iter().any(|(_, agrees)| !agrees)
+    }
+
+    /// Disagrees with both other sources: the strongest mismatch signal,
+    /// since the verified ABI is the contract's actual interface
+    pub fn disagrees_with_all(&self) -> bool {
+        self.agreement.len() >= 2 && self.agreement.iter().all(|(_, agrees)| !agrees)
+    }
+}
+
 /// Difference in a single parameter
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct ParamDiff {
     pub index: usize,
     pub typ: String,
@@ -175,7 +457,8 @@ pub struct ParamDiff {
 }
 
 /// Overall status for the transaction
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, serde::Serialize, Default)]
+#[serde(rename_all = "snake_case")]
 pub enum OverallStatus {
     #[default]
     Pending,
@@ -218,7 +501,8 @@ impl SignatureCache {
 // =============================================================================
 
 /// Status of offline decode (no API comparison, just 4byte lookup result)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum OfflineDecodeStatus {
     /// Successfully decoded via 4byte lookup (green ✅)
     Decoded,
@@ -248,7 +532,7 @@ impl OfflineDecodeStatus {
 }
 
 /// Single transaction within an offline MultiSend batch
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct OfflineMultiSendTx {
     pub index: usize,
     pub operation: u8,
@@ -260,11 +544,13 @@ pub struct OfflineMultiSendTx {
     /// Decode status
     pub status: OfflineDecodeStatus,
     /// UI-only: whether this item is expanded
+    #[serde(skip)]
     pub is_expanded: bool,
 }
 
 /// Result of offline calldata decode
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum OfflineDecodeResult {
     /// Empty calldata (native ETH transfer)
     Empty,
@@ -274,7 +560,11 @@ pub enum OfflineDecodeResult {
         status: OfflineDecodeStatus,
     },
     /// MultiSend batch
-    MultiSend(Vec<OfflineMultiSendTx>),
+    MultiSend {
+        txs: Vec<OfflineMultiSendTx>,
+        /// DELEGATECALL sub-transactions, including inside nested batches
+        delegatecalls: Vec<NestedDelegatecall>,
+    },
     /// Could not parse calldata (shows raw hex)
     RawHex(String),
 }
@@ -1,9 +1,16 @@
 //! Calldata decode UI rendering
 
+use super::admin::{AdminCall, AdminKind};
+use super::approval::Approval;
+use super::nested_safe::{classify_nested_safe, NestedSafeTx};
+use super::parser::get_selector;
+use super::permit::{DeadlineStatus, PermitInfo};
+use super::relay::{classify_relay, RelayCall};
+use super::sourcify::ContractLookup;
 use super::types::*;
+use super::wrapped::classify_wrap;
 use crate::ui::{self, validate_address, AddressValidation};
 use eframe::egui;
-use safe_utils::Of;
 
 /// Check if a value looks like a tuple/array (starts with [ and ends with ])
 fn is_tuple_or_array(value: &str) -> bool {
@@ -77,8 +84,7 @@ fn render_single_value(
             };
 
             // Look up name in address book
-            let chain_id = alloy::primitives::ChainId::of(chain_name).unwrap_or(1);
-            let name = safe_ctx.address_book.get_name(value, chain_id);
+            let name = safe_ctx.name_of(value);
             let label_text = if let Some(n) = name {
                 format!("{} ({})", value, n)
             } else {
@@ -159,11 +165,49 @@ fn render_param_value(
     }
 }
 
+/// Render a locally decoded parameter. Tuples and arrays are shown from
+/// their decoded structure, one row per element, with nested ones as
+/// collapsible sub-trees.
+fn render_local_param(
+    ui: &mut egui::Ui,
+    param: &LocalParam,
+    safe_ctx: &crate::state::SafeContext,
+    color: Option<egui::Color32>,
+    id_salt: &str,
+) {
+    if param.children.is_empty() {
+        render_single_value(ui, &param.value, safe_ctx, color, id_salt);
+        return;
+    }
+
+    ui.vertical(|ui| {
+        for (i, child) in param.children.iter().enumerate() {
+            let child_id = format!("{}_{}", id_salt, i);
+            let label = format!("[{}] {}", i, child.typ);
+            if child.children.is_empty() {
+                ui.horizontal_wrapped(|ui| {
+                    ui.label(egui::RichText::new(format!("{}:", label)).weak().small());
+                    render_single_value(ui, &child.value, safe_ctx, color, &child_id);
+                });
+            } else {
+                let title = format!("{} ({} items)", label, child.children.len());
+                egui::CollapsingHeader::new(egui::RichText::new(title).small())
+                    .id_salt(&child_id)
+                    .default_open(true)
+                    .show(ui, |ui| {
+                        render_local_param(ui, child, safe_ctx, color, &child_id);
+                    });
+            }
+        }
+    });
+}
+
 /// Render the full decode section
 pub fn render_decode_section(
     ui: &mut egui::Ui,
     decode: &mut DecodedTransaction,
     safe_ctx: &crate::state::SafeContext,
+    contracts: &ContractLookup,
 ) {
     ui.add_space(10.0);
 
@@ -178,7 +222,10 @@ pub fn render_decode_section(
             render_single_section(ui, single, &decode.selector, safe_ctx);
         }
         TransactionKind::MultiSend(multi) => {
-            render_multisend_section(ui, multi, safe_ctx);
+            render_multisend_section(ui, multi, safe_ctx, contracts);
+        }
+        TransactionKind::UserOp(bundle) => {
+            render_user_op_section(ui, bundle, safe_ctx);
         }
         TransactionKind::Unknown => {
             ui.horizontal(|ui| {
@@ -193,6 +240,555 @@ pub fn render_decode_section(
     }
 }
 
+/// Render warnings for emergency/admin calls. Fund-moving calls are errors
+/// unless the funds go back to this Safe or the protocol contract itself.
+pub fn render_admin_warnings(
+    ui: &mut egui::Ui,
+    calls: &[AdminCall],
+    safe_ctx: &crate::state::SafeContext,
+) {
+    for call in calls {
+        let prefix = match call.index {
+            Some(i) => format!("TX #{}: ", i + 1),
+            None => String::new(),
+        };
+        let summary = format!("{}{}() {}", prefix, call.method, call.kind.effect());
+
+        if call.kind == AdminKind::Upgrade {
+            // A beacon's upgradeTo looks the same as a proxy's, so assume the
+            // wider blast radius unless the user knows the target
+            let implementation = call.implementation.as_deref().unwrap_or("unknown");
+            ui::error_banner(
+                ui,
+                &format!(
+                    "{} with {}. If {} is a beacon, EVERY proxy pointing at it is upgraded",
+                    summary, implementation, call.target
+                ),
+            );
+            continue;
+        }
+
+        if !call.kind.moves_funds() {
+            match call.kind {
+                AdminKind::Pause => ui::warning_banner(ui, &summary),
+                _ => ui::warning_message(ui, &summary, egui::Color32::from_rgb(220, 180, 50)),
+            }
+            continue;
+        }
+
+        match &call.destination {
+            Some(dest) => {
+                let dest_lower = dest.to_lowercase();
+                let to_safe = dest_lower == safe_ctx.safe_address.to_lowercase();
+                let to_protocol = dest_lower == call.target.to_lowercase();
+                if to_safe || to_protocol {
+                    ui::warning_banner(
+                        ui,
+                        &format!(
+                            "{} to {}",
+                            summary,
+                            if to_safe {
+                                "this Safe"
+                            } else {
+                                "the protocol contract"
+                            }
+                        ),
+                    );
+                } else {
+                    let name = safe_ctx
+                        .name_of(dest)
+                        .map(|n| format!(" ({})", n))
+                        .unwrap_or_default();
+                    ui::error_banner(
+                        ui,
+                        &format!(
+                            "{} to {}{} - neither this Safe nor the protocol",
+                            summary, dest, name
+                        ),
+                    );
+                }
+            }
+            None => ui::warning_banner(ui, &summary),
+        }
+    }
+}
+
+/// Unlimited approvals as warnings; finite ones as notes with the amount,
+/// shown raw and at 18 decimals since the token's decimals aren't known
+pub fn render_approval_warnings(
+    ui: &mut egui::Ui,
+    approvals: &[Approval],
+    safe_ctx: &crate::state::SafeContext,
+) {
+    for approval in approvals {
+        let prefix = match approval.index {
+            Some(i) => format!("TX #{}: ", i + 1),
+            None => String::new(),
+        };
+        let spender = match safe_ctx.name_of(&approval.spender) {
+            Some(name) => format!("{} ({})", approval.spender, name),
+            None => approval.spender.clone(),
+        };
+
+        if approval.is_unlimited() {
+            ui::warning_banner(
+                ui,
+                &format!(
+                    "{}Unlimited approval granted to {} on token {}",
+                    prefix, spender, approval.token
+                ),
+            );
+        } else {
+            let amount = approval.amount.to_string();
+            ui.label(
+                egui::RichText::new(format!(
+                    "ℹ {}Approves {} to spend {} of token {} (= {} at 18 decimals)",
+                    prefix,
+                    spender,
+                    amount,
+                    approval.token,
+                    ui::format_uint_with_decimals(&amount, 18)
+                ))
+                .weak(),
+            );
+        }
+    }
+}
+
+/// Unix seconds as a UTC date, or the raw value past `u64`
+fn utc_time(timestamp: alloy::primitives::U256) -> String {
+    u64::try_from(timestamp)
+        .map(ui::format_utc)
+        .unwrap_or_else(|_| timestamp.to_string())
+}
+
+/// Permits as a structured summary, warning on unlimited amounts and on
+/// deadlines or allowance expirations that have passed or are more than a
+/// year out
+pub fn render_permit_warnings(
+    ui: &mut egui::Ui,
+    permits: &[PermitInfo],
+    safe_ctx: &crate::state::SafeContext,
+) {
+    let now = web_time::SystemTime::now()
+        .duration_since(web_time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+    let named = |address: &str| match safe_ctx.name_of(address) {
+        Some(name) => format!("{} ({})", address, name),
+        None => address.to_string(),
+    };
+
+    for permit in permits {
+        let prefix = match permit.index {
+            Some(i) => format!("TX #{}: ", i + 1),
+            None => String::new(),
+        };
+        let amount = if permit.is_unlimited() {
+            "unlimited".to_string()
+        } else {
+            let raw = permit.value.to_string();
+            let decimals = safe_ctx
+                .chain_id()
+                .and_then(|id| safe_ctx.address_book.decimals(&permit.token, id));
+            match decimals {
+                Some(decimals) => format!(
+                    "{} (= {})",
+                    raw,
+                    ui::format_uint_with_decimals(&raw, decimals)
+                ),
+                None => format!("{} raw units (unknown decimals)", raw),
+            }
+        };
+        let grantee = match (&permit.spender, &permit.recipient) {
+            (_, Some(recipient)) => format!("sends to {}", named(recipient)),
+            (Some(spender), None) => format!("lets {} spend", named(spender)),
+            (None, None) => "lets the caller spend".to_string(),
+        };
+        let deadline = match permit.deadline_status(now) {
+            DeadlineStatus::Never => "never expires".to_string(),
+            _ => format!("deadline {}", utc_time(permit.deadline)),
+        };
+        let expiration = permit
+            .expiration
+            .map(|e| format!(", allowance expires {}", utc_time(e)))
+            .unwrap_or_default();
+        ui.label(
+            egui::RichText::new(format!(
+                "✍ {}{}: {} {} {} of token {} ({}{})",
+                prefix,
+                permit.label,
+                named(&permit.owner),
+                grantee,
+                amount,
+                permit.token,
+                deadline,
+                expiration
+            ))
+            .weak(),
+        );
+
+        if permit.is_unlimited() {
+            ui::warning_banner(
+                ui,
+                &format!("{}{} grants an unlimited amount", prefix, permit.label),
+            );
+        }
+        match permit.deadline_status(now) {
+            DeadlineStatus::Expired => ui::warning_banner(
+                ui,
+                &format!(
+                    "{}{} deadline has passed; this call will revert",
+                    prefix, permit.label
+                ),
+            ),
+            DeadlineStatus::FarFuture | DeadlineStatus::Never => ui::warning_banner(
+                ui,
+                &format!(
+                    "{}{} signature stays usable for more than a year",
+                    prefix, permit.label
+                ),
+            ),
+            DeadlineStatus::Valid => {}
+        }
+        if permit.expiration_status(now) == Some(DeadlineStatus::FarFuture) {
+            ui::warning_banner(
+                ui,
+                &format!(
+                    "{}{} allowance stays active for more than a year",
+                    prefix, permit.label
+                ),
+            );
+        }
+    }
+}
+
+/// One-line summary for wraps/unwraps on the chain's wrapped-native token
+pub fn render_wrap_summary(
+    ui: &mut egui::Ui,
+    to: &str,
+    value: &str,
+    data: &str,
+    safe_ctx: &crate::state::SafeContext,
+) {
+    let wrap = safe_ctx
+        .chain_id()
+        .and_then(|id| classify_wrap(id, to, value, data));
+    if let Some(wrap) = wrap {
+        ui.label(egui::RichText::new(format!("🔄 {}", wrap.describe())).strong());
+        ui.add_space(4.0);
+    }
+}
+
+/// Nested intent of a meta-transaction relay call to `to`, shown above the
+/// outer decode since it's what actually executes
+pub fn render_relay_summary(
+    ui: &mut egui::Ui,
+    to: &str,
+    relay: &RelayCall,
+    safe_ctx: &crate::state::SafeContext,
+) {
+    let id_prefix = format!("relay_{}_{}", to, relay.signature);
+
+    ui::warning_banner(
+        ui,
+        &format!(
+            "Relays an action signed by {} ({}). The nested call below runs with \
+             the signer's authority; review it, not the relay wrapper.",
+            relay.signer, relay.label
+        ),
+    );
+    ui.add_space(4.0);
+
+    ui.label(egui::RichText::new("Relayed call").strong());
+    egui::Grid::new(format!("{}_target", id_prefix))
+        .num_columns(2)
+        .spacing([10.0, 4.0])
+        .show(ui, |ui| {
+            ui.label("Target:");
+            let target = relay.target.as_deref().unwrap_or(to);
+            let name = safe_ctx.name_of(target);
+            ui::address_link(ui, &safe_ctx.chain_name, target, name);
+            ui.end_row();
+
+            if let Some(value) = &relay.value {
+                ui.label("Value:");
+                ui.label(ui::format_wei_value(value));
+                ui.end_row();
+            }
+        });
+    ui.add_space(4.0);
+
+    match &relay.inner {
+        Some(inner) => render_offline_decode(ui, inner, safe_ctx, &id_prefix),
+        None => {
+            ui.label(
+                egui::RichText::new(format!(
+                    "Unknown method {}",
+                    relay.inner_selector().unwrap_or("(no selector)")
+                ))
+                .monospace(),
+            );
+            render_raw_data(ui, &relay.data);
+        }
+    }
+
+    egui::CollapsingHeader::new("Relay details")
+        .id_salt(format!("{}_details", id_prefix))
+        .default_open(false)
+        .show(ui, |ui| {
+            egui::Grid::new(format!("{}_details_grid", id_prefix))
+                .num_columns(2)
+                .spacing([10.0, 4.0])
+                .show(ui, |ui| {
+                    ui.label("Relay method:");
+                    ui.label(egui::RichText::new(&relay.method).monospace());
+                    ui.end_row();
+
+                    ui.label("Signer:");
+                    let name = safe_ctx.name_of(&relay.signer);
+                    ui::address_link(ui, &safe_ctx.chain_name, &relay.signer, name);
+                    ui.end_row();
+
+                    ui.label("Signature:");
+                    ui::copyable_hash(ui, &relay.signature);
+                    ui.end_row();
+                });
+        });
+    ui.add_space(8.0);
+}
+
+/// Safe transaction a parent Safe executes on a child Safe (`to`), decoded
+/// like a top-level one. Its hash is computed when the child is a saved Safe
+/// (so its version is known), for a nonce the user enters.
+pub fn render_nested_safe_summary(
+    ui: &mut egui::Ui,
+    to: &str,
+    nested: &NestedSafeTx,
+    safe_ctx: &crate::state::SafeContext,
+) {
+    let id_prefix = format!("nested_safe_{}_{}", to, nested.signatures);
+
+    ui::warning_banner(
+        ui,
+        &format!(
+            "Executes a transaction on Safe {} with its owners' signatures. The inner \
+             transaction below runs from that Safe.",
+            to
+        ),
+    );
+    ui.add_space(4.0);
+
+    ui.label(egui::RichText::new("Inner Safe transaction").strong());
+    egui::Grid::new(format!("{}_target", id_prefix))
+        .num_columns(2)
+        .spacing([10.0, 4.0])
+        .show(ui, |ui| {
+            ui.label("Target:");
+            let name = safe_ctx.name_of(&nested.to);
+            ui::address_link(ui, &safe_ctx.chain_name, &nested.to, name);
+            ui.end_row();
+
+            ui.label("Value:");
+            ui.label(ui::format_wei_value(&nested.value));
+            ui.end_row();
+
+            ui.label("Operation:");
+            ui.label(ui::operation_text(nested.operation.into()));
+            ui.end_row();
+        });
+    ui.add_space(4.0);
+
+    match &nested.inner {
+        Some(inner) => render_offline_decode(ui, inner, safe_ctx, &id_prefix),
+        None if nested.data == "0x" => {
+            ui.label(egui::RichText::new("No calldata").weak());
+        }
+        None => {
+            ui.label(
+                egui::RichText::new(format!(
+                    "Unknown method {}",
+                    nested.inner_selector().unwrap_or("(no selector)")
+                ))
+                .monospace(),
+            );
+            render_raw_data(ui, &nested.data);
+        }
+    }
+
+    egui::CollapsingHeader::new("Inner transaction details")
+        .id_salt(format!("{}_details", id_prefix))
+        .default_open(false)
+        .show(ui, |ui| {
+            egui::Grid::new(format!("{}_details_grid", id_prefix))
+                .num_columns(2)
+                .spacing([10.0, 4.0])
+                .show(ui, |ui| {
+                    for (label, value) in [
+                        ("safeTxGas:", &nested.safe_tx_gas),
+                        ("baseGas:", &nested.base_gas),
+                        ("gasPrice:", &nested.gas_price),
+                        ("gasToken:", &nested.gas_token),
+                        ("refundReceiver:", &nested.refund_receiver),
+                    ] {
+                        ui.label(label);
+                        ui.label(egui::RichText::new(value).monospace());
+                        ui.end_row();
+                    }
+
+                    ui.label("Signatures:");
+                    ui::copyable_hash(ui, &nested.signatures);
+                    ui.end_row();
+                });
+
+            ui.add_space(4.0);
+            let Some(saved) = safe_ctx.saved_safes.find(&safe_ctx.chain_name, to) else {
+                ui.label(
+                    egui::RichText::new(
+                        "Save the child Safe with its version to compute the inner Safe tx hash.",
+                    )
+                    .small()
+                    .weak(),
+                );
+                return;
+            };
+            let nonce_id = egui::Id::new(&id_prefix).with("nonce");
+            let mut nonce = ui.memory(|m| m.data.get_temp::<String>(nonce_id).unwrap_or_default());
+            ui.horizontal(|ui| {
+                ui.label(format!("{} (v{}) nonce:", saved.label, saved.version));
+                ui::number_input(ui, &mut nonce, "child Safe nonce");
+            });
+            ui.memory_mut(|m| m.data.insert_temp(nonce_id, nonce.clone()));
+            if nonce.trim().is_empty() {
+                return;
+            }
+            match crate::hasher::compute_hashes(
+                &safe_ctx.chain_name,
+                to,
+                &saved.version,
+                &nested.to,
+                &nested.value,
+                &nested.data,
+                nested.operation,
+                &nested.safe_tx_gas,
+                &nested.base_gas,
+                &nested.gas_price,
+                &nested.gas_token,
+                &nested.refund_receiver,
+                nonce.trim(),
+            ) {
+                Ok(hashes) => {
+                    ui.horizontal(|ui| {
+                        ui.label("Safe Tx Hash:");
+                        ui::copyable_hash(ui, &hashes.safe_tx_hash);
+                    });
+                }
+                Err(e) => ui::error_message(ui, &format!("Hash failed: {:#}", e)),
+            }
+        });
+    ui.add_space(8.0);
+}
+
+/// Render the API decode the way the Safe{Wallet} web UI does: method name,
+/// named parameters, and nested actions as numbered collapsible entries
+pub fn render_web_ui_decode(
+    ui: &mut egui::Ui,
+    decode: &WebUiDecode,
+    safe_ctx: &crate::state::SafeContext,
+) {
+    render_web_ui_node(ui, decode, safe_ctx, "web_ui");
+}
+
+fn render_web_ui_node(
+    ui: &mut egui::Ui,
+    decode: &WebUiDecode,
+    safe_ctx: &crate::state::SafeContext,
+    id_prefix: &str,
+) {
+    ui.label(egui::RichText::new(&decode.method).strong().monospace());
+
+    for (i, param) in decode.params.iter().enumerate() {
+        if !param.actions.is_empty() {
+            for (j, action) in param.actions.iter().enumerate() {
+                let action_id = format!("{}_{}_{}", id_prefix, i, j);
+                let title = match &action.decoded {
+                    Some(d) => format!("Action {}: {}", j + 1, d.method),
+                    None if action.data.is_empty() || action.data == "0x" => {
+                        format!("Action {}: Send {}", j + 1, format_wei(&action.value))
+                    }
+                    None => format!("Action {}: Contract interaction", j + 1),
+                };
+
+                egui::CollapsingHeader::new(title)
+                    .id_salt(&action_id)
+                    .show(ui, |ui| {
+                        egui::Grid::new(format!("{}_grid", action_id))
+                            .num_columns(2)
+                            .spacing([10.0, 4.0])
+                            .show(ui, |ui| {
+                                ui.label(egui::RichText::new("Interact with:").small());
+                                render_param_value(
+                                    ui,
+                                    &action.to,
+                                    safe_ctx,
+                                    None,
+                                    &format!("{}_to", action_id),
+                                );
+                                ui.end_row();
+
+                                ui.label(egui::RichText::new("Value:").small());
+                                ui.label(format_wei(&action.value));
+                                ui.end_row();
+
+                                match action.operation {
+                                    Some(0) => {}
+                                    Some(1) => {
+                                        ui.label(egui::RichText::new("Operation:").small());
+                                        ui.label(
+                                            egui::RichText::new("DelegateCall")
+                                                .color(egui::Color32::from_rgb(220, 80, 80)),
+                                        );
+                                        ui.end_row();
+                                    }
+                                    Some(n) => {
+                                        ui.label(egui::RichText::new("Operation:").small());
+                                        ui.label(ui::operation_text(n));
+                                        ui.end_row();
+                                    }
+                                    None => {
+                                        ui.label(egui::RichText::new("Operation:").small());
+                                        ui.label(
+                                            egui::RichText::new(
+                                                "⚠️ Missing or malformed operation",
+                                            )
+                                            .color(egui::Color32::from_rgb(220, 80, 80))
+                                            .strong(),
+                                        );
+                                        ui.end_row();
+                                    }
+                                }
+                            });
+
+                        match &action.decoded {
+                            Some(d) => render_web_ui_node(ui, d, safe_ctx, &action_id),
+                            None if !action.data.is_empty() && action.data != "0x" => {
+                                render_raw_data(ui, &action.data);
+                            }
+                            None => {}
+                        }
+                    });
+            }
+            continue;
+        }
+
+        ui.vertical(|ui| {
+            ui.label(egui::RichText::new(format!("{} ({}):", param.name, param.typ)).small());
+            let id_salt = format!("{}_param_{}", id_prefix, i);
+            render_param_value(ui, &param.value, safe_ctx, None, &id_salt);
+        });
+    }
+}
+
 /// Render single function call decode
 fn render_single_section(
     ui: &mut egui::Ui,
@@ -265,7 +861,104 @@ fn render_single_comparison_with_chain(
 
     // Status message
     ui.add_space(8.0);
-    render_comparison_message(ui, &decode.comparison);
+    if !(decode.comparison.is_match() && decode.is_ambiguous()) {
+        render_comparison_message(ui, &decode.comparison);
+    }
+    if let Some(local) = &decode.local {
+        render_ambiguity(ui, local);
+    }
+    if let Some(check) = &decode.explorer {
+        render_explorer_check(ui, check);
+    }
+}
+
+/// Verdict of the explorer's verified-ABI decode against the other sources
+fn render_explorer_check(ui: &mut egui::Ui, check: &ExplorerCheck) {
+    let verified = match &check.decode {
+        Ok(verified) => verified,
+        Err(e) => {
+            ui.label(
+                egui::RichText::new(format!("Explorer ABI: {}", e))
+                    .small()
+                    .weak(),
+            );
+            return;
+        }
+    };
+    let disagreeing: Vec<&str> = check
+        .agreement
+        .iter()
+        .filter(|(_, agrees)| !agrees)
+        .map(|(source, _)| source.label())
+        .collect();
+
+    if check.disagrees_with_all() {
+        ui::error_banner(
+            ui,
+            &format!(
+                "The explorer's verified ABI decodes this as {}, which no other source matches",
+                verified.signature
+            ),
+        );
+    } else if check.is_mismatch() {
+        ui::warning_banner(
+            ui,
+            &format!(
+                "The explorer's verified ABI ({}) disagrees with {}",
+                verified.signature,
+                disagreeing.join(" and ")
+            ),
+        );
+    } else {
+        ui.label(
+            egui::RichText::new(format!("✓ Explorer ABI agrees: {}", verified.signature))
+                .small()
+                .color(egui::Color32::from_rgb(100, 200, 100)),
+        );
+    }
+}
+
+/// Warn that several signatures decode the calldata and list them, so the
+/// user can judge which function is really being called
+fn render_ambiguity(ui: &mut egui::Ui, local: &LocalDecode) {
+    if local.alternatives.is_empty() {
+        return;
+    }
+    let count = local.alternatives.len() + 1;
+    ui::warning_banner(
+        ui,
+        &format!(
+            "Ambiguous: {} signatures decode this calldata (selector collision). Showing {}.",
+            count, local.signature
+        ),
+    );
+    egui::CollapsingHeader::new(format!("Candidate signatures ({})", count))
+        .id_salt(format!("ambiguity_{}", local.signature))
+        .show(ui, |ui| {
+            for candidate in std::iter::once(local).chain(&local.alternatives) {
+                let params = candidate
+                    .params
+                    .iter()
+                    .map(|p| truncate_param(&p.value, 18))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new(&candidate.signature).monospace());
+                    if candidate.verified {
+                        ui.label(
+                            egui::RichText::new("[verified]")
+                                .color(egui::Color32::from_rgb(100, 200, 100))
+                                .small(),
+                        );
+                    }
+                });
+                ui.label(
+                    egui::RichText::new(format!("  ({})", params))
+                        .weak()
+                        .small(),
+                );
+            }
+        });
 }
 
 /// Render method name row
@@ -338,6 +1031,19 @@ fn render_params_rows(
         // Check if this param has a mismatch
         let has_mismatch = matches!(&decode.comparison, ComparisonResult::ParamMismatch(diffs) if diffs.iter().any(|d| d.index == i));
 
+        // Same-length mismatches (e.g. a poisoned lookalike address) get
+        // their differing characters highlighted instead of plain red/green
+        let diff_mask = match (api_param, local_param) {
+            (Some(ap), Some(lp))
+                if has_mismatch
+                    && !is_tuple_or_array(&ap.value)
+                    && !is_tuple_or_array(&lp.value) =>
+            {
+                char_diff_mask(&ap.value, &lp.value)
+            }
+            _ => None,
+        };
+
         // API param
         if let Some(ap) = api_param {
             let label = format!("{} ({}):", ap.name, ap.typ);
@@ -349,7 +1055,10 @@ fn render_params_rows(
                     None
                 };
                 let id_salt = format!("{}_api_{}", id_prefix, i);
-                render_param_value(ui, &ap.value, safe_ctx, color, &id_salt);
+                match diff_mask.as_deref() {
+                    Some(mask) => render_diff_value(ui, &ap.value, mask, color),
+                    None => render_param_value(ui, &ap.value, safe_ctx, color, &id_salt),
+                }
             });
         } else {
             ui.label(egui::RichText::new("—").weak());
@@ -366,7 +1075,10 @@ fn render_params_rows(
                     None
                 };
                 let id_salt = format!("{}_local_{}", id_prefix, i);
-                render_param_value(ui, &lp.value, safe_ctx, color, &id_salt);
+                match diff_mask.as_deref() {
+                    Some(mask) => render_diff_value(ui, &lp.value, mask, color),
+                    None => render_local_param(ui, lp, safe_ctx, color, &id_salt),
+                }
             });
         } else {
             ui.label(egui::RichText::new("—").weak());
@@ -376,11 +1088,70 @@ fn render_params_rows(
     }
 }
 
+/// Per-character difference mask for two values of equal length.
+/// Case is ignored so EIP-55 checksum casing alone is never flagged.
+/// Returns `None` if the lengths differ or nothing differs.
+fn char_diff_mask(a: &str, b: &str) -> Option<Vec<bool>> {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    if a.len() != b.len() {
+        return None;
+    }
+
+    let mask: Vec<bool> = a
+        .iter()
+        .zip(&b)
+        .map(|(x, y)| !x.eq_ignore_ascii_case(y))
+        .collect();
+    mask.iter().any(|&d| d).then_some(mask)
+}
+
+/// Split a value into runs of `(text, differs)` following a diff mask
+fn diff_segments(value: &str, mask: &[bool]) -> Vec<(String, bool)> {
+    let mut segments: Vec<(String, bool)> = Vec::new();
+
+    for (ch, &differs) in value.chars().zip(mask) {
+        match segments.last_mut() {
+            Some((text, d)) if *d == differs => text.push(ch),
+            _ => segments.push((ch.to_string(), differs)),
+        }
+    }
+
+    segments
+}
+
+/// Render a value with the characters flagged in `mask` underlined and
+/// highlighted, so a single swapped character stands out
+fn render_diff_value(ui: &mut egui::Ui, value: &str, mask: &[bool], color: Option<egui::Color32>) {
+    let font_id = egui::TextStyle::Monospace.resolve(ui.style());
+    let base_color = color.unwrap_or_else(|| ui.visuals().text_color());
+
+    let mut job = egui::text::LayoutJob::default();
+    for (text, differs) in diff_segments(value, mask) {
+        let format = if differs {
+            egui::TextFormat {
+                font_id: font_id.clone(),
+                color: ui.visuals().strong_text_color(),
+                background: base_color.gamma_multiply(0.35),
+                underline: egui::Stroke::new(1.5, base_color),
+                ..Default::default()
+            }
+        } else {
+            egui::TextFormat::simple(font_id.clone(), base_color)
+        };
+        job.append(&text, 0.0, format);
+    }
+
+    ui.label(job)
+        .on_hover_text("Underlined characters differ between the API and local decode");
+}
+
 /// Render MultiSend section
 fn render_multisend_section(
     ui: &mut egui::Ui,
     multi: &mut MultiSendDecode,
     safe_ctx: &crate::state::SafeContext,
+    contracts: &ContractLookup,
 ) {
     // Header with summary and expand/collapse buttons
     ui.horizontal(|ui| {
@@ -392,8 +1163,16 @@ fn render_multisend_section(
             .strong(),
         );
 
-        // Show verification state or summary badges
+        // Show parse progress, verification state or summary badges
         match &multi.verification_state {
+            _ if multi.is_parsing() => {
+                ui.spinner();
+                ui.label(format!(
+                    "Parsing {} of ~{} transactions...",
+                    multi.transactions.len(),
+                    multi.estimated_total()
+                ));
+            }
             VerificationState::Pending => {
                 ui.label(egui::RichText::new("⏳ Waiting...").weak());
             }
@@ -421,13 +1200,191 @@ fn render_multisend_section(
                 tx.is_expanded = false;
             }
         }
+
+        if ui
+            .add_enabled(
+                !multi.is_parsing(),
+                egui::Button::new("📄 Export CSV").small(),
+            )
+            .on_hover_text("Save the sub-transactions as a spreadsheet")
+            .clicked()
+        {
+            let file_name = format!(
+                "rusty-safe-multisend-{}-{}.csv",
+                safe_ctx.chain_name,
+                safe_ctx.safe_address.trim()
+            );
+            multi.csv_export = Some(ui::save_file(&file_name, &super::multisend_to_csv(multi)));
+        }
+        match &multi.csv_export {
+            Some(Ok(saved)) => {
+                ui.label(egui::RichText::new(saved).small().weak());
+            }
+            Some(Err(e)) => ui::error_message(ui, e),
+            None => {}
+        }
     });
 
     ui.add_space(8.0);
 
+    for reentry in &multi.self_reentries {
+        ui::error_banner(
+            ui,
+            &format!(
+                "TX #{} calls this Safe's own {} - the batch re-enters Safe execution",
+                reentry.index + 1,
+                reentry.method
+            ),
+        );
+    }
+    render_delegatecall_warnings(ui, &multi.delegatecalls);
+    if !multi.self_reentries.is_empty() || !multi.delegatecalls.is_empty() {
+        ui.add_space(8.0);
+    }
+
     // Collapsible transactions
     for tx in &mut multi.transactions {
-        render_multisend_tx(ui, tx, safe_ctx);
+        render_multisend_tx(ui, tx, safe_ctx, contracts);
+    }
+}
+
+/// Render an ERC-4337 `handleOps` bundle, one collapsible block per
+/// UserOperation
+fn render_user_op_section(
+    ui: &mut egui::Ui,
+    bundle: &mut UserOpBundle,
+    safe_ctx: &crate::state::SafeContext,
+) {
+    ui.horizontal(|ui| {
+        ui.label(
+            egui::RichText::new(format!(
+                "🧾 handleOps ({} UserOperations, {})",
+                bundle.ops.len(),
+                bundle.entry_point.label()
+            ))
+            .strong(),
+        );
+
+        match &bundle.verification_state {
+            VerificationState::Pending => {
+                ui.label(egui::RichText::new("⏳ Waiting...").weak());
+            }
+            VerificationState::InProgress { total } => {
+                ui.spinner();
+                ui.label(format!("Verifying {} operations...", total));
+            }
+            VerificationState::Complete => {
+                render_summary_badges(ui, &bundle.summary);
+            }
+        }
+    });
+
+    ui.horizontal(|ui| {
+        ui.label("Beneficiary:");
+        let name = safe_ctx.name_of(&bundle.beneficiary);
+        ui::address_link(ui, &safe_ctx.chain_name, &bundle.beneficiary, name);
+    });
+    ui.add_space(8.0);
+
+    for op in &mut bundle.ops {
+        render_user_op(ui, op, safe_ctx);
+    }
+}
+
+/// Render a single UserOperation (collapsible)
+fn render_user_op(ui: &mut egui::Ui, op: &mut UserOperation, safe_ctx: &crate::state::SafeContext) {
+    let (status_emoji, status) = VerifyStatus::of(op.decode.as_ref());
+    let method = op
+        .decode
+        .as_ref()
+        .and_then(|d| d.local.as_ref())
+        .map(|l| l.method.clone())
+        .unwrap_or_else(|| get_selector(&op.call_data));
+    let header = egui::RichText::new(format!(
+        "#{} {} from {} {}",
+        op.index + 1,
+        method,
+        truncate_address(&op.sender),
+        status_emoji
+    ))
+    .color(status.color());
+
+    let response = egui::CollapsingHeader::new(header)
+        .id_salt(format!("user_op_{}", op.index))
+        .open(Some(op.is_expanded))
+        .show(ui, |ui| {
+            ui.add_space(4.0);
+            egui::Grid::new(format!("user_op_details_{}", op.index))
+                .num_columns(2)
+                .spacing([10.0, 4.0])
+                .show(ui, |ui| {
+                    ui.label("Sender:");
+                    let name = safe_ctx.name_of(&op.sender);
+                    ui::address_link(ui, &safe_ctx.chain_name, &op.sender, name);
+                    ui.end_row();
+
+                    ui.label("Nonce:");
+                    ui.label(&op.nonce);
+                    ui.end_row();
+
+                    ui.label("Paymaster:");
+                    match &op.paymaster {
+                        Some(paymaster) => {
+                            let name = safe_ctx.name_of(paymaster);
+                            ui::address_link(ui, &safe_ctx.chain_name, paymaster, name);
+                        }
+                        None => {
+                            ui.label(egui::RichText::new("none (sender pays)").weak());
+                        }
+                    }
+                    ui.end_row();
+
+                    if op.paymaster_data.len() > 2 {
+                        ui.label("Paymaster data:");
+                        render_raw_data(ui, &op.paymaster_data);
+                        ui.end_row();
+                    }
+
+                    if op.init_code.len() > 2 {
+                        ui.label("Init code:");
+                        ui.label(
+                            egui::RichText::new("deploys the sender account")
+                                .color(egui::Color32::from_rgb(220, 180, 50)),
+                        );
+                        ui.end_row();
+                    }
+                });
+
+            ui.add_space(8.0);
+            match &op.decode {
+                Some(decode) => render_single_comparison_with_chain(ui, decode, safe_ctx),
+                None if op.call_data.len() <= 2 => {
+                    ui.label(egui::RichText::new("No callData").weak());
+                }
+                None => {
+                    ui.label(egui::RichText::new("Verification unavailable").weak());
+                    render_raw_data(ui, &op.call_data);
+                }
+            }
+        });
+
+    if response.header_response.clicked() {
+        op.is_expanded = !op.is_expanded;
+    }
+}
+
+/// Error banner per DELEGATECALL found inside a batch
+fn render_delegatecall_warnings(ui: &mut egui::Ui, delegatecalls: &[NestedDelegatecall]) {
+    for dc in delegatecalls {
+        let label = if dc.path.len() > 1 {
+            "Nested delegatecall"
+        } else {
+            "Delegatecall"
+        };
+        ui::error_banner(
+            ui,
+            &format!("{} at batch position {} to {}", label, dc.position(), dc.to),
+        );
     }
 }
 
@@ -439,22 +1396,41 @@ enum VerifyStatus {
     Pending,      // Gray   - still loading
 }
 
-/// Build a compact header with color based on verification status
-fn build_tx_header(tx: &MultiSendTx) -> egui::RichText {
-    let (status_emoji, status) = match &tx.decode {
-        Some(d) if d.comparison.is_match() => ("✓", VerifyStatus::Match),
-        Some(d) if d.comparison.is_mismatch() => ("✗", VerifyStatus::Mismatch),
-        Some(d) => {
-            // Distinguish unverifiable from still-loading
-            match &d.comparison {
-                ComparisonResult::OnlyApi
-                | ComparisonResult::OnlyLocal
-                | ComparisonResult::Failed(_) => ("⚠", VerifyStatus::Unverifiable),
-                _ => ("◇", VerifyStatus::Pending),
+impl VerifyStatus {
+    /// Status and header emoji of a batch entry's decode
+    fn of(decode: Option<&SingleDecode>) -> (&'static str, Self) {
+        match decode {
+            Some(d) if d.comparison.is_match() && d.is_ambiguous() => {
+                ("⚠", VerifyStatus::Unverifiable)
             }
+            Some(d) if d.comparison.is_match() => ("✓", VerifyStatus::Match),
+            Some(d) if d.comparison.is_mismatch() => ("✗", VerifyStatus::Mismatch),
+            Some(d) => {
+                // Distinguish unverifiable from still-loading
+                match &d.comparison {
+                    ComparisonResult::OnlyApi
+                    | ComparisonResult::OnlyLocal
+                    | ComparisonResult::Failed(_) => ("⚠", VerifyStatus::Unverifiable),
+                    _ => ("◇", VerifyStatus::Pending),
+                }
+            }
+            None => ("□", VerifyStatus::Pending),
         }
-        None => ("□", VerifyStatus::Pending),
-    };
+    }
+
+    fn color(&self) -> egui::Color32 {
+        match self {
+            VerifyStatus::Match => egui::Color32::from_rgb(100, 200, 100), // Green
+            VerifyStatus::Mismatch => egui::Color32::from_rgb(220, 80, 80), // Red
+            VerifyStatus::Unverifiable => egui::Color32::from_rgb(220, 180, 50), // Yellow
+            VerifyStatus::Pending => egui::Color32::GRAY,                  // Gray
+        }
+    }
+}
+
+/// Build a compact header with color based on verification status
+fn build_tx_header(tx: &MultiSendTx) -> egui::RichText {
+    let (status_emoji, status) = VerifyStatus::of(tx.decode.as_ref());
 
     // Try to get method name and params - prefer api_decode, then decode.api, then decode.local
     let api_data = tx
@@ -508,23 +1484,45 @@ fn build_tx_header(tx: &MultiSendTx) -> egui::RichText {
         format_wei(&tx.value)
     };
 
-    let header_text = format!(
+    let mut header_text = format!(
         "#{} {} ({}) {}",
         tx.index + 1,
         method_part,
         value_part,
         status_emoji
     );
+    if let Some(tag) = param_mismatch_tag(tx) {
+        header_text.push(' ');
+        header_text.push_str(&tag);
+    }
 
     // Color based on verification status
-    let color = match status {
-        VerifyStatus::Match => egui::Color32::from_rgb(100, 200, 100), // Green
-        VerifyStatus::Mismatch => egui::Color32::from_rgb(220, 80, 80), // Red
-        VerifyStatus::Unverifiable => egui::Color32::from_rgb(220, 180, 50), // Yellow
-        VerifyStatus::Pending => egui::Color32::GRAY,                  // Gray
+    egui::RichText::new(header_text).color(status.color())
+}
+
+/// "[amount differs]" for the first parameter that differs between the API
+/// and local decode, so mismatches can be triaged without expanding
+fn param_mismatch_tag(tx: &MultiSendTx) -> Option<String> {
+    let decode = tx.decode.as_ref()?;
+    let ComparisonResult::ParamMismatch(diffs) = &decode.comparison else {
+        return None;
     };
+    let first = diffs.first()?;
 
-    egui::RichText::new(header_text).color(color)
+    let name = decode
+        .api
+        .as_ref()
+        .or(tx.api_decode.as_ref())
+        .and_then(|api| api.params.get(first.index))
+        .map(|p| p.name.as_str())
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("param #{}", first.index + 1));
+
+    Some(match diffs.len() {
+        1 => format!("[{} differs]", name),
+        n => format!("[{} differs, +{} more]", name, n - 1),
+    })
 }
 
 /// Truncate a parameter value for display in header
@@ -564,11 +1562,45 @@ fn format_wei(wei: &str) -> String {
     format!("{} wei", wei)
 }
 
+/// Sourcify name of a contract next to its address. Shows nothing until
+/// the lookup has finished, if it failed, or on an unidentified chain.
+pub fn render_contract_name(
+    ui: &mut egui::Ui,
+    contracts: &ContractLookup,
+    chain_id: Option<u64>,
+    address: &str,
+) {
+    let Some(chain_id) = chain_id else {
+        return;
+    };
+    match contracts.cached(chain_id, address) {
+        Some(Some(meta)) => {
+            let hover = if meta.exact_match {
+                "Verified on Sourcify (exact match)"
+            } else {
+                "Verified on Sourcify (bytecode match, metadata differs)"
+            };
+            ui.label(
+                egui::RichText::new(&meta.name)
+                    .small()
+                    .color(egui::Color32::from_rgb(100, 200, 100)),
+            )
+            .on_hover_text(hover);
+        }
+        Some(None) => {
+            ui.label(egui::RichText::new("unverified").small().weak())
+                .on_hover_text("No verified source on Sourcify");
+        }
+        None => {}
+    }
+}
+
 /// Render a single MultiSend transaction (collapsible)
 fn render_multisend_tx(
     ui: &mut egui::Ui,
     tx: &mut MultiSendTx,
     safe_ctx: &crate::state::SafeContext,
+    contracts: &ContractLookup,
 ) {
     let header = build_tx_header(tx);
 
@@ -585,10 +1617,11 @@ fn render_multisend_tx(
                 .spacing([10.0, 4.0])
                 .show(ui, |ui| {
                     ui.label("To:");
-                    let chain_id =
-                        alloy::primitives::ChainId::of(&safe_ctx.chain_name).unwrap_or(1);
-                    let name = safe_ctx.address_book.get_name(&tx.to, chain_id);
-                    ui::address_link(ui, &safe_ctx.chain_name, &tx.to, name);
+                    let name = safe_ctx.name_of(&tx.to);
+                    ui.horizontal(|ui| {
+                        ui::address_link(ui, &safe_ctx.chain_name, &tx.to, name);
+                        render_contract_name(ui, contracts, safe_ctx.chain_id(), &tx.to);
+                    });
                     ui.end_row();
 
                     ui.label("Value:");
@@ -596,15 +1629,29 @@ fn render_multisend_tx(
                     ui.end_row();
 
                     ui.label("Operation:");
-                    ui.label(if tx.operation == 0 {
-                        "Call"
-                    } else {
-                        "DelegateCall"
-                    });
+                    ui.label(ui::operation_text(tx.operation.into()));
                     ui.end_row();
                 });
 
             ui.add_space(8.0);
+            if tx.operation > 1 {
+                ui::warning_banner(
+                    ui,
+                    &format!(
+                        "Operation {} is neither Call (0) nor DelegateCall (1). \
+                         This entry is malformed.",
+                        tx.operation
+                    ),
+                );
+                ui.add_space(4.0);
+            }
+            render_wrap_summary(ui, &tx.to, &tx.value, &tx.data, safe_ctx);
+            if let Some(relay) = classify_relay(&tx.data) {
+                render_relay_summary(ui, &tx.to, &relay, safe_ctx);
+            }
+            if let Some(nested) = classify_nested_safe(&tx.data) {
+                render_nested_safe_summary(ui, &tx.to, &nested, safe_ctx);
+            }
 
             // Decode comparison (results already available from bulk verification)
             if let Some(decode) = &tx.decode {
@@ -614,6 +1661,11 @@ fn render_multisend_tx(
             } else {
                 ui.label(egui::RichText::new("Verification unavailable").weak());
             }
+
+            if let Some(nested) = tx.nested.as_deref_mut() {
+                ui.add_space(8.0);
+                render_nested_multisend(ui, nested, tx.index, safe_ctx, contracts);
+            }
         });
 
     // Track expand state (purely visual now)
@@ -622,6 +1674,46 @@ fn render_multisend_tx(
     }
 }
 
+/// Render the batch carried by a MultiSend entry, indented under it
+fn render_nested_multisend(
+    ui: &mut egui::Ui,
+    multi: &mut MultiSendDecode,
+    parent_index: usize,
+    safe_ctx: &crate::state::SafeContext,
+    contracts: &ContractLookup,
+) {
+    ui.horizontal(|ui| {
+        ui.label(
+            egui::RichText::new(format!(
+                "📦 Nested MultiSend ({} transactions)",
+                multi.transactions.len()
+            ))
+            .strong(),
+        );
+        if matches!(multi.verification_state, VerificationState::Complete) {
+            render_summary_badges(ui, &multi.summary);
+        }
+    });
+
+    for reentry in &multi.self_reentries {
+        ui::error_banner(
+            ui,
+            &format!(
+                "Nested TX #{}.{} calls this Safe's own {} - the batch re-enters Safe execution",
+                parent_index + 1,
+                reentry.index + 1,
+                reentry.method
+            ),
+        );
+    }
+
+    ui.indent(("nested_multisend", parent_index), |ui| {
+        for tx in &mut multi.transactions {
+            render_multisend_tx(ui, tx, safe_ctx, contracts);
+        }
+    });
+}
+
 /// Render summary badges for MultiSend
 fn render_summary_badges(ui: &mut egui::Ui, summary: &MultiSendSummary) {
     if summary.verified > 0 {
@@ -739,6 +1831,7 @@ pub fn render_offline_decode_section(
     ui: &mut egui::Ui,
     result: &mut OfflineDecodeResult,
     safe_ctx: &crate::state::SafeContext,
+    contracts: &ContractLookup,
 ) {
     ui.add_space(10.0);
 
@@ -753,8 +1846,8 @@ pub fn render_offline_decode_section(
         OfflineDecodeResult::Single { local, status } => {
             render_offline_single_section(ui, local, status, safe_ctx);
         }
-        OfflineDecodeResult::MultiSend(txs) => {
-            render_offline_multisend_section(ui, txs, safe_ctx);
+        OfflineDecodeResult::MultiSend { txs, delegatecalls } => {
+            render_offline_multisend_section(ui, txs, delegatecalls, safe_ctx, contracts);
         }
         OfflineDecodeResult::RawHex(data) => {
             ui.horizontal(|ui| {
@@ -835,11 +1928,14 @@ fn render_offline_decode(
                 for (i, param) in local.params.iter().enumerate() {
                     ui.label(egui::RichText::new(format!("param{} ({}):", i, param.typ)).small());
                     let id_salt = format!("{}_{}", id_prefix, i);
-                    render_param_value(ui, &param.value, safe_ctx, None, &id_salt);
+                    render_local_param(ui, param, safe_ctx, None, &id_salt);
                     ui.end_row();
                 }
             });
     }
+
+    ui.add_space(4.0);
+    render_ambiguity(ui, local);
 }
 
 /// Render offline status badge
@@ -858,7 +1954,9 @@ fn render_offline_status_badge(ui: &mut egui::Ui, status: &OfflineDecodeStatus)
 fn render_offline_multisend_section(
     ui: &mut egui::Ui,
     txs: &mut [OfflineMultiSendTx],
+    delegatecalls: &[NestedDelegatecall],
     safe_ctx: &crate::state::SafeContext,
+    contracts: &ContractLookup,
 ) {
     // Header with count and expand/collapse buttons
     ui.horizontal(|ui| {
@@ -902,9 +2000,14 @@ fn render_offline_multisend_section(
 
     ui.add_space(8.0);
 
+    if !delegatecalls.is_empty() {
+        render_delegatecall_warnings(ui, delegatecalls);
+        ui.add_space(8.0);
+    }
+
     // Render each transaction
     for tx in txs.iter_mut() {
-        render_offline_multisend_tx(ui, tx, safe_ctx);
+        render_offline_multisend_tx(ui, tx, safe_ctx, contracts);
     }
 }
 
@@ -972,6 +2075,7 @@ fn render_offline_multisend_tx(
     ui: &mut egui::Ui,
     tx: &mut OfflineMultiSendTx,
     safe_ctx: &crate::state::SafeContext,
+    contracts: &ContractLookup,
 ) {
     let header = build_offline_tx_header(tx);
 
@@ -987,10 +2091,11 @@ fn render_offline_multisend_tx(
                 .spacing([10.0, 4.0])
                 .show(ui, |ui| {
                     ui.label("To:");
-                    let chain_id =
-                        alloy::primitives::ChainId::of(&safe_ctx.chain_name).unwrap_or(1);
-                    let name = safe_ctx.address_book.get_name(&tx.to, chain_id);
-                    ui::address_link(ui, &safe_ctx.chain_name, &tx.to, name);
+                    let name = safe_ctx.name_of(&tx.to);
+                    ui.horizontal(|ui| {
+                        ui::address_link(ui, &safe_ctx.chain_name, &tx.to, name);
+                        render_contract_name(ui, contracts, safe_ctx.chain_id(), &tx.to);
+                    });
                     ui.end_row();
 
                     ui.label("Value:");
@@ -998,15 +2103,29 @@ fn render_offline_multisend_tx(
                     ui.end_row();
 
                     ui.label("Operation:");
-                    ui.label(if tx.operation == 0 {
-                        "Call"
-                    } else {
-                        "DelegateCall"
-                    });
+                    ui.label(ui::operation_text(tx.operation.into()));
                     ui.end_row();
                 });
 
             ui.add_space(8.0);
+            if tx.operation > 1 {
+                ui::warning_banner(
+                    ui,
+                    &format!(
+                        "Operation {} is neither Call (0) nor DelegateCall (1). \
+                         This entry is malformed.",
+                        tx.operation
+                    ),
+                );
+                ui.add_space(4.0);
+            }
+            render_wrap_summary(ui, &tx.to, &tx.value, &tx.data, safe_ctx);
+            if let Some(relay) = classify_relay(&tx.data) {
+                render_relay_summary(ui, &tx.to, &relay, safe_ctx);
+            }
+            if let Some(nested) = classify_nested_safe(&tx.data) {
+                render_nested_safe_summary(ui, &tx.to, &nested, safe_ctx);
+            }
 
             // Decode result
             match &tx.status {
@@ -1082,4 +2201,81 @@ mod tests {
             AddressValidation::Invalid
         );
     }
+
+    #[test]
+    fn test_param_mismatch_tag() {
+        let diff = |index| ParamDiff {
+            index,
+            typ: "uint256".to_string(),
+            api_value: "1".to_string(),
+            local_value: "2".to_string(),
+        };
+        let api = ApiDecode {
+            method: "transfer".to_string(),
+            params: vec![
+                ApiParam {
+                    name: "to".to_string(),
+                    typ: "address".to_string(),
+                    value: "0x1111111111111111111111111111111111111111".to_string(),
+                },
+                ApiParam {
+                    name: "amount".to_string(),
+                    typ: "uint256".to_string(),
+                    value: "1".to_string(),
+                },
+            ],
+        };
+        let mut tx = MultiSendTx {
+            index: 2,
+            operation: 0,
+            to: "0x2222222222222222222222222222222222222222".to_string(),
+            value: "0".to_string(),
+            data: "0x".to_string(),
+            api_decode: Some(api.clone()),
+            decode: Some(SingleDecode {
+                api: Some(api),
+                local: None,
+                comparison: ComparisonResult::ParamMismatch(vec![diff(1)]),
+                explorer: None,
+            }),
+            nested: None,
+            is_expanded: false,
+        };
+        assert_eq!(param_mismatch_tag(&tx).as_deref(), Some("[amount differs]"));
+
+        if let Some(decode) = tx.decode.as_mut() {
+            decode.api = None;
+            decode.comparison = ComparisonResult::ParamMismatch(vec![diff(3), diff(4)]);
+        }
+        tx.api_decode = None;
+        assert_eq!(
+            param_mismatch_tag(&tx).as_deref(),
+            Some("[param #4 differs, +1 more]")
+        );
+
+        if let Some(decode) = tx.decode.as_mut() {
+            decode.comparison = ComparisonResult::Match;
+        }
+        assert_eq!(param_mismatch_tag(&tx), None);
+    }
+
+    #[test]
+    fn test_char_diff_mask() {
+        // Lookalike address differing only in the last character
+        let api = "0xAAAA00000000000000000000000000000000001234";
+        let local = "0xaaaa00000000000000000000000000000000001235";
+        let mask = char_diff_mask(api, local).unwrap();
+        assert_eq!(mask.iter().filter(|&&d| d).count(), 1);
+        assert!(mask[mask.len() - 1]);
+
+        // Checksum casing alone is not a difference
+        assert!(char_diff_mask("0xAbC", "0xabc").is_none());
+
+        // Different lengths are not diffed
+        assert!(char_diff_mask("123", "1234").is_none());
+
+        let segments = diff_segments(api, &mask);
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[1], ("4".to_string(), true));
+    }
 }
@@ -0,0 +1,226 @@
+//! ERC-4337 UserOperation bundles
+//!
+//! Unpacks `handleOps` calls on an EntryPoint into their UserOperations so
+//! each operation's `callData` goes through the same independent decode as
+//! a MultiSend sub-transaction. Supports the v0.6 `UserOperation` and the
+//! v0.7 `PackedUserOperation` layouts.
+
+use alloy::dyn_abi::{DynSolValue, JsonAbiExt};
+use alloy::json_abi::Function;
+use alloy::primitives::hex;
+use eyre::{Result, WrapErr};
+
+use super::types::*;
+
+/// `handleOps` on EntryPoint v0.6
+pub const HANDLE_OPS_V06_SELECTOR: &str = "0x1fad948c";
+/// `handleOps` on EntryPoint v0.7
+pub const HANDLE_OPS_V07_SELECTOR: &str = "0x765e827f";
+
+const HANDLE_OPS_V06: &str = "handleOps((address,uint256,bytes,bytes,uint256,uint256,uint256,uint256,uint256,bytes,bytes)[],address)";
+const HANDLE_OPS_V07: &str =
+    "handleOps((address,uint256,bytes,bytes,bytes32,uint256,bytes32,bytes,bytes)[],address)";
+
+/// EntryPoint layout for a selector, if it is a `handleOps` call
+pub fn entry_point_version(selector: &str) -> Option<EntryPointVersion> {
+    match selector.to_lowercase().as_str() {
+        HANDLE_OPS_V06_SELECTOR => Some(EntryPointVersion::V06),
+        HANDLE_OPS_V07_SELECTOR => Some(EntryPointVersion::V07),
+        _ => None,
+    }
+}
+
+fn hex_bytes(value: &DynSolValue) -> Result<Vec<u8>> {
+    match value {
+        DynSolValue::Bytes(b) => Ok(b.clone()),
+        _ => eyre::bail!("Expected bytes"),
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+fn parse_op(index: usize, op: &DynSolValue, version: EntryPointVersion) -> Result<UserOperation> {
+    let DynSolValue::Tuple(fields) = op else {
+        eyre::bail!("UserOperation #{} is not a tuple", index + 1);
+    };
+    let paymaster_field = match version {
+        EntryPointVersion::V06 => 9,
+        EntryPointVersion::V07 => 7,
+    };
+    let field = |i: usize| {
+        fields
+            .get(i)
+            .ok_or_else(|| eyre::eyre!("UserOperation #{} is missing field {}", index + 1, i))
+    };
+
+    let DynSolValue::Address(sender) = field(0)? else {
+        eyre::bail!("UserOperation #{}: sender is not an address", index + 1);
+    };
+    let DynSolValue::Uint(nonce, _) = field(1)? else {
+        eyre::bail!("UserOperation #{}: nonce is not a uint", index + 1);
+    };
+    let paymaster_and_data = hex_bytes(field(paymaster_field)?)?;
+    let (paymaster, paymaster_data) = match paymaster_and_data.len() {
+        0 => (None, String::new()),
+        n if n >= 20 => (
+            Some(to_hex(&paymaster_and_data[..20])),
+            to_hex(&paymaster_and_data[20..]),
+        ),
+        _ => eyre::bail!(
+            "UserOperation #{}: paymasterAndData shorter than an address",
+            index + 1
+        ),
+    };
+
+    Ok(UserOperation {
+        index,
+        sender: sender.to_string(),
+        nonce: nonce.to_string(),
+        init_code: to_hex(&hex_bytes(field(2)?)?),
+        call_data: to_hex(&hex_bytes(field(3)?)?),
+        paymaster,
+        paymaster_data,
+        decode: None,
+        is_expanded: false,
+    })
+}
+
+/// Unpack the UserOperations of a `handleOps` call
+pub fn unpack_user_operations(raw_data: &str) -> Result<UserOpBundle> {
+    let raw_data = raw_data.trim();
+    let version = raw_data
+        .get(..10)
+        .and_then(entry_point_version)
+        .ok_or_else(|| eyre::eyre!("Not a handleOps call"))?;
+    let signature = match version {
+        EntryPointVersion::V06 => HANDLE_OPS_V06,
+        EntryPointVersion::V07 => HANDLE_OPS_V07,
+    };
+
+    let func = Function::parse(signature).wrap_err("Invalid handleOps signature")?;
+    let bytes = hex::decode(raw_data.strip_prefix("0x").unwrap_or(raw_data))
+        .wrap_err("Failed to decode hex")?;
+    let values = func
+        .abi_decode_input(&bytes[4..], true)
+        .wrap_err_with(|| format!("Failed to decode handleOps for {}", version.label()))?;
+
+    let (Some(DynSolValue::Array(ops)), Some(DynSolValue::Address(beneficiary))) =
+        (values.first(), values.get(1))
+    else {
+        eyre::bail!("Unexpected handleOps arguments");
+    };
+    let ops = ops
+        .iter()
+        .enumerate()
+        .map(|(i, op)| parse_op(i, op, version))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut bundle = UserOpBundle {
+        entry_point: version,
+        beneficiary: beneficiary.to_string(),
+        ops,
+        summary: MultiSendSummary::default(),
+        verification_state: VerificationState::Pending,
+    };
+    bundle
+        .summary
+        .update_from(bundle.ops.iter().map(|op| op.decode.as_ref()));
+    Ok(bundle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::{Address, B256, U256};
+
+    const SENDER: &str = "0x1111111111111111111111111111111111111111";
+    const PAYMASTER: &str = "0x2222222222222222222222222222222222222222";
+    const BENEFICIARY: &str = "0x3333333333333333333333333333333333333333";
+
+    fn address(s: &str) -> DynSolValue {
+        DynSolValue::Address(s.parse::<Address>().unwrap())
+    }
+
+    fn uint(n: u64) -> DynSolValue {
+        DynSolValue::Uint(U256::from(n), 256)
+    }
+
+    fn encode(signature: &str, ops: Vec<DynSolValue>) -> String {
+        let calldata = Function::parse(signature)
+            .unwrap()
+            .abi_encode_input(&[DynSolValue::Array(ops), address(BENEFICIARY)])
+            .unwrap();
+        format!("0x{}", hex::encode(calldata))
+    }
+
+    #[test]
+    fn test_selectors_match_signatures() {
+        for (selector, signature) in [
+            (HANDLE_OPS_V06_SELECTOR, HANDLE_OPS_V06),
+            (HANDLE_OPS_V07_SELECTOR, HANDLE_OPS_V07),
+        ] {
+            let func = Function::parse(signature).unwrap();
+            assert_eq!(selector, format!("0x{}", hex::encode(func.selector())));
+        }
+    }
+
+    #[test]
+    fn test_unpack_v06() {
+        let paymaster_and_data = [
+            hex::decode(PAYMASTER.trim_start_matches("0x")).unwrap(),
+            vec![0xab; 4],
+        ]
+        .concat();
+        let op = DynSolValue::Tuple(vec![
+            address(SENDER),
+            uint(7),
+            DynSolValue::Bytes(vec![]),
+            DynSolValue::Bytes(vec![0xa9, 0x05, 0x9c, 0xbb]),
+            uint(1),
+            uint(2),
+            uint(3),
+            uint(4),
+            uint(5),
+            DynSolValue::Bytes(paymaster_and_data),
+            DynSolValue::Bytes(vec![0x01; 65]),
+        ]);
+
+        let bundle = unpack_user_operations(&encode(HANDLE_OPS_V06, vec![op])).unwrap();
+        assert_eq!(bundle.entry_point, EntryPointVersion::V06);
+        assert_eq!(bundle.beneficiary.to_lowercase(), BENEFICIARY);
+        let op = &bundle.ops[0];
+        assert_eq!(op.sender.to_lowercase(), SENDER);
+        assert_eq!(op.nonce, "7");
+        assert_eq!(op.call_data, "0xa9059cbb");
+        assert_eq!(op.paymaster.as_deref(), Some(PAYMASTER));
+        assert_eq!(op.paymaster_data, "0xabababab");
+        assert_eq!(bundle.summary.total, 1);
+    }
+
+    #[test]
+    fn test_unpack_v07_without_paymaster() {
+        let op = DynSolValue::Tuple(vec![
+            address(SENDER),
+            uint(0),
+            DynSolValue::Bytes(vec![]),
+            DynSolValue::Bytes(vec![0x12, 0x34]),
+            DynSolValue::FixedBytes(B256::ZERO, 32),
+            uint(21000),
+            DynSolValue::FixedBytes(B256::ZERO, 32),
+            DynSolValue::Bytes(vec![]),
+            DynSolValue::Bytes(vec![]),
+        ]);
+
+        let bundle = unpack_user_operations(&encode(HANDLE_OPS_V07, vec![op])).unwrap();
+        assert_eq!(bundle.entry_point, EntryPointVersion::V07);
+        assert!(bundle.ops[0].paymaster.is_none());
+        assert_eq!(bundle.ops[0].call_data, "0x1234");
+    }
+
+    #[test]
+    fn test_malformed_handle_ops() {
+        assert!(unpack_user_operations(&format!("{}00", HANDLE_OPS_V06_SELECTOR)).is_err());
+    }
+}
@@ -2,36 +2,45 @@
 //!
 //! Bulk verifies transactions by comparing Safe API decode with independent 4byte lookup.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use super::compare;
 use super::decode_log;
 use super::parser;
-use super::sourcify::SignatureLookup;
+use super::sourcify::{SignatureInfo, SignatureLookup};
 use super::types::*;
 
+/// Safe methods that drive transaction execution (selector, name).
+/// A batch calling these on its own Safe executes recursively.
+const SAFE_EXECUTION_SELECTORS: &[(&str, &str)] = &[
+    ("0x6a761202", "execTransaction"),
+    ("0x468721a7", "execTransactionFromModule"),
+    ("0x5229073f", "execTransactionFromModuleReturnData"),
+    ("0xd4d9bdcd", "approveHash"),
+];
+
 /// Bulk verify all transactions in a MultiSend batch
 ///
-/// 1. Collects all unique selectors from transactions
+/// 1. Collects all unique selectors from transactions, including nested batches
 /// 2. Batch fetches signatures from Sourcify (uses cache)
-/// 3. Decodes each transaction locally
+/// 3. Decodes each transaction locally, then the nested batches
 /// 4. Compares with API decode
-/// 5. Updates summary
-pub async fn verify_multisend_batch(multi: &mut MultiSendDecode, lookup: &SignatureLookup) {
+/// 5. Flags sub-transactions that re-enter the Safe's execution methods
+/// 6. Updates summary, rolling nested batches into it
+pub async fn verify_multisend_batch(
+    multi: &mut MultiSendDecode,
+    lookup: &SignatureLookup,
+    safe_address: &str,
+) {
     decode_log!(
         "Starting bulk verification for {} transactions",
         multi.transactions.len()
     );
 
     // 1. Collect unique selectors from all transactions with calldata
-    let selectors: Vec<String> = multi
-        .transactions
-        .iter()
-        .filter(|tx| tx.data.len() >= 10 && tx.data != "0x")
-        .map(|tx| tx.data[..10].to_lowercase())
-        .collect::<HashSet<_>>()
-        .into_iter()
-        .collect();
+    let mut selectors = HashSet::new();
+    collect_selectors(&multi.transactions, &mut selectors);
+    let selectors: Vec<String> = selectors.into_iter().collect();
 
     decode_log!("Found {} unique selectors to lookup", selectors.len());
 
@@ -40,79 +49,19 @@ pub async fn verify_multisend_batch(multi: &mut MultiSendDecode, lookup: &Signat
     decode_log!("Fetched signatures for {} selectors", signatures.len());
 
     // 3. Decode each transaction
-    for tx in &mut multi.transactions {
-        // Skip empty calldata
-        if tx.data.len() < 10 || tx.data == "0x" {
-            decode_log!("TX #{}: skipping (no calldata)", tx.index);
-            continue;
-        }
-
-        let selector = tx.data[..10].to_lowercase();
-
-        // Get signatures for this selector
-        let sigs = match signatures.get(&selector) {
-            Some(s) if !s.is_empty() => s,
-            _ => {
-                decode_log!("TX #{}: no signatures found for {}", tx.index, selector);
-                // No signatures available - mark as unavailable
-                tx.decode = Some(SingleDecode {
-                    api: tx.api_decode.clone(),
-                    local: None,
-                    comparison: if tx.api_decode.is_some() {
-                        ComparisonResult::OnlyApi
-                    } else {
-                        ComparisonResult::Failed("No signature found".to_string())
-                    },
-                });
-                continue;
-            }
-        };
+    verify_batch_level(&mut multi.transactions, &signatures, safe_address);
 
+    // 5. Flag self-re-entrant sub-transactions
+    multi.self_reentries = detect_self_reentry(&multi.transactions, safe_address);
+    for reentry in &multi.self_reentries {
         decode_log!(
-            "TX #{}: trying {} signatures for {}",
-            tx.index,
-            sigs.len(),
-            selector
+            "TX #{}: calls the Safe's own {}",
+            reentry.index,
+            reentry.method
         );
-
-        // Try each signature until one decodes successfully
-        // Signatures are sorted with verified first, so we prefer verified decodes
-        let mut local_decode = None;
-        for sig_info in sigs {
-            match parser::decode_with_signature(&tx.data, &sig_info.signature, sig_info.verified) {
-                Ok(decoded) => {
-                    decode_log!(
-                        "TX #{}: decoded with {} (verified: {})",
-                        tx.index,
-                        sig_info.signature,
-                        sig_info.verified
-                    );
-                    local_decode = Some(decoded);
-                    break;
-                }
-                Err(e) => {
-                    decode_log!(
-                        "TX #{}: failed to decode with {}: {}",
-                        tx.index,
-                        sig_info.signature,
-                        e
-                    );
-                }
-            }
-        }
-
-        // 4. Compare with API decode
-        let comparison = compare::compare_decodes(tx.api_decode.as_ref(), local_decode.as_ref());
-        decode_log!("TX #{}: comparison result: {:?}", tx.index, comparison);
-
-        tx.decode = Some(SingleDecode {
-            api: tx.api_decode.clone(),
-            local: local_decode,
-            comparison,
-        });
     }
 
-    // 5. Update summary and mark complete
+    // 6. Update summary and mark complete
     multi.summary.update(&multi.transactions);
     multi.verification_state = VerificationState::Complete;
 
@@ -123,3 +72,300 @@ pub async fn verify_multisend_batch(multi: &mut MultiSendDecode, lookup: &Signat
         multi.summary.pending
     );
 }
+
+/// Selectors of every transaction with calldata, at any nesting depth
+fn collect_selectors(transactions: &[MultiSendTx], selectors: &mut HashSet<String>) {
+    for tx in transactions {
+        if tx.data.len() >= 10 && tx.data != "0x" {
+            selectors.insert(tx.data[..10].to_lowercase());
+        }
+        if let Some(nested) = &tx.nested {
+            collect_selectors(&nested.transactions, selectors);
+        }
+    }
+}
+
+/// Decode and compare each transaction of a batch, then verify the batches
+/// nested in it
+fn verify_batch_level(
+    transactions: &mut [MultiSendTx],
+    signatures: &HashMap<String, Vec<SignatureInfo>>,
+    safe_address: &str,
+) {
+    for tx in transactions {
+        verify_tx(tx, signatures);
+        if let Some(nested) = tx.nested.as_deref_mut() {
+            verify_batch_level(&mut nested.transactions, signatures, safe_address);
+            nested.self_reentries = detect_self_reentry(&nested.transactions, safe_address);
+            nested.summary.update(&nested.transactions);
+            nested.verification_state = VerificationState::Complete;
+        }
+    }
+}
+
+/// Decode one batch entry locally and compare it with the service's decode
+fn verify_tx(tx: &mut MultiSendTx, signatures: &HashMap<String, Vec<SignatureInfo>>) {
+    // Skip empty calldata
+    if tx.data.len() < 10 || tx.data == "0x" {
+        decode_log!("TX #{}: skipping (no calldata)", tx.index);
+        return;
+    }
+
+    let selector = tx.data[..10].to_lowercase();
+
+    // Get signatures for this selector
+    let sigs = match signatures.get(&selector) {
+        Some(s) if !s.is_empty() => s,
+        _ => {
+            decode_log!("TX #{}: no signatures found for {}", tx.index, selector);
+            // No signatures available - mark as unavailable
+            tx.decode = Some(SingleDecode {
+                api: tx.api_decode.clone(),
+                local: None,
+                comparison: if tx.api_decode.is_some() {
+                    ComparisonResult::OnlyApi
+                } else {
+                    ComparisonResult::Failed("No signature found".to_string())
+                },
+                explorer: None,
+            });
+            return;
+        }
+    };
+
+    decode_log!(
+        "TX #{}: trying {} signatures for {}",
+        tx.index,
+        sigs.len(),
+        selector
+    );
+
+    let local_decode = decode_with_any(&tx.data, sigs, tx.index);
+
+    // 4. Compare with API decode
+    let comparison = compare::compare_decodes(tx.api_decode.as_ref(), local_decode.as_ref());
+    decode_log!("TX #{}: comparison result: {:?}", tx.index, comparison);
+
+    tx.decode = Some(SingleDecode {
+        api: tx.api_decode.clone(),
+        local: local_decode,
+        comparison,
+        explorer: None,
+    });
+}
+
+/// Decode `data` with the best-fitting signature, keeping any other
+/// signature that also fits as an alternative
+fn decode_with_any(data: &str, sigs: &[SignatureInfo], index: usize) -> Option<LocalDecode> {
+    let decoded = parser::decode_with_candidates(data, sigs);
+    match &decoded {
+        Some(d) => {
+            decode_log!(
+                "TX #{}: decoded with {} (verified: {}, {} other candidates)",
+                index,
+                d.signature,
+                d.verified,
+                d.alternatives.len()
+            );
+        }
+        None => {
+            decode_log!("TX #{}: none of {} signatures decoded", index, sigs.len());
+        }
+    }
+    decoded
+}
+
+/// Bulk verify the `callData` of every UserOperation in a bundle. The
+/// service doesn't decode inner calls of `handleOps`, so a successful
+/// decode is local-only.
+pub async fn verify_user_ops(bundle: &mut UserOpBundle, lookup: &SignatureLookup) {
+    let selectors: Vec<String> = bundle
+        .ops
+        .iter()
+        .filter(|op| op.call_data.len() >= 10)
+        .map(|op| op.call_data[..10].to_lowercase())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    let signatures = lookup.lookup_batch(&selectors).await;
+
+    for op in &mut bundle.ops {
+        if op.call_data.len() < 10 {
+            continue;
+        }
+        let selector = op.call_data[..10].to_lowercase();
+        let local = signatures
+            .get(&selector)
+            .and_then(|sigs| decode_with_any(&op.call_data, sigs, op.index));
+        let comparison = match &local {
+            Some(_) => ComparisonResult::OnlyLocal,
+            None => ComparisonResult::Failed("No signature found".to_string()),
+        };
+        op.decode = Some(SingleDecode {
+            api: None,
+            local,
+            comparison,
+            explorer: None,
+        });
+    }
+
+    update_user_op_summary(bundle);
+    bundle.verification_state = VerificationState::Complete;
+}
+
+/// Recount a bundle's summary. With no service decode to compare against,
+/// an unambiguous local decode is as verified as an inner call gets.
+fn update_user_op_summary(bundle: &mut UserOpBundle) {
+    bundle
+        .summary
+        .update_from(bundle.ops.iter().map(|op| op.decode.as_ref()));
+    let local_only = bundle
+        .ops
+        .iter()
+        .filter_map(|op| op.decode.as_ref())
+        .filter(|d| matches!(d.comparison, ComparisonResult::OnlyLocal) && !d.is_ambiguous())
+        .count();
+    bundle.summary.verified += local_only;
+    bundle.summary.pending -= local_only;
+}
+
+/// Find sub-transactions targeting `safe_address` with an execution selector.
+/// Self-administration calls (addOwner, changeThreshold, ...) are not flagged.
+pub fn detect_self_reentry(transactions: &[MultiSendTx], safe_address: &str) -> Vec<SelfReentry> {
+    let safe_lower = safe_address.trim().to_lowercase();
+    if safe_lower.is_empty() {
+        return vec![];
+    }
+
+    transactions
+        .iter()
+        .filter(|tx| tx.to.to_lowercase() == safe_lower && tx.data.len() >= 10)
+        .filter_map(|tx| {
+            let selector = tx.data[..10].to_lowercase();
+            SAFE_EXECUTION_SELECTORS
+                .iter()
+                .find(|(sel, _)| *sel == selector)
+                .map(|(_, method)| SelfReentry {
+                    index: tx.index,
+                    method: method.to_string(),
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tx(index: usize, to: &str, data: &str) -> MultiSendTx {
+        MultiSendTx {
+            index,
+            operation: 0,
+            to: to.to_string(),
+            value: "0".to_string(),
+            data: data.to_string(),
+            api_decode: None,
+            decode: None,
+            nested: None,
+            is_expanded: false,
+        }
+    }
+
+    #[test]
+    fn test_selectors_match_signatures() {
+        use alloy::primitives::keccak256;
+        let sigs = [
+            "execTransaction(address,uint256,bytes,uint8,uint256,uint256,uint256,address,address,bytes)",
+            "execTransactionFromModule(address,uint256,bytes,uint8)",
+            "execTransactionFromModuleReturnData(address,uint256,bytes,uint8)",
+            "approveHash(bytes32)",
+        ];
+        for ((selector, _), sig) in SAFE_EXECUTION_SELECTORS.iter().zip(sigs) {
+            let hash = keccak256(sig.as_bytes());
+            assert_eq!(*selector, format!("0x{}", alloy::hex::encode(&hash[..4])));
+        }
+    }
+
+    #[test]
+    fn test_detect_self_reentry() {
+        let safe = "0x4F2083f5fBede34C2714aFfb3105539775f7FE64";
+        let other = "0xfe89cc7abb2c4183683ab71653c4cdc9b02d44b7";
+        let txs = vec![
+            // execTransaction on the Safe itself
+            tx(0, &safe.to_lowercase(), "0x6a761202aabb"),
+            // execTransaction on another Safe is fine
+            tx(1, other, "0x6a761202aabb"),
+            // changeThreshold on the Safe is self-administration
+            tx(2, safe, "0x694e80c30000"),
+            // plain ETH transfer to the Safe
+            tx(3, safe, "0x"),
+        ];
+
+        let found = detect_self_reentry(&txs, safe);
+        assert_eq!(
+            found,
+            vec![SelfReentry {
+                index: 0,
+                method: "execTransaction".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn test_user_op_summary_counts_local_decodes() {
+        let local = |alternatives: Vec<LocalDecode>| LocalDecode {
+            signature: "transfer(address,uint256)".to_string(),
+            method: "transfer".to_string(),
+            params: vec![],
+            verified: true,
+            alternatives,
+        };
+        let op = |index: usize, decode: Option<SingleDecode>| UserOperation {
+            index,
+            sender: String::new(),
+            nonce: "0".to_string(),
+            init_code: "0x".to_string(),
+            call_data: "0xa9059cbb".to_string(),
+            paymaster: None,
+            paymaster_data: String::new(),
+            decode,
+            is_expanded: false,
+        };
+        let single = |local: Option<LocalDecode>, comparison| SingleDecode {
+            api: None,
+            local,
+            comparison,
+            explorer: None,
+        };
+        let mut bundle = UserOpBundle {
+            entry_point: EntryPointVersion::V07,
+            beneficiary: String::new(),
+            ops: vec![
+                op(
+                    0,
+                    Some(single(Some(local(vec![])), ComparisonResult::OnlyLocal)),
+                ),
+                // A selector collision stays pending
+                op(
+                    1,
+                    Some(single(
+                        Some(local(vec![local(vec![])])),
+                        ComparisonResult::OnlyLocal,
+                    )),
+                ),
+                op(
+                    2,
+                    Some(single(None, ComparisonResult::Failed("none".to_string()))),
+                ),
+            ],
+            summary: MultiSendSummary::default(),
+            verification_state: VerificationState::Pending,
+        };
+
+        update_user_op_summary(&mut bundle);
+        assert_eq!(bundle.summary.total, 3);
+        assert_eq!(bundle.summary.verified, 1);
+        assert_eq!(bundle.summary.pending, 2);
+        assert_eq!(bundle.summary.mismatched, 0);
+    }
+}
@@ -0,0 +1,169 @@
+//! Wrapped-native token operations
+//!
+//! Recognizes `deposit()` and `withdraw(uint256)` on the canonical
+//! wrapped-native contract of each chain. The value semantics differ:
+//! `deposit` wraps the call's `msg.value`, `withdraw` unwraps its argument.
+//! The same selectors on any other contract keep decoding generically.
+
+use alloy::primitives::U256;
+
+/// `deposit()`
+const DEPOSIT_SELECTOR: &str = "0xd0e30db0";
+/// `withdraw(uint256)`
+const WITHDRAW_SELECTOR: &str = "0x2e1a7d4d";
+
+/// Canonical wrapped-native deployment
+struct WrappedNative {
+    chain_id: u64,
+    address: &'static str,
+    symbol: &'static str,
+    native_symbol: &'static str,
+}
+
+const WRAPPED_NATIVE: &[WrappedNative] = &[
+    WrappedNative {
+        chain_id: 1,
+        address: "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2",
+        symbol: "WETH",
+        native_symbol: "ETH",
+    },
+    WrappedNative {
+        chain_id: 11155111,
+        address: "0xfFf9976782d46CC05630D1f6eBAb18b2324d6B14",
+        symbol: "WETH",
+        native_symbol: "ETH",
+    },
+    WrappedNative {
+        chain_id: 10,
+        address: "0x4200000000000000000000000000000000000006",
+        symbol: "WETH",
+        native_symbol: "ETH",
+    },
+    WrappedNative {
+        chain_id: 8453,
+        address: "0x4200000000000000000000000000000000000006",
+        symbol: "WETH",
+        native_symbol: "ETH",
+    },
+    WrappedNative {
+        chain_id: 42161,
+        address: "0x82aF49447D8a07e3bd95BD0d56f35241523fBab1",
+        symbol: "WETH",
+        native_symbol: "ETH",
+    },
+    WrappedNative {
+        chain_id: 137,
+        address: "0x0d500B1d8E8eF31E21C99d1Db9A6444d3ADf1270",
+        symbol: "WPOL",
+        native_symbol: "POL",
+    },
+    WrappedNative {
+        chain_id: 56,
+        address: "0xbb4CdB9CBd36B01bD1cBaEBF2De08d9173bc095c",
+        symbol: "WBNB",
+        native_symbol: "BNB",
+    },
+    WrappedNative {
+        chain_id: 100,
+        address: "0xe91D153E0b41518A2Ce8Dd3D7944Fa863463a97d",
+        symbol: "WXDAI",
+        native_symbol: "xDAI",
+    },
+    WrappedNative {
+        chain_id: 43114,
+        address: "0xB31f66AA3C1e785363F0875A1B74E27b85FD66c7",
+        symbol: "WAVAX",
+        native_symbol: "AVAX",
+    },
+];
+
+/// Direction of a wrapped-native operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WrapKind {
+    Wrap,
+    Unwrap,
+}
+
+/// A recognized wrap or unwrap
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WrapCall {
+    pub kind: WrapKind,
+    /// Amount in wei: `msg.value` for wraps, the argument for unwraps
+    pub amount: U256,
+    pub symbol: &'static str,
+    pub native_symbol: &'static str,
+}
+
+impl WrapCall {
+    /// e.g. "Wraps 1.5 ETH to WETH"
+    pub fn describe(&self) -> String {
+        let amount = if self.amount.is_zero() {
+            "0".to_string()
+        } else {
+            crate::ui::format_uint_with_decimals(&self.amount.to_string(), 18)
+        };
+        match self.kind {
+            WrapKind::Wrap => format!("Wraps {} {} to {}", amount, self.native_symbol, self.symbol),
+            WrapKind::Unwrap => format!(
+                "Unwraps {} {} to {}",
+                amount, self.symbol, self.native_symbol
+            ),
+        }
+    }
+}
+
+/// Classify a call to `to` on `chain_id`. `value` is decimal wei.
+pub fn classify_wrap(chain_id: u64, to: &str, value: &str, data: &str) -> Option<WrapCall> {
+    let to = to.trim().to_lowercase();
+    let token = WRAPPED_NATIVE
+        .iter()
+        .find(|w| w.chain_id == chain_id && w.address.to_lowercase() == to)?;
+
+    let data = data.trim().to_lowercase();
+    let (kind, amount) = match data.get(..10)? {
+        DEPOSIT_SELECTOR => (WrapKind::Wrap, U256::from_str_radix(value.trim(), 10).ok()?),
+        WITHDRAW_SELECTOR => {
+            let word = data.get(10..74)?;
+            (WrapKind::Unwrap, U256::from_str_radix(word, 16).ok()?)
+        }
+        _ => return None,
+    };
+
+    Some(WrapCall {
+        kind,
+        amount,
+        symbol: token.symbol,
+        native_symbol: token.native_symbol,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WETH: &str = "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2";
+
+    #[test]
+    fn test_wrap() {
+        let wrap = classify_wrap(1, WETH, "1500000000000000000", "0xd0e30db0").unwrap();
+        assert_eq!(wrap.kind, WrapKind::Wrap);
+        assert_eq!(wrap.describe(), "Wraps 1.5 ETH to WETH");
+    }
+
+    #[test]
+    fn test_unwrap() {
+        let data = format!("0x2e1a7d4d{:064x}", 2_000_000_000_000_000_000u128);
+        let unwrap = classify_wrap(1, WETH, "0", &data).unwrap();
+        assert_eq!(unwrap.kind, WrapKind::Unwrap);
+        assert_eq!(unwrap.describe(), "Unwraps 2.0 WETH to ETH");
+    }
+
+    #[test]
+    fn test_other_contracts_and_chains() {
+        // Same selector on an unrelated contract
+        let other = "0x1111111111111111111111111111111111111111";
+        assert!(classify_wrap(1, other, "1", "0xd0e30db0").is_none());
+        // Mainnet WETH address on another chain
+        assert!(classify_wrap(137, WETH, "1", "0xd0e30db0").is_none());
+    }
+}
@@ -1,7 +1,8 @@
 //! Expected values validation
 //!
 //! Allows users to verify that the API-returned transaction matches
-//! their expectations (to, value, data, operation).
+//! their expectations (to, value, data, operation), and that the computed
+//! safeTxHash matches the one shown on their hardware wallet.
 
 use alloy::primitives::{Address, U256};
 use eframe::egui;
@@ -22,8 +23,28 @@ pub struct ExpectedState {
     pub data: String,
     /// Expected operation: None = any, Some(0) = Call, Some(1) = DelegateCall
     pub operation: Option<u8>,
+    /// Expected safeTxHash, e.g. as displayed by a hardware wallet
+    pub safe_tx_hash: String,
     /// Validation result after fetch
     pub result: Option<ValidationResult>,
+    /// Comparison of the computed safeTxHash against `safe_tx_hash`
+    pub hash_result: Option<HashCheck>,
+}
+
+/// Result of comparing the computed safeTxHash against the expected one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HashCheck {
+    Match,
+    Mismatch {
+        expected: String,
+        computed: String,
+        /// Number of the 32 bytes that differ
+        differing_bytes: usize,
+        /// Settings under which the expected hash would match
+        hints: Vec<String>,
+    },
+    /// The expected hash isn't a 32-byte hex value
+    Invalid(String),
 }
 
 /// Result of validating expected values against API response
@@ -49,6 +70,7 @@ impl ExpectedState {
     /// Clear validation result (keeps input values)
     pub fn clear_result(&mut self) {
         self.result = None;
+        self.hash_result = None;
     }
 
     /// Clear all state
@@ -57,7 +79,9 @@ impl ExpectedState {
         self.value.clear();
         self.data.clear();
         self.operation = None;
+        self.safe_tx_hash.clear();
         self.result = None;
+        self.hash_result = None;
     }
 }
 
@@ -68,7 +92,7 @@ impl ExpectedState {
 /// Render the expected values collapsible section
 pub fn render_section(ui: &mut egui::Ui, state: &mut ExpectedState) {
     egui::CollapsingHeader::new("🔍 Verify Expected Values")
-        .default_open(state.has_values())
+        .default_open(state.has_values() || !state.safe_tx_hash.is_empty())
         .show(ui, |ui| {
             ui.add_space(5.0);
             ui.label(
@@ -80,6 +104,16 @@ pub fn render_section(ui: &mut egui::Ui, state: &mut ExpectedState) {
                 .num_columns(2)
                 .spacing([10.0, 6.0])
                 .show(ui, |ui| {
+                    // Expected safeTxHash
+                    ui.label("Expected Safe Tx Hash:");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut state.safe_tx_hash)
+                            .hint_text("0x... (as shown on your hardware wallet)")
+                            .desired_width(380.0)
+                            .font(egui::TextStyle::Monospace),
+                    );
+                    ui.end_row();
+
                     // Expected To
                     ui.label("Expected To:");
                     ui.add(
@@ -122,6 +156,98 @@ pub fn render_section(ui: &mut egui::Ui, state: &mut ExpectedState) {
         });
 }
 
+/// Render the safeTxHash comparison as a banner, with differing bytes of the
+/// two hashes highlighted on mismatch
+pub fn render_hash_check(ui: &mut egui::Ui, state: &ExpectedState) {
+    let Some(check) = &state.hash_result else {
+        return;
+    };
+    let red = egui::Color32::from_rgb(220, 80, 80);
+
+    egui::Frame::none()
+        .inner_margin(10.0)
+        .rounding(6.0)
+        .fill(ui.visuals().faint_bg_color)
+        .show(ui, |ui| match check {
+            HashCheck::Match => {
+                ui.label(
+                    egui::RichText::new("✅ Safe Tx Hash matches the expected hash")
+                        .color(egui::Color32::from_rgb(100, 200, 100))
+                        .size(16.0)
+                        .strong(),
+                );
+            }
+            HashCheck::Mismatch {
+                expected,
+                computed,
+                differing_bytes,
+                hints,
+            } => {
+                ui.label(
+                    egui::RichText::new(format!(
+                        "❌ Safe Tx Hash does NOT match the expected hash ({} of 32 bytes differ)",
+                        differing_bytes
+                    ))
+                    .color(red)
+                    .size(16.0)
+                    .strong(),
+                );
+                ui.add_space(4.0);
+                egui::Grid::new("hash_check_diff")
+                    .num_columns(2)
+                    .spacing([10.0, 4.0])
+                    .show(ui, |ui| {
+                        ui.label("Expected:");
+                        ui.label(hash_diff_job(ui, expected, computed, red));
+                        ui.end_row();
+                        ui.label("Computed:");
+                        ui.label(hash_diff_job(ui, computed, expected, red));
+                        ui.end_row();
+                    });
+                ui.add_space(4.0);
+                if hints.is_empty() {
+                    ui.label(
+                        "Do not sign. Check the chain, Safe address, Safe version and nonce; \
+                         if they are right, the transaction itself differs.",
+                    );
+                } else {
+                    for hint in hints {
+                        ui.label(
+                            egui::RichText::new(format!("• {}", hint))
+                                .color(egui::Color32::from_rgb(220, 180, 50)),
+                        );
+                    }
+                }
+            }
+            HashCheck::Invalid(e) => {
+                ui.label(
+                    egui::RichText::new(format!("⚠️ Expected Safe Tx Hash not checked: {}", e))
+                        .color(egui::Color32::from_rgb(220, 180, 50)),
+                );
+            }
+        });
+}
+
+/// `hash` in monospace, with the bytes that differ from `other` in `color`
+fn hash_diff_job(
+    ui: &egui::Ui,
+    hash: &str,
+    other: &str,
+    color: egui::Color32,
+) -> egui::text::LayoutJob {
+    let font = egui::FontId::monospace(12.0);
+    let plain = ui.visuals().text_color();
+    let mut job = egui::text::LayoutJob::default();
+    job.append("0x", 0.0, egui::TextFormat::simple(font.clone(), plain));
+    let (hash, other) = (strip_hex(hash), strip_hex(other));
+    for (i, byte) in hash.as_bytes().chunks(2).enumerate() {
+        let differs = other.get(i * 2..i * 2 + 2).map(str::as_bytes) != Some(byte);
+        let format = egui::TextFormat::simple(font.clone(), if differs { color } else { plain });
+        job.append(&String::from_utf8_lossy(byte), 0.0, format);
+    }
+    job
+}
+
 /// Render validation result (match/mismatches/parse errors)
 pub fn render_result(ui: &mut egui::Ui, state: &ExpectedState) {
     if let Some(result) = &state.result {
@@ -261,10 +387,43 @@ pub fn validate_against_api(api_tx: &SafeTransaction, state: &ExpectedState) ->
     }
 }
 
+/// Compare the computed safeTxHash byte for byte against the expected one.
+/// Mismatch hints are left empty for the caller to fill in.
+pub fn compare_safe_tx_hash(expected: &str, computed: &str) -> HashCheck {
+    let expected_hex = strip_hex(expected.trim()).to_lowercase();
+    let Ok(expected_bytes) = alloy::hex::decode(&expected_hex) else {
+        return HashCheck::Invalid(format!("'{}' is not hex", expected.trim()));
+    };
+    if expected_bytes.len() != 32 {
+        return HashCheck::Invalid(format!("expected 32 bytes, got {}", expected_bytes.len()));
+    }
+    let computed_bytes = alloy::hex::decode(strip_hex(computed)).unwrap_or_default();
+
+    let differing_bytes = (0..32)
+        .filter(|&i| computed_bytes.get(i) != expected_bytes.get(i))
+        .count();
+    if differing_bytes == 0 {
+        HashCheck::Match
+    } else {
+        HashCheck::Mismatch {
+            expected: format!("0x{}", expected_hex),
+            computed: computed.to_lowercase(),
+            differing_bytes,
+            hints: vec![],
+        }
+    }
+}
+
 //─────────────────────────────────────────────────────────────────────────────
 // HELPERS
 //─────────────────────────────────────────────────────────────────────────────
 
+fn strip_hex(s: &str) -> &str {
+    s.strip_prefix("0x")
+        .or_else(|| s.strip_prefix("0X"))
+        .unwrap_or(s)
+}
+
 fn parse_u256(value: &str) -> Result<U256, ()> {
     let value = value.trim();
     if value.is_empty() || value == "0" {
@@ -293,3 +452,35 @@ fn op_to_string(op: u8) -> String {
         _ => format!("Unknown({})", op),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HASH: &str = "0x8f5b2d3c8e2e4a2b7a1f9c0d4e5f60718293a4b5c6d7e8f90112233445566778";
+
+    #[test]
+    fn test_compare_safe_tx_hash() {
+        assert_eq!(compare_safe_tx_hash(HASH, HASH), HashCheck::Match);
+        // Case and surrounding whitespace don't matter
+        let upper = format!("  0x{}  ", HASH[2..].to_uppercase());
+        assert_eq!(compare_safe_tx_hash(&upper, HASH), HashCheck::Match);
+
+        let other = format!("{}00", &HASH[..64]);
+        match compare_safe_tx_hash(&other, HASH) {
+            HashCheck::Mismatch {
+                differing_bytes, ..
+            } => assert_eq!(differing_bytes, 1),
+            result => panic!("expected a mismatch, got {:?}", result),
+        }
+
+        assert!(matches!(
+            compare_safe_tx_hash("0x1234", HASH),
+            HashCheck::Invalid(_)
+        ));
+        assert!(matches!(
+            compare_safe_tx_hash("not a hash", HASH),
+            HashCheck::Invalid(_)
+        ));
+    }
+}
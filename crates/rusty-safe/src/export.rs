@@ -0,0 +1,479 @@
+//! Machine-readable export of verification results
+//!
+//! Serializes the full decode tree, warnings and hashes into one JSON
+//! document. The layout is versioned via `schema_version`; bump it on any
+//! breaking change so downstream tooling can detect it.
+//!
+//! The attestation hash is a keccak256 over the canonical form of the same
+//! document, so reviewers can compare one short hash instead of the JSON.
+//!
+//! Also builds the diagnostic report attached to bug reports: the same data
+//! plus the inputs, raw service response and build info, with optional
+//! address redaction.
+//!
+//! For re-verifying elsewhere, the hashes can be copied as one text block
+//! and the transaction as a `safe-hash` command line.
+
+use alloy::primitives::{keccak256, Address, PrimitiveSignature, B256};
+use safe_hash::SafeWarnings;
+use serde::Serialize;
+use std::collections::HashMap;
+
+use crate::api::SafeTransaction;
+use crate::decode::DecodedTransaction;
+use crate::state::ComputedHashes;
+
+/// Current export schema version
+pub const EXPORT_SCHEMA_VERSION: u32 = 1;
+
+/// Serializable copy of `SafeWarnings`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct WarningsExport {
+    pub delegatecall: bool,
+    pub non_zero_gas_token: bool,
+    pub non_zero_refund_receiver: bool,
+    pub dangerous_methods: bool,
+    pub argument_mismatches: Vec<MismatchExport>,
+}
+
+/// Serializable copy of a field mismatch
+#[derive(Debug, Clone, Serialize)]
+pub struct MismatchExport {
+    pub field: String,
+    pub api_value: String,
+    pub computed_value: String,
+}
+
+impl From<&SafeWarnings> for WarningsExport {
+    fn from(w: &SafeWarnings) -> Self {
+        Self {
+            delegatecall: w.delegatecall,
+            non_zero_gas_token: w.non_zero_gas_token,
+            non_zero_refund_receiver: w.non_zero_refund_receiver,
+            dangerous_methods: w.dangerous_methods,
+            argument_mismatches: w
+                .argument_mismatches
+                .iter()
+                .map(|m| MismatchExport {
+                    field: m.field.clone(),
+                    api_value: m.api_value.clone(),
+                    computed_value: m.user_value.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Full decoded transaction tree with warnings and hashes
+#[derive(Debug, Serialize)]
+pub struct DecodeTreeExport<'a> {
+    pub schema_version: u32,
+    pub decode: Option<&'a DecodedTransaction>,
+    pub warnings: WarningsExport,
+    pub hashes: Option<&'a ComputedHashes>,
+}
+
+/// Render the verification result as pretty-printed JSON
+pub fn export_decode_tree(
+    decode: Option<&DecodedTransaction>,
+    warnings: &SafeWarnings,
+    hashes: Option<&ComputedHashes>,
+) -> Result<String, serde_json::Error> {
+    serde_json::to_string_pretty(&DecodeTreeExport {
+        schema_version: EXPORT_SCHEMA_VERSION,
+        decode,
+        warnings: warnings.into(),
+        hashes,
+    })
+}
+
+/// All hashes of a transaction, including the Ledger binary literal, as
+/// one aligned text block
+pub fn hashes_text(hashes: &ComputedHashes) -> String {
+    format!(
+        "Domain hash:   {}\nMessage hash:  {}\nSafe tx hash:  {}\nLedger binary: {}\n",
+        hashes.domain_hash,
+        hashes.message_hash,
+        hashes.safe_tx_hash,
+        crate::ui::hash_to_binary_literal(&hashes.safe_tx_hash)
+    )
+}
+
+/// `safe-hash tx` invocation that recomputes the hashes of `tx` offline,
+/// with every field taken from the fetched transaction
+pub fn safe_hash_command(
+    chain_name: &str,
+    safe_address: &str,
+    safe_version: &str,
+    tx: &SafeTransaction,
+) -> String {
+    let args = [
+        ("--chain", chain_name.to_string()),
+        ("--safe-address", safe_address.trim().to_string()),
+        ("--safe-version", safe_version.to_string()),
+        ("--nonce", tx.nonce.to_string()),
+        ("--to", tx.to.to_string()),
+        ("--value", tx.value.clone()),
+        ("--data", tx.data.clone()),
+        ("--operation", tx.operation.to_string()),
+        ("--safe-tx-gas", tx.safe_tx_gas.to_string()),
+        ("--base-gas", tx.base_gas.to_string()),
+        ("--gas-price", tx.gas_price.clone()),
+        ("--gas-token", tx.gas_token.to_string()),
+        ("--refund-receiver", tx.refund_receiver.to_string()),
+    ];
+    let mut command = "safe-hash tx".to_string();
+    for (flag, value) in args {
+        command.push_str(&format!(" \\\n  {} {}", flag, value));
+    }
+    command.push_str(" \\\n  --offline");
+    command
+}
+
+/// Compact JSON with object keys sorted at every level, so the same result
+/// always serializes to the same bytes
+pub fn canonical_json(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(b.0));
+            let fields: Vec<String> = entries
+                .into_iter()
+                .map(|(k, v)| {
+                    format!(
+                        "{}:{}",
+                        serde_json::Value::String(k.clone()),
+                        canonical_json(v)
+                    )
+                })
+                .collect();
+            format!("{{{}}}", fields.join(","))
+        }
+        serde_json::Value::Array(items) => {
+            let items: Vec<String> = items.iter().map(canonical_json).collect();
+            format!("[{}]", items.join(","))
+        }
+        other => other.to_string(),
+    }
+}
+
+/// keccak256 of the canonical verification result. Two reviewers get the
+/// same hash only if they reviewed byte-identical results.
+pub fn attestation_hash(
+    decode: Option<&DecodedTransaction>,
+    warnings: &SafeWarnings,
+    hashes: Option<&ComputedHashes>,
+) -> Result<B256, serde_json::Error> {
+    let value = serde_json::to_value(DecodeTreeExport {
+        schema_version: EXPORT_SCHEMA_VERSION,
+        decode,
+        warnings: warnings.into(),
+        hashes,
+    })?;
+    Ok(keccak256(canonical_json(&value)))
+}
+
+/// A reviewer's signature over an attestation hash, to check
+#[derive(Debug, Clone, Default)]
+pub struct AttestationCheck {
+    pub reviewer: String,
+    pub signature: String,
+}
+
+/// Signer of an EIP-191 (`personal_sign`) signature over `message`
+pub fn recover_signer(message: &[u8], signature: &str) -> Result<Address, String> {
+    let signature = signature.trim();
+    let bytes = alloy::hex::decode(signature.strip_prefix("0x").unwrap_or(signature))
+        .map_err(|e| format!("Signature is not hex: {}", e))?;
+    let signature = PrimitiveSignature::try_from(bytes.as_slice())
+        .map_err(|e| format!("Invalid signature: {}", e))?;
+    signature
+        .recover_address_from_msg(message)
+        .map_err(|e| format!("Recovery failed: {}", e))
+}
+
+impl AttestationCheck {
+    /// Whether the pasted signature over `hash` (signed as a 32-byte
+    /// message, e.g. `cast wallet sign <hash>`) comes from the reviewer
+    pub fn verify(&self, hash: &B256) -> Result<bool, String> {
+        let reviewer: Address = self
+            .reviewer
+            .trim()
+            .parse()
+            .map_err(|_| "Invalid reviewer address".to_string())?;
+        Ok(recover_signer(hash.as_slice(), &self.signature)? == reviewer)
+    }
+}
+
+/// Diagnostic report schema version
+pub const DIAGNOSTIC_SCHEMA_VERSION: u32 = 1;
+
+/// Redaction choices for the diagnostic report
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiagnosticOptions {
+    /// Replace every address with a stable placeholder
+    pub redact_addresses: bool,
+    /// Keep calldata and other long hex blobs. Needed to reproduce a decode,
+    /// but calldata embeds addresses that redaction can't see.
+    pub include_calldata: bool,
+}
+
+impl Default for DiagnosticOptions {
+    fn default() -> Self {
+        Self {
+            redact_addresses: false,
+            include_calldata: true,
+        }
+    }
+}
+
+/// Build that produced the report
+#[derive(Debug, Serialize)]
+pub struct BuildInfo {
+    pub version: &'static str,
+    pub git_hash: &'static str,
+    pub build_time: &'static str,
+    pub target: &'static str,
+}
+
+impl BuildInfo {
+    pub fn current() -> Self {
+        Self {
+            version: env!("CARGO_PKG_VERSION"),
+            git_hash: env!("GIT_HASH"),
+            build_time: env!("BUILD_TIME"),
+            target: if cfg!(target_arch = "wasm32") {
+                "wasm32"
+            } else {
+                "native"
+            },
+        }
+    }
+}
+
+/// What the user entered and which modes were active
+#[derive(Debug, Serialize)]
+pub struct DiagnosticInputs<'a> {
+    pub chain_name: &'a str,
+    pub safe_address: &'a str,
+    pub safe_version: &'a str,
+    pub nonce: &'a str,
+    pub trustless: bool,
+    pub check_counterparties: bool,
+}
+
+/// Everything needed to reproduce a verification
+#[derive(Debug, Serialize)]
+pub struct DiagnosticReport<'a> {
+    pub schema_version: u32,
+    pub build: BuildInfo,
+    pub inputs: DiagnosticInputs<'a>,
+    /// Transaction as returned by the Safe service
+    pub service_response: Option<&'a serde_json::Value>,
+    pub raw_calldata: Option<&'a str>,
+    pub hashes: Option<&'a ComputedHashes>,
+    pub warnings: WarningsExport,
+    pub decode: Option<&'a DecodedTransaction>,
+    pub redacted: bool,
+}
+
+impl DiagnosticReport<'_> {
+    /// Pretty-printed JSON with the redaction options applied
+    pub fn to_json(&self, options: &DiagnosticOptions) -> Result<String, serde_json::Error> {
+        let mut value = serde_json::to_value(self)?;
+        let mut placeholders = HashMap::new();
+        if options.redact_addresses && !self.inputs.safe_address.trim().is_empty() {
+            placeholders.insert(
+                self.inputs.safe_address.trim().to_lowercase(),
+                "<safe>".to_string(),
+            );
+        }
+        redact(&mut value, options, &mut placeholders);
+        serde_json::to_string_pretty(&value)
+    }
+}
+
+/// Whether `s` is `0x` followed by exactly 40 hex characters
+fn is_address(s: &str) -> bool {
+    s.len() == 42 && s.starts_with("0x") && s[2..].chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Replace standalone addresses in `text`. Addresses packed inside longer hex
+/// strings (calldata) are left alone.
+fn redact_text(text: &str, placeholders: &mut HashMap<String, String>) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(pos) = rest.find("0x") {
+        let (before, from) = rest.split_at(pos);
+        out.push_str(before);
+        let hex_len = from[2..]
+            .chars()
+            .take_while(|c| c.is_ascii_hexdigit())
+            .count();
+        let token = &from[..2 + hex_len];
+        if is_address(token) {
+            let next = placeholders
+                .values()
+                .filter(|p| p.starts_with("<address"))
+                .count()
+                + 1;
+            let placeholder = placeholders
+                .entry(token.to_lowercase())
+                .or_insert_with(|| format!("<address {}>", next));
+            out.push_str(placeholder);
+        } else {
+            out.push_str(token);
+        }
+        rest = &from[token.len()..];
+    }
+    out.push_str(rest);
+    out
+}
+
+fn redact(
+    value: &mut serde_json::Value,
+    options: &DiagnosticOptions,
+    placeholders: &mut HashMap<String, String>,
+) {
+    match value {
+        serde_json::Value::String(s) => {
+            let is_blob = s.len() > 66 && is_hex_blob(s);
+            if is_blob && !options.include_calldata {
+                *s = format!("<omitted: {} bytes>", (s.len() - 2) / 2);
+            } else if options.redact_addresses {
+                *s = redact_text(s, placeholders);
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for item in items {
+                redact(item, options, placeholders);
+            }
+        }
+        serde_json::Value::Object(map) => {
+            for item in map.values_mut() {
+                redact(item, options, placeholders);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn is_hex_blob(s: &str) -> bool {
+    s.starts_with("0x") && s[2..].chars().all(|c| c.is_ascii_hexdigit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decode::parse_initial;
+
+    #[test]
+    fn test_canonical_json_sorts_keys() {
+        let a = serde_json::json!({"b": 1, "a": {"d": [1, {"f": 2, "e": 3}], "c": "x"}});
+        let b = serde_json::json!({"a": {"c": "x", "d": [1, {"e": 3, "f": 2}]}, "b": 1});
+        assert_eq!(canonical_json(&a), canonical_json(&b));
+        assert_eq!(
+            canonical_json(&a),
+            r#"{"a":{"c":"x","d":[1,{"e":3,"f":2}]},"b":1}"#
+        );
+    }
+
+    #[test]
+    fn test_safe_hash_command() {
+        let zero = "0x0000000000000000000000000000000000000000";
+        let tx: SafeTransaction = serde_json::from_value(serde_json::json!({
+            "safe": zero, "to": "0x1111111111111111111111111111111111111111",
+            "value": "1000", "data": "0xa9059cbb", "dataDecoded": null,
+            "operation": 1, "gasToken": zero, "safeTxGas": 0, "baseGas": 0,
+            "gasPrice": "0", "refundReceiver": zero, "nonce": 42,
+            "safeTxHash": "0x00", "submissionDate": "", "executionDate": null,
+            "transactionHash": null, "isExecuted": false, "isSuccessful": null,
+            "origin": "", "confirmationsRequired": 1, "confirmations": [],
+        }))
+        .unwrap();
+
+        let command = safe_hash_command("ethereum", " 0xabc ", "1.4.1", &tx);
+        let lines: Vec<&str> = command.lines().collect();
+        assert_eq!(lines[0], "safe-hash tx \\");
+        assert!(lines.contains(&"  --safe-address 0xabc \\"));
+        assert!(lines.contains(&"  --nonce 42 \\"));
+        assert!(lines.contains(&"  --data 0xa9059cbb \\"));
+        assert!(lines.contains(&"  --operation 1 \\"));
+        assert_eq!(lines.last(), Some(&"  --offline"));
+    }
+
+    #[test]
+    fn test_recover_signer() {
+        // web3.js `sign` test vector
+        let signature = "0xb91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd\
+                         6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a0291c";
+        let signer = recover_signer(b"Some data", signature).unwrap();
+        assert_eq!(
+            signer,
+            "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23"
+                .parse::<Address>()
+                .unwrap()
+        );
+        assert!(recover_signer(b"Some data", "0x1234").is_err());
+    }
+
+    #[test]
+    fn test_export_decode_tree() {
+        let decode = parse_initial("0xa9059cbb", None);
+        let json = export_decode_tree(Some(&decode), &SafeWarnings::new(), None).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(value["schema_version"], EXPORT_SCHEMA_VERSION);
+        assert_eq!(value["decode"]["selector"], "0xa9059cbb");
+        assert_eq!(value["decode"]["kind"]["single"]["comparison"], "pending");
+        assert_eq!(value["warnings"]["delegatecall"], false);
+        assert!(value["hashes"].is_null());
+    }
+
+    #[test]
+    fn test_diagnostic_redaction() {
+        let safe = "0x1111111111111111111111111111111111111111";
+        let other = "0x2222222222222222222222222222222222222222";
+        let calldata = format!("0xa9059cbb000000000000000000000000{}", &other[2..]);
+        let response = serde_json::json!({ "safe": safe, "to": other, "owners": [other] });
+        let report = DiagnosticReport {
+            schema_version: DIAGNOSTIC_SCHEMA_VERSION,
+            build: BuildInfo::current(),
+            inputs: DiagnosticInputs {
+                chain_name: "ethereum",
+                safe_address: safe,
+                safe_version: "1.4.1",
+                nonce: "7",
+                trustless: false,
+                check_counterparties: false,
+            },
+            service_response: Some(&response),
+            raw_calldata: Some(&calldata),
+            hashes: None,
+            warnings: WarningsExport::default(),
+            decode: None,
+            redacted: true,
+        };
+
+        let options = DiagnosticOptions {
+            redact_addresses: true,
+            include_calldata: true,
+        };
+        let value: serde_json::Value =
+            serde_json::from_str(&report.to_json(&options).unwrap()).unwrap();
+        assert_eq!(value["inputs"]["safe_address"], "<safe>");
+        assert_eq!(value["service_response"]["safe"], "<safe>");
+        assert_eq!(value["service_response"]["to"], "<address 1>");
+        assert_eq!(value["service_response"]["owners"][0], "<address 1>");
+        // Calldata is kept intact for reproduction
+        assert_eq!(value["raw_calldata"], calldata.as_str());
+
+        let options = DiagnosticOptions {
+            redact_addresses: true,
+            include_calldata: false,
+        };
+        let value: serde_json::Value =
+            serde_json::from_str(&report.to_json(&options).unwrap()).unwrap();
+        assert_eq!(value["raw_calldata"], "<omitted: 36 bytes>");
+    }
+}
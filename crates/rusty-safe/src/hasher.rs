@@ -1,15 +1,24 @@
 //! Hash computation - uses safe_hash library
 
 use crate::api::{
-    check_suspicious_content, tx_signing_hashes, validate_safe_tx_hash, SafeApiResponse,
-    SafeTransaction, TxInput,
+    check_suspicious_content, tx_signing_hashes, validate_safe_tx_hash, SafeTransaction, TxInput,
+};
+use crate::counterparty::CounterpartyHistory;
+use crate::state::{get_chain_name, AuditEntry, ComputedHashes};
+use alloy::primitives::{
+    hex, keccak256, Address, ChainId, FixedBytes, PrimitiveSignature, B256, U256,
 };
-use crate::state::ComputedHashes;
-use alloy::primitives::{hex, Address, ChainId, FixedBytes, U256};
 use eyre::{Result, WrapErr};
 use safe_hash::{Mismatch, SafeHashes, SafeWarnings};
-use safe_utils::{get_safe_api, Of, SafeWalletVersion};
+use safe_utils::{
+    get_safe_api, DomainHasher, Eip712Hasher, MessageHasher, Of, SafeHasher, SafeWalletVersion,
+};
 use serde::Deserialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::future::Future;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
 
 /// Safe info response from API
 #[derive(Debug, Clone, Deserialize)]
@@ -28,6 +37,261 @@ pub struct SafeInfo {
     /// First pending transaction (pre-fetched to avoid duplicate API call)
     #[serde(skip)]
     pub pending_transaction: Option<SafeTransaction>,
+    /// Proposal metadata for `pending_transaction`
+    #[serde(skip)]
+    pub pending_proposal: Option<ProposalInfo>,
+    /// Service response for `pending_transaction`, as received
+    #[serde(skip)]
+    pub pending_raw: Option<serde_json::Value>,
+}
+
+/// Proposal metadata the Safe service attaches to a transaction.
+/// Not part of `SafeTransaction`, so it's read from the raw response.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProposalInfo {
+    pub proposer: Option<Address>,
+    /// Set when a delegate proposed on the proposer's behalf
+    pub proposed_by_delegate: Option<Address>,
+}
+
+/// Transactions for a nonce with their proposal metadata
+#[derive(Debug, Clone, Default)]
+pub struct FetchedTransactions {
+    pub transactions: Vec<SafeTransaction>,
+    /// Keyed by lowercase safeTxHash
+    pub proposals: HashMap<String, ProposalInfo>,
+    /// Service response for each transaction, as received. Keyed by lowercase safeTxHash.
+    pub raw: HashMap<String, serde_json::Value>,
+}
+
+impl FetchedTransactions {
+    /// Append another fetch, e.g. the next nonce of a range
+    pub fn extend(&mut self, other: FetchedTransactions) {
+        self.transactions.extend(other.transactions);
+        self.proposals.extend(other.proposals);
+        self.raw.extend(other.raw);
+    }
+}
+
+/// Most nonces fetched by one range request
+pub const MAX_NONCE_RANGE: u64 = 50;
+
+/// Transactions for a nonce range, with the nonces that failed
+#[derive(Debug, Clone, Default)]
+pub struct FetchedRange {
+    pub fetched: FetchedTransactions,
+    /// Per-nonce errors; the rest of the range is still returned
+    pub errors: Vec<(u64, String)>,
+}
+
+/// Parsed `origin` field. The Safe{Wallet} UI stores JSON like
+/// `{"url": "...", "name": "..."}`; other clients may send free text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OriginInfo {
+    pub name: Option<String>,
+    pub url: Option<String>,
+    pub raw: String,
+}
+
+/// Parse a transaction's `origin` metadata. Returns `None` if empty.
+pub fn parse_origin(origin: &str) -> Option<OriginInfo> {
+    let raw = origin.trim();
+    if raw.is_empty() {
+        return None;
+    }
+
+    let json: Option<serde_json::Value> = serde_json::from_str(raw).ok();
+    let field = |key: &str| {
+        json.as_ref()
+            .and_then(|v| v.get(key))
+            .and_then(|v| v.as_str())
+            .filter(|s| !s.is_empty())
+            .map(str::to_string)
+    };
+
+    Some(OriginInfo {
+        name: field("name"),
+        url: field("url"),
+        raw: raw.to_string(),
+    })
+}
+
+/// Read proposer metadata from one raw transaction JSON object
+fn parse_proposal(item: &serde_json::Value) -> ProposalInfo {
+    let address = |key: &str| {
+        item.get(key)
+            .and_then(|v| v.as_str())
+            .and_then(|s| s.parse::<Address>().ok())
+    };
+    ProposalInfo {
+        proposer: address("proposer"),
+        proposed_by_delegate: address("proposedByDelegate"),
+    }
+}
+
+/// Split raw transaction JSON objects into typed transactions and their
+/// proposal metadata
+fn parse_transactions_with_proposals(items: Vec<serde_json::Value>) -> Result<FetchedTransactions> {
+    let mut fetched = FetchedTransactions::default();
+    for item in items {
+        let proposal = parse_proposal(&item);
+        let tx: SafeTransaction =
+            serde_json::from_value(item.clone()).wrap_err("Failed to parse Safe transaction")?;
+        let key = tx.safe_tx_hash.to_lowercase();
+        fetched.proposals.insert(key.clone(), proposal);
+        fetched.raw.insert(key, item);
+        fetched.transactions.push(tx);
+    }
+    Ok(fetched)
+}
+
+/// How an owner confirmed a transaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfirmationKind {
+    /// Off-chain signature; carries the service's signatureType (EOA, ETH_SIGN, ...)
+    OffChain(String),
+    /// On-chain `approveHash(bytes32)`, packed as a pre-validated (v=1) signature
+    ApprovedHash {
+        /// Whether the signature's `r` encodes the confirming owner, as the
+        /// Safe requires for pre-validated signatures
+        owner_matches: bool,
+    },
+}
+
+/// One owner confirmation as reported by the service
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfirmationInfo {
+    pub owner: Address,
+    pub kind: ConfirmationKind,
+    /// Transaction that made an on-chain approval, when the service has it
+    pub transaction_hash: Option<String>,
+}
+
+/// Read confirmations from one raw transaction JSON object. A confirmation is
+/// an on-chain approval when the service says `APPROVED_HASH` or its packed
+/// signature has v=1. An approval with no signature names nobody else, so it
+/// matches its owner.
+pub fn parse_confirmations(item: &serde_json::Value) -> Vec<ConfirmationInfo> {
+    let Some(confirmations) = item.get("confirmations").and_then(|c| c.as_array()) else {
+        return vec![];
+    };
+
+    confirmations
+        .iter()
+        .filter_map(|c| {
+            let owner: Address = c.get("owner")?.as_str()?.parse().ok()?;
+            let sig_type = c
+                .get("signatureType")
+                .and_then(|t| t.as_str())
+                .unwrap_or_default();
+            let signature = c
+                .get("signature")
+                .and_then(|s| s.as_str())
+                .and_then(|s| hex::decode(s.trim_start_matches("0x")).ok())
+                .unwrap_or_default();
+            let pre_validated = signature.len() == 65 && signature[64] == 1;
+
+            let kind = if sig_type == "APPROVED_HASH" || pre_validated {
+                let owner_matches = signature.is_empty()
+                    || (signature.len() == 65
+                        && signature[..12].iter().all(|b| *b == 0)
+                        && &signature[12..32] == owner.as_slice());
+                ConfirmationKind::ApprovedHash { owner_matches }
+            } else {
+                ConfirmationKind::OffChain(sig_type.to_string())
+            };
+            let transaction_hash = c
+                .get("transactionHash")
+                .and_then(|h| h.as_str())
+                .map(str::to_string);
+            Some(ConfirmationInfo {
+                owner,
+                kind,
+                transaction_hash,
+            })
+        })
+        .collect()
+}
+
+/// Problem with a transaction's collected confirmations
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfirmationIssue {
+    /// Signer is not a current owner of the Safe
+    NotOwner(Address),
+    /// Signer confirmed more than once
+    Duplicate(Address),
+    /// Valid confirmations (unique current owners) don't reach the threshold
+    BelowThreshold { valid: usize, threshold: u64 },
+}
+
+/// Check the service's confirmations against the fetched owners and
+/// threshold. Only unique signers that are current owners count, since the
+/// Safe rejects the rest at execution.
+pub fn validate_confirmations(tx: &SafeTransaction, info: &SafeInfo) -> Vec<ConfirmationIssue> {
+    let mut issues = vec![];
+    let mut seen: Vec<Address> = vec![];
+    let mut valid = 0;
+
+    for confirmation in &tx.confirmations {
+        let signer = confirmation.owner;
+        if seen.contains(&signer) {
+            issues.push(ConfirmationIssue::Duplicate(signer));
+            continue;
+        }
+        seen.push(signer);
+        if info.owners.contains(&signer) {
+            valid += 1;
+        } else {
+            issues.push(ConfirmationIssue::NotOwner(signer));
+        }
+    }
+
+    if (valid as u64) < info.threshold {
+        issues.push(ConfirmationIssue::BelowThreshold {
+            valid,
+            threshold: info.threshold,
+        });
+    }
+    issues
+}
+
+/// Recover the signer of one packed Safe signature (`r ‖ s ‖ v`) over
+/// `safe_tx_hash`, following the Safe's `v` encoding: 27/28 sign the hash
+/// directly, 31/32 sign it with the `eth_sign` prefix, 1 is an on-chain
+/// approval naming the owner in `r`. Contract signatures (v=0) are checked
+/// by the contract itself and have nothing to recover.
+fn recover_safe_signature(signature: &str, safe_tx_hash: &B256) -> Option<Address> {
+    let bytes = hex::decode(signature.trim().trim_start_matches("0x")).ok()?;
+    let v = *bytes.get(64)?;
+    match v {
+        1 => Some(Address::from_slice(&bytes[12..32])),
+        27 | 28 => PrimitiveSignature::from_bytes_and_parity(&bytes[..64], v == 28)
+            .recover_address_from_prehash(safe_tx_hash)
+            .ok(),
+        31 | 32 => PrimitiveSignature::from_bytes_and_parity(&bytes[..64], v == 32)
+            .recover_address_from_msg(safe_tx_hash.as_slice())
+            .ok(),
+        _ => None,
+    }
+}
+
+/// Whether a packed Safe signature is an EIP-1271 contract signature (v=0)
+pub fn is_contract_signature(signature: &str) -> bool {
+    hex::decode(signature.trim().trim_start_matches("0x"))
+        .is_ok_and(|bytes| bytes.get(64) == Some(&0))
+}
+
+/// Pair each confirmation's reported signer with the address its signature
+/// recovers to over `safe_tx_hash`. `None` when there is nothing to recover
+/// (contract signatures) or the signature is malformed.
+pub fn recover_confirmation_signers(
+    tx: &SafeTransaction,
+    safe_tx_hash: B256,
+) -> Vec<(Address, Option<Address>)> {
+    tx.confirmations
+        .iter()
+        .map(|c| (c.owner, recover_safe_signature(&c.signature, &safe_tx_hash)))
+        .collect()
 }
 
 /// Response for pending transactions (includes count_unique_nonce)
@@ -35,7 +299,7 @@ pub struct SafeInfo {
 #[serde(rename_all = "camelCase")]
 struct PendingTxResponse {
     count_unique_nonce: Option<u64>,
-    results: Vec<SafeTransaction>,
+    results: Vec<serde_json::Value>,
 }
 
 /// Deserialize a string number to u64
@@ -49,22 +313,313 @@ where
         .map_err(|_| D::Error::custom(format!("Failed to parse '{}' as u64", s)))
 }
 
+/// Retries after the first attempt when the settings don't say otherwise
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+/// Delay before the first retry; doubles with each further one
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+/// Upper bound on any single wait, including a server's `Retry-After`
+const RETRY_MAX_DELAY: Duration = Duration::from_secs(30);
+
+/// Retry budget for Safe API requests, kept process-wide so the setting
+/// applies without threading it through every fetch
+static MAX_RETRIES: AtomicU32 = AtomicU32::new(DEFAULT_MAX_RETRIES);
+
+/// Apply the retry setting. Call whenever it may have changed.
+pub fn set_max_retries(retries: u32) {
+    MAX_RETRIES.store(retries, Ordering::Relaxed);
+}
+
+/// Rate limiting and server-side failures are worth another attempt; any
+/// other status is the final answer
+fn is_retryable(status: reqwest::StatusCode) -> bool {
+    status == reqwest::StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// Wait before retry number `attempt` (0-based): `Retry-After` seconds when
+/// the server sent them, exponential backoff otherwise
+fn retry_delay(attempt: u32, retry_after: Option<&str>) -> Duration {
+    let delay = match retry_after.and_then(|s| s.trim().parse::<u64>().ok()) {
+        Some(secs) => Duration::from_secs(secs),
+        None => RETRY_BASE_DELAY.saturating_mul(1 << attempt.min(16)),
+    };
+    delay.min(RETRY_MAX_DELAY)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await;
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn sleep(duration: Duration) {
+    use wasm_bindgen_futures::js_sys::Promise;
+    let promise = Promise::new(&mut |resolve, _| {
+        if let Some(window) = web_sys::window() {
+            let _ = window.set_timeout_with_callback_and_timeout_and_arguments_0(
+                &resolve,
+                duration.as_millis() as i32,
+            );
+        }
+    });
+    let _ = wasm_bindgen_futures::JsFuture::from(promise).await;
+}
+
+/// GET from the Safe API, retrying network errors, 429s and 5xx with
+/// backoff. Other statuses are returned for the caller to handle; when the
+/// retries run out the error says how many attempts were made.
+async fn api_get(url: &str) -> Result<reqwest::Response> {
+    let max_retries = MAX_RETRIES.load(Ordering::Relaxed);
+    let mut attempt = 0;
+    loop {
+        let (error, retry_after) = match reqwest::get(url).await {
+            Ok(response) if !is_retryable(response.status()) => return Ok(response),
+            Ok(response) => {
+                let retry_after = response
+                    .headers()
+                    .get(reqwest::header::RETRY_AFTER)
+                    .and_then(|v| v.to_str().ok())
+                    .map(str::to_string);
+                (eyre::eyre!("API error: {}", response.status()), retry_after)
+            }
+            Err(e) => (eyre::Report::new(e).wrap_err("Network error"), None),
+        };
+
+        if attempt >= max_retries {
+            return Err(error.wrap_err(format!(
+                "Gave up after {} attempt{}",
+                attempt + 1,
+                if attempt == 0 { "" } else { "s" }
+            )));
+        }
+        sleep(retry_delay(attempt, retry_after.as_deref())).await;
+        attempt += 1;
+    }
+}
+
+/// Run `future`, failing if it hasn't finished within `limit`
+pub(crate) async fn with_timeout<T>(
+    limit: Duration,
+    future: impl Future<Output = Result<T>>,
+) -> Result<T> {
+    let mut future = std::pin::pin!(future);
+    let mut deadline = std::pin::pin!(sleep(limit));
+    std::future::poll_fn(|cx| {
+        if let std::task::Poll::Ready(output) = future.as_mut().poll(cx) {
+            return std::task::Poll::Ready(output);
+        }
+        if deadline.as_mut().poll(cx).is_ready() {
+            return std::task::Poll::Ready(Err(eyre::eyre!(
+                "Timed out after {}s",
+                limit.as_secs()
+            )));
+        }
+        std::task::Poll::Pending
+    })
+    .await
+}
+
+/// User-configured Transaction Service base URLs by chain name, consulted
+/// before the built-in host
+static SERVICE_OVERRIDES: Mutex<Vec<(String, String)>> = Mutex::new(Vec::new());
+
+/// Apply the service URL overrides. Call whenever they may have changed.
+pub fn set_service_overrides(overrides: &HashMap<String, String>) {
+    let mut current = SERVICE_OVERRIDES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    current.clear();
+    current.extend(overrides.iter().map(|(k, v)| (k.clone(), v.clone())));
+}
+
+/// Override for `chain_name`, if one is configured
+pub fn service_override(chain_name: &str) -> Option<String> {
+    SERVICE_OVERRIDES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .iter()
+        .find(|(chain, _)| chain == chain_name)
+        .map(|(_, url)| url.clone())
+}
+
+/// Transaction Service base URL for a chain: the override if set, the
+/// built-in host otherwise
+pub fn safe_api_url(chain_name: &str) -> Result<String> {
+    match service_override(chain_name) {
+        Some(url) => Ok(url),
+        None => default_safe_api_url(chain_name),
+    }
+}
+
+/// Built-in Transaction Service base URL for a chain
+pub fn default_safe_api_url(chain_name: &str) -> Result<String> {
+    if is_custom_chain(chain_name) {
+        eyre::bail!(
+            "No Transaction Service URL set for custom chain {}",
+            chain_name
+        );
+    }
+    let chain_id = chain_id_of(chain_name)?;
+    get_safe_api(chain_id).map_err(|e| eyre::eyre!("Failed to get API URL: {}", e))
+}
+
+/// Chain ID for a chain name. Chains safe_utils doesn't know are named by
+/// their decimal ID (see `get_chain_name`), so a bare number is accepted as
+/// a custom chain.
+pub fn chain_id_of(chain_name: &str) -> Result<u64> {
+    if let Ok(id) = ChainId::of(chain_name) {
+        return Ok(id);
+    }
+    match chain_name.trim().parse::<u64>() {
+        Ok(id) if id > 0 => Ok(id),
+        _ => eyre::bail!("Invalid chain '{}'", chain_name),
+    }
+}
+
+/// A chain given by ID alone, outside safe_utils' built-in list
+pub fn is_custom_chain(chain_name: &str) -> bool {
+    ChainId::of(chain_name).is_err() && chain_id_of(chain_name).is_ok()
+}
+
+/// Check a custom chain entry: returns its chain name and normalized
+/// Transaction Service URL. Built-in chains are refused so their hashes
+/// can't be computed against an unexpected service by accident.
+pub fn validate_custom_chain(
+    chain_id: &str,
+    service_url: &str,
+) -> Result<(String, String), String> {
+    let id: u64 = match chain_id.trim().parse() {
+        Ok(id) if id > 0 => id,
+        _ => return Err(format!("Invalid chain ID '{}'", chain_id.trim())),
+    };
+    let name = get_chain_name(id);
+    if !is_custom_chain(&name) {
+        return Err(format!(
+            "Chain {} is built in as '{}'; select it instead",
+            id, name
+        ));
+    }
+    Ok((name, validate_service_url(service_url)?))
+}
+
+/// Check a Transaction Service base URL and normalize it to the form the
+/// fetches append paths to (no trailing slash)
+pub fn validate_service_url(input: &str) -> Result<String, String> {
+    let url = reqwest::Url::parse(input.trim()).map_err(|e| format!("Invalid URL: {}", e))?;
+    if !matches!(url.scheme(), "https" | "http") {
+        return Err(format!("Unsupported scheme '{}'", url.scheme()));
+    }
+    if url.host_str().is_none() {
+        return Err("URL has no host".to_string());
+    }
+    if url.query().is_some() || url.fragment().is_some() {
+        return Err("URL must not have a query or fragment".to_string());
+    }
+    Ok(url.as_str().trim_end_matches('/').to_string())
+}
+
+/// Hosts whose share links are accepted
+const SHARE_LINK_HOSTS: &[&str] = &["app.safe.global"];
+
+/// EIP-3770 short names Safe{Wallet} puts in front of the Safe address, and
+/// the chain each one means
+const CHAIN_SHORT_NAMES: &[(&str, &str)] = &[
+    ("eth", "ethereum"),
+    ("oeth", "optimism"),
+    ("bnb", "bsc"),
+    ("gno", "gnosis"),
+    ("matic", "polygon"),
+    ("zkevm", "polygon-zkevm"),
+    ("zksync", "zksync"),
+    ("wc", "worldchain"),
+    ("mantle", "mantle"),
+    ("base", "base"),
+    ("arb1", "arbitrum"),
+    ("celo", "celo"),
+    ("avax", "avalanche"),
+    ("linea", "linea"),
+    ("blast", "blast"),
+    ("scr", "scroll"),
+    ("aurora", "aurora"),
+    ("sep", "sepolia"),
+    ("basesep", "base-sepolia"),
+    ("chi", "gnosis-chiado"),
+];
+
+/// What a Safe{Wallet} share link points at
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShareLink {
+    pub chain_name: String,
+    pub safe_address: String,
+    /// Set for single-transaction links; queue and other Safe pages leave it
+    /// empty
+    pub safe_tx_hash: Option<String>,
+}
+
+/// Parse a Safe{Wallet} link such as
+/// `https://app.safe.global/transactions/tx?safe=eth:0x…&id=multisig_0x…_0x…`
+/// or `https://app.safe.global/transactions/queue?safe=eth:0x…`
+pub fn parse_safe_share_url(input: &str) -> Result<ShareLink, String> {
+    let url = reqwest::Url::parse(input.trim()).map_err(|e| format!("Invalid URL: {}", e))?;
+    let host = url.host_str().unwrap_or_default();
+    if url.scheme() != "https" || !SHARE_LINK_HOSTS.contains(&host) {
+        return Err(format!(
+            "Not a Safe{{Wallet}} link (expected {})",
+            SHARE_LINK_HOSTS.join(", ")
+        ));
+    }
+    let param = |name: &str| {
+        url.query_pairs()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.into_owned())
+    };
+
+    let safe = param("safe").ok_or("Link has no safe parameter")?;
+    let (short_name, address) = safe
+        .split_once(':')
+        .ok_or_else(|| format!("'{}' has no chain prefix", safe))?;
+    let chain_name = CHAIN_SHORT_NAMES
+        .iter()
+        .find(|(short, _)| *short == short_name)
+        .map(|(_, chain)| chain.to_string())
+        .ok_or_else(|| format!("Unsupported chain prefix '{}'", short_name))?;
+    let safe_address: Address = address
+        .parse()
+        .map_err(|_| format!("Invalid Safe address '{}'", address))?;
+
+    let safe_tx_hash = match url.path().trim_end_matches('/') {
+        "/transactions/tx" => {
+            // `multisig_<safe>_<safeTxHash>`
+            let id = param("id").ok_or("Transaction link has no id parameter")?;
+            let hash = id
+                .rsplit('_')
+                .next()
+                .filter(|_| id.starts_with("multisig_"))
+                .and_then(|h| h.parse::<B256>().ok())
+                .ok_or_else(|| format!("Unsupported transaction id '{}'", id))?;
+            Some(format!("{:?}", hash))
+        }
+        _ => None,
+    };
+
+    Ok(ShareLink {
+        chain_name,
+        safe_address: safe_address.to_checksum(None),
+        safe_tx_hash,
+    })
+}
+
 /// Fetch Safe info from API (async - works on WASM)
 pub async fn fetch_safe_info(chain_name: &str, safe_address: &str) -> Result<SafeInfo> {
-    let chain_id = ChainId::of(chain_name)
-        .map_err(|e| eyre::eyre!("Invalid chain '{}': {}", chain_name, e))?;
-
     let addr: Address = safe_address
         .trim()
         .parse()
         .wrap_err("Invalid Safe address")?;
 
-    let api_url =
-        get_safe_api(chain_id).map_err(|e| eyre::eyre!("Failed to get API URL: {}", e))?;
+    let api_url = safe_api_url(chain_name)?;
 
     let url = format!("{}/api/v1/safes/{}/", api_url, addr);
 
-    let response = reqwest::get(&url).await.wrap_err("Network error")?;
+    let response = api_get(&url).await?;
 
     if !response.status().is_success() {
         eyre::bail!("API error: {}", response.status());
@@ -81,151 +636,739 @@ pub async fn fetch_safe_info(chain_name: &str, safe_address: &str) -> Result<Saf
         "{}/api/v1/safes/{}/multisig-transactions/?executed=false&limit=1",
         api_url, addr
     );
-    if let Ok(pending_response) = reqwest::get(&pending_url).await {
+    if let Ok(pending_response) = api_get(&pending_url).await {
         if let Ok(pending_data) = pending_response.json::<PendingTxResponse>().await {
             safe_info.pending_nonce_count = pending_data.count_unique_nonce;
             // Capture the first pending transaction to avoid duplicate fetch
-            safe_info.pending_transaction = pending_data.results.into_iter().next();
+            let first = pending_data.results.into_iter().take(1).collect();
+            if let Ok(mut fetched) = parse_transactions_with_proposals(first) {
+                safe_info.pending_transaction = fetched.transactions.pop();
+                safe_info.pending_proposal = fetched.proposals.into_values().next();
+                safe_info.pending_raw = fetched.raw.into_values().next();
+            }
         }
     }
 
     Ok(safe_info)
 }
 
+/// A Safe deployment of the same address on one chain
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SafePresence {
+    pub chain_name: String,
+    pub nonce: u64,
+    pub threshold: u64,
+    pub owners: usize,
+    pub version: String,
+}
+
+/// Budget for one chain in the multi-chain lookup, retries included, so a
+/// slow service can't hold the scan up
+const CHAIN_LOOKUP_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Check whether `safe_address` is a Safe on `chain_name` (async - works on
+/// WASM). `Ok(None)` when the chain's service doesn't know the address.
+pub async fn fetch_safe_presence(
+    chain_name: &str,
+    safe_address: &str,
+) -> Result<Option<SafePresence>> {
+    let addr: Address = safe_address
+        .trim()
+        .parse()
+        .wrap_err("Invalid Safe address")?;
+
+    let api_url = safe_api_url(chain_name)?;
+    let url = format!("{}/api/v1/safes/{}/", api_url, addr);
+
+    let lookup = async {
+        let response = api_get(&url).await?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !response.status().is_success() {
+            eyre::bail!("API error: {}", response.status());
+        }
+        let info: SafeInfo = response
+            .json()
+            .await
+            .wrap_err("Failed to parse Safe info")?;
+        Ok(Some(info))
+    };
+    let Some(info) = with_timeout(CHAIN_LOOKUP_TIMEOUT, lookup).await? else {
+        return Ok(None);
+    };
+    Ok(Some(SafePresence {
+        chain_name: chain_name.to_string(),
+        nonce: info.nonce,
+        threshold: info.threshold,
+        owners: info.owners.len(),
+        version: info.version,
+    }))
+}
+
+/// Canonical singleton deployments and the version they implement. The L1
+/// and L2 (event-emitting) variants hash transactions the same way.
+const SINGLETON_VERSIONS: &[(&str, &str)] = &[
+    ("0x41675C099F32341bf84BFc5382aF534df5C7461a", "1.4.1"),
+    ("0x29fcB43b46531BcA003ddC8FCB67FFE91900C762", "1.4.1"),
+    ("0xd9Db270c1B5E3Bd161E8c8503c55cEABeE709552", "1.3.0"),
+    ("0x3E5c63644E683549055b9Be8653de26E0B4CD36E", "1.3.0"),
+    ("0x69f4D1788e39c87893C980c06EdF4b7f686e2938", "1.3.0"),
+    ("0xfb1bffC9d739B8D520DaF37dF666da4C687191EA", "1.3.0"),
+    ("0x6851D6fDFAfD08c0295C392436245E5bc78B0185", "1.2.0"),
+    ("0x34CfAC646f301356fAa8B21e94227e3583Fe3F5F", "1.1.1"),
+    ("0xaE32496491b53841efb51829d6f886387708F99B", "1.1.0"),
+    ("0xb6029EA3B2c51D09a50B53CA8012FeEB05bDa35A", "1.0.0"),
+];
+
+/// Version implemented by a known singleton deployment
+pub fn version_for_singleton(singleton: Address) -> Option<&'static str> {
+    SINGLETON_VERSIONS
+        .iter()
+        .find(|(address, _)| address.parse::<Address>().ok() == Some(singleton))
+        .map(|(_, version)| *version)
+}
+
+/// Infer a Safe's version from the singleton its proxy points at (async -
+/// works on WASM). Fails if the singleton isn't a known deployment, in
+/// which case the manually selected version should be kept.
+pub async fn infer_safe_version(chain_name: &str, safe_address: &str) -> Result<String> {
+    let addr: Address = safe_address
+        .trim()
+        .parse()
+        .wrap_err("Invalid Safe address")?;
+
+    let api_url = safe_api_url(chain_name)?;
+    let url = format!("{}/api/v1/safes/{}/", api_url, addr);
+
+    let response = api_get(&url).await?;
+    if !response.status().is_success() {
+        eyre::bail!("API error: {}", response.status());
+    }
+    let body: serde_json::Value = response
+        .json()
+        .await
+        .wrap_err("Failed to parse Safe info")?;
+
+    let singleton: Address = body["masterCopy"]
+        .as_str()
+        .ok_or_else(|| eyre::eyre!("Safe info has no singleton address"))?
+        .parse()
+        .wrap_err("Invalid singleton address")?;
+    let version = version_for_singleton(singleton)
+        .ok_or_else(|| eyre::eyre!("Unrecognized singleton {}", singleton))?;
+    SafeWalletVersion::parse(version)
+        .map_err(|e| eyre::eyre!("Invalid Safe version '{}': {}", version, e))?;
+    Ok(version.to_string())
+}
+
 /// Fetch transactions from Safe API (async - works on WASM)
 pub async fn fetch_transactions(
     chain_name: &str,
     safe_address: &str,
     nonce: u64,
-) -> Result<Vec<SafeTransaction>> {
-    let chain_id = ChainId::of(chain_name)
-        .map_err(|e| eyre::eyre!("Invalid chain '{}': {}", chain_name, e))?;
-
+) -> Result<FetchedTransactions> {
     let addr: Address = safe_address
         .trim()
         .parse()
         .wrap_err("Invalid Safe address")?;
 
-    let api_url =
-        get_safe_api(chain_id).map_err(|e| eyre::eyre!("Failed to get API URL: {}", e))?;
+    let api_url = safe_api_url(chain_name)?;
     let url = format!(
         "{}/api/v1/safes/{}/multisig-transactions/?nonce={}",
         api_url, addr, nonce
     );
 
-    let response = reqwest::get(&url).await.wrap_err("Network error")?;
+    let response = api_get(&url).await?;
     if !response.status().is_success() {
         eyre::bail!("API error: {}", response.status());
     }
 
-    let api_response: SafeApiResponse = response
+    let mut body: serde_json::Value = response
         .json()
         .await
         .wrap_err("Failed to parse Safe transaction response")?;
+    let items = match body.get_mut("results").map(serde_json::Value::take) {
+        Some(serde_json::Value::Array(items)) => items,
+        _ => eyre::bail!("Failed to parse Safe transaction response: missing results"),
+    };
 
-    if api_response.results.is_empty() {
+    if items.is_empty() {
         eyre::bail!("No transaction found for the specified nonce");
     }
 
-    Ok(api_response.results)
+    parse_transactions_with_proposals(items)
 }
 
-/// Compute hashes for a transaction using safe_hash::tx_signing_hashes
-pub fn compute_hashes(
+/// Fetch every proposal sharing a nonce with the transaction `safe_tx_hash`
+/// (async - works on WASM). Errors if the transaction belongs to another
+/// Safe.
+pub async fn fetch_transactions_for_hash(
     chain_name: &str,
     safe_address: &str,
-    version: &str,
-    to: &str,
-    value: &str,
-    data: &str,
-    operation: u8,
-    safe_tx_gas: &str,
-    base_gas: &str,
-    gas_price: &str,
-    gas_token: &str,
-    refund_receiver: &str,
-    nonce: &str,
-) -> Result<ComputedHashes> {
-    let chain_id = ChainId::of(chain_name)
-        .map_err(|e| eyre::eyre!("Invalid chain '{}': {}", chain_name, e))?;
-
-    let safe_version = SafeWalletVersion::parse(version)
-        .map_err(|e| eyre::eyre!("Invalid Safe version '{}': {}", version, e))?;
-
-    let safe_addr: Address = safe_address
+    safe_tx_hash: &str,
+) -> Result<FetchedTransactions> {
+    let addr: Address = safe_address
         .trim()
         .parse()
         .wrap_err("Invalid Safe address")?;
 
-    let to_addr: Address = to.trim().parse().wrap_err("Invalid 'to' address")?;
+    let api_url = safe_api_url(chain_name)?;
+    let url = format!(
+        "{}/api/v1/multisig-transactions/{}/",
+        api_url,
+        safe_tx_hash.trim()
+    );
+    let response = api_get(&url).await?;
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        eyre::bail!("No transaction found for hash {}", safe_tx_hash);
+    }
+    if !response.status().is_success() {
+        eyre::bail!("API error: {}", response.status());
+    }
 
-    let value_u256 = parse_u256(value).wrap_err("Invalid value")?;
-    let safe_tx_gas_u256 = parse_u256(safe_tx_gas).wrap_err("Invalid safeTxGas")?;
-    let base_gas_u256 = parse_u256(base_gas).wrap_err("Invalid baseGas")?;
-    let gas_price_u256 = parse_u256(gas_price).wrap_err("Invalid gasPrice")?;
-    let nonce_u64: u64 = nonce.trim().parse().wrap_err("Invalid nonce")?;
+    let tx: SafeTransaction = response
+        .json()
+        .await
+        .wrap_err("Failed to parse Safe transaction response")?;
+    let tx_safe = tx.safe.to_string();
+    if !tx_safe.eq_ignore_ascii_case(&addr.to_string()) {
+        eyre::bail!("Transaction belongs to Safe {}, not {}", tx_safe, addr);
+    }
+    fetch_transactions(chain_name, safe_address, tx.nonce).await
+}
 
-    let gas_token_addr: Address = gas_token
-        .trim()
-        .parse()
-        .wrap_err("Invalid gas token address")?;
+/// Where a nonce stands in the Safe's queue
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NonceStatus {
+    /// The Safe's nonce has moved past it: this or a competing transaction
+    /// already executed
+    Executed { current: u64 },
+    /// Next to execute, with no competing proposal
+    Executable,
+    /// Earlier nonces have to execute first. `missing` have no proposal at
+    /// all, so the queue is stuck until someone proposes one.
+    Blocked {
+        pending: Vec<u64>,
+        missing: Vec<u64>,
+    },
+    /// Several proposals share the nonce; executing one invalidates the rest
+    Conflict { count: usize },
+}
 
-    let refund_receiver_addr: Address = refund_receiver
+/// Place `target` relative to the Safe's current nonce and the queued
+/// transactions between the two
+pub fn analyze_nonce_position(
+    info: &SafeInfo,
+    queued: &[SafeTransaction],
+    target: u64,
+) -> NonceStatus {
+    if target < info.nonce {
+        return NonceStatus::Executed {
+            current: info.nonce,
+        };
+    }
+
+    let (pending, missing): (Vec<u64>, Vec<u64>) =
+        (info.nonce..target).partition(|nonce| queued.iter().any(|tx| tx.nonce == *nonce));
+    if !pending.is_empty() || !missing.is_empty() {
+        return NonceStatus::Blocked { pending, missing };
+    }
+
+    match queued.iter().filter(|tx| tx.nonce == target).count() {
+        count if count > 1 => NonceStatus::Conflict { count },
+        _ => NonceStatus::Executable,
+    }
+}
+
+/// Unexecuted transactions with nonces `from..=to` (async - works on WASM).
+/// Reads the first page only, which covers any realistic queue.
+pub async fn fetch_queued_transactions(
+    chain_name: &str,
+    safe_address: &str,
+    from: u64,
+    to: u64,
+) -> Result<Vec<SafeTransaction>> {
+    let addr: Address = safe_address
         .trim()
         .parse()
-        .wrap_err("Invalid refund receiver address")?;
+        .wrap_err("Invalid Safe address")?;
+    let api_url = safe_api_url(chain_name)?;
+    let url = format!(
+        "{}/api/v1/safes/{}/multisig-transactions/?executed=false&nonce__gte={}&nonce__lte={}&limit=100",
+        api_url, addr, from, to
+    );
 
-    // Normalize and validate data - must be valid hex
-    let data_normalized = data.strip_prefix("0x").unwrap_or(data).trim();
-    let data_with_prefix = if data_normalized.is_empty() {
-        "0x".to_string()
+    let response = api_get(&url).await?;
+    if !response.status().is_success() {
+        eyre::bail!("API error: {}", response.status());
+    }
+    let mut body: serde_json::Value = response
+        .json()
+        .await
+        .wrap_err("Failed to parse queued transactions")?;
+    let items = match body.get_mut("results").map(serde_json::Value::take) {
+        Some(serde_json::Value::Array(items)) => items,
+        _ => eyre::bail!("Failed to parse queued transactions: missing results"),
+    };
+
+    Ok(parse_transactions_with_proposals(items)?.transactions)
+}
+
+/// Fetch the Safe's nonce and queue, then place `target` in it
+pub async fn check_nonce_position(
+    chain_name: &str,
+    safe_address: &str,
+    target: u64,
+) -> Result<NonceStatus> {
+    let info = fetch_safe_info(chain_name, safe_address).await?;
+    let queued = if target >= info.nonce {
+        fetch_queued_transactions(chain_name, safe_address, info.nonce, target).await?
     } else {
-        // Validate hex characters
-        if !data_normalized.chars().all(|c| c.is_ascii_hexdigit()) {
-            eyre::bail!("Invalid data: contains non-hex characters");
-        }
-        // Validate even length (each byte is 2 hex chars)
-        if data_normalized.len() % 2 != 0 {
-            eyre::bail!("Invalid data: odd number of hex characters");
-        }
-        format!("0x{}", data_normalized)
+        vec![]
     };
+    Ok(analyze_nonce_position(&info, &queued, target))
+}
 
-    // Create TxInput for safe_hash::tx_signing_hashes
-    let tx_input = TxInput::new(
-        to_addr,
-        value_u256,
-        data_with_prefix,
+/// Validate an inclusive nonce range against `MAX_NONCE_RANGE`
+pub fn check_nonce_range(start: u64, end: u64) -> Result<()> {
+    eyre::ensure!(
+        start <= end,
+        "Range start {} is after range end {}",
+        start,
+        end
+    );
+    eyre::ensure!(
+        end - start < MAX_NONCE_RANGE,
+        "Range {}..={} is {} nonces; the limit is {}",
+        start,
+        end,
+        end - start + 1,
+        MAX_NONCE_RANGE
+    );
+    Ok(())
+}
+
+/// Fetch transactions for nonces `start..=end` (async - works on WASM). A
+/// nonce that fails is recorded in `errors` and the rest of the range is
+/// still fetched.
+pub async fn fetch_transaction_range(
+    chain_name: &str,
+    safe_address: &str,
+    start: u64,
+    end: u64,
+) -> Result<FetchedRange> {
+    check_nonce_range(start, end)?;
+    // Fail once up front rather than once per nonce
+    chain_id_of(chain_name)?;
+    safe_address
+        .trim()
+        .parse::<Address>()
+        .wrap_err("Invalid Safe address")?;
+
+    let mut range = FetchedRange::default();
+    for nonce in start..=end {
+        match fetch_transactions(chain_name, safe_address, nonce).await {
+            Ok(fetched) => range.fetched.extend(fetched),
+            Err(e) => range.errors.push((nonce, format!("{:#}", e))),
+        }
+    }
+    Ok(range)
+}
+
+/// Pages of executed history scanned for counterparties (100 transactions each)
+const HISTORY_PAGES: usize = 5;
+
+/// Fetch a Safe's executed transactions and collect the addresses it has paid
+/// or approved (async - works on WASM). Scans the most recent
+/// `HISTORY_PAGES` pages.
+pub async fn fetch_counterparty_history(
+    chain_name: &str,
+    safe_address: &str,
+) -> Result<CounterpartyHistory> {
+    let addr: Address = safe_address
+        .trim()
+        .parse()
+        .wrap_err("Invalid Safe address")?;
+
+    let api_url = safe_api_url(chain_name)?;
+    let mut url = Some(format!(
+        "{}/api/v1/safes/{}/multisig-transactions/?executed=true&limit=100",
+        api_url, addr
+    ));
+
+    let mut history = CounterpartyHistory::new(chain_name, safe_address);
+    for _ in 0..HISTORY_PAGES {
+        let Some(page_url) = url.take() else {
+            break;
+        };
+        let response = api_get(&page_url).await?;
+        if !response.status().is_success() {
+            eyre::bail!("API error: {}", response.status());
+        }
+        let body: serde_json::Value = response
+            .json()
+            .await
+            .wrap_err("Failed to parse transaction history")?;
+
+        let str_field = |item: &serde_json::Value, key: &str| {
+            item.get(key)
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string()
+        };
+        for item in body["results"].as_array().into_iter().flatten() {
+            history.add_transaction(
+                &str_field(item, "to"),
+                &str_field(item, "value"),
+                &str_field(item, "data"),
+            );
+        }
+        url = body["next"].as_str().map(str::to_string);
+    }
+
+    Ok(history)
+}
+
+/// Body of an off-chain Safe message
+#[derive(Debug, Clone, PartialEq)]
+pub enum SafeMessageBody {
+    /// Signed as an EIP-191 personal message
+    Text(String),
+    /// EIP-712 typed data
+    TypedData(serde_json::Value),
+}
+
+/// A signature collected for a Safe message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageConfirmation {
+    pub owner: String,
+    pub signature: String,
+    /// e.g. `EOA`, `ETH_SIGN`, `CONTRACT_SIGNATURE`
+    pub signature_type: String,
+}
+
+/// A Safe message as stored by the Transaction Service
+#[derive(Debug, Clone, PartialEq)]
+pub struct FetchedSafeMessage {
+    /// Safe message hash reported by the service
+    pub message_hash: String,
+    pub body: SafeMessageBody,
+    /// Concatenated owner signatures, set once the threshold is met
+    pub prepared_signature: Option<String>,
+    pub confirmations: Vec<MessageConfirmation>,
+    pub proposed_by: Option<String>,
+}
+
+fn parse_safe_message(item: &serde_json::Value) -> Result<FetchedSafeMessage> {
+    let str_field = |item: &serde_json::Value, key: &str| {
+        item.get(key).and_then(|v| v.as_str()).map(str::to_string)
+    };
+    let body = match item.get("message") {
+        Some(serde_json::Value::String(text)) => SafeMessageBody::Text(text.clone()),
+        Some(typed @ serde_json::Value::Object(_)) => SafeMessageBody::TypedData(typed.clone()),
+        _ => eyre::bail!("Safe message has no string or EIP-712 body"),
+    };
+    let confirmations = item
+        .get("confirmations")
+        .and_then(|c| c.as_array())
+        .into_iter()
+        .flatten()
+        .map(|c| MessageConfirmation {
+            owner: str_field(c, "owner").unwrap_or_default(),
+            signature: str_field(c, "signature").unwrap_or_default(),
+            signature_type: str_field(c, "signatureType").unwrap_or_default(),
+        })
+        .collect();
+
+    Ok(FetchedSafeMessage {
+        message_hash: str_field(item, "messageHash")
+            .ok_or_else(|| eyre::eyre!("Safe message has no messageHash"))?,
+        body,
+        prepared_signature: str_field(item, "preparedSignature").filter(|s| !s.is_empty()),
+        confirmations,
+        proposed_by: str_field(item, "proposedBy"),
+    })
+}
+
+/// Fetch an off-chain Safe message and its signatures (async - works on
+/// WASM). Looks the hash up in the Safe's message list, scanning at most
+/// `HISTORY_PAGES` pages.
+pub async fn fetch_safe_message(
+    chain_name: &str,
+    safe_address: &str,
+    message_hash: &str,
+) -> Result<FetchedSafeMessage> {
+    let addr: Address = safe_address
+        .trim()
+        .parse()
+        .wrap_err("Invalid Safe address")?;
+    let wanted: B256 = message_hash
+        .trim()
+        .parse()
+        .wrap_err("Invalid message hash")?;
+
+    let api_url = safe_api_url(chain_name)?;
+    let mut url = Some(format!(
+        "{}/api/v1/safes/{}/messages/?limit=100",
+        api_url, addr
+    ));
+
+    for _ in 0..HISTORY_PAGES {
+        let Some(page_url) = url.take() else {
+            break;
+        };
+        let response = api_get(&page_url).await?;
+        if !response.status().is_success() {
+            eyre::bail!("API error: {}", response.status());
+        }
+        let body: serde_json::Value = response
+            .json()
+            .await
+            .wrap_err("Failed to parse Safe messages")?;
+
+        let found = body["results"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .find(|item| {
+                item["messageHash"]
+                    .as_str()
+                    .and_then(|h| h.parse::<B256>().ok())
+                    == Some(wanted)
+            });
+        if let Some(item) = found {
+            return parse_safe_message(item);
+        }
+        url = body["next"].as_str().map(str::to_string);
+    }
+
+    eyre::bail!("No message {} found for this Safe", wanted)
+}
+
+/// Compute the Safe message hash of a message body for the given Safe. This
+/// is the hash the service reports as `messageHash` and owners sign.
+pub fn safe_message_hash(
+    chain_name: &str,
+    safe_address: &str,
+    version: &str,
+    body: &SafeMessageBody,
+) -> Result<B256> {
+    let chain_id = chain_id_of(chain_name)?;
+    let safe_version = SafeWalletVersion::parse(version)
+        .map_err(|e| eyre::eyre!("Invalid Safe version '{}': {}", version, e))?;
+    let safe_addr: Address = safe_address
+        .trim()
+        .parse()
+        .wrap_err("Invalid Safe address")?;
+
+    let message_hash = match body {
+        SafeMessageBody::Text(text) => MessageHasher::new(text.clone()).hash(),
+        SafeMessageBody::TypedData(typed) => {
+            let eip712 = Eip712Hasher::new(typed.to_string())
+                .hash()
+                .map_err(|e| eyre::eyre!("Failed to parse EIP-712 data: {}", e))?;
+            let eip712_hash: B256 = eip712
+                .eip_712_hash
+                .parse()
+                .wrap_err("Invalid EIP-712 hash")?;
+            MessageHasher::new_from_bytes(eip712_hash).hash()
+        }
+    };
+    let domain_hash = DomainHasher::new(safe_version, chain_id, safe_addr).hash();
+    Ok(SafeHasher::new(domain_hash, message_hash).hash())
+}
+
+/// The normalized SafeTx fields and domain, exactly as fed into the hashers.
+/// Serializes in EIP-712 field order so it can be fed to another tool.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HashingInputs {
+    pub chain_id: u64,
+    pub verifying_contract: Address,
+    pub version: String,
+    pub to: Address,
+    /// Decimal
+    pub value: String,
+    /// Hex with `0x` prefix; `0x` when empty
+    pub data: String,
+    pub operation: u8,
+    pub safe_tx_gas: String,
+    pub base_gas: String,
+    pub gas_price: String,
+    pub gas_token: Address,
+    pub refund_receiver: Address,
+    pub nonce: u64,
+}
+
+impl HashingInputs {
+    /// Pretty-printed JSON, in field order
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+}
+
+/// Validate and normalize the transaction fields for hashing
+pub fn hashing_inputs(
+    chain_name: &str,
+    safe_address: &str,
+    version: &str,
+    to: &str,
+    value: &str,
+    data: &str,
+    operation: u8,
+    safe_tx_gas: &str,
+    base_gas: &str,
+    gas_price: &str,
+    gas_token: &str,
+    refund_receiver: &str,
+    nonce: &str,
+) -> Result<HashingInputs> {
+    let chain_id = chain_id_of(chain_name)?;
+
+    // Checked here so bad versions fail before hashing
+    SafeWalletVersion::parse(version)
+        .map_err(|e| eyre::eyre!("Invalid Safe version '{}': {}", version, e))?;
+
+    let safe_addr: Address = safe_address
+        .trim()
+        .parse()
+        .wrap_err("Invalid Safe address")?;
+
+    let to_addr: Address = to.trim().parse().wrap_err("Invalid 'to' address")?;
+
+    eyre::ensure!(
+        operation <= 1,
+        "Invalid operation {}: must be 0 (Call) or 1 (DelegateCall)",
+        operation
+    );
+
+    let value_u256 = parse_u256(value).wrap_err("Invalid value")?;
+    let safe_tx_gas_u256 = parse_u256(safe_tx_gas).wrap_err("Invalid safeTxGas")?;
+    let base_gas_u256 = parse_u256(base_gas).wrap_err("Invalid baseGas")?;
+    let gas_price_u256 = parse_u256(gas_price).wrap_err("Invalid gasPrice")?;
+    let nonce_u64: u64 = nonce.trim().parse().wrap_err("Invalid nonce")?;
+
+    let gas_token_addr: Address = gas_token
+        .trim()
+        .parse()
+        .wrap_err("Invalid gas token address")?;
+
+    let refund_receiver_addr: Address = refund_receiver
+        .trim()
+        .parse()
+        .wrap_err("Invalid refund receiver address")?;
+
+    // Normalize and validate data - must be valid hex
+    let data_normalized = data.strip_prefix("0x").unwrap_or(data).trim();
+    let data_with_prefix = if data_normalized.is_empty() {
+        "0x".to_string()
+    } else {
+        // Validate hex characters
+        if !data_normalized.chars().all(|c| c.is_ascii_hexdigit()) {
+            eyre::bail!("Invalid data: contains non-hex characters");
+        }
+        // Validate even length (each byte is 2 hex chars)
+        if data_normalized.len() % 2 != 0 {
+            eyre::bail!("Invalid data: odd number of hex characters");
+        }
+        format!("0x{}", data_normalized)
+    };
+
+    Ok(HashingInputs {
+        chain_id,
+        verifying_contract: safe_addr,
+        version: version.to_string(),
+        to: to_addr,
+        value: value_u256.to_string(),
+        data: data_with_prefix,
         operation,
-        safe_tx_gas_u256,
-        base_gas_u256,
-        gas_price_u256,
-        gas_token_addr,
-        refund_receiver_addr,
+        safe_tx_gas: safe_tx_gas_u256.to_string(),
+        base_gas: base_gas_u256.to_string(),
+        gas_price: gas_price_u256.to_string(),
+        gas_token: gas_token_addr,
+        refund_receiver: refund_receiver_addr,
+        nonce: nonce_u64,
+    })
+}
+
+/// Hash already normalized inputs using safe_hash::tx_signing_hashes
+pub fn hash_inputs(inputs: &HashingInputs) -> Result<ComputedHashes> {
+    let safe_version = SafeWalletVersion::parse(&inputs.version)
+        .map_err(|e| eyre::eyre!("Invalid Safe version '{}': {}", inputs.version, e))?;
+    // Create TxInput for safe_hash::tx_signing_hashes
+    let tx_input = TxInput::new(
+        inputs.to,
+        parse_u256(&inputs.value)?,
+        inputs.data.clone(),
+        inputs.operation,
+        parse_u256(&inputs.safe_tx_gas)?,
+        parse_u256(&inputs.base_gas)?,
+        parse_u256(&inputs.gas_price)?,
+        inputs.gas_token,
+        inputs.refund_receiver,
         String::new(), // signatures not needed for hash computation
     );
 
     // Use safe_hash::tx_signing_hashes
-    let hashes: SafeHashes =
-        tx_signing_hashes(&tx_input, safe_addr, nonce_u64, chain_id, safe_version);
+    let hashes: SafeHashes = tx_signing_hashes(
+        &tx_input,
+        inputs.verifying_contract,
+        inputs.nonce,
+        inputs.chain_id,
+        safe_version,
+    );
 
     Ok(ComputedHashes {
         domain_hash: format!("0x{}", hex::encode(hashes.domain_hash)),
         message_hash: format!("0x{}", hex::encode(hashes.message_hash)),
         safe_tx_hash: format!("0x{}", hex::encode(hashes.safe_tx_hash)),
         matches_api: None,
+        trustless: false,
     })
 }
 
-/// Compute hashes from a SafeTransaction (fetched from API)
-/// Returns (hashes, optional_mismatch)
-pub fn compute_hashes_from_api_tx(
+/// Compute hashes for a transaction using safe_hash::tx_signing_hashes
+pub fn compute_hashes(
+    chain_name: &str,
+    safe_address: &str,
+    version: &str,
+    to: &str,
+    value: &str,
+    data: &str,
+    operation: u8,
+    safe_tx_gas: &str,
+    base_gas: &str,
+    gas_price: &str,
+    gas_token: &str,
+    refund_receiver: &str,
+    nonce: &str,
+) -> Result<ComputedHashes> {
+    hash_inputs(&hashing_inputs(
+        chain_name,
+        safe_address,
+        version,
+        to,
+        value,
+        data,
+        operation,
+        safe_tx_gas,
+        base_gas,
+        gas_price,
+        gas_token,
+        refund_receiver,
+        nonce,
+    )?)
+}
+
+/// Hashing inputs for a transaction from the Safe Transaction Service
+pub fn hashing_inputs_from_api_tx(
     chain_name: &str,
     safe_address: &str,
     version: &str,
     tx: &SafeTransaction,
-) -> Result<(ComputedHashes, Option<Mismatch>)> {
-    let hashes = compute_hashes(
+) -> Result<HashingInputs> {
+    hashing_inputs(
         chain_name,
         safe_address,
         version,
@@ -239,7 +1382,23 @@ pub fn compute_hashes_from_api_tx(
         &format!("{}", tx.gas_token),
         &format!("{}", tx.refund_receiver),
         &tx.nonce.to_string(),
-    )?;
+    )
+}
+
+/// Compute hashes from a SafeTransaction (fetched from API)
+/// Returns (hashes, optional_mismatch)
+pub fn compute_hashes_from_api_tx(
+    chain_name: &str,
+    safe_address: &str,
+    version: &str,
+    tx: &SafeTransaction,
+) -> Result<(ComputedHashes, Option<Mismatch>)> {
+    let hashes = hash_inputs(&hashing_inputs_from_api_tx(
+        chain_name,
+        safe_address,
+        version,
+        tx,
+    )?)?;
 
     // Use validate_safe_tx_hash from safe-hash
     let computed_hash_bytes = hex::decode(
@@ -262,6 +1421,103 @@ pub fn compute_hashes_from_api_tx(
     Ok((final_hashes, mismatch))
 }
 
+/// Re-verify every transaction in a Safe Transaction Service export.
+///
+/// Accepts a bare JSON array of transactions or a paginated
+/// `{"results": [...]}` response. Entries are parsed individually so one
+/// malformed transaction doesn't abort the whole audit.
+pub fn audit_transaction_export(
+    chain_name: &str,
+    safe_address: &str,
+    version: &str,
+    json: &str,
+) -> Result<Vec<AuditEntry>> {
+    let value: serde_json::Value = serde_json::from_str(json.trim()).wrap_err("Invalid JSON")?;
+
+    let items = match value {
+        serde_json::Value::Array(items) => items,
+        serde_json::Value::Object(mut obj) => match obj.remove("results") {
+            Some(serde_json::Value::Array(items)) => items,
+            _ => eyre::bail!("Expected a JSON array or an object with a \"results\" array"),
+        },
+        _ => eyre::bail!("Expected a JSON array or an object with a \"results\" array"),
+    };
+
+    let entries = items
+        .into_iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let nonce = item.get("nonce").and_then(|n| match n {
+                serde_json::Value::Number(n) => n.as_u64(),
+                serde_json::Value::String(s) => s.parse().ok(),
+                _ => None,
+            });
+            let reported_hash = item
+                .get("safeTxHash")
+                .and_then(|h| h.as_str())
+                .unwrap_or_default()
+                .to_string();
+
+            let result = serde_json::from_value::<SafeTransaction>(item)
+                .map_err(|e| format!("Failed to parse transaction: {}", e))
+                .and_then(|tx| {
+                    compute_hashes_from_api_tx(chain_name, safe_address, version, &tx)
+                        .map_err(|e| format!("{:#}", e))
+                });
+
+            AuditEntry {
+                index,
+                nonce,
+                reported_hash,
+                result,
+            }
+        })
+        .collect();
+
+    Ok(entries)
+}
+
+/// Fields the hash depends on, as named in Transaction Service JSON. `data`
+/// is left out since the service sends `null` for empty calldata.
+const REQUIRED_TX_FIELDS: [&str; 10] = [
+    "safe",
+    "to",
+    "value",
+    "operation",
+    "safeTxGas",
+    "baseGas",
+    "gasPrice",
+    "gasToken",
+    "refundReceiver",
+    "nonce",
+];
+
+/// Parse a single pasted Safe transaction, as returned by the Transaction
+/// Service, for verification without fetching. Returns the raw JSON too so
+/// it can go in reports like a fetched response.
+pub fn parse_transaction_json(
+    json: &str,
+) -> std::result::Result<(SafeTransaction, serde_json::Value), String> {
+    let value: serde_json::Value =
+        serde_json::from_str(json.trim()).map_err(|e| format!("Invalid JSON: {}", e))?;
+    let obj = value
+        .as_object()
+        .ok_or("Expected a single transaction object")?;
+
+    let missing: Vec<&str> = REQUIRED_TX_FIELDS
+        .iter()
+        .copied()
+        .filter(|field| obj.get(*field).map_or(true, |v| v.is_null()))
+        .collect();
+    if !missing.is_empty() {
+        return Err(format!("Missing field(s): {}", missing.join(", ")));
+    }
+
+    let tx = serde_json::from_value::<SafeTransaction>(value.clone())
+        .map_err(|e| format!("Invalid transaction: {}", e))?;
+    Ok((tx, value))
+}
+
 fn parse_u256(value: &str) -> Result<U256> {
     let value = value.trim();
     if value.is_empty() || value == "0" {
@@ -336,6 +1592,363 @@ pub fn get_warnings_for_tx(
     Ok(check_suspicious_content(&tx_input, None))
 }
 
+/// Most a transaction can pay out through the Safe's gas refund
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefundEstimate {
+    /// Zero for the native token
+    pub gas_token: Address,
+    /// `(safeTxGas + baseGas) * gasPrice`, in the gas token's base units
+    pub max_refund: U256,
+    /// safeTxGas is 0, so gas used isn't capped and the refund can exceed
+    /// `max_refund`
+    pub uncapped: bool,
+    pub refund_receiver: Address,
+}
+
+impl RefundEstimate {
+    /// A zero refundReceiver pays whoever executes the transaction
+    /// (tx.origin). Normal for relayed ETH refunds, suspicious for a token.
+    pub fn pays_executor_in_token(&self) -> bool {
+        self.refund_receiver.is_zero() && !self.gas_token.is_zero()
+    }
+}
+
+/// Refund the gas parameters allow. `None` if gasPrice is zero (no refund)
+/// or a field doesn't parse, which the warnings already report.
+pub fn estimate_refund(
+    safe_tx_gas: &str,
+    base_gas: &str,
+    gas_price: &str,
+    gas_token: &str,
+    refund_receiver: &str,
+) -> Option<RefundEstimate> {
+    let gas_price = parse_u256(gas_price).ok()?;
+    if gas_price.is_zero() {
+        return None;
+    }
+    let safe_tx_gas = parse_u256(safe_tx_gas).ok()?;
+    let base_gas = parse_u256(base_gas).ok()?;
+
+    Some(RefundEstimate {
+        gas_token: gas_token.trim().parse().ok()?,
+        max_refund: safe_tx_gas
+            .saturating_add(base_gas)
+            .saturating_mul(gas_price),
+        uncapped: safe_tx_gas.is_zero(),
+        refund_receiver: refund_receiver.trim().parse().ok()?,
+    })
+}
+
+/// `addOwnerWithThreshold(address,uint256)`
+const ADD_OWNER_SELECTOR: &str = "0x0d582f13";
+/// `removeOwner(address,address,uint256)`
+const REMOVE_OWNER_SELECTOR: &str = "0xf8dc5dd9";
+/// `swapOwner(address,address,address)`
+const SWAP_OWNER_SELECTOR: &str = "0xe318b52b";
+/// `changeThreshold(uint256)`
+const CHANGE_THRESHOLD_SELECTOR: &str = "0x694e80c3";
+
+/// Selectors of addOwnerWithThreshold, removeOwner, swapOwner and changeThreshold
+const DANGEROUS_SELECTORS: [&str; 4] = [
+    ADD_OWNER_SELECTOR,
+    REMOVE_OWNER_SELECTOR,
+    SWAP_OWNER_SELECTOR,
+    CHANGE_THRESHOLD_SELECTOR,
+];
+
+/// `changeMasterCopy(address)`
+const CHANGE_MASTER_COPY_SELECTOR: &str = "0x7de7edef";
+/// `setGuard(address)`
+const SET_GUARD_SELECTOR: &str = "0xe19a9dd9";
+/// `setFallbackHandler(address)`
+const SET_FALLBACK_HANDLER_SELECTOR: &str = "0xf08a0323";
+/// `enableModule(address)`
+const ENABLE_MODULE_SELECTOR: &str = "0x610b5925";
+/// `disableModule(address,address)`
+const DISABLE_MODULE_SELECTOR: &str = "0xe009cfde";
+
+/// Migration contracts that rewrite storage slot 0 (the singleton) when
+/// delegatecalled, with the new implementation baked into the contract
+const SINGLETON_MIGRATIONS: [(&str, &str); 5] = [
+    ("0x8fd3ab80", "migrate()"),
+    ("0xf6682ab0", "migrateSingleton()"),
+    ("0xed007fc6", "migrateWithFallbackHandler()"),
+    ("0x07f464a4", "migrateL2Singleton()"),
+    ("0x68cb3d94", "migrateL2WithFallbackHandler()"),
+];
+
+/// A change to a Safe's own configuration, which can replace or bypass its
+/// logic
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigChangeKind {
+    /// `changeMasterCopy` to a new singleton
+    MasterCopy(Address),
+    /// Delegatecall into a singleton migration contract
+    SingletonMigration(&'static str),
+    /// `setGuard`; the zero address removes the guard
+    Guard(Address),
+    /// `setFallbackHandler`
+    FallbackHandler(Address),
+    /// `enableModule`; a module can execute transactions without signatures
+    EnableModule(Address),
+    /// `disableModule`
+    DisableModule(Address),
+}
+
+/// A recognized configuration change
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigChange {
+    /// MultiSend index, `None` for a single call
+    pub index: Option<usize>,
+    /// Contract called (the Safe itself, or the migration contract)
+    pub target: String,
+    pub kind: ConfigChangeKind,
+}
+
+/// Address in argument word `index` of `data`
+fn address_arg(data: &str, index: usize) -> Option<Address> {
+    let word = data.get(10 + index * 64..10 + (index + 1) * 64)?;
+    Some(Address::from_slice(&hex::decode(word).ok()?[12..]))
+}
+
+fn classify_config_change(
+    index: Option<usize>,
+    target: &str,
+    data: &str,
+    operation: u8,
+) -> Option<ConfigChange> {
+    let data = data.trim().to_lowercase();
+    let selector = data.get(..10)?;
+    let kind = match selector {
+        CHANGE_MASTER_COPY_SELECTOR => ConfigChangeKind::MasterCopy(address_arg(&data, 0)?),
+        SET_GUARD_SELECTOR => ConfigChangeKind::Guard(address_arg(&data, 0)?),
+        SET_FALLBACK_HANDLER_SELECTOR => ConfigChangeKind::FallbackHandler(address_arg(&data, 0)?),
+        ENABLE_MODULE_SELECTOR => ConfigChangeKind::EnableModule(address_arg(&data, 0)?),
+        // disableModule(prevModule, module)
+        DISABLE_MODULE_SELECTOR => ConfigChangeKind::DisableModule(address_arg(&data, 1)?),
+        _ if operation == 1 => {
+            let (_, method) = SINGLETON_MIGRATIONS
+                .iter()
+                .find(|(migration, _)| *migration == selector)?;
+            ConfigChangeKind::SingletonMigration(method)
+        }
+        _ => return None,
+    };
+    Some(ConfigChange {
+        index,
+        target: target.to_string(),
+        kind,
+    })
+}
+
+/// Find configuration changes in a transaction, including MultiSend
+/// sub-transactions. Works on the raw calldata so it doesn't depend on any
+/// decode.
+pub fn detect_config_changes(to: &str, data: &str, operation: u8) -> Vec<ConfigChange> {
+    let is_multisend = data
+        .trim()
+        .get(..10)
+        .is_some_and(|s| s.eq_ignore_ascii_case(crate::decode::MULTISEND_SELECTOR));
+    if is_multisend {
+        let txs = crate::decode::decode_multisend_bytes(data.trim())
+            .and_then(|packed| crate::decode::unpack_multisend_transactions(&packed));
+        if let Ok(txs) = txs {
+            return txs
+                .iter()
+                .filter_map(|tx| {
+                    classify_config_change(Some(tx.index), &tx.to, &tx.data, tx.operation)
+                })
+                .collect();
+        }
+    }
+    classify_config_change(None, to, data, operation)
+        .into_iter()
+        .collect()
+}
+
+/// An owner or threshold change a Safe makes on itself
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OwnerCall {
+    AddOwner {
+        owner: Address,
+        threshold: u64,
+    },
+    RemoveOwner {
+        owner: Address,
+        threshold: u64,
+    },
+    SwapOwner {
+        old_owner: Address,
+        new_owner: Address,
+    },
+    ChangeThreshold(u64),
+}
+
+impl OwnerCall {
+    /// Parse a call from its selector and ABI-encoded arguments
+    fn parse(selector: &str, params: &str) -> Option<Self> {
+        let params = params.trim().strip_prefix("0x").unwrap_or(params.trim());
+        let word = |i: usize| hex::decode(params.get(i * 64..(i + 1) * 64)?).ok();
+        let address = |i: usize| Some(Address::from_slice(&word(i)?[12..]));
+        let number = |i: usize| u64::try_from(U256::from_be_slice(&word(i)?)).ok();
+
+        match selector.to_lowercase().as_str() {
+            ADD_OWNER_SELECTOR => Some(Self::AddOwner {
+                owner: address(0)?,
+                threshold: number(1)?,
+            }),
+            // removeOwner(prevOwner, owner, threshold)
+            REMOVE_OWNER_SELECTOR => Some(Self::RemoveOwner {
+                owner: address(1)?,
+                threshold: number(2)?,
+            }),
+            // swapOwner(prevOwner, oldOwner, newOwner)
+            SWAP_OWNER_SELECTOR => Some(Self::SwapOwner {
+                old_owner: address(1)?,
+                new_owner: address(2)?,
+            }),
+            CHANGE_THRESHOLD_SELECTOR => Some(Self::ChangeThreshold(number(0)?)),
+            _ => None,
+        }
+    }
+
+    pub fn summary(&self) -> String {
+        match self {
+            Self::AddOwner { owner, threshold } => {
+                format!("Adds owner {} and sets threshold to {}", owner, threshold)
+            }
+            Self::RemoveOwner { owner, threshold } => {
+                format!(
+                    "Removes owner {} and sets threshold to {}",
+                    owner, threshold
+                )
+            }
+            Self::SwapOwner {
+                old_owner,
+                new_owner,
+            } => format!("Replaces owner {} with {}", old_owner, new_owner),
+            Self::ChangeThreshold(threshold) => format!("Sets threshold to {}", threshold),
+        }
+    }
+
+    /// Threshold after the call, if it sets one
+    pub fn threshold(&self) -> Option<u64> {
+        match self {
+            Self::AddOwner { threshold, .. }
+            | Self::RemoveOwner { threshold, .. }
+            | Self::ChangeThreshold(threshold) => Some(*threshold),
+            Self::SwapOwner { .. } => None,
+        }
+    }
+
+    /// Net change in the number of owners
+    pub fn owner_delta(&self) -> i64 {
+        match self {
+            Self::AddOwner { .. } => 1,
+            Self::RemoveOwner { .. } => -1,
+            Self::SwapOwner { .. } | Self::ChangeThreshold(_) => 0,
+        }
+    }
+}
+
+/// A recognized owner-management call on the Safe itself
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnerManagement {
+    /// MultiSend index, `None` for a single call
+    pub index: Option<usize>,
+    pub call: OwnerCall,
+}
+
+/// Human summary of an owner-management call, e.g. "Adds owner 0x… and
+/// sets threshold to 3". `params` is the ABI-encoded arguments after the
+/// selector.
+pub fn describe_owner_management(selector: &str, params: &str) -> Option<String> {
+    OwnerCall::parse(selector, params).map(|call| call.summary())
+}
+
+/// Find owner-management calls the Safe makes on itself, including MultiSend
+/// sub-transactions. Calls to other contracts with the same selectors are
+/// left to the generic dangerous-method warning.
+pub fn detect_owner_management(safe_address: &str, to: &str, data: &str) -> Vec<OwnerManagement> {
+    let Ok(safe) = safe_address.trim().parse::<Address>() else {
+        return vec![];
+    };
+    let classify = |index: Option<usize>, to: &str, data: &str| {
+        if to.trim().parse::<Address>().ok()? != safe {
+            return None;
+        }
+        let data = data.trim();
+        let call = OwnerCall::parse(data.get(..10)?, data.get(10..)?)?;
+        Some(OwnerManagement { index, call })
+    };
+
+    let is_multisend = data
+        .trim()
+        .get(..10)
+        .is_some_and(|s| s.eq_ignore_ascii_case(crate::decode::MULTISEND_SELECTOR));
+    if is_multisend {
+        let txs = crate::decode::decode_multisend_bytes(data.trim())
+            .and_then(|packed| crate::decode::unpack_multisend_transactions(&packed));
+        if let Ok(txs) = txs {
+            return txs
+                .iter()
+                .filter_map(|tx| classify(Some(tx.index), &tx.to, &tx.data))
+                .collect();
+        }
+    }
+    classify(None, to, data).into_iter().collect()
+}
+
+/// Whether a transaction calls the Safe itself, directly or from a MultiSend
+/// sub-transaction. Such calls can change anything the Safe's own methods
+/// can: owners, threshold, modules, guard and fallback handler.
+pub fn calls_self(safe_address: &str, to: &str, data: &str) -> bool {
+    let Ok(safe) = safe_address.trim().parse::<Address>() else {
+        return false;
+    };
+    let is_safe = |to: &str| to.trim().parse::<Address>().is_ok_and(|to| to == safe);
+    if is_safe(to) {
+        return true;
+    }
+
+    let is_multisend = data
+        .trim()
+        .get(..10)
+        .is_some_and(|s| s.eq_ignore_ascii_case(crate::decode::MULTISEND_SELECTOR));
+    is_multisend
+        && crate::decode::decode_multisend_bytes(data.trim())
+            .and_then(|packed| crate::decode::unpack_multisend_transactions(&packed))
+            .is_ok_and(|txs| txs.iter().any(|tx| is_safe(&tx.to)))
+}
+
+/// Apply the calls in order to the current owner count and threshold, and
+/// report the first that leaves the threshold at zero or above the number of
+/// owners. The Safe rejects such a call, so the whole transaction would fail.
+pub fn check_owner_threshold(
+    changes: &[OwnerManagement],
+    owner_count: usize,
+    threshold: u64,
+) -> Option<String> {
+    let mut owners = owner_count as i64;
+    let mut threshold = threshold;
+    for change in changes {
+        owners += change.call.owner_delta();
+        if let Some(new_threshold) = change.call.threshold() {
+            threshold = new_threshold;
+        }
+        if threshold == 0 || threshold as i64 > owners {
+            return Some(format!(
+                "{} leaves threshold {} with {} owner(s). The Safe rejects this, so the \
+                 transaction will fail.",
+                change.call.summary(),
+                threshold,
+                owners.max(0)
+            ));
+        }
+    }
+    None
+}
+
 /// Generate warnings from a SafeTransaction (from API)
 /// Returns Err if API returned invalid values (indicates API data corruption)
 pub fn get_warnings_from_api_tx(
@@ -375,5 +1988,1180 @@ pub fn get_warnings_from_api_tx(
         }
     }
 
+    // And from the raw selector, so the check doesn't depend on the API decode
+    if let Some(selector) = tx.data.get(..10) {
+        if DANGEROUS_SELECTORS.contains(&selector.to_lowercase().as_str()) {
+            warnings.dangerous_methods = true;
+        }
+    }
+
     Ok(warnings)
 }
+
+/// EIP-712 domain type used by Safe >= 1.3.0
+const DOMAIN_TYPE_WITH_CHAIN_ID: &str = "EIP712Domain(uint256 chainId,address verifyingContract)";
+/// EIP-712 domain type used by Safe < 1.3.0 (no chain id)
+const DOMAIN_TYPE_LEGACY: &str = "EIP712Domain(address verifyingContract)";
+
+/// The inputs to a Safe domain separator, for comparing against another
+/// implementation. Safe domains have no `name` or `version` fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DomainComponents {
+    pub type_string: &'static str,
+    pub type_hash: B256,
+    /// `None` for versions whose domain doesn't include the chain id
+    pub chain_id: Option<u64>,
+    pub verifying_contract: Address,
+    /// `abi.encode(typeHash, [chainId,] verifyingContract)`
+    pub encoded: Vec<u8>,
+    /// keccak256 of `encoded`
+    pub domain_separator: B256,
+    /// Whether safe_utils' `DomainHasher` produces the same separator
+    pub matches_library: bool,
+}
+
+/// Look for the setting that turns `tx` into `expected`: another Safe
+/// version on the same chain, or another chain at the same version. Each
+/// hint names what matched; empty when nothing does.
+pub fn diagnose_hash_mismatch(
+    expected: &str,
+    chain_name: &str,
+    safe_address: &str,
+    version: &str,
+    tx: &SafeTransaction,
+) -> Vec<String> {
+    let expected = expected.trim().to_lowercase();
+    let matches = |chain: &str, version: &str| {
+        compute_hashes_from_api_tx(chain, safe_address, version, tx)
+            .is_ok_and(|(hashes, _)| hashes.safe_tx_hash.to_lowercase() == expected)
+    };
+
+    let mut hints = Vec::new();
+    let versions: Vec<&str> = crate::state::SAFE_VERSIONS
+        .iter()
+        .copied()
+        .filter(|v| *v != version && matches(chain_name, v))
+        .collect();
+    if !versions.is_empty() {
+        hints.push(format!(
+            "Matches Safe version {} - check the version in the sidebar",
+            versions.join(" / ")
+        ));
+    }
+    let chains: Vec<String> = safe_utils::get_all_supported_chain_names()
+        .into_iter()
+        .filter(|c| c != chain_name && matches(c, version))
+        .collect();
+    if !chains.is_empty() {
+        hints.push(format!(
+            "Matches chain {} - the hash was computed for a different chain ID",
+            chains.join(" / ")
+        ));
+    }
+    hints
+}
+
+/// Re-derive the domain separator from its raw components
+pub fn domain_components(
+    chain_name: &str,
+    safe_address: &str,
+    version: &str,
+) -> Result<DomainComponents> {
+    let chain_id = chain_id_of(chain_name)?;
+    let safe_version = SafeWalletVersion::parse(version)
+        .map_err(|e| eyre::eyre!("Invalid Safe version '{}': {}", version, e))?;
+    let safe_addr: Address = safe_address
+        .trim()
+        .parse()
+        .wrap_err("Invalid Safe address")?;
+
+    let mut parts = version
+        .trim()
+        .split('.')
+        .map(|p| p.parse::<u32>().unwrap_or(0));
+    let (major, minor) = (parts.next().unwrap_or(0), parts.next().unwrap_or(0));
+    let includes_chain_id = (major, minor) >= (1, 3);
+
+    let type_string = if includes_chain_id {
+        DOMAIN_TYPE_WITH_CHAIN_ID
+    } else {
+        DOMAIN_TYPE_LEGACY
+    };
+    let type_hash = keccak256(type_string.as_bytes());
+
+    let mut encoded = type_hash.to_vec();
+    if includes_chain_id {
+        encoded.extend_from_slice(&U256::from(chain_id).to_be_bytes::<32>());
+    }
+    encoded.extend_from_slice(safe_addr.into_word().as_slice());
+    let domain_separator = keccak256(&encoded);
+
+    Ok(DomainComponents {
+        type_string,
+        type_hash,
+        chain_id: includes_chain_id.then_some(chain_id),
+        verifying_contract: safe_addr,
+        encoded,
+        domain_separator,
+        matches_library: matches_library(domain_separator, safe_version, chain_id, safe_addr),
+    })
+}
+
+/// Whether safe_utils' `DomainHasher` produces `separator` for these inputs
+fn matches_library(
+    separator: B256,
+    version: SafeWalletVersion,
+    chain_id: u64,
+    safe_address: Address,
+) -> bool {
+    DomainHasher::new(version, chain_id, safe_address).hash() == separator
+}
+
+/// A field of a typed-data domain that disagrees with the selected Safe
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DomainMismatch {
+    /// The signature would be valid on another chain
+    ChainId { found: u64, expected: u64 },
+    /// No `chainId`, so the signature can be replayed on any chain
+    MissingChainId,
+    /// Addressed to a different contract. Expected for dapp messages (the
+    /// Safe signs on behalf of itself for another verifier), suspicious for
+    /// messages meant for the Safe.
+    VerifyingContract { found: String, expected: Address },
+}
+
+/// `chainId` may be a JSON number, a decimal string or a hex string
+fn parse_domain_chain_id(value: &serde_json::Value) -> Result<u64> {
+    match value {
+        serde_json::Value::Number(n) => n
+            .as_u64()
+            .ok_or_else(|| eyre::eyre!("chainId {} is not a u64", n)),
+        serde_json::Value::String(s) => {
+            let s = s.trim();
+            match s.strip_prefix("0x") {
+                Some(hex) => u64::from_str_radix(hex, 16),
+                None => s.parse(),
+            }
+            .wrap_err_with(|| format!("Invalid chainId '{}'", s))
+        }
+        other => eyre::bail!("Invalid chainId {}", other),
+    }
+}
+
+/// Compare the `domain` of EIP-712 JSON against the selected chain and Safe
+pub fn validate_eip712_domain(
+    json: &str,
+    chain_id: u64,
+    safe_address: Address,
+) -> Result<Vec<DomainMismatch>> {
+    let typed_data: serde_json::Value =
+        serde_json::from_str(json).wrap_err("Invalid EIP-712 JSON")?;
+    let domain = typed_data
+        .get("domain")
+        .ok_or_else(|| eyre::eyre!("EIP-712 JSON has no domain"))?;
+
+    let mut mismatches = Vec::new();
+    match domain.get("chainId") {
+        Some(value) => {
+            let found = parse_domain_chain_id(value)?;
+            if found != chain_id {
+                mismatches.push(DomainMismatch::ChainId {
+                    found,
+                    expected: chain_id,
+                });
+            }
+        }
+        None => mismatches.push(DomainMismatch::MissingChainId),
+    }
+
+    if let Some(contract) = domain.get("verifyingContract").and_then(|v| v.as_str()) {
+        if contract.trim().parse::<Address>().ok() != Some(safe_address) {
+            mismatches.push(DomainMismatch::VerifyingContract {
+                found: contract.to_string(),
+                expected: safe_address,
+            });
+        }
+    }
+    Ok(mismatches)
+}
+
+/// A problem with the `types` of EIP-712 JSON, found before hashing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypeIssue {
+    /// `primaryType` isn't defined in `types`
+    UndefinedPrimaryType(String),
+    /// A field's type is neither a base type nor defined in `types`
+    UndefinedType { name: String, field: String },
+    /// Defined but unreachable from `primaryType` or the domain. Doesn't
+    /// change the hash, but often means a field's type is misspelled.
+    Unused(String),
+    /// Types referencing each other in a loop, the first repeated last.
+    /// Only instantiable when the loop passes through an array.
+    Circular {
+        cycle: Vec<String>,
+        through_array: bool,
+    },
+}
+
+impl TypeIssue {
+    /// Whether the typed data can't be hashed until this is fixed
+    pub fn is_error(&self) -> bool {
+        match self {
+            TypeIssue::Unused(_) => false,
+            TypeIssue::Circular { through_array, .. } => !through_array,
+            _ => true,
+        }
+    }
+
+    pub fn message(&self) -> String {
+        match self {
+            TypeIssue::UndefinedPrimaryType(name) => {
+                format!("primaryType '{}' is not defined in types", name)
+            }
+            TypeIssue::UndefinedType { name, field } => {
+                format!("Type '{}' used by {} is not defined in types", name, field)
+            }
+            TypeIssue::Unused(name) => format!("Type '{}' is defined but never used", name),
+            TypeIssue::Circular {
+                cycle,
+                through_array,
+            } => {
+                let path = cycle.join(" → ");
+                if *through_array {
+                    format!("Recursive type: {}", path)
+                } else {
+                    format!("Circular type reference: {} (no finite value)", path)
+                }
+            }
+        }
+    }
+}
+
+/// Struct name with array suffixes removed: `Person[][2]` → `Person`
+fn base_type(ty: &str) -> &str {
+    ty.split('[').next().unwrap_or(ty).trim()
+}
+
+/// EIP-712 atomic and dynamic types, which need no definition
+fn is_builtin_type(ty: &str) -> bool {
+    match ty {
+        "bool" | "address" | "string" | "bytes" => true,
+        _ => {
+            if let Some(size) = ty.strip_prefix("bytes") {
+                size.parse::<usize>().is_ok_and(|n| (1..=32).contains(&n))
+            } else if let Some(bits) = ty.strip_prefix("uint").or_else(|| ty.strip_prefix("int")) {
+                bits.parse::<usize>()
+                    .is_ok_and(|n| n % 8 == 0 && (8..=256).contains(&n))
+            } else {
+                false
+            }
+        }
+    }
+}
+
+/// Check that every type referenced from EIP-712 JSON is defined, and flag
+/// unused and circular definitions. Malformed `types` are an `Err`.
+pub fn validate_eip712_types(json: &str) -> Result<Vec<TypeIssue>> {
+    let typed_data: serde_json::Value =
+        serde_json::from_str(json).wrap_err("Invalid EIP-712 JSON")?;
+    let types = typed_data
+        .get("types")
+        .and_then(|t| t.as_object())
+        .ok_or_else(|| eyre::eyre!("EIP-712 JSON has no types object"))?;
+    let primary = typed_data
+        .get("primaryType")
+        .and_then(|p| p.as_str())
+        .ok_or_else(|| eyre::eyre!("EIP-712 JSON has no primaryType"))?;
+
+    // Struct name -> (field name, field type)
+    let mut structs: BTreeMap<&str, Vec<(&str, &str)>> = BTreeMap::new();
+    for (name, fields) in types {
+        let fields = fields
+            .as_array()
+            .ok_or_else(|| eyre::eyre!("types.{} must be an array of fields", name))?;
+        let mut parsed = Vec::with_capacity(fields.len());
+        for (i, field) in fields.iter().enumerate() {
+            let get = |key: &str| field.get(key).and_then(|v| v.as_str());
+            match (get("name"), get("type")) {
+                (Some(field_name), Some(ty)) => parsed.push((field_name, ty)),
+                _ => eyre::bail!("types.{}[{}] needs a string name and type", name, i),
+            }
+        }
+        structs.insert(name.as_str(), parsed);
+    }
+
+    let mut issues = Vec::new();
+    if !structs.contains_key(primary) {
+        issues.push(TypeIssue::UndefinedPrimaryType(primary.to_string()));
+    }
+    for (name, fields) in &structs {
+        for (field, ty) in fields {
+            let base = base_type(ty);
+            if !is_builtin_type(base) && !structs.contains_key(base) {
+                issues.push(TypeIssue::UndefinedType {
+                    name: base.to_string(),
+                    field: format!("{}.{}", name, field),
+                });
+            }
+        }
+    }
+
+    let mut reachable = BTreeSet::new();
+    let mut pending = vec![primary, "EIP712Domain"];
+    while let Some(name) = pending.pop() {
+        if let Some(fields) = structs.get(name) {
+            if reachable.insert(name) {
+                pending.extend(fields.iter().map(|(_, ty)| base_type(ty)));
+            }
+        }
+    }
+    issues.extend(
+        structs
+            .keys()
+            .filter(|name| !reachable.contains(*name))
+            .map(|name| TypeIssue::Unused(name.to_string())),
+    );
+
+    let mut done = BTreeSet::new();
+    for name in structs.keys() {
+        if !done.contains(name) {
+            find_type_cycles(name, &structs, &mut Vec::new(), &mut done, &mut issues);
+        }
+    }
+    Ok(issues)
+}
+
+/// Depth-first search recording a `Circular` issue for each reference back
+/// into `path`. Each path entry carries whether its edge to the next entry is
+/// an array.
+fn find_type_cycles<'a>(
+    name: &'a str,
+    structs: &BTreeMap<&'a str, Vec<(&'a str, &'a str)>>,
+    path: &mut Vec<(&'a str, bool)>,
+    done: &mut BTreeSet<&'a str>,
+    issues: &mut Vec<TypeIssue>,
+) {
+    path.push((name, false));
+    for (_, ty) in &structs[name] {
+        let next = base_type(ty);
+        if !structs.contains_key(next) || done.contains(next) {
+            continue;
+        }
+        if let Some(last) = path.last_mut() {
+            last.1 = ty.contains('[');
+        }
+        match path.iter().position(|(n, _)| *n == next) {
+            Some(start) => {
+                let looped = &path[start..];
+                let mut cycle: Vec<String> = looped.iter().map(|(n, _)| n.to_string()).collect();
+                cycle.push(next.to_string());
+                issues.push(TypeIssue::Circular {
+                    cycle,
+                    through_array: looped.iter().any(|(_, array)| *array),
+                });
+            }
+            None => find_type_cycles(next, structs, path, done, issues),
+        }
+    }
+    path.pop();
+    done.insert(name);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compute_hashes_rejects_invalid_operation() {
+        let zero = "0x0000000000000000000000000000000000000000";
+        let safe = "0x1111111111111111111111111111111111111111";
+        let err = compute_hashes(
+            "ethereum", safe, "1.4.1", safe, "0", "0x", 2, "0", "0", "0", zero, zero, "0",
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("Invalid operation 2"));
+    }
+
+    #[test]
+    fn test_hashing_inputs_normalized() {
+        let zero = "0x0000000000000000000000000000000000000000";
+        let safe = "0x1111111111111111111111111111111111111111";
+        let inputs = hashing_inputs(
+            "ethereum", safe, "1.4.1", safe, " 0x10 ", "", 0, "", "0", "0", zero, zero, "7",
+        )
+        .unwrap();
+        assert_eq!(inputs.chain_id, 1);
+        assert_eq!(inputs.value, "16");
+        assert_eq!(inputs.data, "0x");
+        assert_eq!(inputs.safe_tx_gas, "0");
+        assert_eq!(inputs.nonce, 7);
+
+        let json = inputs.to_json();
+        let keys = [
+            "\"chainId\"",
+            "\"verifyingContract\"",
+            "\"to\"",
+            "\"nonce\"",
+        ];
+        let order: Vec<usize> = keys.iter().map(|key| json.find(key).unwrap()).collect();
+        assert!(order.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_diagnose_hash_mismatch() {
+        let zero = "0x0000000000000000000000000000000000000000";
+        let safe = "0x1111111111111111111111111111111111111111";
+        let tx: SafeTransaction = serde_json::from_value(serde_json::json!({
+            "safe": safe, "to": zero, "value": "0", "data": "0x", "dataDecoded": null,
+            "operation": 0, "gasToken": zero, "safeTxGas": 0, "baseGas": 0,
+            "gasPrice": "0", "refundReceiver": zero, "nonce": 3,
+            "safeTxHash": "0x00", "submissionDate": "", "executionDate": null,
+            "transactionHash": null, "isExecuted": false, "isSuccessful": null,
+            "origin": "", "confirmationsRequired": 1, "confirmations": [],
+        }))
+        .unwrap();
+        let (on_polygon, _) = compute_hashes_from_api_tx("polygon", safe, "1.4.1", &tx).unwrap();
+
+        let hints =
+            diagnose_hash_mismatch(&on_polygon.safe_tx_hash, "ethereum", safe, "1.4.1", &tx);
+        assert!(hints.iter().any(|h| h.contains("polygon")), "{:?}", hints);
+        assert!(diagnose_hash_mismatch(
+            &format!("0x{}", "ab".repeat(32)),
+            "ethereum",
+            safe,
+            "1.4.1",
+            &tx
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn test_audit_transaction_export() {
+        let zero = "0x0000000000000000000000000000000000000000";
+        let safe = "0x1111111111111111111111111111111111111111";
+        let tx = |nonce: serde_json::Value, hash: &str| {
+            serde_json::json!({
+                "safe": safe, "to": zero, "value": "0", "data": "0x", "dataDecoded": null,
+                "operation": 0, "gasToken": zero, "safeTxGas": 0, "baseGas": 0,
+                "gasPrice": "0", "refundReceiver": zero, "nonce": nonce,
+                "safeTxHash": hash, "submissionDate": "", "executionDate": null,
+                "transactionHash": null, "isExecuted": false, "isSuccessful": null,
+                "origin": "", "confirmationsRequired": 1, "confirmations": [],
+            })
+        };
+        let parsed: SafeTransaction = serde_json::from_value(tx(3.into(), "0x00")).unwrap();
+        let (hashes, _) = compute_hashes_from_api_tx("ethereum", safe, "1.4.1", &parsed).unwrap();
+
+        let export = serde_json::json!({
+            "results": [
+                tx(3.into(), &hashes.safe_tx_hash),
+                tx(4.into(), &hashes.safe_tx_hash),
+                { "nonce": 5 },
+            ]
+        });
+        let entries =
+            audit_transaction_export("ethereum", safe, "1.4.1", &export.to_string()).unwrap();
+        assert_eq!(entries.len(), 3);
+
+        assert_eq!(entries[0].index, 0);
+        assert_eq!(entries[0].nonce, Some(3));
+        assert_eq!(entries[0].reported_hash, hashes.safe_tx_hash);
+        assert!(entries[0].passed());
+
+        assert_eq!(entries[1].nonce, Some(4));
+        assert!(matches!(&entries[1].result, Ok((_, Some(_)))));
+        assert!(!entries[1].passed());
+
+        assert_eq!(entries[2].nonce, Some(5));
+        assert!(entries[2].result.is_err());
+        assert!(!entries[2].passed());
+
+        let bare = serde_json::json!([tx(3.into(), &hashes.safe_tx_hash)]);
+        let entries =
+            audit_transaction_export("ethereum", safe, "1.4.1", &bare.to_string()).unwrap();
+        assert!(entries[0].passed());
+        assert!(audit_transaction_export("ethereum", safe, "1.4.1", "42").is_err());
+    }
+
+    #[test]
+    fn test_chain_id_of() {
+        assert_eq!(chain_id_of("ethereum").unwrap(), 1);
+        assert!(!is_custom_chain("ethereum"));
+        assert_eq!(chain_id_of("987654").unwrap(), 987654);
+        assert!(is_custom_chain("987654"));
+        assert!(chain_id_of("0").is_err());
+        assert!(chain_id_of("not-a-chain").is_err());
+        assert!(default_safe_api_url("987654").is_err());
+
+        let (name, url) = validate_custom_chain(" 987654 ", "https://tx.example.org/").unwrap();
+        assert_eq!(name, "987654");
+        assert_eq!(url, "https://tx.example.org");
+        assert!(validate_custom_chain("1", "https://tx.example.org").is_err());
+        assert!(validate_custom_chain("abc", "https://tx.example.org").is_err());
+        assert!(validate_custom_chain("987654", "tx.example.org").is_err());
+    }
+
+    #[test]
+    fn test_validate_service_url() {
+        assert_eq!(
+            validate_service_url(" https://tx.example.org/ ").unwrap(),
+            "https://tx.example.org"
+        );
+        assert_eq!(
+            validate_service_url("http://localhost:8000/txs").unwrap(),
+            "http://localhost:8000/txs"
+        );
+        assert!(validate_service_url("tx.example.org").is_err());
+        assert!(validate_service_url("ftp://tx.example.org").is_err());
+        assert!(validate_service_url("https://tx.example.org/?a=1").is_err());
+    }
+
+    #[test]
+    fn test_parse_safe_share_url() {
+        let safe = "0x1111111111111111111111111111111111111111";
+        let hash = format!("0x{}", "ab".repeat(32));
+        let link = parse_safe_share_url(&format!(
+            "https://app.safe.global/transactions/tx?safe=eth:{}&id=multisig_{}_{}",
+            safe, safe, hash
+        ))
+        .unwrap();
+        assert_eq!(link.chain_name, "ethereum");
+        assert_eq!(link.safe_address, safe);
+        assert_eq!(link.safe_tx_hash, Some(hash));
+
+        let queue = parse_safe_share_url(&format!(
+            "https://app.safe.global/transactions/queue?safe=arb1:{}",
+            safe
+        ))
+        .unwrap();
+        assert_eq!(queue.chain_name, "arbitrum");
+        assert_eq!(queue.safe_tx_hash, None);
+
+        let from = |url: &str| parse_safe_share_url(&url.replace("SAFE", safe));
+        assert!(from("https://evil.example/transactions/queue?safe=eth:SAFE").is_err());
+        assert!(from("https://app.safe.global/transactions/queue?safe=xyz:SAFE").is_err());
+        assert!(from("https://app.safe.global/transactions/queue?safe=SAFE").is_err());
+        assert!(from("https://app.safe.global/transactions/tx?safe=eth:SAFE").is_err());
+        assert!(from("https://app.safe.global/transactions/tx?safe=eth:SAFE&id=0x12").is_err());
+    }
+
+    #[test]
+    fn test_retry_delay() {
+        assert_eq!(retry_delay(0, None), Duration::from_millis(500));
+        assert_eq!(retry_delay(2, None), Duration::from_secs(2));
+        assert_eq!(retry_delay(1, Some("7")), Duration::from_secs(7));
+        // HTTP-date form isn't parsed; fall back to backoff
+        assert_eq!(
+            retry_delay(1, Some("Wed, 21 Oct 2015 07:28:00 GMT")),
+            Duration::from_secs(1)
+        );
+        assert_eq!(retry_delay(1, Some("3600")), RETRY_MAX_DELAY);
+        assert_eq!(retry_delay(40, None), RETRY_MAX_DELAY);
+        assert!(is_retryable(reqwest::StatusCode::TOO_MANY_REQUESTS));
+        assert!(is_retryable(reqwest::StatusCode::BAD_GATEWAY));
+        assert!(!is_retryable(reqwest::StatusCode::NOT_FOUND));
+    }
+
+    #[test]
+    fn test_analyze_nonce_position() {
+        let zero = "0x0000000000000000000000000000000000000000";
+        let queued_tx = |nonce: u64| -> SafeTransaction {
+            serde_json::from_value(serde_json::json!({
+                "safe": zero, "to": zero, "value": "0", "data": "0x", "dataDecoded": null,
+                "operation": 0, "gasToken": zero, "safeTxGas": 0, "baseGas": 0,
+                "gasPrice": "0", "refundReceiver": zero, "nonce": nonce,
+                "safeTxHash": "0x00", "submissionDate": "", "executionDate": null,
+                "transactionHash": null, "isExecuted": false, "isSuccessful": null,
+                "origin": "", "confirmationsRequired": 1, "confirmations": [],
+            }))
+            .unwrap()
+        };
+        let info = SafeInfo {
+            address: Address::ZERO,
+            nonce: 5,
+            threshold: 1,
+            owners: vec![],
+            modules: vec![],
+            version: "1.4.1".to_string(),
+            pending_nonce_count: None,
+            pending_transaction: None,
+            pending_proposal: None,
+            pending_raw: None,
+        };
+        let queued: Vec<_> = [5, 5, 7, 8].into_iter().map(queued_tx).collect();
+
+        assert_eq!(
+            analyze_nonce_position(&info, &queued, 3),
+            NonceStatus::Executed { current: 5 }
+        );
+        assert_eq!(
+            analyze_nonce_position(&info, &queued, 5),
+            NonceStatus::Conflict { count: 2 }
+        );
+        assert_eq!(
+            analyze_nonce_position(&info, &queued, 8),
+            NonceStatus::Blocked {
+                pending: vec![5, 7],
+                missing: vec![6]
+            }
+        );
+        assert_eq!(
+            analyze_nonce_position(&info, &queued[2..3], 5),
+            NonceStatus::Executable
+        );
+    }
+
+    #[test]
+    fn test_estimate_refund() {
+        let zero = "0x0000000000000000000000000000000000000000";
+        let token = "0x6B175474E89094C44Da98b954EedeAC495271d0F";
+
+        assert_eq!(estimate_refund("100", "50", "0", token, zero), None);
+
+        let refund = estimate_refund("100", "50", "1000", token, zero).unwrap();
+        assert_eq!(refund.max_refund, U256::from(150_000));
+        assert!(!refund.uncapped);
+        assert!(refund.pays_executor_in_token());
+
+        // ETH refund to the executor is the usual relayer setup
+        let refund = estimate_refund("0", "21000", "0x3b9aca00", zero, zero).unwrap();
+        assert_eq!(refund.max_refund, U256::from(21_000_000_000_000u64));
+        assert!(refund.uncapped);
+        assert!(!refund.pays_executor_in_token());
+
+        assert_eq!(estimate_refund("x", "0", "1", zero, zero), None);
+    }
+
+    #[test]
+    fn test_validate_eip712_domain() {
+        let safe: Address = "0x1111111111111111111111111111111111111111"
+            .parse()
+            .unwrap();
+        let typed_data = |domain: &str| format!(r#"{{"domain": {}, "message": {{}}}}"#, domain);
+
+        let matching = typed_data(
+            r#"{"chainId": "0x89", "verifyingContract": "0x1111111111111111111111111111111111111111"}"#,
+        );
+        assert!(validate_eip712_domain(&matching, 137, safe)
+            .unwrap()
+            .is_empty());
+
+        let wrong_chain = typed_data(r#"{"chainId": 1}"#);
+        assert_eq!(
+            validate_eip712_domain(&wrong_chain, 137, safe).unwrap(),
+            vec![DomainMismatch::ChainId {
+                found: 1,
+                expected: 137
+            }]
+        );
+
+        let other_contract = typed_data(
+            r#"{"chainId": "137", "verifyingContract": "0x2222222222222222222222222222222222222222"}"#,
+        );
+        assert!(matches!(
+            validate_eip712_domain(&other_contract, 137, safe).unwrap()[..],
+            [DomainMismatch::VerifyingContract { .. }]
+        ));
+
+        let no_chain = typed_data(r#"{"name": "Test"}"#);
+        assert_eq!(
+            validate_eip712_domain(&no_chain, 137, safe).unwrap(),
+            vec![DomainMismatch::MissingChainId]
+        );
+
+        assert!(validate_eip712_domain(&typed_data(r#"{"chainId": true}"#), 1, safe).is_err());
+        assert!(validate_eip712_domain("{}", 1, safe).is_err());
+    }
+
+    #[test]
+    fn test_validate_eip712_types() {
+        let typed_data = |types: &str, primary: &str| {
+            format!(
+                r#"{{"types": {}, "primaryType": "{}", "domain": {{}}, "message": {{}}}}"#,
+                types, primary
+            )
+        };
+
+        let nested = typed_data(
+            r#"{
+                "EIP712Domain": [{"name": "name", "type": "string"}],
+                "Order": [{"name": "maker", "type": "Person"}, {"name": "items", "type": "Item[][2]"}],
+                "Person": [{"name": "wallet", "type": "address"}],
+                "Item": [{"name": "id", "type": "uint256"}, {"name": "tag", "type": "bytes32"}]
+            }"#,
+            "Order",
+        );
+        assert!(validate_eip712_types(&nested).unwrap().is_empty());
+
+        let missing = typed_data(
+            r#"{"Mail": [{"name": "from", "type": "Persn"}, {"name": "n", "type": "uint7"}],
+                "Person": [{"name": "wallet", "type": "address"}]}"#,
+            "Mail",
+        );
+        let issues = validate_eip712_types(&missing).unwrap();
+        assert_eq!(
+            issues,
+            vec![
+                TypeIssue::UndefinedType {
+                    name: "Persn".into(),
+                    field: "Mail.from".into()
+                },
+                TypeIssue::UndefinedType {
+                    name: "uint7".into(),
+                    field: "Mail.n".into()
+                },
+                TypeIssue::Unused("Person".into()),
+            ]
+        );
+        assert!(!issues[2].is_error());
+
+        let issues = validate_eip712_types(&typed_data(r#"{"Mail": []}"#, "Letter")).unwrap();
+        assert_eq!(issues[0], TypeIssue::UndefinedPrimaryType("Letter".into()));
+
+        let circular = typed_data(
+            r#"{"A": [{"name": "b", "type": "B"}], "B": [{"name": "a", "type": "A"}],
+                "Node": [{"name": "children", "type": "Node[]"}]}"#,
+            "A",
+        );
+        let issues = validate_eip712_types(&circular).unwrap();
+        assert!(issues.contains(&TypeIssue::Circular {
+            cycle: vec!["A".into(), "B".into(), "A".into()],
+            through_array: false,
+        }));
+        let recursive = TypeIssue::Circular {
+            cycle: vec!["Node".into(), "Node".into()],
+            through_array: true,
+        };
+        assert!(issues.contains(&recursive));
+        assert!(!recursive.is_error());
+
+        assert!(validate_eip712_types(&typed_data(r#"{"Mail": [{"name": 1}]}"#, "Mail")).is_err());
+        assert!(validate_eip712_types(r#"{"primaryType": "Mail"}"#).is_err());
+    }
+
+    #[test]
+    fn test_parse_safe_message() {
+        let hash = format!("0x{}", "ab".repeat(32));
+        let item = serde_json::json!({
+            "messageHash": hash,
+            "message": "Hello Safe",
+            "preparedSignature": "",
+            "proposedBy": "0x1111111111111111111111111111111111111111",
+            "confirmations": [{
+                "owner": "0x1111111111111111111111111111111111111111",
+                "signature": "0x01",
+                "signatureType": "EOA"
+            }]
+        });
+        let message = parse_safe_message(&item).unwrap();
+        assert_eq!(message.message_hash, hash);
+        assert_eq!(
+            message.body,
+            SafeMessageBody::Text("Hello Safe".to_string())
+        );
+        assert_eq!(message.prepared_signature, None);
+        assert_eq!(message.confirmations.len(), 1);
+        assert_eq!(message.confirmations[0].signature_type, "EOA");
+
+        let typed = serde_json::json!({
+            "messageHash": hash,
+            "message": {"types": {}, "domain": {}, "primaryType": "Mail", "message": {}}
+        });
+        assert!(matches!(
+            parse_safe_message(&typed).unwrap().body,
+            SafeMessageBody::TypedData(_)
+        ));
+
+        assert!(parse_safe_message(&serde_json::json!({"message": "x"})).is_err());
+        assert!(parse_safe_message(&serde_json::json!({"messageHash": hash})).is_err());
+    }
+
+    #[test]
+    fn test_safe_message_hash() {
+        let safe = "0x1111111111111111111111111111111111111111";
+        let body = SafeMessageBody::Text("Hello Safe".to_string());
+        // keccak256(0x1901 || domainSeparator || keccak256(abi.encode(
+        //     SAFE_MSG_TYPEHASH, keccak256(eip191Hash("Hello Safe"))))),
+        // as CompatibilityFallbackHandler.getMessageHashForSafe computes it
+        assert_eq!(
+            safe_message_hash("ethereum", safe, "1.4.1", &body).unwrap(),
+            "0x0125ae8e374452b320c1cc49931c43eaa8d374be4a593adbd11d679c75603c01"
+                .parse::<B256>()
+                .unwrap()
+        );
+        assert_ne!(
+            safe_message_hash("polygon", safe, "1.4.1", &body).unwrap(),
+            safe_message_hash("ethereum", safe, "1.4.1", &body).unwrap()
+        );
+        assert!(safe_message_hash("ethereum", safe, "nope", &body).is_err());
+    }
+
+    #[test]
+    fn test_singleton_versions() {
+        for (address, version) in SINGLETON_VERSIONS {
+            let singleton = Address::parse_checksummed(address, None)
+                .unwrap_or_else(|e| panic!("{} is not checksummed: {}", address, e));
+            assert!(crate::state::SAFE_VERSIONS.contains(version));
+            assert_eq!(version_for_singleton(singleton), Some(*version));
+        }
+        assert_eq!(version_for_singleton(Address::ZERO), None);
+    }
+
+    #[test]
+    fn test_parse_origin() {
+        let origin = parse_origin(r#"{"url":"https://app.uniswap.org","name":"Uniswap"}"#).unwrap();
+        assert_eq!(origin.name.as_deref(), Some("Uniswap"));
+        assert_eq!(origin.url.as_deref(), Some("https://app.uniswap.org"));
+
+        // Free text is kept raw
+        let origin = parse_origin("my-script").unwrap();
+        assert_eq!(origin.name, None);
+        assert_eq!(origin.raw, "my-script");
+
+        assert!(parse_origin("  ").is_none());
+    }
+
+    #[test]
+    fn test_parse_proposal() {
+        let item = serde_json::json!({
+            "proposer": "0x4F2083f5fBede34C2714aFfb3105539775f7FE64",
+            "proposedByDelegate": null,
+        });
+        let proposal = parse_proposal(&item);
+        assert_eq!(
+            proposal.proposer,
+            Some(
+                "0x4F2083f5fBede34C2714aFfb3105539775f7FE64"
+                    .parse()
+                    .unwrap()
+            )
+        );
+        assert_eq!(proposal.proposed_by_delegate, None);
+
+        // Older responses have no proposer field
+        assert_eq!(
+            parse_proposal(&serde_json::json!({})),
+            ProposalInfo::default()
+        );
+    }
+
+    #[test]
+    fn test_validate_confirmations() {
+        let owner_a = "0x1111111111111111111111111111111111111111";
+        let owner_b = "0x2222222222222222222222222222222222222222";
+        let stranger = "0x3333333333333333333333333333333333333333";
+        let zero = "0x0000000000000000000000000000000000000000";
+        let info: SafeInfo = serde_json::from_value(serde_json::json!({
+            "address": zero,
+            "nonce": "5",
+            "threshold": 2,
+            "owners": [owner_a, owner_b],
+            "modules": [],
+            "version": "1.4.1",
+        }))
+        .unwrap();
+        let confirmation = |owner: &str| {
+            serde_json::json!({
+                "owner": owner,
+                "signature": "0x",
+                "signatureType": "EOA",
+            })
+        };
+        let tx = |signers: &[&str]| -> SafeTransaction {
+            serde_json::from_value(serde_json::json!({
+                "safe": zero, "to": zero, "value": "0", "data": "0x", "dataDecoded": null,
+                "operation": 0, "gasToken": zero, "safeTxGas": 0, "baseGas": 0,
+                "gasPrice": "0", "refundReceiver": zero, "nonce": 5,
+                "safeTxHash": "0x00", "submissionDate": "", "executionDate": null,
+                "transactionHash": null, "isExecuted": false, "isSuccessful": null,
+                "origin": "", "confirmationsRequired": 2,
+                "confirmations": signers.iter().map(|s| confirmation(s)).collect::<Vec<_>>(),
+            }))
+            .unwrap()
+        };
+
+        assert!(validate_confirmations(&tx(&[owner_a, owner_b]), &info).is_empty());
+
+        let stranger: Address = stranger.parse().unwrap();
+        let owner_a_addr: Address = owner_a.parse().unwrap();
+        assert_eq!(
+            validate_confirmations(&tx(&[owner_a, owner_a, &stranger.to_string()]), &info),
+            vec![
+                ConfirmationIssue::Duplicate(owner_a_addr),
+                ConfirmationIssue::NotOwner(stranger),
+                ConfirmationIssue::BelowThreshold {
+                    valid: 1,
+                    threshold: 2
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_recover_safe_signature() {
+        let owner: Address = "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23"
+            .parse()
+            .unwrap();
+        // web3.js `sign` test vector, recovered against its prefixed hash
+        let hash = alloy::primitives::eip191_hash_message(b"Some data");
+        let signature = "0xb91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd\
+                         6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a0291c";
+        assert_eq!(recover_safe_signature(signature, &hash), Some(owner));
+
+        let approved = format!("0x{:0>64}{}01", hex::encode(owner), "0".repeat(64));
+        assert_eq!(recover_safe_signature(&approved, &hash), Some(owner));
+
+        let contract = format!("0x{:0>64}{:064x}00", hex::encode(owner), 65);
+        assert!(is_contract_signature(&contract));
+        assert_eq!(recover_safe_signature(&contract, &hash), None);
+        assert_eq!(recover_safe_signature("0x1234", &hash), None);
+    }
+
+    #[test]
+    fn test_recover_eth_sign_signature() {
+        // Private key 1, whose address is the generator point's
+        let owner: Address = "0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf"
+            .parse()
+            .unwrap();
+        let safe_tx_hash = B256::repeat_byte(0xab);
+        // Signed over eip191Hash(safe_tx_hash), as eth_sign does
+        let rs = "bb50e2d89a4ed70663d080659fe0ad4b9bc3e06c17a227433966cb59ceee020d\
+                  30d40a4cb76d64c0b0cad5d367da93f9a777b985f0bd2c5dfc5a7486206007c9";
+
+        let signature = format!("0x{}{:02x}", rs, 32);
+        assert_eq!(
+            recover_safe_signature(&signature, &safe_tx_hash),
+            Some(owner)
+        );
+        // Wrong parity, or the same r and s read as a direct signature
+        for v in [31u8, 28] {
+            let signature = format!("0x{}{:02x}", rs, v);
+            assert_ne!(
+                recover_safe_signature(&signature, &safe_tx_hash),
+                Some(owner)
+            );
+        }
+    }
+
+    #[test]
+    fn test_check_nonce_range() {
+        assert!(check_nonce_range(5, 5).is_ok());
+        assert!(check_nonce_range(0, MAX_NONCE_RANGE - 1).is_ok());
+        assert!(check_nonce_range(0, MAX_NONCE_RANGE).is_err());
+        assert!(check_nonce_range(6, 5).is_err());
+    }
+
+    #[test]
+    fn test_config_change_selectors() {
+        let selector = |signature: &str| format!("0x{}", hex::encode(&keccak256(signature)[..4]));
+        assert_eq!(
+            CHANGE_MASTER_COPY_SELECTOR,
+            selector("changeMasterCopy(address)")
+        );
+        assert_eq!(SET_GUARD_SELECTOR, selector("setGuard(address)"));
+        assert_eq!(
+            SET_FALLBACK_HANDLER_SELECTOR,
+            selector("setFallbackHandler(address)")
+        );
+        assert_eq!(ENABLE_MODULE_SELECTOR, selector("enableModule(address)"));
+        assert_eq!(
+            DISABLE_MODULE_SELECTOR,
+            selector("disableModule(address,address)")
+        );
+        for (migration, method) in SINGLETON_MIGRATIONS {
+            assert_eq!(migration, selector(method), "selector for {}", method);
+        }
+    }
+
+    #[test]
+    fn test_detect_config_changes() {
+        let safe = "0x1111111111111111111111111111111111111111";
+        let singleton = "0x2222222222222222222222222222222222222222";
+        let data = format!("{}{:0>64}", CHANGE_MASTER_COPY_SELECTOR, &singleton[2..]);
+        assert_eq!(
+            detect_config_changes(safe, &data, 0),
+            vec![ConfigChange {
+                index: None,
+                target: safe.to_string(),
+                kind: ConfigChangeKind::MasterCopy(singleton.parse().unwrap()),
+            }]
+        );
+
+        // A migration only rewrites the Safe's storage when delegatecalled
+        let migrate = SINGLETON_MIGRATIONS[1].0;
+        assert_eq!(detect_config_changes(singleton, migrate, 1).len(), 1);
+        assert!(detect_config_changes(singleton, migrate, 0).is_empty());
+        assert!(detect_config_changes(safe, "0xa9059cbb", 0).is_empty());
+
+        let remove_guard = format!("{}{:064x}", SET_GUARD_SELECTOR, 0);
+        assert_eq!(
+            detect_config_changes(safe, &remove_guard, 0)[0].kind,
+            ConfigChangeKind::Guard(Address::ZERO)
+        );
+
+        let module = "0x3333333333333333333333333333333333333333";
+        let enable = format!("{}{:0>64}", ENABLE_MODULE_SELECTOR, &module[2..]);
+        assert_eq!(
+            detect_config_changes(safe, &enable, 0)[0].kind,
+            ConfigChangeKind::EnableModule(module.parse().unwrap())
+        );
+        let disable = format!(
+            "{}{:0>64}{:0>64}",
+            DISABLE_MODULE_SELECTOR,
+            &singleton[2..],
+            &module[2..]
+        );
+        assert_eq!(
+            detect_config_changes(safe, &disable, 0)[0].kind,
+            ConfigChangeKind::DisableModule(module.parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_parse_transaction_json_errors() {
+        let err = parse_transaction_json("{").unwrap_err();
+        assert!(err.starts_with("Invalid JSON"), "{}", err);
+        assert_eq!(
+            parse_transaction_json("[]").unwrap_err(),
+            "Expected a single transaction object"
+        );
+        let err = parse_transaction_json(r#"{"to": "0x00", "nonce": null}"#).unwrap_err();
+        assert!(err.contains("safe, value,"), "{}", err);
+        assert!(err.ends_with("nonce"), "{}", err);
+    }
+
+    #[test]
+    fn test_owner_management() {
+        let safe = "0x1111111111111111111111111111111111111111";
+        let owner = "0x2222222222222222222222222222222222222222";
+        let params = format!("{:0>64}{:064x}", &owner[2..], 3);
+        assert_eq!(
+            describe_owner_management(ADD_OWNER_SELECTOR, &params).unwrap(),
+            format!("Adds owner {} and sets threshold to 3", owner)
+        );
+        assert!(describe_owner_management("0xa9059cbb", &params).is_none());
+
+        // Only calls on the Safe itself count
+        let data = format!("{}{}", ADD_OWNER_SELECTOR, params);
+        let changes = detect_owner_management(safe, safe, &data);
+        assert_eq!(changes.len(), 1);
+        assert!(detect_owner_management(safe, owner, &data).is_empty());
+
+        // 2 owners + 1 = 3 owners, threshold 3 is fine; with 1 owner it isn't
+        assert!(check_owner_threshold(&changes, 2, 1).is_none());
+        assert!(check_owner_threshold(&changes, 1, 1).is_some());
+
+        let zero_threshold = format!("{}{:064x}", CHANGE_THRESHOLD_SELECTOR, 0);
+        let changes = detect_owner_management(safe, safe, &zero_threshold);
+        assert!(check_owner_threshold(&changes, 3, 2).is_some());
+    }
+
+    #[test]
+    fn test_calls_self() {
+        let safe = "0x1111111111111111111111111111111111111111";
+        let other = "0x2222222222222222222222222222222222222222";
+        assert!(calls_self(safe, safe, "0x"));
+        assert!(!calls_self(safe, other, "0x"));
+        assert!(!calls_self("", safe, "0x"));
+    }
+
+    #[test]
+    fn test_parse_confirmations() {
+        let owner = "0x4F2083f5fBede34C2714aFfb3105539775f7FE64";
+        let pre_validated = format!(
+            "0x000000000000000000000000{}{}01",
+            &owner[2..],
+            "00".repeat(32)
+        );
+        let item = serde_json::json!({
+            "confirmations": [
+                {
+                    "owner": owner, "signature": pre_validated, "signatureType": "APPROVED_HASH",
+                    "transactionHash": "0xabc",
+                },
+                { "owner": owner, "signature": format!("0x{}1b", "11".repeat(64)), "signatureType": "EOA" },
+            ]
+        });
+        let confirmations = parse_confirmations(&item);
+        assert_eq!(
+            confirmations[0].kind,
+            ConfirmationKind::ApprovedHash {
+                owner_matches: true
+            }
+        );
+        assert_eq!(confirmations[0].transaction_hash.as_deref(), Some("0xabc"));
+        assert_eq!(
+            confirmations[1].kind,
+            ConfirmationKind::OffChain("EOA".to_string())
+        );
+        assert_eq!(confirmations[1].transaction_hash, None);
+        assert!(parse_confirmations(&serde_json::json!({})).is_empty());
+    }
+
+    #[test]
+    fn test_parse_confirmations_approved_hash_without_signature() {
+        let owner = "0x4F2083f5fBede34C2714aFfb3105539775f7FE64";
+        let item = serde_json::json!({
+            "confirmations": [
+                { "owner": owner, "signature": null, "signatureType": "APPROVED_HASH" },
+                { "owner": owner, "signature": "", "signatureType": "APPROVED_HASH" },
+                { "owner": owner, "signatureType": "APPROVED_HASH" },
+            ]
+        });
+        let confirmations = parse_confirmations(&item);
+        assert_eq!(confirmations.len(), 3);
+        for confirmation in confirmations {
+            assert_eq!(
+                confirmation.kind,
+                ConfirmationKind::ApprovedHash {
+                    owner_matches: true
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_domain_components() {
+        let safe = "0x4F2083f5fBede34C2714aFfb3105539775f7FE64";
+
+        let modern = domain_components("ethereum", safe, "1.4.1").unwrap();
+        assert_eq!(
+            modern.type_hash,
+            "0x47e79534a245952e8b16893a336b85a3d9ea9fa8c573f3d803afb92a79469218"
+                .parse::<B256>()
+                .unwrap()
+        );
+        assert_eq!(modern.chain_id, Some(1));
+        assert_eq!(modern.encoded.len(), 96);
+        assert_eq!(modern.domain_separator, keccak256(&modern.encoded));
+        assert_eq!(
+            modern.domain_separator,
+            "0xfacf7a68664ed3dabebe8e263b4cf03d3e1ba8bbda764c9697c80c7e38b41c62"
+                .parse::<B256>()
+                .unwrap()
+        );
+        assert!(modern.matches_library);
+
+        let legacy = domain_components("ethereum", safe, "1.1.1").unwrap();
+        assert_eq!(legacy.chain_id, None);
+        assert_eq!(legacy.encoded.len(), 64);
+        assert_eq!(
+            legacy.domain_separator,
+            "0xb4a3274804120e7cc729ac5f937a7e5e700514a0df46e755b0c0be6923680f51"
+                .parse::<B256>()
+                .unwrap()
+        );
+        assert!(legacy.matches_library);
+
+        // A separator for other inputs doesn't match
+        let matches = |separator, chain_id| {
+            let version = SafeWalletVersion::parse("1.4.1").unwrap();
+            matches_library(separator, version, chain_id, modern.verifying_contract)
+        };
+        assert!(matches(modern.domain_separator, 1));
+        assert!(!matches(modern.domain_separator, 137));
+        assert!(!matches(legacy.domain_separator, 1));
+
+        assert!(domain_components("ethereum", "0xnope", "1.4.1").is_err());
+    }
+}
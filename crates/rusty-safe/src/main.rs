@@ -4,12 +4,20 @@
 
 mod api;
 mod app;
+mod counterparty;
 mod decode;
 mod expected;
+mod export;
 mod hasher;
+mod persist;
+mod roster;
+mod shortcuts;
 mod sidebar;
 mod state;
+mod trust;
 mod ui;
+mod verify;
+mod warnings;
 
 // Web entry point
 #[cfg(target_arch = "wasm32")]
@@ -54,6 +62,11 @@ fn main() {
 // Native entry point
 #[cfg(not(target_arch = "wasm32"))]
 fn main() -> eframe::Result<()> {
+    let args: Vec<String> = std::env::args().collect();
+    if args.get(1).map(String::as_str) == Some("verify") {
+        std::process::exit(verify::run_cli(&args[2..]));
+    }
+
     tracing_subscriber::fmt()
         .with_env_filter(
             tracing_subscriber::EnvFilter::from_default_env()
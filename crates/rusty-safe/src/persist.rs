@@ -0,0 +1,165 @@
+//! Versioned persistence
+//!
+//! Every JSON blob written to eframe storage is wrapped in an envelope
+//! carrying its schema version. On load, older versions are upgraded one
+//! step at a time through the blob's migration chain, so a format change
+//! never silently drops saved data. Blobs written before versioning was
+//! introduced are treated as version 0.
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Upgrades a blob from version `n` to `n + 1`
+pub type Migration = fn(Value) -> Result<Value, String>;
+
+/// Schema of one persisted blob
+pub struct Schema {
+    /// Storage key
+    pub key: &'static str,
+    /// Version written by this build
+    pub version: u32,
+    /// `migrations[n]` upgrades version `n` to `n + 1`.
+    /// Must have exactly `version` entries.
+    pub migrations: &'static [Migration],
+}
+
+/// Migration for formats that didn't change when versioning was added
+pub fn unchanged(value: Value) -> Result<Value, String> {
+    Ok(value)
+}
+
+#[derive(Serialize)]
+struct EnvelopeRef<'a, T> {
+    schema_version: u32,
+    data: &'a T,
+}
+
+#[derive(Deserialize)]
+struct Envelope {
+    schema_version: u32,
+    data: Value,
+}
+
+impl Schema {
+    /// Serialize `value` at the current version
+    pub fn encode<T: Serialize>(&self, value: &T) -> Option<String> {
+        serde_json::to_string(&EnvelopeRef {
+            schema_version: self.version,
+            data: value,
+        })
+        .ok()
+    }
+
+    /// Parse a stored blob, migrating it to the current version. Fails on
+    /// versions newer than this build knows about rather than guessing.
+    pub fn decode<T: DeserializeOwned>(&self, raw: &str) -> Result<T, String> {
+        let value: Value = serde_json::from_str(raw)
+            .map_err(|e| format!("Saved {} is not valid JSON: {}", self.key, e))?;
+
+        let (mut version, mut data) = match serde_json::from_value::<Envelope>(value.clone()) {
+            Ok(envelope) => (envelope.schema_version, envelope.data),
+            Err(_) => (0, value),
+        };
+
+        if version > self.version {
+            return Err(format!(
+                "Saved {} uses schema version {}, but this version of Rusty-Safe only \
+                 understands up to {}. It was left untouched; update Rusty-Safe to use it.",
+                self.key, version, self.version
+            ));
+        }
+
+        while version < self.version {
+            let migrate = self
+                .migrations
+                .get(version as usize)
+                .ok_or_else(|| format!("No migration for {} from version {}", self.key, version))?;
+            data = migrate(data).map_err(|e| {
+                format!(
+                    "Failed to upgrade saved {} from version {}: {}",
+                    self.key, version, e
+                )
+            })?;
+            version += 1;
+        }
+
+        serde_json::from_value(data)
+            .map_err(|e| format!("Saved {} could not be read: {}", self.key, e))
+    }
+
+    /// Load and migrate the blob from storage. `Ok(None)` if nothing is saved.
+    pub fn load<T: DeserializeOwned>(
+        &self,
+        storage: Option<&dyn eframe::Storage>,
+    ) -> Result<Option<T>, String> {
+        match storage.and_then(|s| s.get_string(self.key)) {
+            Some(raw) => self.decode(&raw).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Write the blob at the current version
+    pub fn save<T: Serialize>(&self, storage: &mut dyn eframe::Storage, value: &T) {
+        if let Some(json) = self.encode(value) {
+            storage.set_string(self.key, json);
+        }
+    }
+}
+
+/// A blob that failed to load. Its key isn't written back this session so
+/// the stored data survives for a build that can read it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoadIssue {
+    pub key: &'static str,
+    pub message: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rename_list(mut value: Value) -> Result<Value, String> {
+        let items = value
+            .get_mut("list")
+            .map(Value::take)
+            .ok_or("missing list")?;
+        Ok(serde_json::json!({ "items": items }))
+    }
+
+    const TEST_SCHEMA: Schema = Schema {
+        key: "test",
+        version: 2,
+        migrations: &[unchanged, rename_list],
+    };
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Current {
+        items: Vec<u32>,
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let value = Current { items: vec![1, 2] };
+        let json = TEST_SCHEMA.encode(&value).unwrap();
+        assert!(json.contains("\"schema_version\":2"));
+        assert_eq!(TEST_SCHEMA.decode::<Current>(&json).unwrap(), value);
+    }
+
+    #[test]
+    fn test_migrates_unversioned_blob() {
+        let legacy = r#"{"list": [3]}"#;
+        let value: Current = TEST_SCHEMA.decode(legacy).unwrap();
+        assert_eq!(value.items, vec![3]);
+
+        let v1 = r#"{"schema_version": 1, "data": {"list": [4]}}"#;
+        assert_eq!(TEST_SCHEMA.decode::<Current>(v1).unwrap().items, vec![4]);
+    }
+
+    #[test]
+    fn test_rejects_future_version() {
+        let future = r#"{"schema_version": 3, "data": {"items": []}}"#;
+        let err = TEST_SCHEMA.decode::<Current>(future).unwrap_err();
+        assert!(err.contains("schema version 3"));
+    }
+}
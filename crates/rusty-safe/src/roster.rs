@@ -0,0 +1,720 @@
+//! Payroll roster reconciliation
+//!
+//! Matches the payments in a MultiSend batch against an expected roster of
+//! recipient → amount, flagging unexpected payees, forgotten payees and
+//! amount discrepancies. Payments are read from the raw calldata only, never
+//! from the service's decode.
+
+use alloy::primitives::{hex, Address, U256};
+use eframe::egui;
+use serde::Deserialize;
+
+use crate::decode::{
+    decode_multisend_bytes, get_selector, unpack_multisend_transactions, MultiSendTx,
+    MULTISEND_SELECTOR,
+};
+use crate::state::SafeContext;
+use crate::ui;
+
+/// `transfer(address,uint256)`
+const TRANSFER_SELECTOR: [u8; 4] = [0xa9, 0x05, 0x9c, 0xbb];
+/// How deep to follow nested batches
+const MAX_NESTING_DEPTH: usize = 8;
+
+//─────────────────────────────────────────────────────────────────────────────
+// STATE
+//─────────────────────────────────────────────────────────────────────────────
+
+/// State for the expected roster input
+#[derive(Debug, Clone, Default)]
+pub struct RosterState {
+    /// Raw CSV or JSON input
+    pub input: String,
+    /// Parsed roster entries
+    pub entries: Vec<RosterEntry>,
+    /// Parse error for the current input
+    pub error: Option<String>,
+}
+
+/// One expected payment
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RosterEntry {
+    /// Recipient address (lowercase)
+    pub address: String,
+    /// Amount in the asset's smallest unit (wei, token base units)
+    pub amount: U256,
+    /// Token contract (lowercase), `None` for the native asset
+    pub token: Option<String>,
+}
+
+/// JSON roster entry: `{"address": "0x..", "amount": "1000", "token": "0x.."}`
+#[derive(Deserialize)]
+struct RosterEntryJson {
+    address: String,
+    amount: String,
+    #[serde(default)]
+    token: Option<String>,
+}
+
+impl RosterState {
+    /// Re-parse the input into entries
+    pub fn update(&mut self) {
+        match parse_roster(&self.input) {
+            Ok(entries) => {
+                self.entries = entries;
+                self.error = None;
+            }
+            Err(e) => {
+                self.entries.clear();
+                self.error = Some(e);
+            }
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.input.clear();
+        self.entries.clear();
+        self.error = None;
+    }
+}
+
+//─────────────────────────────────────────────────────────────────────────────
+// RECONCILIATION
+//─────────────────────────────────────────────────────────────────────────────
+
+/// A payment found in the batch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Payment {
+    /// Index at each nesting level, outermost first
+    pub path: Vec<usize>,
+    /// Recipient address (lowercase)
+    pub recipient: String,
+    pub amount: U256,
+    /// Token contract (lowercase), `None` for native transfers
+    pub token: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RosterStatus {
+    Match,
+    AmountMismatch,
+    /// On the roster, not paid in the batch
+    Missing,
+    /// Paid in the batch, not on the roster
+    Unexpected,
+}
+
+/// One row of the reconciliation table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RosterRow {
+    pub address: String,
+    pub token: Option<String>,
+    pub expected: Option<U256>,
+    /// Sum of all matching payments in the batch
+    pub actual: Option<U256>,
+    /// 1-based batch positions of the matching payments, e.g. "2.1"
+    pub positions: Vec<String>,
+    pub status: RosterStatus,
+}
+
+/// Payments in a MultiSend batch, worked out once per transaction
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BatchPayments {
+    pub payments: Vec<Payment>,
+    /// Positions of sub-transactions that aren't payments
+    pub other_calls: Vec<String>,
+}
+
+impl BatchPayments {
+    /// Payments in a multiSend call's batch, `None` if `data` isn't one
+    pub fn from_calldata(data: &str) -> Option<Self> {
+        unpack_batch(data).map(|transactions| Self::from_transactions(&transactions))
+    }
+
+    pub fn from_transactions(transactions: &[MultiSendTx]) -> Self {
+        let mut found = Self::default();
+        collect_payments(transactions, &[], &mut found);
+        found
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Reconciliation {
+    pub rows: Vec<RosterRow>,
+    /// Positions of sub-transactions that aren't payments and weren't
+    /// reconciled
+    pub other_calls: Vec<String>,
+}
+
+impl Reconciliation {
+    pub fn is_clean(&self) -> bool {
+        self.rows.iter().all(|r| r.status == RosterStatus::Match)
+    }
+}
+
+/// 1-based batch position, e.g. "2.1" for the first call of the nested
+/// batch at position 2
+fn position(path: &[usize]) -> String {
+    path.iter()
+        .map(|i| (i + 1).to_string())
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// The batch a multiSend call carries, `None` if `data` isn't one
+fn unpack_batch(data: &str) -> Option<Vec<MultiSendTx>> {
+    let data = data.trim();
+    if get_selector(data) != MULTISEND_SELECTOR {
+        return None;
+    }
+    decode_multisend_bytes(data)
+        .and_then(|packed| unpack_multisend_transactions(&packed))
+        .ok()
+}
+
+/// An ERC20 `transfer(address,uint256)` called (operation 0) by a
+/// sub-transaction, decoded from its raw calldata
+pub fn token_transfer(tx: &MultiSendTx, path: &[usize]) -> Option<Payment> {
+    if tx.operation != 0 {
+        return None;
+    }
+    let data = tx.data.trim();
+    let bytes = hex::decode(data.strip_prefix("0x").unwrap_or(data)).ok()?;
+    if bytes.len() != 68 || bytes[..4] != TRANSFER_SELECTOR {
+        return None;
+    }
+    // The address word must be left-padded with zeros
+    if bytes[4..16].iter().any(|&b| b != 0) {
+        return None;
+    }
+    Some(Payment {
+        path: path.to_vec(),
+        recipient: Address::from_slice(&bytes[16..36])
+            .to_string()
+            .to_lowercase(),
+        amount: U256::from_be_slice(&bytes[36..68]),
+        token: Some(tx.to.to_lowercase()),
+    })
+}
+
+/// Payments in `transactions` and their nested batches, and the positions
+/// of the calls that aren't payments. The native `value` of every plain
+/// call is a payment to its `to`, whatever the call. Other operations
+/// aren't reconciled, except a nested batch delegatecalled the way
+/// MultiSend runs one, whose payments come from the Safe too.
+fn collect_payments(transactions: &[MultiSendTx], prefix: &[usize], found: &mut BatchPayments) {
+    for tx in transactions {
+        let mut path = prefix.to_vec();
+        path.push(tx.index);
+        if tx.operation != 0 {
+            match unpack_batch(&tx.data).filter(|_| tx.operation == 1) {
+                Some(nested) if path.len() < MAX_NESTING_DEPTH => {
+                    collect_payments(&nested, &path, found)
+                }
+                _ => found.other_calls.push(position(&path)),
+            }
+            continue;
+        }
+        let Ok(value) = U256::from_str_radix(tx.value.trim(), 10) else {
+            found.other_calls.push(position(&path));
+            continue;
+        };
+        if !value.is_zero() {
+            found.payments.push(Payment {
+                path: path.clone(),
+                recipient: tx.to.to_lowercase(),
+                amount: value,
+                token: None,
+            });
+        }
+
+        let data = tx.data.trim();
+        if data.is_empty() || data == "0x" {
+            if value.is_zero() {
+                found.other_calls.push(position(&path));
+            }
+        } else if let Some(payment) = token_transfer(tx, &path) {
+            found.payments.push(payment);
+        } else {
+            found.other_calls.push(position(&path));
+        }
+    }
+}
+
+/// Reconcile the batch's payments, including those in nested batches,
+/// against the roster. Amounts are only summed within one asset.
+pub fn reconcile(roster: &[RosterEntry], batch: &BatchPayments) -> Reconciliation {
+    let payments = &batch.payments;
+    let other_calls = batch.other_calls.clone();
+
+    let mut claimed = vec![false; payments.len()];
+    let mut rows = Vec::new();
+
+    for entry in roster {
+        let mut actual = U256::ZERO;
+        let mut positions = Vec::new();
+        for (i, p) in payments.iter().enumerate() {
+            if !claimed[i] && p.recipient == entry.address && p.token == entry.token {
+                claimed[i] = true;
+                actual += p.amount;
+                positions.push(position(&p.path));
+            }
+        }
+
+        let status = if positions.is_empty() {
+            RosterStatus::Missing
+        } else if actual == entry.amount {
+            RosterStatus::Match
+        } else {
+            RosterStatus::AmountMismatch
+        };
+        rows.push(RosterRow {
+            address: entry.address.clone(),
+            token: entry.token.clone(),
+            expected: Some(entry.amount),
+            actual: (!positions.is_empty()).then_some(actual),
+            positions,
+            status,
+        });
+    }
+
+    for (p, _) in payments.iter().zip(&claimed).filter(|(_, &c)| !c) {
+        rows.push(RosterRow {
+            address: p.recipient.clone(),
+            token: p.token.clone(),
+            expected: None,
+            actual: Some(p.amount),
+            positions: vec![position(&p.path)],
+            status: RosterStatus::Unexpected,
+        });
+    }
+
+    Reconciliation { rows, other_calls }
+}
+
+/// Parse a roster from CSV (`address,amount[,token]`) or a JSON array of
+/// `{address, amount, token?}` objects. Amounts are in base units, decimal
+/// or 0x-prefixed hex.
+pub fn parse_roster(input: &str) -> Result<Vec<RosterEntry>, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(vec![]);
+    }
+
+    if input.starts_with('[') {
+        let items: Vec<RosterEntryJson> =
+            serde_json::from_str(input).map_err(|e| format!("Invalid roster JSON: {}", e))?;
+        return items
+            .into_iter()
+            .enumerate()
+            .map(|(i, item)| {
+                make_entry(&item.address, &item.amount, item.token.as_deref())
+                    .map_err(|e| format!("Entry {}: {}", i, e))
+            })
+            .collect();
+    }
+
+    let mut entries = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with("address,") {
+            continue;
+        }
+        let parts: Vec<&str> = line.split(',').collect();
+        if parts.len() < 2 {
+            return Err(format!("Line {}: expected address,amount[,token]", i + 1));
+        }
+        let entry = make_entry(parts[0], parts[1], parts.get(2).copied())
+            .map_err(|e| format!("Line {}: {}", i + 1, e))?;
+        entries.push(entry);
+    }
+    Ok(entries)
+}
+
+fn make_entry(address: &str, amount: &str, token: Option<&str>) -> Result<RosterEntry, String> {
+    let address = parse_address(address)?;
+    let amount = parse_amount(amount)?;
+    let token = match token.map(str::trim) {
+        None | Some("") => None,
+        Some(t) => Some(parse_address(t)?),
+    };
+    Ok(RosterEntry {
+        address,
+        amount,
+        token,
+    })
+}
+
+fn parse_address(value: &str) -> Result<String, String> {
+    let value = value.trim();
+    value
+        .parse::<alloy::primitives::Address>()
+        .map(|_| value.to_lowercase())
+        .map_err(|_| format!("invalid address '{}'", value))
+}
+
+fn parse_amount(value: &str) -> Result<U256, String> {
+    let value = value.trim();
+    let parsed = match value.strip_prefix("0x") {
+        Some(hex) => U256::from_str_radix(hex, 16),
+        None => U256::from_str_radix(value, 10),
+    };
+    parsed.map_err(|_| format!("invalid amount '{}'", value))
+}
+
+//─────────────────────────────────────────────────────────────────────────────
+// UI
+//─────────────────────────────────────────────────────────────────────────────
+
+/// Render the roster input collapsible section, returns whether the entries
+/// changed
+pub fn render_section(ui: &mut egui::Ui, state: &mut RosterState) -> bool {
+    let mut changed = false;
+    egui::CollapsingHeader::new("👥 Expected Payroll Roster")
+        .default_open(!state.input.is_empty())
+        .show(ui, |ui| {
+            ui.add_space(5.0);
+            ui.label(
+                egui::RichText::new(
+                    "Paste address,amount[,token] lines or a JSON array. Amounts in base units \
+                     (wei / token units); no token means the native asset. Checked against \
+                     MultiSend batches, including nested ones.",
+                )
+                .small(),
+            );
+            ui.add_space(5.0);
+
+            let response = ui.add(
+                egui::TextEdit::multiline(&mut state.input)
+                    .hint_text("0x...,1000000\n0x...,2500000,0xA0b8...eB48")
+                    .desired_width(f32::INFINITY)
+                    .desired_rows(4)
+                    .font(egui::TextStyle::Monospace),
+            );
+            if response.changed() {
+                state.update();
+                changed = true;
+            }
+
+            if let Some(error) = &state.error {
+                ui::error_message(ui, error);
+            } else if !state.entries.is_empty() {
+                ui.horizontal(|ui| {
+                    ui.label(
+                        egui::RichText::new(format!("{} roster entries", state.entries.len()))
+                            .small(),
+                    );
+                    if ui.small_button("🗑 Clear").clicked() {
+                        state.clear();
+                        changed = true;
+                    }
+                });
+            }
+        });
+    changed
+}
+
+/// Render the reconciliation table for a MultiSend batch
+pub fn render_reconciliation(ui: &mut egui::Ui, result: &Reconciliation, ctx: &SafeContext) {
+    ui.add_space(15.0);
+    ui::section_header(ui, "Roster Reconciliation");
+
+    if result.is_clean() {
+        ui::success_banner(ui, "✅ Batch pays exactly the roster");
+    } else {
+        let count = |s| result.rows.iter().filter(|r| r.status == s).count();
+        let unexpected = count(RosterStatus::Unexpected);
+        let missing = count(RosterStatus::Missing);
+        let mismatched = count(RosterStatus::AmountMismatch);
+        if unexpected > 0 {
+            ui::error_banner(
+                ui,
+                &format!("{} unexpected payee(s) not on the roster", unexpected),
+            );
+        }
+        if missing > 0 {
+            ui::warning_banner(
+                ui,
+                &format!("{} roster payee(s) missing from the batch", missing),
+            );
+        }
+        if mismatched > 0 {
+            ui::error_banner(ui, &format!("{} amount discrepancy(ies)", mismatched));
+        }
+    }
+    if !result.other_calls.is_empty() {
+        ui::warning_banner(
+            ui,
+            &format!(
+                "{} sub-transaction(s) are not plain transfers and weren't reconciled: {}",
+                result.other_calls.len(),
+                result
+                    .other_calls
+                    .iter()
+                    .map(|p| format!("#{}", p))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        );
+    }
+
+    ui.add_space(5.0);
+    egui::Grid::new("roster_reconciliation")
+        .num_columns(6)
+        .striped(true)
+        .spacing([12.0, 4.0])
+        .show(ui, |ui| {
+            ui.label(egui::RichText::new("Status").strong());
+            ui.label(egui::RichText::new("Recipient").strong());
+            ui.label(egui::RichText::new("Asset").strong());
+            ui.label(egui::RichText::new("Expected").strong());
+            ui.label(egui::RichText::new("In Batch").strong());
+            ui.label(egui::RichText::new("Tx").strong());
+            ui.end_row();
+
+            for row in &result.rows {
+                let (text, color) = match row.status {
+                    RosterStatus::Match => ("✅ Match", egui::Color32::from_rgb(100, 200, 100)),
+                    RosterStatus::AmountMismatch => {
+                        ("❌ Amount", egui::Color32::from_rgb(220, 80, 80))
+                    }
+                    RosterStatus::Missing => ("⚠️ Missing", egui::Color32::from_rgb(220, 180, 50)),
+                    RosterStatus::Unexpected => {
+                        ("❌ Unexpected", egui::Color32::from_rgb(220, 80, 80))
+                    }
+                };
+                ui.label(egui::RichText::new(text).color(color));
+
+                let name = ctx.name_of(&row.address);
+                ui::address_link(ui, &ctx.chain_name, &row.address, name);
+
+                match &row.token {
+                    Some(token) => {
+                        let name = ctx.name_of(token);
+                        ui::address_link(ui, &ctx.chain_name, token, name);
+                    }
+                    None => {
+                        ui.label("native");
+                    }
+                }
+
+                let fmt = |v: &Option<U256>| v.map_or("—".to_string(), |v| v.to_string());
+                ui.monospace(fmt(&row.expected));
+                ui.monospace(fmt(&row.actual));
+                ui.label(
+                    row.positions
+                        .iter()
+                        .map(|p| format!("#{}", p))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                );
+                ui.end_row();
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decode::{ApiDecode, ApiParam};
+
+    const ALICE: &str = "0x1111111111111111111111111111111111111111";
+    const BOB: &str = "0x2222222222222222222222222222222222222222";
+    const CAROL: &str = "0x3333333333333333333333333333333333333333";
+    const USDC: &str = "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
+
+    fn native(index: usize, to: &str, value: &str) -> MultiSendTx {
+        MultiSendTx {
+            index,
+            operation: 0,
+            to: to.to_string(),
+            value: value.to_string(),
+            data: "0x".to_string(),
+            api_decode: None,
+            decode: None,
+            nested: None,
+            is_expanded: false,
+        }
+    }
+
+    fn erc20(index: usize, to: &str, amount: u64) -> MultiSendTx {
+        MultiSendTx {
+            data: format!("0xa9059cbb{:0>64}{:064x}", &to[2..], amount),
+            ..native(index, USDC, "0")
+        }
+    }
+
+    /// `multiSend(bytes)` calldata packing `transactions`
+    fn multisend_data(transactions: &[MultiSendTx]) -> String {
+        let mut packed = Vec::new();
+        for tx in transactions {
+            let data = hex::decode(&tx.data[2..]).unwrap();
+            packed.push(tx.operation);
+            packed.extend(hex::decode(&tx.to[2..]).unwrap());
+            let value = U256::from_str_radix(&tx.value, 10).unwrap();
+            packed.extend(value.to_be_bytes::<32>());
+            packed.extend(U256::from(data.len()).to_be_bytes::<32>());
+            packed.extend(data);
+        }
+        let padding = (32 - packed.len() % 32) % 32;
+        format!(
+            "{}{:064x}{:064x}{}{}",
+            MULTISEND_SELECTOR,
+            32,
+            packed.len(),
+            hex::encode(&packed),
+            "00".repeat(padding)
+        )
+    }
+
+    fn batch(transactions: Vec<MultiSendTx>) -> BatchPayments {
+        BatchPayments::from_transactions(&transactions)
+    }
+
+    #[test]
+    fn test_parse_roster() {
+        let csv = format!(
+            "address,amount,token\n{},1000\n{},0x10,{}\n",
+            ALICE, BOB, USDC
+        );
+        let entries = parse_roster(&csv).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].token, None);
+        assert_eq!(entries[1].amount, U256::from(16));
+        assert_eq!(entries[1].token.as_deref(), Some(USDC));
+
+        let json = format!(r#"[{{"address": "{}", "amount": "5"}}]"#, CAROL);
+        assert_eq!(parse_roster(&json).unwrap()[0].amount, U256::from(5));
+
+        assert!(parse_roster("0xnope,1").is_err());
+        assert!(parse_roster(&format!("{},abc", ALICE)).is_err());
+    }
+
+    #[test]
+    fn test_reconcile() {
+        let roster = parse_roster(&format!(
+            "{},1000\n{},500,{}\n{},42\n",
+            ALICE, BOB, USDC, CAROL
+        ))
+        .unwrap();
+        let multi = batch(vec![
+            native(0, ALICE, "1000"),
+            erc20(1, BOB, 400),
+            erc20(2, "0x4444444444444444444444444444444444444444", 7),
+        ]);
+
+        let result = reconcile(&roster, &multi);
+        let status: Vec<_> = result.rows.iter().map(|r| r.status).collect();
+        assert_eq!(
+            status,
+            vec![
+                RosterStatus::Match,
+                RosterStatus::AmountMismatch,
+                RosterStatus::Missing,
+                RosterStatus::Unexpected,
+            ]
+        );
+        assert_eq!(result.rows[1].actual, Some(U256::from(400)));
+        assert!(result.other_calls.is_empty());
+        assert!(!result.is_clean());
+    }
+
+    #[test]
+    fn test_reconcile_sums_split_payments() {
+        let roster = parse_roster(&format!("{},1000", ALICE)).unwrap();
+        let multi = batch(vec![native(0, ALICE, "600"), native(1, ALICE, "400")]);
+        let result = reconcile(&roster, &multi);
+        assert!(result.is_clean());
+        assert_eq!(result.rows[0].positions, vec!["1", "2"]);
+    }
+
+    #[test]
+    fn test_reconcile_never_sums_across_assets() {
+        // 600 wei plus 400 USDC units must not satisfy a 1000 wei entry
+        let roster = parse_roster(&format!("{},1000", ALICE)).unwrap();
+        let multi = batch(vec![native(0, ALICE, "600"), erc20(1, ALICE, 400)]);
+        let result = reconcile(&roster, &multi);
+        assert!(!result.is_clean());
+        assert_eq!(result.rows[0].status, RosterStatus::AmountMismatch);
+        assert_eq!(result.rows[0].actual, Some(U256::from(600)));
+        assert_eq!(result.rows[1].status, RosterStatus::Unexpected);
+        assert_eq!(result.rows[1].token.as_deref(), Some(USDC));
+    }
+
+    #[test]
+    fn test_reconcile_token_transfer_value() {
+        let roster = parse_roster(&format!("{},500,{}", BOB, USDC)).unwrap();
+        let mut transfer = erc20(0, BOB, 500);
+        transfer.value = "7".to_string();
+        let result = reconcile(&roster, &batch(vec![transfer]));
+        assert_eq!(result.rows[0].status, RosterStatus::Match);
+        // The ETH sent along with the transfer goes to the token contract
+        assert_eq!(result.rows[1].status, RosterStatus::Unexpected);
+        assert_eq!(result.rows[1].address, USDC);
+        assert_eq!(result.rows[1].actual, Some(U256::from(7)));
+    }
+
+    #[test]
+    fn test_reconcile_nested_batch() {
+        let roster = parse_roster(&format!("{},1000\n{},500,{}", ALICE, BOB, USDC)).unwrap();
+        let mut carrier = native(1, CAROL, "0");
+        carrier.operation = 1;
+        carrier.data = multisend_data(&[erc20(0, BOB, 500)]);
+        let multi = batch(vec![native(0, ALICE, "1000"), carrier]);
+
+        let result = reconcile(&roster, &multi);
+        assert!(result.is_clean());
+        assert_eq!(result.rows[1].positions, vec!["2.1"]);
+        assert!(result.other_calls.is_empty());
+
+        // A batch that's called rather than delegatecalled doesn't pay from the Safe
+        let mut carrier = native(1, CAROL, "0");
+        carrier.data = multisend_data(&[erc20(0, BOB, 500)]);
+        let result = reconcile(&roster, &batch(vec![native(0, ALICE, "1000"), carrier]));
+        assert_eq!(result.rows[1].status, RosterStatus::Missing);
+        assert_eq!(result.other_calls, vec!["2"]);
+
+        let batch = BatchPayments::from_calldata(&multisend_data(&[native(0, ALICE, "1000")]));
+        assert!(reconcile(&roster[..1], &batch.unwrap()).is_clean());
+        assert_eq!(BatchPayments::from_calldata("0xa9059cbb"), None);
+    }
+
+    #[test]
+    fn test_reconcile_skips_delegatecall_transfer() {
+        // A delegatecalled transfer runs the callee's code against the Safe's
+        // storage, so it's no evidence that the token moved
+        let roster = parse_roster(&format!("{},500,{}", BOB, USDC)).unwrap();
+        let mut transfer = erc20(0, BOB, 500);
+        transfer.operation = 1;
+        let result = reconcile(&roster, &batch(vec![transfer]));
+        assert_eq!(result.rows[0].status, RosterStatus::Missing);
+        assert_eq!(result.rows[0].actual, None);
+        assert_eq!(result.other_calls, vec!["1"]);
+    }
+
+    #[test]
+    fn test_reconcile_ignores_api_decode() {
+        // The service claims a transfer the calldata doesn't contain
+        let roster = parse_roster(&format!("{},500,{}", BOB, USDC)).unwrap();
+        let mut transfer = erc20(0, BOB, 500);
+        transfer.data = "0xa9059cbb".to_string();
+        transfer.api_decode = Some(ApiDecode {
+            method: "transfer".to_string(),
+            params: vec![
+                ApiParam {
+                    name: "to".to_string(),
+                    typ: "address".to_string(),
+                    value: BOB.to_string(),
+                },
+                ApiParam {
+                    name: "value".to_string(),
+                    typ: "uint256".to_string(),
+                    value: "500".to_string(),
+                },
+            ],
+        });
+        let result = reconcile(&roster, &batch(vec![transfer]));
+        assert_eq!(result.rows[0].status, RosterStatus::Missing);
+        assert_eq!(result.other_calls, vec!["1"]);
+    }
+}
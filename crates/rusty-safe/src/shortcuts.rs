@@ -0,0 +1,233 @@
+//! Keyboard shortcuts and the command palette
+//!
+//! Keys map to an `Action` that the app dispatches into the same handlers
+//! its buttons use. Nothing fires while a text field has keyboard focus, so
+//! typing a nonce or pasting calldata is never hijacked.
+
+use eframe::egui;
+
+use crate::app::Tab;
+
+/// Something a shortcut or palette entry does
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    FetchAndVerify,
+    NextNonce,
+    PreviousNonce,
+    SwitchTab(Tab),
+    FetchSafeDetails,
+    ToggleAddressBook,
+    OpenPalette,
+}
+
+/// A command palette entry
+pub struct Command {
+    pub label: &'static str,
+    /// Key hint shown next to the label
+    pub shortcut: Option<&'static str>,
+    pub action: Action,
+}
+
+pub const COMMANDS: &[Command] = &[
+    Command {
+        label: "Fetch & Verify",
+        shortcut: Some("Enter"),
+        action: Action::FetchAndVerify,
+    },
+    Command {
+        label: "Next nonce",
+        shortcut: Some("→"),
+        action: Action::NextNonce,
+    },
+    Command {
+        label: "Previous nonce",
+        shortcut: Some("←"),
+        action: Action::PreviousNonce,
+    },
+    Command {
+        label: "Go to Verify Safe API",
+        shortcut: None,
+        action: Action::SwitchTab(Tab::VerifySafeApi),
+    },
+    Command {
+        label: "Go to Message",
+        shortcut: None,
+        action: Action::SwitchTab(Tab::Message),
+    },
+    Command {
+        label: "Go to EIP-712",
+        shortcut: None,
+        action: Action::SwitchTab(Tab::Eip712),
+    },
+    Command {
+        label: "Go to Offline",
+        shortcut: None,
+        action: Action::SwitchTab(Tab::Offline),
+    },
+    Command {
+        label: "Go to Bulk Audit",
+        shortcut: None,
+        action: Action::SwitchTab(Tab::Audit),
+    },
+    Command {
+        label: "Go to History",
+        shortcut: None,
+        action: Action::SwitchTab(Tab::History),
+    },
+    Command {
+        label: "Fetch Safe details",
+        shortcut: None,
+        action: Action::FetchSafeDetails,
+    },
+    Command {
+        label: "Toggle address book",
+        shortcut: None,
+        action: Action::ToggleAddressBook,
+    },
+];
+
+/// Shortcut pressed this frame, if any. Enter and the arrows only act on the
+/// Verify tab with no widget focused, so they never press a focused button
+/// or steal keyboard navigation; when they do act they're consumed.
+pub fn read(ctx: &egui::Context, active_tab: Tab) -> Option<Action> {
+    if ctx.wants_keyboard_input() {
+        return None;
+    }
+    let nonce_keys = active_tab == Tab::VerifySafeApi && ctx.memory(|m| m.focused().is_none());
+    ctx.input_mut(|i| {
+        if i.consume_key(egui::Modifiers::COMMAND, egui::Key::K) {
+            Some(Action::OpenPalette)
+        } else if !nonce_keys {
+            None
+        } else if i.consume_key(egui::Modifiers::NONE, egui::Key::Enter) {
+            Some(Action::FetchAndVerify)
+        } else if i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowRight) {
+            Some(Action::NextNonce)
+        } else if i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowLeft) {
+            Some(Action::PreviousNonce)
+        } else {
+            None
+        }
+    })
+}
+
+/// Case-insensitive match of every query word against the label
+fn matches(query: &str, label: &str) -> bool {
+    let label = label.to_lowercase();
+    query
+        .to_lowercase()
+        .split_whitespace()
+        .all(|word| label.contains(word))
+}
+
+/// Command palette UI state
+#[derive(Default)]
+pub struct Palette {
+    pub open: bool,
+    query: String,
+    selected: usize,
+}
+
+impl Palette {
+    pub fn open(&mut self) {
+        self.open = true;
+        self.query.clear();
+        self.selected = 0;
+    }
+
+    /// Show the palette if open. Returns the chosen action, closing it.
+    pub fn show(&mut self, ctx: &egui::Context) -> Option<Action> {
+        if !self.open {
+            return None;
+        }
+
+        let filtered: Vec<&Command> = COMMANDS
+            .iter()
+            .filter(|c| matches(&self.query, c.label))
+            .collect();
+        self.selected = self.selected.min(filtered.len().saturating_sub(1));
+
+        // Navigation keys are taken before the query field sees them
+        let (up, down, enter, escape) = ctx.input_mut(|i| {
+            (
+                i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowUp),
+                i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowDown),
+                i.consume_key(egui::Modifiers::NONE, egui::Key::Enter),
+                i.consume_key(egui::Modifiers::NONE, egui::Key::Escape),
+            )
+        });
+        if escape {
+            self.open = false;
+            return None;
+        }
+        if up {
+            self.selected = self.selected.saturating_sub(1);
+        }
+        if down && self.selected + 1 < filtered.len() {
+            self.selected += 1;
+        }
+        let mut chosen = if enter {
+            filtered.get(self.selected).map(|c| c.action)
+        } else {
+            None
+        };
+
+        let mut open = self.open;
+        egui::Window::new("Command palette")
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_TOP, [0.0, 80.0])
+            .fixed_size([360.0, 0.0])
+            .show(ctx, |ui| {
+                let response = ui.add(
+                    egui::TextEdit::singleline(&mut self.query)
+                        .hint_text("Type a command…")
+                        .desired_width(f32::INFINITY),
+                );
+                response.request_focus();
+                if response.changed() {
+                    self.selected = 0;
+                }
+                ui.add_space(6.0);
+
+                if filtered.is_empty() {
+                    ui.label(egui::RichText::new("No matching command").weak());
+                }
+                for (i, command) in filtered.iter().enumerate() {
+                    ui.horizontal(|ui| {
+                        if ui
+                            .selectable_label(i == self.selected, command.label)
+                            .clicked()
+                        {
+                            chosen = Some(command.action);
+                        }
+                        if let Some(shortcut) = command.shortcut {
+                            ui.with_layout(
+                                egui::Layout::right_to_left(egui::Align::Center),
+                                |ui| {
+                                    ui.label(egui::RichText::new(shortcut).weak().monospace());
+                                },
+                            );
+                        }
+                    });
+                }
+            });
+
+        self.open = open && chosen.is_none();
+        chosen
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches() {
+        assert!(matches("", "Fetch & Verify"));
+        assert!(matches("verify fetch", "Fetch & Verify"));
+        assert!(matches("OFFLINE", "Go to Offline"));
+        assert!(!matches("audit", "Go to Offline"));
+    }
+}
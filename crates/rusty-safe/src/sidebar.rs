@@ -1,15 +1,23 @@
 //! Sidebar component for Safe context (chain, address, version, info)
 
-use crate::hasher::SafeInfo;
-use crate::state::{SafeContext, SidebarState, SAFE_VERSIONS};
+use crate::hasher::{
+    chain_id_of, default_safe_api_url, is_custom_chain, validate_custom_chain,
+    validate_service_url, SafeInfo,
+};
+use crate::state::{
+    ChainScan, RiskLevel, SafeContext, SavedSafe, SidebarState, WarningKind, SAFE_VERSIONS,
+};
 use crate::ui;
 use eframe::egui;
-use safe_utils::Of;
 
 /// Sidebar action returned after rendering
 pub enum SidebarAction {
     None,
     FetchDetails,
+    /// Infer the version from the Safe's singleton
+    DetectVersion,
+    /// Look the address up on the configured chains
+    ScanChains,
     ClearStorage,
 }
 
@@ -21,6 +29,7 @@ pub fn render(
     safe_info: &Option<SafeInfo>,
     safe_info_loading: bool,
     chain_names: &[String],
+    chain_scan: &ChainScan,
 ) -> SidebarAction {
     let mut action = SidebarAction::None;
 
@@ -56,7 +65,7 @@ pub fn render(
                                 if ui.add(
                                     egui::Button::new(egui::RichText::new("🗑 Delete Data").size(14.0))
                                         .frame(false)
-                                ).on_hover_text("Clear cached data").clicked() {
+                                ).on_hover_text("Clear cached data. The audit log is kept; clear it from the History tab").clicked() {
                                     action = SidebarAction::ClearStorage;
                                 }
                             });
@@ -136,6 +145,15 @@ pub fn render(
                 ui.separator();
                 ui.add_space(12.0);
 
+                // Saved data this build couldn't read
+                for issue in &safe_ctx.load_issues {
+                    ui.label(
+                        egui::RichText::new(format!("⚠️ {}", issue.message))
+                            .color(egui::Color32::from_rgb(220, 180, 50)),
+                    );
+                    ui.add_space(6.0);
+                }
+
                 // Chain selection
                 ui.horizontal(|ui| {
                     ui.label(egui::RichText::new("Chain:").strong());
@@ -150,8 +168,68 @@ pub fn render(
                                     chain_name,
                                 );
                             }
+                            let mut custom: Vec<String> = safe_ctx
+                                .service_overrides
+                                .keys()
+                                .filter(|name| is_custom_chain(name))
+                                .cloned()
+                                .collect();
+                            custom.sort_by_key(|name| chain_id_of(name).unwrap_or_default());
+                            for chain_name in custom {
+                                let label = format!("{} (custom)", chain_name);
+                                ui.selectable_value(&mut safe_ctx.chain_name, chain_name, label);
+                            }
                         });
                 });
+
+                egui::CollapsingHeader::new("Custom chain")
+                    .id_salt("custom_chain")
+                    .show(ui, |ui| {
+                        ui.label(
+                            egui::RichText::new(
+                                "For chains not in the list: hashes use this chain ID, fetches use this Transaction Service.",
+                            )
+                            .small()
+                            .weak(),
+                        );
+                        ui.horizontal(|ui| {
+                            ui.label("Chain ID");
+                            ui.add(
+                                egui::TextEdit::singleline(&mut sidebar.custom_chain_id_input)
+                                    .desired_width(100.0),
+                            );
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label("Service");
+                            ui.add(
+                                egui::TextEdit::singleline(&mut sidebar.custom_chain_url_input)
+                                    .hint_text("https://...")
+                                    .desired_width(150.0),
+                            );
+                        });
+                        if ui.button("Use chain").clicked() {
+                            match validate_custom_chain(
+                                &sidebar.custom_chain_id_input,
+                                &sidebar.custom_chain_url_input,
+                            ) {
+                                Ok((chain_name, url)) => {
+                                    safe_ctx.service_overrides.insert(chain_name.clone(), url);
+                                    safe_ctx.chain_name = chain_name;
+                                    sidebar.custom_chain_id_input.clear();
+                                    sidebar.custom_chain_url_input.clear();
+                                    sidebar.custom_chain_error = None;
+                                }
+                                Err(e) => sidebar.custom_chain_error = Some(e),
+                            }
+                        }
+                        if let Some(e) = &sidebar.custom_chain_error {
+                            ui.label(
+                                egui::RichText::new(e)
+                                    .small()
+                                    .color(egui::Color32::from_rgb(220, 80, 80)),
+                            );
+                        }
+                    });
                 ui.add_space(12.0);
                 
                 // Safe Address with recent suggestions
@@ -198,9 +276,8 @@ pub fn render(
                             egui::Frame::popup(ui.style())
                                 .show(ui, |ui| {
                                     ui.set_min_width(below_rect.width());
-                                    let chain_id = alloy::primitives::ChainId::of(&safe_ctx.chain_name).map(u64::from).unwrap_or(1);
                                     for addr in &safe_ctx.recent_addresses.clone() {
-                                        let name = safe_ctx.address_book.get_name(addr, chain_id);
+                                        let name = safe_ctx.name_of(addr);
                                         let label_text = if let Some(n) = name {
                                             format!("{} ({})", addr, n)
                                         } else {
@@ -260,8 +337,29 @@ pub fn render(
                                     );
                                 }
                             });
+
+                        let is_valid_address = safe_ctx.safe_address.starts_with("0x")
+                            && safe_ctx.safe_address.len() == 42;
+                        if ui.add_enabled(is_valid_address && !sidebar.version_detecting, egui::Button::new("🔎 Detect"))
+                            .on_hover_text("Detect the version from the Safe's singleton contract")
+                            .clicked()
+                        {
+                            action = SidebarAction::DetectVersion;
+                        }
+                        if sidebar.version_detecting {
+                            ui.spinner();
+                        }
                     }
                 });
+                match &sidebar.version_detection {
+                    Some(Ok(message)) => {
+                        ui.label(egui::RichText::new(message).weak().small());
+                    }
+                    Some(Err(e)) => {
+                        ui.label(egui::RichText::new(e).small().color(egui::Color32::from_rgb(220, 180, 50)));
+                    }
+                    None => {}
+                }
                 ui.add_space(16.0);
 
                 // Fetch Details button - more prominent
@@ -302,8 +400,7 @@ pub fn render(
                             let addr = format!("{:?}", owner);
                             ui.horizontal(|ui| {
                                 // ui.label("└");
-                                let chain_id = alloy::primitives::ChainId::of(&safe_ctx.chain_name).unwrap_or(1);
-                                let name = safe_ctx.address_book.get_name(&addr, chain_id);
+                                let name = safe_ctx.name_of(&addr);
                                 ui::address_link(ui, &safe_ctx.chain_name, &addr, name);
                             });
                         }
@@ -320,8 +417,7 @@ pub fn render(
                                 let addr = format!("{:?}", module);
                                 ui.horizontal(|ui| {
                                     // ui.label("└");
-                                    let chain_id = alloy::primitives::ChainId::of(&safe_ctx.chain_name).unwrap_or(1);
-                                    let name = safe_ctx.address_book.get_name(&addr, chain_id);
+                                    let name = safe_ctx.name_of(&addr);
                                     ui::address_link(ui, &safe_ctx.chain_name, &addr, name);
                                 });
                             }
@@ -334,6 +430,309 @@ pub fn render(
                         ui.label(format!("{}", info.nonce));
                     });
                 }
+
+                ui.add_space(10.0);
+                ui.separator();
+                egui::CollapsingHeader::new(egui::RichText::new("⭐ Saved Safes").strong())
+                    .show(ui, |ui| {
+                        let mut remove = None;
+                        for (i, saved) in safe_ctx.saved_safes.safes.iter().enumerate() {
+                            ui.horizontal(|ui| {
+                                let current = saved.chain == safe_ctx.chain_name
+                                    && saved.address.eq_ignore_ascii_case(safe_ctx.safe_address.trim());
+                                if ui.selectable_label(current, &saved.label)
+                                    .on_hover_text(format!(
+                                        "{} on {} (v{})",
+                                        saved.address, saved.chain, saved.version
+                                    ))
+                                    .clicked()
+                                {
+                                    safe_ctx.chain_name = saved.chain.clone();
+                                    safe_ctx.safe_address = saved.address.clone();
+                                    safe_ctx.safe_version = saved.version.clone();
+                                }
+                                ui.label(egui::RichText::new(&saved.chain).small().weak());
+                                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                    if ui.small_button("✕").on_hover_text("Remove").clicked() {
+                                        remove = Some(i);
+                                    }
+                                });
+                            });
+                        }
+                        if let Some(i) = remove {
+                            safe_ctx.saved_safes.safes.remove(i);
+                        }
+                        if safe_ctx.saved_safes.safes.is_empty() {
+                            ui.label(egui::RichText::new("No saved Safes yet").weak());
+                        }
+
+                        ui.add_space(4.0);
+                        ui.horizontal(|ui| {
+                            ui.add(
+                                egui::TextEdit::singleline(&mut sidebar.saved_safe_label_input)
+                                    .hint_text("Label")
+                                    .desired_width(120.0),
+                            );
+                            let can_save = is_valid_address
+                                && !sidebar.saved_safe_label_input.trim().is_empty();
+                            if ui.add_enabled(can_save, egui::Button::new("Save current"))
+                                .on_hover_text("Save the selected chain, address and version")
+                                .clicked()
+                            {
+                                safe_ctx.saved_safes.save(SavedSafe {
+                                    label: sidebar.saved_safe_label_input.trim().to_string(),
+                                    chain: safe_ctx.chain_name.clone(),
+                                    address: safe_ctx.safe_address.trim().to_string(),
+                                    version: safe_ctx.safe_version.clone(),
+                                });
+                                sidebar.saved_safe_label_input.clear();
+                            }
+                        });
+
+                        egui::CollapsingHeader::new("Import / export")
+                            .id_salt("saved_safes_transfer")
+                            .show(ui, |ui| {
+                                if ui.button("📋 Copy JSON")
+                                    .on_hover_text("Copy the saved Safes to paste into another machine")
+                                    .clicked()
+                                {
+                                    ui::copy_to_clipboard(&safe_ctx.saved_safes.export_json());
+                                    sidebar.saved_safes_message =
+                                        Some(Ok("Saved Safes copied".to_string()));
+                                }
+                                ui.add_space(4.0);
+                                ui::multiline_input(
+                                    ui,
+                                    &mut sidebar.saved_safes_import_text,
+                                    "Paste exported JSON",
+                                    3,
+                                );
+                                let can_import = !sidebar.saved_safes_import_text.trim().is_empty();
+                                if ui.add_enabled(can_import, egui::Button::new("📥 Import")).clicked() {
+                                    sidebar.saved_safes_message = Some(
+                                        safe_ctx
+                                            .saved_safes
+                                            .import_json(&sidebar.saved_safes_import_text)
+                                            .map(|(count, skipped)| {
+                                                if skipped > 0 {
+                                                    format!("Imported {}, skipped {} invalid", count, skipped)
+                                                } else {
+                                                    format!("Imported {}", count)
+                                                }
+                                            }),
+                                    );
+                                    if matches!(sidebar.saved_safes_message, Some(Ok(_))) {
+                                        sidebar.saved_safes_import_text.clear();
+                                    }
+                                }
+                                match &sidebar.saved_safes_message {
+                                    Some(Ok(message)) => {
+                                        ui.label(egui::RichText::new(message).small().weak());
+                                    }
+                                    Some(Err(e)) => {
+                                        ui.label(
+                                            egui::RichText::new(e)
+                                                .small()
+                                                .color(egui::Color32::from_rgb(220, 80, 80)),
+                                        );
+                                    }
+                                    None => {}
+                                }
+                            });
+                    });
+
+                egui::CollapsingHeader::new(egui::RichText::new("🌐 Other Chains").strong())
+                    .show(ui, |ui| {
+                        ui.horizontal(|ui| {
+                            let button = egui::Button::new("🔍 Find on other chains");
+                            if ui.add_enabled(is_valid_address && chain_scan.pending == 0, button)
+                                .on_hover_text("Check which of the selected chains this address is a Safe on")
+                                .clicked()
+                            {
+                                action = SidebarAction::ScanChains;
+                            }
+                            if chain_scan.pending > 0 {
+                                ui.spinner();
+                                ui.label(egui::RichText::new(format!("{} left", chain_scan.pending)).weak());
+                            }
+                        });
+
+                        let scanned_current = !chain_scan.address.is_empty()
+                            && chain_scan.address.eq_ignore_ascii_case(safe_ctx.safe_address.trim());
+                        if scanned_current {
+                            ui.add_space(4.0);
+                            for presence in &chain_scan.found {
+                                ui.horizontal(|ui| {
+                                    let current = presence.chain_name == safe_ctx.chain_name;
+                                    if ui.selectable_label(current, &presence.chain_name)
+                                        .on_hover_text("Switch to this chain")
+                                        .clicked()
+                                    {
+                                        safe_ctx.chain_name = presence.chain_name.clone();
+                                    }
+                                    ui.label(
+                                        egui::RichText::new(format!(
+                                            "nonce {} · {}/{} · v{}",
+                                            presence.nonce, presence.threshold, presence.owners, presence.version
+                                        ))
+                                        .small(),
+                                    );
+                                });
+                            }
+                            if chain_scan.pending == 0 && chain_scan.found.is_empty() {
+                                ui.label(egui::RichText::new("Not a Safe on any selected chain").weak());
+                            }
+                        }
+
+                        ui.add_space(4.0);
+                        egui::CollapsingHeader::new("Chains to check")
+                            .id_salt("chain_scan_chains")
+                            .show(ui, |ui| {
+                                for chain_name in chain_names {
+                                    let selected = &mut safe_ctx.chain_scan.chains;
+                                    let mut checked = selected.contains(chain_name);
+                                    if ui.checkbox(&mut checked, chain_name).changed() {
+                                        if checked {
+                                            selected.push(chain_name.clone());
+                                        } else {
+                                            selected.retain(|c| c != chain_name);
+                                        }
+                                    }
+                                }
+                            });
+                    });
+
+                egui::CollapsingHeader::new(egui::RichText::new("🔒 Clipboard Privacy").strong())
+                    .show(ui, |ui| {
+                        ui.checkbox(
+                            &mut safe_ctx.clipboard.confirm_before_copy,
+                            "Confirm before copying",
+                        );
+                        ui.horizontal(|ui| {
+                            ui.label("Clear clipboard after");
+                            ui.add(
+                                egui::DragValue::new(&mut safe_ctx.clipboard.clear_after_secs)
+                                    .range(0..=600)
+                                    .suffix(" s"),
+                            );
+                        });
+                        ui.label(
+                            egui::RichText::new(
+                                "0 = never. Clearing overwrites whatever is on the clipboard at that moment.",
+                            )
+                            .small()
+                            .weak(),
+                        );
+                    });
+
+                egui::CollapsingHeader::new(egui::RichText::new("🚦 Warning Policy").strong())
+                    .show(ui, |ui| {
+                        egui::Grid::new("warning_policy")
+                            .num_columns(2)
+                            .spacing([8.0, 4.0])
+                            .show(ui, |ui| {
+                                for kind in WarningKind::ALL {
+                                    ui.label(kind.label());
+                                    let level = safe_ctx.warning_policy.level_mut(kind);
+                                    egui::ComboBox::from_id_salt(("warning_policy", kind.label()))
+                                        .selected_text(level.label())
+                                        .width(70.0)
+                                        .show_ui(ui, |ui| {
+                                            for option in RiskLevel::ALL {
+                                                ui.selectable_value(level, option, option.label());
+                                            }
+                                        });
+                                    ui.end_row();
+                                }
+                            });
+                        ui.label(
+                            egui::RichText::new(
+                                "BLOCK disables hash copy buttons until the warnings are acknowledged.",
+                            )
+                            .small()
+                            .weak(),
+                        );
+                    });
+
+                egui::CollapsingHeader::new(egui::RichText::new("🌐 Safe API").strong())
+                    .show(ui, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.label("Retries");
+                            ui.add(
+                                egui::DragValue::new(&mut safe_ctx.api.max_retries).range(0..=10),
+                            );
+                        });
+                        ui.label(
+                            egui::RichText::new(
+                                "On rate limiting (429) and server errors, with backoff. Honors Retry-After.",
+                            )
+                            .small()
+                            .weak(),
+                        );
+
+                        ui.add_space(8.0);
+                        let chain = safe_ctx.chain_name.clone();
+                        ui.label(format!("Transaction Service for {}", chain));
+                        match safe_ctx.service_overrides.get(&chain) {
+                            Some(url) => {
+                                ui.label(egui::RichText::new(url).monospace().small());
+                            }
+                            None => {
+                                let default = default_safe_api_url(&chain).unwrap_or_default();
+                                ui.label(
+                                    egui::RichText::new(format!("{} (default)", default))
+                                        .monospace()
+                                        .small()
+                                        .weak(),
+                                );
+                            }
+                        }
+                        ui.horizontal(|ui| {
+                            ui.add(
+                                egui::TextEdit::singleline(&mut sidebar.service_url_input)
+                                    .hint_text("https://...")
+                                    .desired_width(150.0),
+                            );
+                            if ui.button("Set").clicked() {
+                                match validate_service_url(&sidebar.service_url_input) {
+                                    Ok(url) => {
+                                        safe_ctx.service_overrides.insert(chain.clone(), url);
+                                        sidebar.service_url_input.clear();
+                                        sidebar.service_url_error = None;
+                                    }
+                                    Err(e) => sidebar.service_url_error = Some(e),
+                                }
+                            }
+                            if safe_ctx.service_overrides.contains_key(&chain)
+                                && ui.button("Reset").on_hover_text("Use the default host").clicked()
+                            {
+                                safe_ctx.service_overrides.remove(&chain);
+                            }
+                        });
+                        if let Some(e) = &sidebar.service_url_error {
+                            ui.label(
+                                egui::RichText::new(e)
+                                    .small()
+                                    .color(egui::Color32::from_rgb(220, 80, 80)),
+                            );
+                        }
+
+                        ui.add_space(8.0);
+                        ui.label("Etherscan API key");
+                        ui.add(
+                            egui::TextEdit::singleline(&mut safe_ctx.api.explorer_api_key)
+                                .password(true)
+                                .hint_text("optional")
+                                .desired_width(150.0),
+                        );
+                        ui.label(
+                            egui::RichText::new(
+                                "Also decodes with the target's verified ABI and flags disagreements.",
+                            )
+                            .small()
+                            .weak(),
+                        );
+                    });
                 
                 ui.add_space(20.0);
             });
@@ -6,10 +6,25 @@
 //! Storage is handled via eframe's built-in persistence (works on both WASM and native).
 
 use crate::api::SafeTransaction;
-use crate::decode::DecodedTransaction;
+use crate::counterparty::CounterpartyHistory;
+use crate::decode::{
+    classify_nested_safe, classify_relay, detect_admin_calls, detect_approvals,
+    detect_offline_admin_calls, detect_offline_approvals, detect_permits, parser, AdminCall,
+    Approval, DecodedTransaction, NestedDelegatecall, NestedSafeTx, OfflineDecodeResult,
+    PermitInfo, RelayCall, WebUiDecode,
+};
 use crate::expected::ExpectedState;
-use safe_hash::SafeWarnings;
+use crate::export::{AttestationCheck, DiagnosticOptions};
+use crate::hasher::{
+    calls_self, chain_id_of, detect_config_changes, detect_owner_management, ConfigChange,
+    HashingInputs, NonceStatus, OwnerManagement, ProposalInfo, SafePresence, DEFAULT_MAX_RETRIES,
+};
+use crate::persist::{self, LoadIssue, Schema};
+use crate::roster::{self, BatchPayments, Reconciliation, RosterEntry, RosterState};
+use crate::trust;
+use safe_hash::{Mismatch, SafeWarnings};
 use safe_utils::get_all_supported_chain_names;
+use std::collections::HashMap;
 
 /// Storage key for cached Safe address
 const SAFE_ADDRESS_KEY: &str = "safe_address";
@@ -17,8 +32,72 @@ const SAFE_ADDRESS_KEY: &str = "safe_address";
 const RECENT_ADDRESSES_KEY: &str = "recent_addresses";
 /// Storage key for address book
 const ADDRESS_BOOK_KEY: &str = "address_book";
+/// Storage key for clipboard privacy settings
+const CLIPBOARD_SETTINGS_KEY: &str = "clipboard_settings";
+/// Storage key for the multi-chain lookup chain set
+const CHAIN_SCAN_SETTINGS_KEY: &str = "chain_scan_settings";
+/// Storage key for Safe API request settings
+const API_SETTINGS_KEY: &str = "api_settings";
+/// Storage key for per-chain Transaction Service URLs
+const SERVICE_OVERRIDES_KEY: &str = "service_overrides";
+/// Storage key for warning severity levels
+const WARNING_POLICY_KEY: &str = "warning_policy";
+/// Storage key for the saved Safes list
+const SAVED_SAFES_KEY: &str = "saved_safes";
+/// Storage key for the verification audit log
+const AUDIT_LOG_KEY: &str = "audit_log";
+/// Schemas of the persisted blobs. When a format changes, bump its version
+/// and append a migration from the previous one.
+const RECENT_ADDRESSES_SCHEMA: Schema = Schema {
+    key: RECENT_ADDRESSES_KEY,
+    version: 1,
+    migrations: &[persist::unchanged],
+};
+const ADDRESS_BOOK_SCHEMA: Schema = Schema {
+    key: ADDRESS_BOOK_KEY,
+    version: 1,
+    migrations: &[persist::unchanged],
+};
+const CLIPBOARD_SETTINGS_SCHEMA: Schema = Schema {
+    key: CLIPBOARD_SETTINGS_KEY,
+    version: 1,
+    migrations: &[persist::unchanged],
+};
+const CHAIN_SCAN_SETTINGS_SCHEMA: Schema = Schema {
+    key: CHAIN_SCAN_SETTINGS_KEY,
+    version: 1,
+    migrations: &[persist::unchanged],
+};
+const API_SETTINGS_SCHEMA: Schema = Schema {
+    key: API_SETTINGS_KEY,
+    version: 1,
+    migrations: &[persist::unchanged],
+};
+const SERVICE_OVERRIDES_SCHEMA: Schema = Schema {
+    key: SERVICE_OVERRIDES_KEY,
+    version: 1,
+    migrations: &[persist::unchanged],
+};
+const WARNING_POLICY_SCHEMA: Schema = Schema {
+    key: WARNING_POLICY_KEY,
+    version: 1,
+    migrations: &[persist::unchanged],
+};
+const SAVED_SAFES_SCHEMA: Schema = Schema {
+    key: SAVED_SAFES_KEY,
+    version: 1,
+    migrations: &[persist::unchanged],
+};
+const AUDIT_LOG_SCHEMA: Schema = Schema {
+    key: AUDIT_LOG_KEY,
+    version: 1,
+    migrations: &[persist::unchanged],
+};
 /// Max recent addresses to keep
 const MAX_RECENT_ADDRESSES: usize = 10;
+/// Max audit log entries to keep; the oldest are dropped first so the log
+/// stays well inside the browser's storage quota
+const MAX_AUDIT_LOG_ENTRIES: usize = 1_000;
 
 /// Add address to recent list (most recent first, deduped, capped)
 pub fn add_recent_address(addresses: &mut Vec<String>, address: &str) {
@@ -61,7 +140,356 @@ pub struct SafeContext {
     pub safe_address: String,
     pub safe_version: String,
     pub recent_addresses: Vec<String>,
+    pub saved_safes: SavedSafes,
     pub address_book: AddressBook,
+    pub clipboard: ClipboardSettings,
+    pub chain_scan: ChainScanSettings,
+    pub api: ApiSettings,
+    /// Transaction Service base URL by chain name, replacing the built-in
+    /// host for self-hosted or regional deployments
+    pub service_overrides: HashMap<String, String>,
+    pub warning_policy: WarningPolicy,
+    pub audit_log: AuditLog,
+    /// Saved blobs that couldn't be loaded; these aren't written back
+    pub load_issues: Vec<LoadIssue>,
+}
+
+/// Clipboard privacy settings, applied by every copy button. Off by default.
+#[derive(Debug, Clone, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+pub struct ClipboardSettings {
+    /// Ask before writing to the clipboard
+    pub confirm_before_copy: bool,
+    /// Overwrite the clipboard this many seconds after a copy (0 = never)
+    pub clear_after_secs: u32,
+}
+
+/// Safe API request settings
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ApiSettings {
+    /// Retries on rate limiting (429) and server errors before giving up
+    pub max_retries: u32,
+    /// Etherscan API key for the verified-ABI decode (empty = off). Stored
+    /// locally only.
+    #[serde(default)]
+    pub explorer_api_key: String,
+}
+
+impl Default for ApiSettings {
+    fn default() -> Self {
+        Self {
+            max_retries: DEFAULT_MAX_RETRIES,
+            explorer_api_key: String::new(),
+        }
+    }
+}
+
+/// How seriously a team treats a kind of warning, lowest first
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+pub enum RiskLevel {
+    Info,
+    Warn,
+    /// Copying hashes for signing needs an explicit override
+    Block,
+}
+
+impl RiskLevel {
+    pub const ALL: [RiskLevel; 3] = [RiskLevel::Info, RiskLevel::Warn, RiskLevel::Block];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            RiskLevel::Info => "INFO",
+            RiskLevel::Warn => "WARN",
+            RiskLevel::Block => "BLOCK",
+        }
+    }
+}
+
+/// Warnings the policy assigns a level to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WarningKind {
+    Delegatecall,
+    NonZeroGasToken,
+    NonZeroRefundReceiver,
+    DangerousMethods,
+    ArgumentMismatch,
+    UnlimitedApproval,
+    /// Singleton, guard, fallback handler or module change
+    ConfigChange,
+    /// The Safe calls itself
+    SelfCall,
+}
+
+impl WarningKind {
+    pub const ALL: [WarningKind; 8] = [
+        WarningKind::Delegatecall,
+        WarningKind::NonZeroGasToken,
+        WarningKind::NonZeroRefundReceiver,
+        WarningKind::DangerousMethods,
+        WarningKind::ArgumentMismatch,
+        WarningKind::UnlimitedApproval,
+        WarningKind::ConfigChange,
+        WarningKind::SelfCall,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            WarningKind::Delegatecall => "delegatecall",
+            WarningKind::NonZeroGasToken => "gas token",
+            WarningKind::NonZeroRefundReceiver => "refund receiver",
+            WarningKind::DangerousMethods => "owner/threshold change",
+            WarningKind::ArgumentMismatch => "API mismatch",
+            WarningKind::UnlimitedApproval => "unlimited approval",
+            WarningKind::ConfigChange => "module/guard/singleton change",
+            WarningKind::SelfCall => "self-call",
+        }
+    }
+
+    /// Kinds raised by `warnings` and by the checks that live outside
+    /// `SafeWarnings`. Delegatecalls are judged by `flags` alone, so a
+    /// trusted MultiSend's batch is looked into rather than waved through.
+    pub fn active(warnings: &SafeWarnings, flags: &RiskFlags) -> Vec<WarningKind> {
+        let raised = [
+            flags.delegatecall,
+            warnings.non_zero_gas_token,
+            warnings.non_zero_refund_receiver,
+            warnings.dangerous_methods || flags.owner_change,
+            !warnings.argument_mismatches.is_empty(),
+            flags.unlimited_approval,
+            flags.config_change,
+            flags.self_call,
+        ];
+        Self::ALL
+            .into_iter()
+            .zip(raised)
+            .filter_map(|(kind, raised)| raised.then_some(kind))
+            .collect()
+    }
+}
+
+/// Findings outside `SafeWarnings` that the policy weighs
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RiskFlags {
+    /// A non-call operation to a target not meant to be delegatecalled, at
+    /// the top level or inside a batch
+    pub delegatecall: bool,
+    pub unlimited_approval: bool,
+    /// Owner or threshold change the Safe makes on itself
+    pub owner_change: bool,
+    pub config_change: bool,
+    pub self_call: bool,
+}
+
+/// Level assigned to each warning kind
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct WarningPolicy {
+    pub delegatecall: RiskLevel,
+    pub non_zero_gas_token: RiskLevel,
+    pub non_zero_refund_receiver: RiskLevel,
+    pub dangerous_methods: RiskLevel,
+    pub argument_mismatch: RiskLevel,
+    pub unlimited_approval: RiskLevel,
+    pub config_change: RiskLevel,
+    pub self_call: RiskLevel,
+}
+
+impl Default for WarningPolicy {
+    fn default() -> Self {
+        Self {
+            delegatecall: RiskLevel::Block,
+            non_zero_gas_token: RiskLevel::Warn,
+            non_zero_refund_receiver: RiskLevel::Warn,
+            dangerous_methods: RiskLevel::Warn,
+            argument_mismatch: RiskLevel::Block,
+            unlimited_approval: RiskLevel::Warn,
+            config_change: RiskLevel::Block,
+            self_call: RiskLevel::Warn,
+        }
+    }
+}
+
+/// Aggregate risk of a result: the highest level raised and the kinds at it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RiskSummary {
+    pub level: RiskLevel,
+    pub kinds: Vec<WarningKind>,
+}
+
+impl RiskSummary {
+    /// Banner text, e.g. "RISK: BLOCK — delegatecall + unlimited approval"
+    pub fn headline(&self) -> String {
+        let kinds: Vec<&str> = self.kinds.iter().map(|k| k.label()).collect();
+        format!("RISK: {} — {}", self.level.label(), kinds.join(" + "))
+    }
+
+    /// Whether copying hashes for signing needs an explicit override
+    pub fn blocks_copy(&self) -> bool {
+        self.level >= RiskLevel::Block
+    }
+}
+
+impl WarningPolicy {
+    pub fn level_mut(&mut self, kind: WarningKind) -> &mut RiskLevel {
+        match kind {
+            WarningKind::Delegatecall => &mut self.delegatecall,
+            WarningKind::NonZeroGasToken => &mut self.non_zero_gas_token,
+            WarningKind::NonZeroRefundReceiver => &mut self.non_zero_refund_receiver,
+            WarningKind::DangerousMethods => &mut self.dangerous_methods,
+            WarningKind::ArgumentMismatch => &mut self.argument_mismatch,
+            WarningKind::UnlimitedApproval => &mut self.unlimited_approval,
+            WarningKind::ConfigChange => &mut self.config_change,
+            WarningKind::SelfCall => &mut self.self_call,
+        }
+    }
+
+    pub fn level(&self, kind: WarningKind) -> RiskLevel {
+        match kind {
+            WarningKind::Delegatecall => self.delegatecall,
+            WarningKind::NonZeroGasToken => self.non_zero_gas_token,
+            WarningKind::NonZeroRefundReceiver => self.non_zero_refund_receiver,
+            WarningKind::DangerousMethods => self.dangerous_methods,
+            WarningKind::ArgumentMismatch => self.argument_mismatch,
+            WarningKind::UnlimitedApproval => self.unlimited_approval,
+            WarningKind::ConfigChange => self.config_change,
+            WarningKind::SelfCall => self.self_call,
+        }
+    }
+
+    /// Aggregate the raised kinds, `None` when nothing is raised
+    pub fn assess(&self, kinds: &[WarningKind]) -> Option<RiskSummary> {
+        let level = kinds.iter().map(|&k| self.level(k)).max()?;
+        Some(RiskSummary {
+            level,
+            kinds: kinds
+                .iter()
+                .copied()
+                .filter(|&k| self.level(k) == level)
+                .collect(),
+        })
+    }
+}
+
+/// One Verify-tab verification as recorded in the audit log
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditLogEntry {
+    /// Unix seconds
+    pub timestamp: u64,
+    pub chain: String,
+    pub safe_address: String,
+    pub nonce: u64,
+    /// Locally computed safeTxHash, empty when hashing failed
+    pub safe_tx_hash: String,
+    /// Whether it matched the Transaction Service's hash; `None` when there
+    /// was nothing to compare against
+    pub matches_api: Option<bool>,
+    /// Highest warning level raised under the policy in force at the time
+    pub top_severity: Option<RiskLevel>,
+}
+
+impl AuditLogEntry {
+    /// Timestamp as `YYYY-MM-DD HH:MM:SS UTC`
+    pub fn time_utc(&self) -> String {
+        crate::ui::format_utc(self.timestamp)
+    }
+}
+
+/// Local record of verifications, keeping the newest
+/// `MAX_AUDIT_LOG_ENTRIES`. Entries from earlier sessions are never edited;
+/// Delete Data leaves the log in place and only the History tab's confirmed
+/// Clear removes it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AuditLog {
+    entries: Vec<AuditLogEntry>,
+    /// How many of the newest entries were recorded this session
+    #[serde(skip)]
+    session_entries: usize,
+}
+
+impl AuditLog {
+    pub fn append(&mut self, entry: AuditLogEntry) {
+        self.entries.push(entry);
+        self.session_entries += 1;
+        if self.entries.len() > MAX_AUDIT_LOG_ENTRIES {
+            let excess = self.entries.len() - MAX_AUDIT_LOG_ENTRIES;
+            self.entries.drain(..excess);
+        }
+        self.session_entries = self.session_entries.min(self.entries.len());
+    }
+
+    /// Record a verification. Verifying the same transaction again in this
+    /// session updates its entry instead of appending a duplicate.
+    pub fn record(&mut self, entry: AuditLogEntry) {
+        let session_start = self.entries.len() - self.session_entries;
+        let existing = self.entries[session_start..].iter_mut().find(|e| {
+            e.chain == entry.chain
+                && e.safe_address.eq_ignore_ascii_case(&entry.safe_address)
+                && e.nonce == entry.nonce
+                && e.safe_tx_hash == entry.safe_tx_hash
+        });
+        match existing {
+            Some(existing) => *existing = entry,
+            None => self.append(entry),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.session_entries = 0;
+    }
+
+    /// Entries, oldest first
+    pub fn entries(&self) -> &[AuditLogEntry] {
+        &self.entries
+    }
+
+    /// One JSON object per line, oldest first
+    pub fn to_jsonl(&self) -> String {
+        self.entries
+            .iter()
+            .filter_map(|entry| serde_json::to_string(entry).ok())
+            .map(|line| line + "\n")
+            .collect()
+    }
+}
+
+/// Chains checked by the multi-chain Safe lookup
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ChainScanSettings {
+    pub chains: Vec<String>,
+}
+
+impl Default for ChainScanSettings {
+    fn default() -> Self {
+        let chains = [
+            "ethereum",
+            "optimism",
+            "arbitrum",
+            "base",
+            "polygon",
+            "gnosis",
+            "bsc",
+            "avalanche",
+        ];
+        Self {
+            chains: chains.iter().map(|c| c.to_string()).collect(),
+        }
+    }
+}
+
+/// Progress of a multi-chain Safe lookup
+#[derive(Debug, Clone, Default)]
+pub struct ChainScan {
+    /// Bumped on every lookup so late results from an older one are dropped
+    pub run: u64,
+    /// Address being looked up
+    pub address: String,
+    /// Chains not yet answered
+    pub pending: usize,
+    /// Chains where the address is a Safe, in the configured order
+    pub found: Vec<SafePresence>,
 }
 
 /// Address book entry
@@ -70,6 +498,15 @@ pub struct AddressBookEntry {
     pub address: String,
     pub name: String,
     pub chain_id: u64,
+    /// Treated as a known-good contract by the trust registry
+    #[serde(default)]
+    pub trusted: bool,
+    /// A trusted MultiSend deployment: DELEGATECALLs to it are expected
+    #[serde(default)]
+    pub multisend: bool,
+    /// Token decimals, for showing amounts of this token
+    #[serde(default)]
+    pub decimals: Option<u8>,
 }
 
 /// Result of address validation
@@ -116,6 +553,67 @@ pub fn normalize_address(value: &str) -> Option<String> {
     }
 }
 
+/// A Safe the user manages, restored into the sidebar in one click
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SavedSafe {
+    pub label: String,
+    pub chain: String,
+    pub address: String,
+    pub version: String,
+}
+
+/// Saved Safes, one per chain and address, in the order they were saved
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SavedSafes {
+    pub safes: Vec<SavedSafe>,
+}
+
+impl SavedSafes {
+    /// Add a Safe, replacing the entry for the same chain and address
+    pub fn save(&mut self, safe: SavedSafe) {
+        match self
+            .safes
+            .iter_mut()
+            .find(|s| s.chain == safe.chain && s.address.eq_ignore_ascii_case(&safe.address))
+        {
+            Some(existing) => *existing = safe,
+            None => self.safes.push(safe),
+        }
+    }
+
+    /// Saved entry for `address` on `chain`
+    pub fn find(&self, chain: &str, address: &str) -> Option<&SavedSafe> {
+        self.safes
+            .iter()
+            .find(|s| s.chain == chain && s.address.eq_ignore_ascii_case(address.trim()))
+    }
+
+    /// The list as JSON, for moving it to another machine
+    pub fn export_json(&self) -> String {
+        serde_json::to_string_pretty(&self.safes).unwrap_or_default()
+    }
+
+    /// Merge an exported list. Returns (imported, skipped); entries with an
+    /// invalid address or unknown version are skipped.
+    pub fn import_json(&mut self, json: &str) -> Result<(usize, usize), String> {
+        let safes: Vec<SavedSafe> =
+            serde_json::from_str(json.trim()).map_err(|e| format!("Invalid JSON: {}", e))?;
+        let mut count = 0;
+        let mut skipped = 0;
+        for mut safe in safes {
+            match normalize_address(safe.address.trim()) {
+                Some(address) if SAFE_VERSIONS.contains(&safe.version.as_str()) => {
+                    safe.address = address;
+                    self.save(safe);
+                    count += 1;
+                }
+                _ => skipped += 1,
+            }
+        }
+        Ok((count, skipped))
+    }
+}
+
 /// Address book collection
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 pub struct AddressBook {
@@ -131,6 +629,15 @@ impl AddressBook {
             .map(|e| e.name.clone())
     }
 
+    /// Decimals recorded for a token, if any
+    pub fn decimals(&self, address: &str, chain_id: u64) -> Option<u8> {
+        let addr_lower = address.to_lowercase();
+        self.entries
+            .iter()
+            .find(|e| e.address.to_lowercase() == addr_lower && e.chain_id == chain_id)
+            .and_then(|e| e.decimals)
+    }
+
     pub fn add_or_update(&mut self, mut entry: AddressBookEntry) {
         // Normalize address
         if let Some(normalized) = normalize_address(&entry.address) {
@@ -144,6 +651,9 @@ impl AddressBook {
             .find(|e| e.address.to_lowercase() == addr_lower && e.chain_id == entry.chain_id)
         {
             existing.name = entry.name;
+            if entry.decimals.is_some() {
+                existing.decimals = entry.decimals;
+            }
         } else {
             self.entries.push(entry);
         }
@@ -191,6 +701,9 @@ impl AddressBook {
                 address,
                 name,
                 chain_id,
+                trusted: false,
+                multisend: false,
+                decimals: None,
             });
             count += 1;
         }
@@ -220,46 +733,122 @@ impl SafeContext {
             .cloned()
             .unwrap_or_else(|| chains.first().cloned().unwrap_or_default());
 
-        let (safe_address, recent_addresses, address_book) = if let Some(storage) = storage {
-            let addr = storage.get_string(SAFE_ADDRESS_KEY).unwrap_or_default();
-            let recent: Vec<String> = storage
-                .get_string(RECENT_ADDRESSES_KEY)
-                .and_then(|s| serde_json::from_str(&s).ok())
-                .unwrap_or_default();
-            let book: AddressBook = storage
-                .get_string(ADDRESS_BOOK_KEY)
-                .and_then(|s| serde_json::from_str(&s).ok())
-                .unwrap_or_default();
-            (addr, recent, book)
-        } else {
-            (String::new(), Vec::new(), AddressBook::default())
-        };
+        let safe_address = storage
+            .and_then(|s| s.get_string(SAFE_ADDRESS_KEY))
+            .unwrap_or_default();
+        let mut load_issues = Vec::new();
+        let recent_addresses = load_or_default(&RECENT_ADDRESSES_SCHEMA, storage, &mut load_issues);
+        let saved_safes = load_or_default(&SAVED_SAFES_SCHEMA, storage, &mut load_issues);
+        let address_book = load_or_default(&ADDRESS_BOOK_SCHEMA, storage, &mut load_issues);
+        let clipboard = load_or_default(&CLIPBOARD_SETTINGS_SCHEMA, storage, &mut load_issues);
+        let chain_scan = load_or_default(&CHAIN_SCAN_SETTINGS_SCHEMA, storage, &mut load_issues);
+        let api = load_or_default(&API_SETTINGS_SCHEMA, storage, &mut load_issues);
+        let service_overrides =
+            load_or_default(&SERVICE_OVERRIDES_SCHEMA, storage, &mut load_issues);
+        let warning_policy = load_or_default(&WARNING_POLICY_SCHEMA, storage, &mut load_issues);
+        let audit_log = load_or_default(&AUDIT_LOG_SCHEMA, storage, &mut load_issues);
 
         Self {
             chain_name: default_chain,
             safe_address,
             safe_version: SAFE_VERSIONS[0].to_string(),
             recent_addresses,
+            saved_safes,
             address_book,
+            clipboard,
+            chain_scan,
+            api,
+            service_overrides,
+            warning_policy,
+            audit_log,
+            load_issues,
         }
     }
 
     /// Save SafeContext to eframe storage
     pub fn save(&self, storage: &mut dyn eframe::Storage) {
         storage.set_string(SAFE_ADDRESS_KEY, self.safe_address.clone());
-        if let Ok(json) = serde_json::to_string(&self.recent_addresses) {
-            storage.set_string(RECENT_ADDRESSES_KEY, json);
+        let writable = |schema: &Schema| !self.load_issues.iter().any(|i| i.key == schema.key);
+        if writable(&RECENT_ADDRESSES_SCHEMA) {
+            RECENT_ADDRESSES_SCHEMA.save(storage, &self.recent_addresses);
+        }
+        if writable(&SAVED_SAFES_SCHEMA) {
+            SAVED_SAFES_SCHEMA.save(storage, &self.saved_safes);
+        }
+        if writable(&ADDRESS_BOOK_SCHEMA) {
+            ADDRESS_BOOK_SCHEMA.save(storage, &self.address_book);
+        }
+        if writable(&CLIPBOARD_SETTINGS_SCHEMA) {
+            CLIPBOARD_SETTINGS_SCHEMA.save(storage, &self.clipboard);
+        }
+        if writable(&CHAIN_SCAN_SETTINGS_SCHEMA) {
+            CHAIN_SCAN_SETTINGS_SCHEMA.save(storage, &self.chain_scan);
         }
-        if let Ok(json) = serde_json::to_string(&self.address_book) {
-            storage.set_string(ADDRESS_BOOK_KEY, json);
+        if writable(&API_SETTINGS_SCHEMA) {
+            API_SETTINGS_SCHEMA.save(storage, &self.api);
+        }
+        if writable(&SERVICE_OVERRIDES_SCHEMA) {
+            SERVICE_OVERRIDES_SCHEMA.save(storage, &self.service_overrides);
+        }
+        if writable(&WARNING_POLICY_SCHEMA) {
+            WARNING_POLICY_SCHEMA.save(storage, &self.warning_policy);
+        }
+        if writable(&AUDIT_LOG_SCHEMA) {
+            AUDIT_LOG_SCHEMA.save(storage, &self.audit_log);
         }
     }
 
-    /// Clear all stored data
+    /// Chain ID of the selected chain, `None` if its name can't be resolved
+    pub fn chain_id(&self) -> Option<u64> {
+        chain_id_of(&self.chain_name).ok()
+    }
+
+    /// Address book name of `address` on the selected chain. A chain that
+    /// can't be resolved has no names, rather than borrowing mainnet's.
+    pub fn name_of(&self, address: &str) -> Option<String> {
+        self.address_book.get_name(address, self.chain_id()?)
+    }
+
+    /// Clear all stored data except the audit log
     pub fn clear(&mut self) {
         self.safe_address.clear();
         self.recent_addresses.clear();
+        self.saved_safes.safes.clear();
         self.address_book.entries.clear();
+        self.clipboard = ClipboardSettings::default();
+        self.chain_scan = ChainScanSettings::default();
+        self.api = ApiSettings::default();
+        self.service_overrides.clear();
+        self.warning_policy = WarningPolicy::default();
+        // Deleting is explicit, so unreadable blobs may now be overwritten.
+        // The audit log is kept, so an unreadable one stays protected too.
+        self.load_issues.retain(|issue| issue.key == AUDIT_LOG_KEY);
+    }
+
+    /// Remove every audit log entry. Also lets an unreadable saved log be
+    /// overwritten.
+    pub fn clear_audit_log(&mut self) {
+        self.audit_log.clear();
+        self.load_issues.retain(|issue| issue.key != AUDIT_LOG_KEY);
+    }
+}
+
+/// Load a blob, falling back to the default (and recording why) if the saved
+/// data can't be read
+fn load_or_default<T: serde::de::DeserializeOwned + Default>(
+    schema: &Schema,
+    storage: Option<&dyn eframe::Storage>,
+    issues: &mut Vec<LoadIssue>,
+) -> T {
+    match schema.load(storage) {
+        Ok(value) => value.unwrap_or_default(),
+        Err(message) => {
+            issues.push(LoadIssue {
+                key: schema.key,
+                message,
+            });
+            T::default()
+        }
     }
 }
 
@@ -273,6 +862,22 @@ impl Default for SafeContext {
 #[derive(Default)]
 pub struct SidebarState {
     pub collapsed: bool,
+    pub version_detecting: bool,
+    /// Outcome of the last version detection, for display
+    pub version_detection: Option<Result<String, String>>,
+    /// Transaction Service URL being entered for the selected chain
+    pub service_url_input: String,
+    pub service_url_error: Option<String>,
+    /// Custom chain being added: chain ID and Transaction Service URL
+    pub custom_chain_id_input: String,
+    pub custom_chain_url_input: String,
+    pub custom_chain_error: Option<String>,
+    /// Label for saving the current Safe
+    pub saved_safe_label_input: String,
+    /// Pasted saved-Safes JSON to import
+    pub saved_safes_import_text: String,
+    /// Outcome of the last saved-Safes import or export, for display
+    pub saved_safes_message: Option<Result<String, String>>,
 }
 
 // =============================================================================
@@ -283,16 +888,69 @@ pub struct SidebarState {
 #[derive(Default)]
 pub struct TxVerifyState {
     pub nonce: String,
+    /// Last nonce for "Fetch range" (fetches `nonce` through this)
+    pub range_end: String,
+    /// Pasted Safe{Wallet} share link
+    pub share_link_input: String,
+    /// Transaction a share link points at, fetched once the Safe info (and
+    /// so the version) has loaded
+    pub share_link_pending: Option<String>,
+    /// Linked transaction being fetched, selected once its nonce arrives
+    pub share_link_hash: Option<String>,
+    /// Pasted Transaction Service JSON for a single transaction
+    pub tx_json_input: String,
     pub expected: ExpectedState,
+    /// Expected payroll roster, reconciled against MultiSend batches
+    pub roster: RosterState,
     pub decode: Option<DecodedTransaction>,
+    /// API decode in Safe{Wallet} web UI form, for the reference view
+    pub web_ui_decode: Option<WebUiDecode>,
+    /// Show the web UI reference view next to our decode
+    pub show_web_ui_decode: bool,
     pub show_full_data: bool,
+    /// Ignore the service's decode and reported hash; verify from raw fields only
+    pub trustless: bool,
+    /// Flag transfers/approvals to addresses absent from the Safe's history.
+    /// Opt-in since it fetches the executed transaction history.
+    pub check_counterparties: bool,
+    pub counterparty_history: Option<CounterpartyHistory>,
+    pub counterparty_loading: bool,
+    pub counterparty_error: Option<String>,
     pub fetched_tx: Option<SafeTransaction>,
+    /// `fetched_tx` is still to be recorded in the audit log, once its
+    /// decode has settled
+    pub audit_pending: bool,
     pub fetched_txs: Vec<SafeTransaction>,
+    /// Nonce range of `fetched_txs`, if they came from a range fetch
+    pub fetched_range: Option<(u64, u64)>,
+    /// Nonces in `fetched_range` that failed to fetch
+    pub range_errors: Vec<(u64, String)>,
+    /// Proposer metadata for `fetched_txs`, keyed by lowercase safeTxHash
+    pub proposals: HashMap<String, ProposalInfo>,
+    /// Raw service responses for `fetched_txs`, keyed by lowercase safeTxHash
+    pub raw_responses: HashMap<String, serde_json::Value>,
+    /// Redaction choices for the diagnostic report
+    pub diagnostics: DiagnosticOptions,
+    /// Pasted reviewer signature over the attestation hash
+    pub attestation: AttestationCheck,
+    /// Outcome of the last report export: where it was saved, or the error
+    pub report_export: Option<Result<String, String>>,
+    /// Why the last "Copy JSON" couldn't produce the JSON
+    pub copy_json_error: Option<String>,
+    /// Queue position of the fetched nonce, for single-nonce fetches
+    pub nonce_status: Option<Result<NonceStatus, String>>,
+    /// Transaction Service the results came from
+    pub service_url: Option<String>,
     pub selected_tx_index: Option<usize>,
     pub hashes: Option<ComputedHashes>,
+    /// Inputs behind `hashes`, captured with them when the fetch ran
+    pub hashing_inputs: Option<HashingInputs>,
     pub warnings: SafeWarnings,
     /// Set when warnings couldn't be computed due to parse errors
     pub warnings_error: Option<String>,
+    pub findings: TxFindings,
+    /// Hash copying allowed despite a Block-level warning
+    pub copy_override: bool,
     pub is_loading: bool,
     pub error: Option<String>,
 }
@@ -301,23 +959,134 @@ impl TxVerifyState {
     pub fn clear_results(&mut self) {
         self.fetched_tx = None;
         self.fetched_txs.clear();
+        self.fetched_range = None;
+        self.range_errors.clear();
+        self.proposals.clear();
+        self.raw_responses.clear();
+        self.attestation.signature.clear();
+        self.report_export = None;
+        self.copy_json_error = None;
+        self.nonce_status = None;
+        self.service_url = None;
+        self.share_link_pending = None;
+        self.share_link_hash = None;
         self.selected_tx_index = None;
         self.hashes = None;
+        self.hashing_inputs = None;
         self.warnings = SafeWarnings::new();
         self.warnings_error = None;
+        self.findings = TxFindings::default();
+        self.copy_override = false;
         self.expected.clear_result();
         self.decode = None;
+        self.web_ui_decode = None;
         self.error = None;
     }
 }
 
 /// Computed hash results (display strings)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct ComputedHashes {
     pub domain_hash: String,
     pub message_hash: String,
     pub safe_tx_hash: String,
     pub matches_api: Option<bool>,
+    /// Whether these were computed in trustless mode, fixed when the fetch ran
+    pub trustless: bool,
+}
+
+/// Findings about a transaction's calls, worked out once when it's fetched
+/// or computed rather than on every frame
+#[derive(Debug, Clone, Default)]
+pub struct TxFindings {
+    pub config_changes: Vec<ConfigChange>,
+    /// Meta-transaction relay the call is, if any
+    pub relay: Option<RelayCall>,
+    /// Safe transaction executed on a child Safe, if the call is one
+    pub nested_safe: Option<NestedSafeTx>,
+    /// Owner and threshold changes `safe_address` makes on itself
+    pub owner_changes: Vec<OwnerManagement>,
+    pub permits: Vec<PermitInfo>,
+    /// Every operation that isn't a plain call: the transaction itself at
+    /// position 1, then anything inside the MultiSend batch it carries
+    pub delegatecalls: Vec<NestedDelegatecall>,
+    /// Whether the transaction or one of its batch entries calls the Safe
+    pub self_call: bool,
+    /// Payments in the MultiSend batch the transaction carries, if any
+    pub payments: Option<BatchPayments>,
+    /// `payments` checked against the roster, kept until the roster changes
+    pub reconciliation: Option<Reconciliation>,
+    /// Emergency and admin calls, from the decode once it's in
+    pub admin_calls: Vec<AdminCall>,
+    /// Token approvals, from the decode once it's in
+    pub approvals: Vec<Approval>,
+}
+
+impl TxFindings {
+    pub fn detect(safe_address: &str, to: &str, data: &str, operation: u8) -> Self {
+        Self {
+            config_changes: detect_config_changes(to, data, operation),
+            owner_changes: detect_owner_management(safe_address, to, data),
+            relay: classify_relay(data),
+            nested_safe: classify_nested_safe(data),
+            permits: detect_permits(to, data),
+            delegatecalls: parser::find_delegatecalls([(operation, to, data)]),
+            self_call: calls_self(safe_address, to, data),
+            payments: BatchPayments::from_calldata(data),
+            reconciliation: None,
+            admin_calls: vec![],
+            approvals: vec![],
+        }
+    }
+
+    /// Refresh the findings read from the Verify tab's decode, which fills
+    /// in after the fetch. `to` is the transaction's target.
+    pub fn update_decoded(&mut self, decode: &DecodedTransaction, to: &str) {
+        self.admin_calls = detect_admin_calls(decode, to);
+        self.approvals = detect_approvals(decode, to);
+    }
+
+    /// Refresh the findings read from an offline decode
+    pub fn update_offline_decoded(&mut self, decode: &OfflineDecodeResult, to: &str) {
+        self.admin_calls = detect_offline_admin_calls(decode, to);
+        self.approvals = detect_offline_approvals(decode, to);
+    }
+
+    /// Whether an approval or permit grants an unlimited allowance
+    pub fn unlimited_approval(&self) -> bool {
+        self.approvals.iter().any(|a| a.is_unlimited())
+            || self.permits.iter().any(|p| p.is_unlimited())
+    }
+
+    /// Check the batch's payments against the roster `entries`, `None`
+    /// without either
+    pub fn reconcile(&mut self, entries: &[RosterEntry]) {
+        self.reconciliation = self
+            .payments
+            .as_ref()
+            .filter(|_| !entries.is_empty())
+            .map(|payments| roster::reconcile(entries, payments));
+    }
+
+    /// What the warning policy weighs beyond `SafeWarnings`. A DELEGATECALL
+    /// into a library meant for it (e.g. the canonical MultiSend) only
+    /// counts through the batch: any non-call operation inside it to a
+    /// target that isn't such a library still does. Without a `chain_id`
+    /// no target is trusted.
+    pub fn risk_flags(&self, chain_id: Option<u64>, book: &AddressBook) -> RiskFlags {
+        let expected = |address: &str| {
+            chain_id
+                .and_then(|id| trust::lookup(address, id, book))
+                .is_some_and(|t| t.expects_delegatecall())
+        };
+        RiskFlags {
+            delegatecall: self.delegatecalls.iter().any(|d| !expected(&d.to)),
+            owner_change: !self.owner_changes.is_empty(),
+            config_change: !self.config_changes.is_empty(),
+            self_call: self.self_call,
+            unlimited_approval: self.unlimited_approval(),
+        }
+    }
 }
 
 /// Message verification UI state
@@ -327,6 +1096,13 @@ pub struct MsgVerifyState {
     pub is_hex: bool,
     pub hashes: Option<MsgHashes>,
     pub error: Option<String>,
+    /// Safe message hash to fetch from the Transaction Service
+    pub fetch_hash: String,
+    pub fetch_loading: bool,
+    pub fetch_error: Option<String>,
+    pub fetched: Option<crate::hasher::FetchedSafeMessage>,
+    /// Hash recomputed locally from the fetched body, or why it couldn't be
+    pub local_hash: Option<Result<String, String>>,
 }
 
 #[derive(Debug, Clone)]
@@ -342,6 +1118,10 @@ pub struct Eip712State {
     pub json_input: String,
     pub standalone: bool,
     pub hashes: Option<Eip712Hashes>,
+    /// Domain fields that disagree with the selected chain and Safe
+    pub domain_mismatches: Vec<crate::hasher::DomainMismatch>,
+    /// Undefined, unused and circular types found before hashing
+    pub type_issues: Vec<crate::hasher::TypeIssue>,
     pub error: Option<String>,
 }
 
@@ -361,8 +1141,6 @@ pub struct Eip712Hashes {
 // OFFLINE MODE STATE
 // =============================================================================
 
-use crate::decode::OfflineDecodeResult;
-
 /// Offline verification UI state (manual transaction input)
 pub struct OfflineState {
     // Transaction inputs
@@ -376,6 +1154,8 @@ pub struct OfflineState {
     pub gas_price: String,
     pub gas_token: String,
     pub refund_receiver: String,
+    /// Only decode the calldata: no Safe address, target or hashing needed
+    pub decode_only: bool,
 
     // Results
     pub decode_result: Option<OfflineDecodeResult>,
@@ -383,10 +1163,16 @@ pub struct OfflineState {
     pub warnings: SafeWarnings,
     /// Set when warnings couldn't be computed due to parse errors
     pub warnings_error: Option<String>,
+    /// Findings for the inputs as they were when computed
+    pub findings: TxFindings,
+    /// Hash copying allowed despite a Block-level warning
+    pub copy_override: bool,
 
     // State
     pub is_loading: bool,
     pub error: Option<String>,
+    /// Undo/redo history of the inputs (results are never undone)
+    pub history: UndoStack<OfflineInputs>,
 }
 
 impl Default for OfflineState {
@@ -402,12 +1188,16 @@ impl Default for OfflineState {
             gas_price: "0".to_string(),
             gas_token: "0x0000000000000000000000000000000000000000".to_string(),
             refund_receiver: "0x0000000000000000000000000000000000000000".to_string(),
+            decode_only: false,
             decode_result: None,
             hashes: None,
             warnings: SafeWarnings::new(),
             warnings_error: None,
+            findings: TxFindings::default(),
+            copy_override: false,
             is_loading: false,
             error: None,
+            history: UndoStack::default(),
         }
     }
 }
@@ -418,14 +1208,200 @@ impl OfflineState {
         self.hashes = None;
         self.warnings = SafeWarnings::new();
         self.warnings_error = None;
+        self.findings = TxFindings::default();
+        self.copy_override = false;
         self.error = None;
     }
+
+    /// Snapshot of the transaction inputs
+    pub fn inputs(&self) -> OfflineInputs {
+        OfflineInputs {
+            to: self.to.clone(),
+            value: self.value.clone(),
+            data: self.data.clone(),
+            operation: self.operation,
+            nonce: self.nonce.clone(),
+            safe_tx_gas: self.safe_tx_gas.clone(),
+            base_gas: self.base_gas.clone(),
+            gas_price: self.gas_price.clone(),
+            gas_token: self.gas_token.clone(),
+            refund_receiver: self.refund_receiver.clone(),
+        }
+    }
+
+    pub fn restore(&mut self, inputs: OfflineInputs) {
+        self.to = inputs.to;
+        self.value = inputs.value;
+        self.data = inputs.data;
+        self.operation = inputs.operation;
+        self.nonce = inputs.nonce;
+        self.safe_tx_gas = inputs.safe_tx_gas;
+        self.base_gas = inputs.base_gas;
+        self.gas_price = inputs.gas_price;
+        self.gas_token = inputs.gas_token;
+        self.refund_receiver = inputs.refund_receiver;
+    }
+
+    /// Record the current inputs as an undo step (no-op if unchanged)
+    pub fn commit_inputs(&mut self) {
+        let inputs = self.inputs();
+        self.history.commit(inputs);
+    }
+
+    pub fn undo(&mut self) {
+        let inputs = self.inputs();
+        if let Some(previous) = self.history.undo(inputs) {
+            self.restore(previous);
+        }
+    }
+
+    pub fn redo(&mut self) {
+        let inputs = self.inputs();
+        if let Some(next) = self.history.redo(inputs) {
+            self.restore(next);
+        }
+    }
+}
+
+/// The undoable Offline tab inputs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OfflineInputs {
+    pub to: String,
+    pub value: String,
+    pub data: String,
+    pub operation: u8,
+    pub nonce: String,
+    pub safe_tx_gas: String,
+    pub base_gas: String,
+    pub gas_price: String,
+    pub gas_token: String,
+    pub refund_receiver: String,
+}
+
+/// Max undo steps kept
+const MAX_UNDO_STEPS: usize = 50;
+
+/// Bounded undo/redo history of committed snapshots
+#[derive(Debug, Clone)]
+pub struct UndoStack<T> {
+    undo: Vec<T>,
+    redo: Vec<T>,
+    /// Last committed snapshot
+    current: Option<T>,
+}
+
+impl<T> Default for UndoStack<T> {
+    fn default() -> Self {
+        Self {
+            undo: Vec::new(),
+            redo: Vec::new(),
+            current: None,
+        }
+    }
+}
+
+impl<T: Clone + PartialEq> UndoStack<T> {
+    /// Set the baseline snapshot if none was committed yet
+    pub fn init(&mut self, state: T) {
+        if self.current.is_none() {
+            self.current = Some(state);
+        }
+    }
+
+    /// Record `state`. The first commit only sets the baseline.
+    pub fn commit(&mut self, state: T) {
+        match self.current.replace(state.clone()) {
+            Some(previous) if previous != state => {
+                self.undo.push(previous);
+                if self.undo.len() > MAX_UNDO_STEPS {
+                    self.undo.remove(0);
+                }
+                self.redo.clear();
+            }
+            _ => {}
+        }
+    }
+
+    /// Step back from `state`, committing it first if it has uncommitted edits
+    pub fn undo(&mut self, state: T) -> Option<T> {
+        self.commit(state);
+        let previous = self.undo.pop()?;
+        if let Some(current) = self.current.replace(previous.clone()) {
+            self.redo.push(current);
+        }
+        Some(previous)
+    }
+
+    /// Step forward again. Uncommitted edits in `state` discard the redo steps.
+    pub fn redo(&mut self, state: T) -> Option<T> {
+        self.commit(state);
+        let next = self.redo.pop()?;
+        if let Some(current) = self.current.replace(next.clone()) {
+            self.undo.push(current);
+        }
+        Some(next)
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+}
+
+/// Bulk audit UI state (re-verify a Safe Transaction Service export)
+#[derive(Debug, Default)]
+pub struct AuditState {
+    pub json_input: String,
+    pub entries: Vec<AuditEntry>,
+    /// Entry whose details are expanded
+    pub selected: Option<usize>,
+    pub error: Option<String>,
+}
+
+/// Audit result for one exported transaction
+#[derive(Debug, Clone)]
+pub struct AuditEntry {
+    pub index: usize,
+    pub nonce: Option<u64>,
+    /// safeTxHash as reported in the export
+    pub reported_hash: String,
+    /// Recomputed hashes and the mismatch against the reported hash, if any
+    pub result: Result<(ComputedHashes, Option<Mismatch>), String>,
+}
+
+impl AuditEntry {
+    pub fn passed(&self) -> bool {
+        matches!(&self.result, Ok((_, None)))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_undo_stack() {
+        let mut stack = UndoStack::default();
+        stack.commit("a");
+        stack.commit("b");
+        stack.commit("b");
+        assert!(stack.can_undo());
+
+        // Uncommitted "c" is recorded before stepping back
+        assert_eq!(stack.undo("c"), Some("b"));
+        assert_eq!(stack.undo("b"), Some("a"));
+        assert_eq!(stack.undo("a"), None);
+        assert_eq!(stack.redo("a"), Some("b"));
+
+        // A new edit drops the redo steps
+        stack.commit("d");
+        assert!(!stack.can_redo());
+        assert_eq!(stack.undo("d"), Some("b"));
+    }
+
     #[test]
     fn test_address_book_csv() {
         let mut book = AddressBook::default();
@@ -464,14 +1440,301 @@ mod tests {
             address: "0x123".to_string(),
             name: "Old".to_string(),
             chain_id: 1,
+            trusted: false,
+            multisend: false,
+            decimals: None,
         });
         book.add_or_update(AddressBookEntry {
             address: "0x123".to_string(),
             name: "New".to_string(),
             chain_id: 1,
+            trusted: false,
+            multisend: false,
+            decimals: Some(6),
         });
 
         assert_eq!(book.entries.len(), 1);
         assert_eq!(book.get_name("0x123", 1), Some("New".to_string()));
+        assert_eq!(book.decimals("0x123", 1), Some(6));
+
+        // Renaming without decimals keeps the recorded ones
+        book.add_or_update(AddressBookEntry {
+            address: "0x123".to_string(),
+            name: "Renamed".to_string(),
+            chain_id: 1,
+            trusted: false,
+            multisend: false,
+            decimals: None,
+        });
+        assert_eq!(book.decimals("0x123", 1), Some(6));
+    }
+
+    #[test]
+    fn test_warning_policy_assess() {
+        let policy = WarningPolicy::default();
+        assert_eq!(policy.assess(&[]), None);
+
+        let mut warnings = SafeWarnings::new();
+        warnings.non_zero_gas_token = true;
+        let flags = RiskFlags {
+            delegatecall: true,
+            unlimited_approval: true,
+            ..Default::default()
+        };
+        let kinds = WarningKind::active(&warnings, &flags);
+        assert_eq!(
+            kinds,
+            vec![
+                WarningKind::Delegatecall,
+                WarningKind::NonZeroGasToken,
+                WarningKind::UnlimitedApproval
+            ]
+        );
+        let summary = policy.assess(&kinds).unwrap();
+        assert_eq!(summary.level, RiskLevel::Block);
+        assert_eq!(summary.headline(), "RISK: BLOCK — delegatecall");
+
+        let mut policy = policy;
+        *policy.level_mut(WarningKind::UnlimitedApproval) = RiskLevel::Block;
+        assert_eq!(
+            policy.assess(&kinds).unwrap().headline(),
+            "RISK: BLOCK — delegatecall + unlimited approval"
+        );
+        *policy.level_mut(WarningKind::Delegatecall) = RiskLevel::Info;
+        *policy.level_mut(WarningKind::UnlimitedApproval) = RiskLevel::Info;
+        assert_eq!(policy.assess(&kinds).unwrap().level, RiskLevel::Warn);
+    }
+
+    /// multiSend(bytes) calldata for `(operation, to, data)` entries
+    fn multisend_data(entries: &[(u8, &str, &[u8])]) -> String {
+        let mut packed = Vec::new();
+        for (operation, to, data) in entries {
+            packed.push(*operation);
+            packed.extend(alloy::hex::decode(&to[2..]).unwrap());
+            packed.extend([0u8; 32]);
+            packed.extend(alloy::primitives::U256::from(data.len()).to_be_bytes::<32>());
+            packed.extend_from_slice(data);
+        }
+        let padding = (32 - packed.len() % 32) % 32;
+        format!(
+            "{}{:064x}{:064x}{}{}",
+            crate::decode::MULTISEND_SELECTOR,
+            32,
+            packed.len(),
+            alloy::hex::encode(&packed),
+            "00".repeat(padding)
+        )
+    }
+
+    #[test]
+    fn test_delegatecall_to_canonical_multisend_not_raised() {
+        let safe = "0x2222222222222222222222222222222222222222";
+        let multisend = "0xA238CBeb142c10Ef7Ad8442C6D1f9E89e07e7761";
+        let target = "0x1111111111111111111111111111111111111111";
+        let warnings = SafeWarnings::new();
+        let book = AddressBook::default();
+
+        let data = multisend_data(&[(0, target, &[]), (0, target, &[0xab])]);
+        let flags = TxFindings::detect(safe, multisend, &data, 1).risk_flags(Some(1), &book);
+        let kinds = WarningKind::active(&warnings, &flags);
+        assert!(kinds.is_empty());
+        assert_eq!(WarningPolicy::default().assess(&kinds), None);
+
+        let flags = TxFindings::detect(safe, target, "0x", 1).risk_flags(Some(1), &book);
+        assert_eq!(
+            WarningKind::active(&warnings, &flags),
+            vec![WarningKind::Delegatecall]
+        );
+
+        // On a chain that can't be identified the MultiSend isn't recognized
+        let chain_id = chain_id_of("not-a-chain").ok();
+        assert_eq!(chain_id, None);
+        let flags = TxFindings::detect(safe, multisend, &data, 1).risk_flags(chain_id, &book);
+        assert_eq!(
+            WarningKind::active(&warnings, &flags),
+            vec![WarningKind::Delegatecall]
+        );
+    }
+
+    #[test]
+    fn test_inner_delegatecall_in_canonical_multisend_blocks() {
+        let safe = "0x2222222222222222222222222222222222222222";
+        let multisend = "0xA238CBeb142c10Ef7Ad8442C6D1f9E89e07e7761";
+        let unknown = "0x1111111111111111111111111111111111111111";
+        let book = AddressBook::default();
+
+        let data = multisend_data(&[(0, unknown, &[]), (1, unknown, &[0xab])]);
+        let findings = TxFindings::detect(safe, multisend, &data, 1);
+        assert_eq!(findings.delegatecalls.len(), 2);
+        let flags = findings.risk_flags(Some(1), &book);
+        assert!(flags.delegatecall);
+
+        let risk = WarningPolicy::default()
+            .assess(&WarningKind::active(&SafeWarnings::new(), &flags))
+            .unwrap();
+        assert_eq!(risk.level, RiskLevel::Block);
+        assert_eq!(risk.kinds, vec![WarningKind::Delegatecall]);
+        assert!(risk.blocks_copy());
+    }
+
+    #[test]
+    fn test_config_and_self_calls_raised() {
+        let safe = "0x2222222222222222222222222222222222222222";
+        let module = "0x3333333333333333333333333333333333333333";
+        let enable = format!("0x610b5925{:0>64}", &module[2..]);
+        let findings = TxFindings::detect(safe, safe, &enable, 0);
+        let flags = findings.risk_flags(Some(1), &AddressBook::default());
+        assert!(flags.config_change && flags.self_call && !flags.delegatecall);
+
+        let kinds = WarningKind::active(&SafeWarnings::new(), &flags);
+        assert_eq!(
+            kinds,
+            vec![WarningKind::ConfigChange, WarningKind::SelfCall]
+        );
+        let risk = WarningPolicy::default().assess(&kinds).unwrap();
+        assert_eq!(risk.kinds, vec![WarningKind::ConfigChange]);
+        assert!(risk.blocks_copy());
+
+        // An owner change through a batch counts as one even without the
+        // service's dangerous-method flag
+        let add_owner = format!("0x0d582f13{:0>64}{:064x}", &module[2..], 2);
+        let add_owner = alloy::hex::decode(&add_owner[2..]).unwrap();
+        let data = multisend_data(&[(0, safe, &add_owner)]);
+        let multisend = "0x40A2aCCbd92BCA938b02010E17A5b8929b49130D";
+        let flags = TxFindings::detect(safe, multisend, &data, 1)
+            .risk_flags(Some(1), &AddressBook::default());
+        assert!(flags.owner_change && flags.self_call && !flags.delegatecall);
+        assert!(WarningKind::active(&SafeWarnings::new(), &flags)
+            .contains(&WarningKind::DangerousMethods));
+    }
+
+    #[test]
+    fn test_decoded_approval_raises_unlimited_approval() {
+        let token = "0x1111111111111111111111111111111111111111";
+        let spender = "0x2222222222222222222222222222222222222222";
+        let book = AddressBook::default();
+        let param = |typ: &str, value: String| crate::decode::LocalParam {
+            typ: typ.to_string(),
+            value,
+            children: vec![],
+        };
+        let decode = OfflineDecodeResult::Single {
+            local: crate::decode::LocalDecode {
+                signature: "approve(address,uint256)".to_string(),
+                method: "approve".to_string(),
+                params: vec![
+                    param("address", spender.to_string()),
+                    param("uint256", alloy::primitives::U256::MAX.to_string()),
+                ],
+                verified: false,
+                alternatives: vec![],
+            },
+            status: crate::decode::OfflineDecodeStatus::Decoded,
+        };
+
+        // The decode fills in after the calldata checks
+        let mut findings = TxFindings::detect(spender, token, "0x095ea7b3", 0);
+        assert!(!findings.risk_flags(Some(1), &book).unlimited_approval);
+        findings.update_offline_decoded(&decode, token);
+        assert_eq!(findings.approvals.len(), 1);
+        let flags = findings.risk_flags(Some(1), &book);
+        assert!(flags.unlimited_approval);
+        assert!(WarningKind::active(&SafeWarnings::new(), &flags)
+            .contains(&WarningKind::UnlimitedApproval));
+    }
+
+    #[test]
+    fn test_saved_safes_import_export() {
+        let safe = |label: &str, chain: &str| SavedSafe {
+            label: label.to_string(),
+            chain: chain.to_string(),
+            address: "0x1111111111111111111111111111111111111111".to_string(),
+            version: "1.4.1".to_string(),
+        };
+        let mut saved = SavedSafes::default();
+        saved.save(safe("Treasury", "ethereum"));
+        saved.save(safe("Ops", "base"));
+        saved.save(safe("Main treasury", "ethereum"));
+        assert_eq!(saved.safes.len(), 2);
+        assert_eq!(saved.safes[0].label, "Main treasury");
+
+        let mut restored = SavedSafes::default();
+        assert_eq!(restored.import_json(&saved.export_json()), Ok((2, 0)));
+        assert_eq!(restored, saved);
+
+        let mut bad = safe("Bad", "ethereum");
+        bad.version = "9.9.9".to_string();
+        let json = serde_json::to_string(&vec![bad]).unwrap();
+        assert_eq!(restored.import_json(&json), Ok((0, 1)));
+        assert!(restored.import_json("not json").is_err());
+    }
+
+    #[test]
+    fn test_audit_log_round_trip() {
+        let entry = |nonce: u64, matches_api: Option<bool>| AuditLogEntry {
+            timestamp: 1_700_000_000 + nonce,
+            chain: "ethereum".to_string(),
+            safe_address: "0x1111111111111111111111111111111111111111".to_string(),
+            nonce,
+            safe_tx_hash: format!("0x{}", "ab".repeat(32)),
+            matches_api,
+            top_severity: matches_api.and_then(|m| (!m).then_some(RiskLevel::Block)),
+        };
+        let mut log = AuditLog::default();
+        log.append(entry(7, Some(true)));
+        log.append(entry(8, None));
+        log.append(entry(9, Some(false)));
+
+        let jsonl = log.to_jsonl();
+        let lines: Vec<&str> = jsonl.lines().collect();
+        assert_eq!(lines.len(), 3);
+        let first: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(first["safeTxHash"], format!("0x{}", "ab".repeat(32)));
+        assert_eq!(first["matchesApi"], true);
+        assert!(lines[2].contains(r#""topSeverity":"Block""#));
+
+        let saved = AUDIT_LOG_SCHEMA.encode(&log).unwrap();
+        let loaded: AuditLog = AUDIT_LOG_SCHEMA.decode(&saved).unwrap();
+        assert_eq!(loaded.entries(), log.entries());
+        assert_eq!(loaded.entries()[1].nonce, 8);
+        assert_eq!(loaded.entries()[0].time_utc(), "2023-11-14 22:13:27 UTC");
+    }
+
+    #[test]
+    fn test_audit_log_record_and_cap() {
+        let entry = |nonce: u64, timestamp: u64| AuditLogEntry {
+            timestamp,
+            chain: "ethereum".to_string(),
+            safe_address: "0x1111111111111111111111111111111111111111".to_string(),
+            nonce,
+            safe_tx_hash: format!("0x{:064x}", nonce),
+            matches_api: Some(true),
+            top_severity: None,
+        };
+
+        // An entry loaded from an earlier session is never updated
+        let mut earlier = AuditLog::default();
+        earlier.append(entry(1, 100));
+        let saved = AUDIT_LOG_SCHEMA.encode(&earlier).unwrap();
+        let mut log: AuditLog = AUDIT_LOG_SCHEMA.decode(&saved).unwrap();
+        log.record(entry(1, 200));
+        assert_eq!(log.entries().len(), 2);
+
+        // Re-verifying in the same session updates the entry
+        log.record(entry(2, 300));
+        log.record(entry(2, 400));
+        assert_eq!(log.entries().len(), 3);
+        assert_eq!(log.entries()[2].timestamp, 400);
+
+        for nonce in 0..MAX_AUDIT_LOG_ENTRIES as u64 + 5 {
+            log.record(entry(nonce + 10, nonce));
+        }
+        assert_eq!(log.entries().len(), MAX_AUDIT_LOG_ENTRIES);
+        assert_eq!(log.entries()[0].nonce, 15);
+
+        log.clear();
+        assert!(log.entries().is_empty());
+        log.record(entry(1, 500));
+        assert_eq!(log.entries().len(), 1);
     }
 }
@@ -0,0 +1,236 @@
+//! Known-good contract registry
+//!
+//! Bundled list of well-known contracts (Safe libraries, WETH, Permit2,
+//! canonical bridges) plus address book entries the user marked as trusted.
+//! Used to annotate transaction targets and downgrade warnings that are
+//! expected for them, e.g. a DELEGATECALL into the official MultiSend.
+
+use crate::state::AddressBook;
+
+/// What kind of contract a trusted entry is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrustCategory {
+    /// Official Safe library meant to be delegatecalled (MultiSend, SignMessageLib)
+    SafeLibrary,
+    Token,
+    Dex,
+    Bridge,
+    Approvals,
+    /// Marked trusted by the user in the address book
+    User,
+    /// Marked by the user as a trusted MultiSend deployment
+    UserMultiSend,
+}
+
+impl TrustCategory {
+    pub fn label(&self) -> &'static str {
+        match self {
+            TrustCategory::SafeLibrary => "Safe library",
+            TrustCategory::Token => "Token",
+            TrustCategory::Dex => "DEX",
+            TrustCategory::Bridge => "Bridge",
+            TrustCategory::Approvals => "Approvals",
+            TrustCategory::User => "User trusted",
+            TrustCategory::UserMultiSend => "User trusted MultiSend",
+        }
+    }
+}
+
+/// A recognized contract
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrustedContract {
+    pub label: String,
+    pub category: TrustCategory,
+}
+
+impl TrustedContract {
+    /// Whether a DELEGATECALL to this contract is the normal way to use it
+    pub fn expects_delegatecall(&self) -> bool {
+        matches!(
+            self.category,
+            TrustCategory::SafeLibrary | TrustCategory::UserMultiSend
+        )
+    }
+}
+
+/// Bundled registry entry. `chain_id: None` means the same address on every
+/// chain (CREATE2 deployments).
+struct KnownContract {
+    chain_id: Option<u64>,
+    address: &'static str,
+    label: &'static str,
+    category: TrustCategory,
+}
+
+const KNOWN_CONTRACTS: &[KnownContract] = &[
+    // Safe libraries (canonical deployments)
+    KnownContract {
+        chain_id: None,
+        address: "0xA238CBeb142c10Ef7Ad8442C6D1f9E89e07e7761",
+        label: "MultiSend 1.3.0",
+        category: TrustCategory::SafeLibrary,
+    },
+    KnownContract {
+        chain_id: None,
+        address: "0x40A2aCCbd92BCA938b02010E17A5b8929b49130D",
+        label: "MultiSendCallOnly 1.3.0",
+        category: TrustCategory::SafeLibrary,
+    },
+    KnownContract {
+        chain_id: None,
+        address: "0x38869bf66a61cF6bDB996A6aE40D5853Fd43B526",
+        label: "MultiSend 1.4.1",
+        category: TrustCategory::SafeLibrary,
+    },
+    KnownContract {
+        chain_id: None,
+        address: "0x9641d764fc13c8B624c04430C7356C1C7C8102e2",
+        label: "MultiSendCallOnly 1.4.1",
+        category: TrustCategory::SafeLibrary,
+    },
+    KnownContract {
+        chain_id: None,
+        address: "0xA65387F16B013cf2Af4605Ad8aA5ec25a2cbA3a2",
+        label: "SignMessageLib 1.3.0",
+        category: TrustCategory::SafeLibrary,
+    },
+    KnownContract {
+        chain_id: None,
+        address: "0xd53cd0aB83D845Ac265BE939c57F53AD838012c9",
+        label: "SignMessageLib 1.4.1",
+        category: TrustCategory::SafeLibrary,
+    },
+    // Approvals
+    KnownContract {
+        chain_id: None,
+        address: "0x000000000022D473030F116dDEE9F6B43aC78BA3",
+        label: "Permit2",
+        category: TrustCategory::Approvals,
+    },
+    // Wrapped native tokens
+    KnownContract {
+        chain_id: Some(1),
+        address: "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2",
+        label: "WETH",
+        category: TrustCategory::Token,
+    },
+    KnownContract {
+        chain_id: Some(42161),
+        address: "0x82aF49447D8a07e3bd95BD0d56f35241523fBab1",
+        label: "WETH",
+        category: TrustCategory::Token,
+    },
+    KnownContract {
+        chain_id: Some(10),
+        address: "0x4200000000000000000000000000000000000006",
+        label: "WETH",
+        category: TrustCategory::Token,
+    },
+    KnownContract {
+        chain_id: Some(8453),
+        address: "0x4200000000000000000000000000000000000006",
+        label: "WETH",
+        category: TrustCategory::Token,
+    },
+    // DEX routers
+    KnownContract {
+        chain_id: Some(1),
+        address: "0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD",
+        label: "Uniswap Universal Router",
+        category: TrustCategory::Dex,
+    },
+    // Canonical bridges (L1 side)
+    KnownContract {
+        chain_id: Some(1),
+        address: "0x72Ce9c846789fdB6fC1f34aC4AD25Dd9ef7031ef",
+        label: "Arbitrum L1 Gateway Router",
+        category: TrustCategory::Bridge,
+    },
+    KnownContract {
+        chain_id: Some(1),
+        address: "0x99C9fc46f92E8a1c0deC1b1747d010903E884bE1",
+        label: "Optimism L1 Standard Bridge",
+        category: TrustCategory::Bridge,
+    },
+    KnownContract {
+        chain_id: Some(1),
+        address: "0x3154Cf16ccdb4C6d922629664174b904d80F2C35",
+        label: "Base L1 Standard Bridge",
+        category: TrustCategory::Bridge,
+    },
+];
+
+/// Look up an address in the bundled registry, then in the user's trusted
+/// address book entries. Bundled entries win so a user label can't shadow them.
+pub fn lookup(address: &str, chain_id: u64, book: &AddressBook) -> Option<TrustedContract> {
+    let addr_lower = address.to_lowercase();
+
+    let bundled = KNOWN_CONTRACTS.iter().find(|c| {
+        c.address.to_lowercase() == addr_lower && c.chain_id.map_or(true, |id| id == chain_id)
+    });
+    if let Some(c) = bundled {
+        return Some(TrustedContract {
+            label: c.label.to_string(),
+            category: c.category,
+        });
+    }
+
+    book.entries
+        .iter()
+        .find(|e| e.trusted && e.address.to_lowercase() == addr_lower && e.chain_id == chain_id)
+        .map(|e| TrustedContract {
+            label: e.name.clone(),
+            category: if e.multisend {
+                TrustCategory::UserMultiSend
+            } else {
+                TrustCategory::User
+            },
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::AddressBookEntry;
+
+    #[test]
+    fn test_lookup_bundled() {
+        let book = AddressBook::default();
+
+        // Chain-agnostic entry, lowercase input
+        let multisend = lookup("0xa238cbeb142c10ef7ad8442c6d1f9e89e07e7761", 137, &book).unwrap();
+        assert_eq!(multisend.label, "MultiSend 1.3.0");
+        assert!(multisend.expects_delegatecall());
+
+        // Chain-specific entry only matches its chain
+        let weth = "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2";
+        assert!(lookup(weth, 1, &book).is_some());
+        assert!(lookup(weth, 137, &book).is_none());
+    }
+
+    #[test]
+    fn test_lookup_user_trusted() {
+        let mut book = AddressBook::default();
+        book.entries.push(AddressBookEntry {
+            address: "0xFe89cc7aBB2C4183683ab71653C4cdc9B02D44b7".to_string(),
+            name: "Treasury".to_string(),
+            chain_id: 1,
+            trusted: false,
+            multisend: false,
+            decimals: None,
+        });
+        let addr = "0xfe89cc7abb2c4183683ab71653c4cdc9b02d44b7";
+        assert!(lookup(addr, 1, &book).is_none());
+
+        book.entries[0].trusted = true;
+        let trusted = lookup(addr, 1, &book).unwrap();
+        assert_eq!(trusted.category, TrustCategory::User);
+        assert!(!trusted.expects_delegatecall());
+
+        // Only a trusted entry's MultiSend mark counts
+        book.entries[0].multisend = true;
+        assert!(lookup(addr, 1, &book).unwrap().expects_delegatecall());
+        book.entries[0].trusted = false;
+        assert!(lookup(addr, 1, &book).is_none());
+    }
+}
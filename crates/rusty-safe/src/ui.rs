@@ -1,13 +1,26 @@
 //! UI helper components
 
 use eframe::egui;
+use std::sync::{Mutex, MutexGuard};
+use web_time::{Duration, Instant};
+
+use crate::state::{ClipboardSettings, RiskLevel, RiskSummary};
 
 /// Get block explorer URL for an address on a given chain
 /// Supports all chains from safe-utils: arbitrum, aurora, avalanche, base, blast, bsc,
 /// celo, ethereum, gnosis, linea, mantle, monad, optimism, polygon, scroll, sepolia,
 /// worldchain, xlayer, zksync, base-sepolia, gnosis-chiado, polygon-zkevm
 pub fn get_explorer_address_url(chain_name: &str, address: &str) -> String {
-    let base = match chain_name.to_lowercase().as_str() {
+    format!("{}/address/{}", explorer_base(chain_name), address)
+}
+
+/// Get block explorer URL for a transaction hash
+pub fn get_explorer_tx_url(chain_name: &str, tx_hash: &str) -> String {
+    format!("{}/tx/{}", explorer_base(chain_name), tx_hash)
+}
+
+fn explorer_base(chain_name: &str) -> &'static str {
+    match chain_name.to_lowercase().as_str() {
         // Mainnets
         "ethereum" | "mainnet" => "https://etherscan.io",
         "arbitrum" => "https://arbiscan.io",
@@ -34,8 +47,22 @@ pub fn get_explorer_address_url(chain_name: &str, address: &str) -> String {
         "gnosis-chiado" => "https://gnosis-chiado.blockscout.com",
         // Fallback
         _ => "https://etherscan.io",
-    };
-    format!("{}/address/{}", base, address)
+    }
+}
+
+/// Transaction hash linking to the block explorer, with a copy button
+pub fn tx_hash_link(ui: &mut egui::Ui, chain_name: &str, tx_hash: &str) {
+    ui.horizontal(|ui| {
+        let link = ui
+            .link(egui::RichText::new(tx_hash).monospace().size(11.0))
+            .on_hover_text("Open in block explorer");
+        if link.clicked() {
+            open_url_new_tab(&get_explorer_tx_url(chain_name, tx_hash));
+        }
+        if ui.small_button("📋").on_hover_text("Copy").clicked() {
+            copy_to_clipboard(tx_hash);
+        }
+    });
 }
 
 /// Open URL in a new browser tab
@@ -51,6 +78,53 @@ pub fn open_url_new_tab(url: &str) {
     let _ = open::that(url);
 }
 
+/// Save `contents` as `file_name` via a browser download. Returns a
+/// description of where it went.
+#[cfg(target_arch = "wasm32")]
+pub fn save_file(file_name: &str, contents: &str) -> Result<String, String> {
+    use eframe::wasm_bindgen::JsCast as _;
+
+    let encoded: String = contents
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect();
+    let document = web_sys::window()
+        .and_then(|w| w.document())
+        .ok_or("No document")?;
+    let link = document
+        .create_element("a")
+        .map_err(|_| "Failed to create download link")?;
+    let mime = if file_name.ends_with(".csv") {
+        "text/csv"
+    } else {
+        "application/json"
+    };
+    link.set_attribute("href", &format!("data:{};charset=utf-8,{}", mime, encoded))
+        .and_then(|_| link.set_attribute("download", file_name))
+        .map_err(|_| "Failed to set up download link")?;
+    link.dyn_into::<web_sys::HtmlElement>()
+        .map_err(|_| "Download link is not an HTML element")?
+        .click();
+    Ok(format!("Downloaded {}", file_name))
+}
+
+/// Save `contents` as `file_name` in the working directory. Returns a
+/// description of where it went.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn save_file(file_name: &str, contents: &str) -> Result<String, String> {
+    let path = std::env::current_dir()
+        .map_err(|e| format!("No working directory: {}", e))?
+        .join(file_name);
+    std::fs::write(&path, contents)
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+    Ok(format!("Saved {}", path.display()))
+}
+
 pub use crate::state::{validate_address, AddressValidation};
 
 /// Render an address as a clickable hyperlink that opens in block explorer
@@ -71,7 +145,7 @@ pub fn address_link(
 
     ui.horizontal(|ui| {
         let label_text = if let Some(n) = name {
-            format!("{} ({})", address, n)
+            format!("{} ({})", n, address)
         } else {
             address.to_string()
         };
@@ -133,16 +207,118 @@ pub fn labeled_field_with_copy(ui: &mut egui::Ui, label: &str, value: &str) -> b
     copied
 }
 
-/// Copy to clipboard (platform-specific)
-#[cfg(not(target_arch = "wasm32"))]
+/// Copy state shared by every copy button, so the privacy settings apply
+/// without threading them through each call site
+struct ClipboardGuard {
+    settings: ClipboardSettings,
+    /// Copy waiting for confirmation
+    pending: Option<String>,
+    /// When to overwrite the clipboard
+    clear_at: Option<Instant>,
+}
+
+impl ClipboardGuard {
+    fn write(&mut self, text: &str) {
+        write_clipboard(text);
+        let secs = self.settings.clear_after_secs;
+        self.clear_at = (secs > 0).then(|| Instant::now() + Duration::from_secs(secs.into()));
+    }
+}
+
+static CLIPBOARD: Mutex<ClipboardGuard> = Mutex::new(ClipboardGuard {
+    settings: ClipboardSettings {
+        confirm_before_copy: false,
+        clear_after_secs: 0,
+    },
+    pending: None,
+    clear_at: None,
+});
+
+fn clipboard_guard() -> MutexGuard<'static, ClipboardGuard> {
+    CLIPBOARD
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Copy to clipboard, honoring the clipboard privacy settings
 pub fn copy_to_clipboard(text: &str) {
+    let mut guard = clipboard_guard();
+    if guard.settings.confirm_before_copy {
+        guard.pending = Some(text.to_string());
+    } else {
+        guard.write(text);
+    }
+}
+
+/// Apply clipboard settings, run the auto-clear timer and show the copy
+/// confirmation. Call once per frame.
+pub fn render_clipboard_guard(ctx: &egui::Context, settings: &ClipboardSettings) {
+    let pending = {
+        let mut guard = clipboard_guard();
+        guard.settings = settings.clone();
+        if let Some(clear_at) = guard.clear_at {
+            let now = Instant::now();
+            if now >= clear_at {
+                write_clipboard("");
+                guard.clear_at = None;
+            } else {
+                ctx.request_repaint_after(clear_at - now);
+            }
+        }
+        guard.pending.clone()
+    };
+
+    let Some(text) = pending else {
+        return;
+    };
+
+    let mut decision = None;
+    egui::Window::new("Copy to clipboard?")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.add(egui::Label::new(egui::RichText::new(&text).monospace()).wrap());
+            if settings.clear_after_secs > 0 {
+                ui.label(
+                    egui::RichText::new(format!(
+                        "The clipboard is cleared {} seconds after copying",
+                        settings.clear_after_secs
+                    ))
+                    .small()
+                    .weak(),
+                );
+            }
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                if primary_button(ui, "📋 Copy").clicked() {
+                    decision = Some(true);
+                }
+                if secondary_button(ui, "Cancel").clicked() {
+                    decision = Some(false);
+                }
+            });
+        });
+
+    if let Some(confirmed) = decision {
+        let mut guard = clipboard_guard();
+        guard.pending = None;
+        if confirmed {
+            guard.write(&text);
+        }
+    }
+}
+
+/// Write to the system clipboard (platform-specific)
+#[cfg(not(target_arch = "wasm32"))]
+fn write_clipboard(text: &str) {
     if let Ok(mut clipboard) = arboard::Clipboard::new() {
         let _ = clipboard.set_text(text);
     }
 }
 
 #[cfg(target_arch = "wasm32")]
-pub fn copy_to_clipboard(text: &str) {
+fn write_clipboard(text: &str) {
     if let Some(window) = web_sys::window() {
         let navigator = window.navigator();
         let clipboard = navigator.clipboard();
@@ -266,6 +442,45 @@ pub fn warning_banner(ui: &mut egui::Ui, message: &str) {
         });
 }
 
+/// Single banner summarizing the warnings under the team's policy
+pub fn risk_banner(ui: &mut egui::Ui, risk: &RiskSummary) {
+    match risk.level {
+        RiskLevel::Block => error_banner(ui, &risk.headline()),
+        RiskLevel::Warn => warning_banner(ui, &risk.headline()),
+        RiskLevel::Info => {
+            ui.label(egui::RichText::new(format!("ℹ {}", risk.headline())).weak());
+        }
+    }
+}
+
+/// Under a Block-level risk, hash copy buttons stay disabled until the user
+/// ticks the override. Returns whether copying is allowed.
+pub fn copy_gate(ui: &mut egui::Ui, risk: Option<&RiskSummary>, copy_override: &mut bool) -> bool {
+    if !risk.is_some_and(RiskSummary::blocks_copy) {
+        return true;
+    }
+    ui.checkbox(
+        copy_override,
+        egui::RichText::new("I've reviewed the blocking warnings; allow copying hashes")
+            .color(egui::Color32::from_rgb(220, 120, 120)),
+    );
+    *copy_override
+}
+
+/// Copy button for a hash, disabled when `allowed` is false. Ctrl+C also
+/// copies while the button has keyboard focus (reached with Tab).
+pub fn hash_copy_button(ui: &mut egui::Ui, hash: &str, allowed: bool) {
+    let response = ui
+        .add_enabled(allowed, egui::Button::new("📋").small())
+        .on_hover_text("Copy (or focus and Ctrl+C)")
+        .on_disabled_hover_text("Blocked by the warning policy; tick the override above to copy");
+    let copy_key = response.has_focus()
+        && ui.input(|i| i.events.iter().any(|e| matches!(e, egui::Event::Copy)));
+    if response.clicked() || copy_key {
+        copy_to_clipboard(hash);
+    }
+}
+
 /// Warning message display
 pub fn warning_message(ui: &mut egui::Ui, message: &str, color: egui::Color32) {
     ui.horizontal(|ui| {
@@ -274,6 +489,18 @@ pub fn warning_message(ui: &mut egui::Ui, message: &str, color: egui::Color32) {
     });
 }
 
+/// Label for a Safe `Enum.Operation`. Only 0 and 1 exist; anything else is
+/// a malformed transaction and must not pass for a DelegateCall.
+pub fn operation_text(operation: u64) -> egui::RichText {
+    match operation {
+        0 => egui::RichText::new("Call (0)"),
+        1 => egui::RichText::new("DelegateCall (1)"),
+        n => egui::RichText::new(format!("⚠️ Invalid operation ({})", n))
+            .color(egui::Color32::from_rgb(220, 80, 80))
+            .strong(),
+    }
+}
+
 /// Display a hash value with copy button
 pub fn copyable_hash(ui: &mut egui::Ui, hash: &str) {
     ui.horizontal(|ui| {
@@ -341,7 +568,7 @@ pub fn card_highlighted(ui: &mut egui::Ui, add_contents: impl FnOnce(&mut egui::
 }
 
 // =============================================================================
-// LEDGER BINARY FORMAT
+// HARDWARE WALLET HASH FORMATS
 // =============================================================================
 
 /// Convert a hex hash to binary literal format for Ledger display
@@ -380,6 +607,89 @@ pub fn hash_to_binary_literal(hash: &str) -> String {
     result
 }
 
+/// Hash as Trezor shows it when blind signing: lowercase hex in groups of
+/// four characters, four groups per line
+/// e.g., "0xad06b099..." -> "ad06 b099 ...\n..."
+pub fn hash_to_trezor_blind_sign(hash: &str) -> String {
+    let hex = hash.strip_prefix("0x").unwrap_or(hash).to_lowercase();
+    hex.as_bytes()
+        .chunks(16)
+        .map(|line| {
+            line.chunks(4)
+                .filter_map(|group| std::str::from_utf8(group).ok())
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Hash as GridPlus Lattice shows it: 0x-prefixed, in words of eight hex
+/// characters
+/// e.g., "0xad06b099..." -> "0xad06b099 ..."
+pub fn hash_to_chunked_words(hash: &str) -> String {
+    let hex = hash.strip_prefix("0x").unwrap_or(hash).to_lowercase();
+    let words = hex
+        .as_bytes()
+        .chunks(8)
+        .filter_map(|word| std::str::from_utf8(word).ok())
+        .collect::<Vec<_>>()
+        .join(" ");
+    format!("0x{}", words)
+}
+
+/// Hardware wallet whose on-screen format the hash row follows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HashDevice {
+    #[default]
+    Ledger,
+    Trezor,
+    GridPlus,
+}
+
+impl HashDevice {
+    pub const ALL: [HashDevice; 3] = [HashDevice::Ledger, HashDevice::Trezor, HashDevice::GridPlus];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            HashDevice::Ledger => "Ledger Binary",
+            HashDevice::Trezor => "Trezor",
+            HashDevice::GridPlus => "GridPlus",
+        }
+    }
+
+    pub fn format(&self, hash: &str) -> String {
+        match self {
+            HashDevice::Ledger => hash_to_binary_literal(hash),
+            HashDevice::Trezor => hash_to_trezor_blind_sign(hash),
+            HashDevice::GridPlus => hash_to_chunked_words(hash),
+        }
+    }
+}
+
+/// Grid row showing `hash` the way the picked device displays it. The pick
+/// is shared by every Hash Results grid.
+pub fn render_device_hash_row(ui: &mut egui::Ui, hash: &str, copy_allowed: bool) {
+    let device_id = egui::Id::new("hash_device");
+    let mut device = ui.memory(|m| m.data.get_temp::<HashDevice>(device_id).unwrap_or_default());
+
+    egui::ComboBox::from_id_salt(ui.id().with("hash_device_picker"))
+        .selected_text(egui::RichText::new(format!("{}:", device.label())).strong())
+        .show_ui(ui, |ui| {
+            for option in HashDevice::ALL {
+                ui.selectable_value(&mut device, option, option.label());
+            }
+        })
+        .response
+        .on_hover_text("Show the hash as this hardware wallet displays it");
+    ui.memory_mut(|m| m.data.insert_temp(device_id, device));
+
+    let formatted = device.format(hash);
+    ui.label(egui::RichText::new(&formatted).monospace().size(12.0));
+    hash_copy_button(ui, &formatted, copy_allowed);
+    ui.end_row();
+}
+
 // =============================================================================
 // UINT DECIMAL POPUP
 // =============================================================================
@@ -456,6 +766,29 @@ pub fn format_uint_with_decimals(value: &str, decimals: u8) -> String {
     }
 }
 
+/// Unix seconds as `YYYY-MM-DD HH:MM:SS UTC`
+pub fn format_utc(timestamp: u64) -> String {
+    // Civil date from days since the epoch (Howard Hinnant's algorithm)
+    let (days, secs) = (timestamp / 86_400, timestamp % 86_400);
+    let z = days + 719_468;
+    let doe = z % 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = z / 146_097 * 400 + yoe + u64::from(month <= 2);
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC",
+        year,
+        month,
+        day,
+        secs / 3_600,
+        secs % 3_600 / 60,
+        secs % 60
+    )
+}
+
 /// Add thousand separators to a numeric string
 fn add_thousand_separators(s: &str) -> String {
     let chars: Vec<char> = s.chars().collect();
@@ -545,3 +878,32 @@ pub fn render_uint_with_popup(ui: &mut egui::Ui, value: &str, id_salt: &str) {
     // Store state
     ui.memory_mut(|m| m.data.insert_temp(popup_id, state));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// keccak256 of empty input, in mixed case to check normalization
+    const EMPTY_KECCAK: &str = "0xC5D2460186F7233C927E7DB2DCC703C0E500B653CA82273B7BFAD8045D85A470";
+
+    #[test]
+    fn test_hash_to_trezor_blind_sign() {
+        let expected = "c5d2 4601 86f7 233c\n\
+                        927e 7db2 dcc7 03c0\n\
+                        e500 b653 ca82 273b\n\
+                        7bfa d804 5d85 a470";
+        assert_eq!(hash_to_trezor_blind_sign(EMPTY_KECCAK), expected);
+        assert_eq!(
+            hash_to_trezor_blind_sign(EMPTY_KECCAK.trim_start_matches("0x")),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_hash_to_chunked_words() {
+        assert_eq!(
+            hash_to_chunked_words(EMPTY_KECCAK),
+            "0xc5d24601 86f7233c 927e7db2 dcc703c0 e500b653 ca82273b 7bfad804 5d85a470"
+        );
+    }
+}
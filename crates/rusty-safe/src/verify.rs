@@ -0,0 +1,387 @@
+//! Headless verification
+//!
+//! The Verify Safe API tab's checks without the GUI: fetch the transactions
+//! for a nonce, recompute their hashes and warnings, and decode the calldata
+//! independently. The tab runs the same functions, so `rusty-safe verify`
+//! reports exactly what the GUI shows and the JSON can be diffed in CI.
+
+use eyre::Result;
+use safe_hash::SafeWarnings;
+use serde::Serialize;
+
+use crate::api::SafeTransaction;
+use crate::decode::{
+    self, ComparisonResult, DecodedTransaction, LocalDecode, OverallStatus, SignatureLookup,
+    TransactionKind,
+};
+use crate::export::{WarningsExport, EXPORT_SCHEMA_VERSION};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::hasher::fetch_transactions;
+use crate::hasher::{
+    chain_id_of, compute_hashes_from_api_tx, get_warnings_from_api_tx, hashing_inputs_from_api_tx,
+    HashingInputs,
+};
+use crate::state::ComputedHashes;
+
+/// Hashes and warnings for one fetched transaction
+#[derive(Debug, Clone, Default)]
+pub struct TxChecks {
+    pub hashes: Option<ComputedHashes>,
+    /// Normalized fields and domain `hashes` were computed from
+    pub inputs: Option<HashingInputs>,
+    pub hash_error: Option<String>,
+    pub warnings: SafeWarnings,
+    /// Set when warnings couldn't be computed due to parse errors
+    pub warnings_error: Option<String>,
+}
+
+/// Recompute hashes and warnings for a service transaction. In trustless
+/// mode the service's decode is ignored and a reported hash mismatch is
+/// left to the service-claims comparison.
+pub fn check_transaction(
+    chain_name: &str,
+    safe_address: &str,
+    safe_version: &str,
+    tx: &SafeTransaction,
+    trustless: bool,
+) -> TxChecks {
+    let mut checks = TxChecks::default();
+    match compute_hashes_from_api_tx(chain_name, safe_address, safe_version, tx) {
+        Ok((hashes, mismatch)) => {
+            if let Some(m) = mismatch.filter(|_| !trustless) {
+                checks.warnings.argument_mismatches.push(m);
+            }
+            checks.hashes = Some(ComputedHashes {
+                trustless,
+                ..hashes
+            });
+            checks.inputs =
+                hashing_inputs_from_api_tx(chain_name, safe_address, safe_version, tx).ok();
+        }
+        Err(e) => checks.hash_error = Some(format!("Hash computation failed: {:#}", e)),
+    }
+
+    let raw_tx = SafeTransaction {
+        data_decoded: tx.data_decoded.clone().filter(|_| !trustless),
+        ..tx.clone()
+    };
+    match get_warnings_from_api_tx(&raw_tx, chain_id_of(chain_name).ok()) {
+        Ok(warnings) => checks.warnings.union(warnings),
+        Err(e) => checks.warnings_error = Some(format!("{:#}", e)),
+    }
+    checks
+}
+
+/// Decode a single call with the first signature for `selector` that fits
+pub async fn decode_single(
+    lookup: &SignatureLookup,
+    selector: &str,
+    data: &str,
+) -> Result<LocalDecode, String> {
+    let signatures = lookup
+        .lookup(selector)
+        .await
+        .map_err(|e| format!("{:#}", e))?;
+
+    if signatures.is_empty() {
+        return Err("No signatures found for selector".into());
+    }
+
+    // Signatures are sorted with verified first, so we prefer verified decodes
+    decode::decode_with_candidates(data, &signatures).ok_or_else(|| {
+        format!(
+            "None of {} signatures decoded successfully",
+            signatures.len()
+        )
+    })
+}
+
+/// Overall status of a decode whose verification has finished
+pub fn overall_status(decode: &DecodedTransaction) -> OverallStatus {
+    match &decode.kind {
+        TransactionKind::Single(single) => match &single.comparison {
+            // The verified ABI outranks an API/4byte agreement
+            _ if single.explorer.as_ref().is_some_and(|e| e.is_mismatch()) => {
+                OverallStatus::HasMismatches
+            }
+            ComparisonResult::Match if single.is_ambiguous() => OverallStatus::PartiallyVerified,
+            ComparisonResult::Match => OverallStatus::AllMatch,
+            ComparisonResult::MethodMismatch { .. } | ComparisonResult::ParamMismatch(_) => {
+                OverallStatus::HasMismatches
+            }
+            _ => OverallStatus::PartiallyVerified,
+        },
+        TransactionKind::MultiSend(multi) => {
+            if multi.summary.mismatched > 0 {
+                OverallStatus::HasMismatches
+            } else if multi.summary.verified == multi.summary.total {
+                OverallStatus::AllMatch
+            } else if multi.summary.verified > 0 {
+                OverallStatus::PartiallyVerified
+            } else {
+                OverallStatus::Pending
+            }
+        }
+        // Inner calls are never decoded by the service, so at best local-only
+        TransactionKind::UserOp(bundle) => {
+            if bundle.summary.mismatched > 0 {
+                OverallStatus::HasMismatches
+            } else if bundle.summary.verified > 0 {
+                OverallStatus::PartiallyVerified
+            } else {
+                OverallStatus::Pending
+            }
+        }
+        TransactionKind::Empty | TransactionKind::Unknown => decode.status.clone(),
+    }
+}
+
+/// Run the whole independent decode in one go: finish parsing, look up
+/// signatures and compare against the service's decode
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn verify_decode(
+    decode: &mut DecodedTransaction,
+    lookup: &SignatureLookup,
+    safe_address: &str,
+) {
+    while matches!(&decode.kind, TransactionKind::MultiSend(m) if m.is_parsing()) {
+        decode::continue_parse(decode);
+    }
+
+    match &mut decode.kind {
+        TransactionKind::Single(single) if !decode.selector.is_empty() => {
+            match decode_single(lookup, &decode.selector, &decode.raw_data).await {
+                Ok(local) => {
+                    single.comparison = decode::compare_decodes(single.api.as_ref(), Some(&local));
+                    single.local = Some(local);
+                }
+                Err(e) => single.comparison = ComparisonResult::Failed(e),
+            }
+        }
+        TransactionKind::MultiSend(multi) => {
+            decode::verify_multisend_batch(multi, lookup, safe_address).await
+        }
+        TransactionKind::UserOp(bundle) => decode::verify_user_ops(bundle, lookup).await,
+        _ => return,
+    }
+    decode.status = overall_status(decode);
+}
+
+/// Verification result for one transaction
+#[derive(Debug, Serialize)]
+pub struct TransactionReport {
+    /// safeTxHash as reported by the service
+    pub safe_tx_hash: String,
+    /// Service response for the transaction, as received
+    pub transaction: Option<serde_json::Value>,
+    pub hashes: Option<ComputedHashes>,
+    pub hash_error: Option<String>,
+    pub warnings: WarningsExport,
+    pub warnings_error: Option<String>,
+    pub decode: DecodedTransaction,
+}
+
+/// Verification result for every transaction proposed at a nonce. Carries
+/// its inputs and creation time so a saved report stands on its own.
+#[derive(Debug, Serialize)]
+pub struct VerificationReport {
+    pub schema_version: u32,
+    /// Unix time in seconds
+    pub generated_at: u64,
+    pub chain: String,
+    pub safe_address: String,
+    pub safe_version: String,
+    pub nonce: u64,
+    pub transactions: Vec<TransactionReport>,
+}
+
+impl VerificationReport {
+    pub fn new(
+        chain_name: &str,
+        safe_address: &str,
+        safe_version: &str,
+        nonce: u64,
+        transactions: Vec<TransactionReport>,
+    ) -> Self {
+        let generated_at = web_time::SystemTime::now()
+            .duration_since(web_time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        Self {
+            schema_version: EXPORT_SCHEMA_VERSION,
+            generated_at,
+            chain: chain_name.to_string(),
+            safe_address: safe_address.to_string(),
+            safe_version: safe_version.to_string(),
+            nonce,
+            transactions,
+        }
+    }
+}
+
+/// Fetch and verify every transaction proposed for `nonce`
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn verify_nonce(
+    chain_name: &str,
+    safe_address: &str,
+    safe_version: &str,
+    nonce: u64,
+) -> Result<VerificationReport> {
+    let fetched = fetch_transactions(chain_name, safe_address, nonce).await?;
+    let lookup = SignatureLookup::new();
+
+    let mut transactions = Vec::with_capacity(fetched.transactions.len());
+    for tx in &fetched.transactions {
+        let checks = check_transaction(chain_name, safe_address, safe_version, tx, false);
+        let mut decode = decode::parse_initial(&tx.data, tx.data_decoded.as_ref());
+        verify_decode(&mut decode, &lookup, safe_address).await;
+        transactions.push(TransactionReport {
+            safe_tx_hash: tx.safe_tx_hash.clone(),
+            transaction: fetched.raw.get(&tx.safe_tx_hash.to_lowercase()).cloned(),
+            hashes: checks.hashes,
+            hash_error: checks.hash_error,
+            warnings: (&checks.warnings).into(),
+            warnings_error: checks.warnings_error,
+            decode,
+        });
+    }
+
+    Ok(VerificationReport::new(
+        chain_name,
+        safe_address,
+        safe_version,
+        nonce,
+        transactions,
+    ))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+const USAGE: &str = "Usage: rusty-safe verify --chain <name> --safe <address> --nonce <n> \
+                     [--version <safe version>]";
+
+/// Arguments of `rusty-safe verify`
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, PartialEq, Eq)]
+struct CliArgs {
+    chain: String,
+    safe: String,
+    version: String,
+    nonce: u64,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn parse_args(args: &[String]) -> Result<CliArgs, String> {
+    let mut chain = None;
+    let mut safe = None;
+    let mut version = "1.4.1".to_string();
+    let mut nonce = None;
+
+    let mut args = args.iter();
+    while let Some(flag) = args.next() {
+        let value = args
+            .next()
+            .ok_or_else(|| format!("Missing value for {}", flag))?;
+        match flag.as_str() {
+            "--chain" => chain = Some(value.clone()),
+            "--safe" => safe = Some(value.clone()),
+            "--version" => version = value.clone(),
+            "--nonce" => {
+                nonce = Some(
+                    value
+                        .parse()
+                        .map_err(|_| format!("Invalid nonce '{}'", value))?,
+                )
+            }
+            _ => return Err(format!("Unknown argument '{}'", flag)),
+        }
+    }
+
+    Ok(CliArgs {
+        chain: chain.ok_or("Missing --chain")?,
+        safe: safe.ok_or("Missing --safe")?,
+        version,
+        nonce: nonce.ok_or("Missing --nonce")?,
+    })
+}
+
+/// `rusty-safe verify ...`: print the report as JSON. Returns the process
+/// exit code.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn run_cli(args: &[String]) -> i32 {
+    let args = match parse_args(args) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            return 2;
+        }
+    };
+
+    let runtime = match tokio::runtime::Runtime::new() {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            eprintln!("Failed to start runtime: {}", e);
+            return 1;
+        }
+    };
+    let report = runtime.block_on(verify_nonce(
+        &args.chain,
+        &args.safe,
+        &args.version,
+        args.nonce,
+    ));
+    match report.map(|r| serde_json::to_string_pretty(&r)) {
+        Ok(Ok(json)) => {
+            println!("{}", json);
+            0
+        }
+        Ok(Err(e)) => {
+            eprintln!("Failed to serialize report: {}", e);
+            1
+        }
+        Err(e) => {
+            eprintln!("Verification failed: {:#}", e);
+            1
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(str::to_string).collect()
+    }
+
+    #[test]
+    #[cfg(not(target_arch = "wasm32"))]
+    fn test_parse_args() {
+        let parsed = parse_args(&args("--chain ethereum --safe 0xabc --nonce 7")).unwrap();
+        assert_eq!(
+            parsed,
+            CliArgs {
+                chain: "ethereum".to_string(),
+                safe: "0xabc".to_string(),
+                version: "1.4.1".to_string(),
+                nonce: 7,
+            }
+        );
+        assert!(parse_args(&args("--chain ethereum --safe 0xabc")).is_err());
+        assert!(parse_args(&args("--chain ethereum --safe 0xabc --nonce x")).is_err());
+        assert!(parse_args(&args("--chain")).is_err());
+        assert!(parse_args(&args("--bogus 1")).is_err());
+    }
+
+    #[test]
+    fn test_overall_status_single() {
+        let mut decode = decode::parse_initial("0xa9059cbb", None);
+        let TransactionKind::Single(single) = &mut decode.kind else {
+            panic!("expected a single call");
+        };
+        single.comparison = ComparisonResult::Failed("no signature".to_string());
+        assert!(matches!(
+            overall_status(&decode),
+            OverallStatus::PartiallyVerified
+        ));
+    }
+}
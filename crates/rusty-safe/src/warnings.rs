@@ -0,0 +1,99 @@
+//! Explanations of the transaction warnings
+//!
+//! The warning banners are terse. Each can expand into what the warning
+//! means, why it's risky and what a legitimate use looks like, for signers
+//! less familiar with Safe internals.
+
+use crate::state::WarningKind;
+
+/// Explanatory text for one kind of warning
+pub struct Explanation {
+    pub meaning: &'static str,
+    pub risk: &'static str,
+    pub legitimate: &'static str,
+}
+
+impl Explanation {
+    /// Plain text for pasting into review notes
+    pub fn text(&self, kind: WarningKind) -> String {
+        format!(
+            "Warning: {}\nWhat it means: {}\nWhy it's risky: {}\nLegitimate use: {}",
+            kind.label(),
+            self.meaning,
+            self.risk,
+            self.legitimate
+        )
+    }
+}
+
+pub fn explain(kind: WarningKind) -> Explanation {
+    match kind {
+        WarningKind::Delegatecall => Explanation {
+            meaning: "The Safe runs the target's code in its own context (operation 1), \
+                      instead of calling it as a separate contract.",
+            risk: "The target's code can rewrite the Safe's storage: change owners, the \
+                   threshold, modules or the guard, or move every asset. A malicious or \
+                   buggy target takes full control of the Safe.",
+            legitimate: "Batches through the official MultiSend or MultiSendCallOnly \
+                         libraries, and SignMessageLib for off-chain message signing. \
+                         Check the target is one of those canonical deployments.",
+        },
+        WarningKind::NonZeroGasToken => Explanation {
+            meaning: "The executor is refunded for gas in an ERC-20 token instead of the \
+                      native currency.",
+            risk: "Combined with a high gas price, the refund can drain the Safe's token \
+                   balance to whoever executes the transaction.",
+            legitimate: "Relayer setups where the Safe pays execution fees in a stablecoin. \
+                         Check the token, the gas price and the refund estimate.",
+        },
+        WarningKind::NonZeroRefundReceiver => Explanation {
+            meaning: "The gas refund goes to a fixed address rather than to whoever \
+                      executes the transaction.",
+            risk: "With a non-zero gas price, every execution pays that address out of the \
+                   Safe, possibly far more than the gas actually used.",
+            legitimate: "A relayer service that executes on the Safe's behalf and is paid \
+                         for it. The receiver should be the relayer you expect.",
+        },
+        WarningKind::DangerousMethods => Explanation {
+            meaning: "The transaction changes the Safe's owners or threshold.",
+            risk: "Adding an owner or lowering the threshold can hand control of the Safe \
+                   to someone else; removing owners can lock the remaining ones out.",
+            legitimate: "Planned signer rotations and threshold changes. Confirm the new \
+                         owner addresses and threshold out of band.",
+        },
+        WarningKind::ArgumentMismatch => Explanation {
+            meaning: "A field reported by the Safe Transaction Service differs from the \
+                      value recomputed locally.",
+            risk: "The service or something between you and it may be showing a different \
+                   transaction from the one you would sign.",
+            legitimate: "None. Don't sign until the difference is explained.",
+        },
+        WarningKind::UnlimitedApproval => Explanation {
+            meaning: "A spender may move an unlimited amount of a token, through an \
+                      `approve` call or a signed permit.",
+            risk: "The spender can take the Safe's entire current and future balance of \
+                   the token, now or at any later time, including after a compromise of \
+                   the spender contract.",
+            legitimate: "Well-known protocols such as DEX routers or Permit2, for Safes that \
+                         trade often. Prefer an exact amount when the use is one-off.",
+        },
+        WarningKind::ConfigChange => Explanation {
+            meaning: "The transaction replaces the Safe's singleton, sets its guard or \
+                      fallback handler, or enables or disables a module.",
+            risk: "A new singleton or fallback handler changes the Safe's code; a guard \
+                   can block every later transaction; a module can move assets without \
+                   any owner signature.",
+            legitimate: "Upgrades to an official Safe release and vetted modules or guards \
+                         the owners agreed on. Check every address against its official \
+                         deployment.",
+        },
+        WarningKind::SelfCall => Explanation {
+            meaning: "The Safe calls one of its own methods, directly or from a batch.",
+            risk: "Only the Safe can call its management methods, so a self-call is how \
+                   owners, the threshold, modules, the guard and the fallback handler \
+                   change.",
+            legitimate: "Planned configuration changes. Check which method is called and \
+                         with what arguments.",
+        },
+    }
+}